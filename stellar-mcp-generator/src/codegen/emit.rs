@@ -0,0 +1,293 @@
+//! Safe emission of literals, comments and identifiers in generated code
+//!
+//! Names and doc strings come from the contract spec, which anyone can write.
+//! Everything taken from a spec goes through these functions before it is
+//! placed in TypeScript, Python, Rust or Go source, so a doc containing
+//! quotes, `*/`, `"""` or line separators stays inside its literal or
+//! comment, and a parameter named `default` or `from` still produces a valid
+//! identifier.
+//!
+//! Spec strings are only ever emitted into single-quoted TypeScript strings,
+//! double-quoted Python strings, (non-raw) Rust strings and interpreted Go
+//! strings, never into template literals, f-strings or format strings, so
+//! backticks, `${` and braces need no escaping.
+
+/// Words that can't name a TypeScript binding (variable, parameter or
+/// function) in strict-mode module code
+const TS_RESERVED: &[&str] = &[
+    "arguments", "await", "break", "case", "catch", "class", "const", "continue", "debugger",
+    "default", "delete", "do", "else", "enum", "eval", "export", "extends", "false", "finally",
+    "for", "function", "if", "implements", "import", "in", "instanceof", "interface", "let",
+    "new", "null", "package", "private", "protected", "public", "return", "static", "super",
+    "switch", "this", "throw", "true", "try", "typeof", "var", "void", "while", "with", "yield",
+];
+
+/// Python keywords, which can't be used as names
+const PY_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+    "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+    "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+    "try", "while", "with", "yield",
+];
+
+/// Single-quoted TypeScript string literal
+pub fn ts_string(value: &str) -> String {
+    format!("'{}'", ts_string_body(value))
+}
+
+/// Double-quoted TypeScript string literal (JSON string syntax), as the
+/// official contract bindings write them
+pub fn ts_double_quoted(value: &str) -> String {
+    escape_line_separators(serde_json::Value::from(value).to_string())
+}
+
+/// Pretty-printed JSON value as a TypeScript expression
+pub fn ts_json(value: &serde_json::Value) -> String {
+    escape_line_separators(format!("{:#}", value))
+}
+
+/// JSON allows U+2028 and U+2029 in strings, JavaScript before ES2019 doesn't
+fn escape_line_separators(json: String) -> String {
+    json.replace('\u{2028}', "\\u2028").replace('\u{2029}', "\\u2029")
+}
+
+/// Escaped content of a single-quoted TypeScript string literal, for
+/// templates that supply the quotes themselves (`'{{{description}}}'`)
+pub fn ts_string_body(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            // Line terminators in JavaScript, even inside string literals before ES2019
+            '\u{2028}' => out.push_str("\\u2028"),
+            '\u{2029}' => out.push_str("\\u2029"),
+            c if c.is_control() => out.push_str(&format!("\\x{:02x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Text for the body of a `/** ... */` comment: `*/` is broken up and every
+/// line after the first starts with `line_prefix` (e.g. `" * "`)
+pub fn ts_comment_text(text: &str, line_prefix: &str) -> String {
+    comment_lines(text)
+        .iter()
+        .map(|line| line.replace("*/", "*\\/"))
+        .collect::<Vec<_>>()
+        .join(&format!("\n{}", line_prefix))
+}
+
+/// JSDoc comment for a spec doc string, indented by `indent` (empty if there
+/// is no doc)
+pub fn ts_doc_comment(doc: Option<&str>, indent: &str) -> String {
+    let Some(doc) = doc.map(str::trim).filter(|d| !d.is_empty()) else {
+        return String::new();
+    };
+    let mut content = format!("{}/**\n", indent);
+    for line in comment_lines(doc) {
+        let line = line.trim_end().replace("*/", "*\\/");
+        if line.is_empty() {
+            content.push_str(&format!("{} *\n", indent));
+        } else {
+            content.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    content.push_str(&format!("{} */\n", indent));
+    content
+}
+
+/// TypeScript identifier for a spec name: characters that can't appear in an
+/// identifier become `_`, a leading digit gets a `_` prefix and reserved
+/// words get a `_` suffix (`default` → `default_`)
+pub fn ts_identifier(name: &str) -> String {
+    let ident = identifier(name, |c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if TS_RESERVED.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+/// Property access that also works for non-identifier names (tuple structs
+/// have fields named `0`, `1`, ...). Reserved words are valid property names.
+pub fn ts_property(expr: &str, name: &str) -> String {
+    if is_ts_identifier_name(name) {
+        format!("{}.{}", expr, name)
+    } else {
+        format!("{}[{}]", expr, ts_string(name))
+    }
+}
+
+/// Object literal key for a name, quoted unless it is an identifier name
+pub fn ts_object_key(name: &str) -> String {
+    if is_ts_identifier_name(name) {
+        name.to_string()
+    } else {
+        ts_string(name)
+    }
+}
+
+fn is_ts_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Double-quoted Python string literal
+pub fn py_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => push_py_char(&mut out, c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Escaped content of a `"""` docstring. Line breaks are kept; quotes are
+/// escaped so the text can't close the docstring, whatever it ends with.
+pub fn py_docstring_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, line) in comment_lines(text).iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        for c in line.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '"' => out.push_str("\\\""),
+                '\t' => out.push('\t'),
+                c => push_py_char(&mut out, c),
+            }
+        }
+    }
+    out
+}
+
+/// Python docstring for a spec doc string, indented by `indent` (empty if
+/// there is no doc)
+pub fn py_docstring(doc: Option<&str>, indent: &str) -> String {
+    let Some(doc) = doc.map(str::trim).filter(|d| !d.is_empty()) else {
+        return String::new();
+    };
+    let doc = py_docstring_text(doc);
+    if !doc.contains('\n') {
+        return format!("{}\"\"\"{}\"\"\"\n", indent, doc);
+    }
+    let mut content = format!("{}\"\"\"\n", indent);
+    for line in doc.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            content.push('\n');
+        } else {
+            content.push_str(&format!("{}{}\n", indent, line));
+        }
+    }
+    content.push_str(&format!("{}\"\"\"\n", indent));
+    content
+}
+
+/// `#` comment lines for a spec doc string, indented by `indent` (empty if
+/// there is no doc), for definitions that can't carry a docstring
+pub fn py_comment(doc: Option<&str>, indent: &str) -> String {
+    let Some(doc) = doc.map(str::trim).filter(|d| !d.is_empty()) else {
+        return String::new();
+    };
+    comment_lines(doc)
+        .iter()
+        .map(|line| match line.trim_end() {
+            "" => format!("{}#\n", indent),
+            line => format!("{}# {}\n", indent, line),
+        })
+        .collect()
+}
+
+/// Python identifier for a spec name: characters that can't appear in an
+/// identifier become `_`, a leading digit gets a `_` prefix and keywords get
+/// a `_` suffix (`from` → `from_`)
+pub fn py_identifier(name: &str) -> String {
+    let ident = identifier(name, |c| c.is_ascii_alphanumeric() || c == '_');
+    if PY_KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+/// Rust string literal. `Debug` escapes quotes, backslashes and control
+/// characters, and its `\u{..}` escapes are valid Rust.
+pub fn rs_string(value: &str) -> String {
+    format!("{:?}", value)
+}
+
+/// Interpreted Go string literal. Go source can't contain raw line breaks
+/// in these literals, NUL bytes or a byte order mark past the start of the
+/// file, so control characters and U+FEFF are written as `\u` escapes.
+pub fn go_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() || c == '\u{feff}' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Replace disallowed characters with `_` and make sure the result doesn't
+/// start with a digit (or is empty)
+fn identifier(name: &str, allowed: impl Fn(char) -> bool) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if allowed(c) { c } else { '_' })
+        .collect();
+    if !ident.starts_with(|c: char| !c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// Lines of a comment or docstring. Every line terminator either language
+/// recognises (`\r\n`, `\r`, `\n`, U+2028, U+2029) splits lines, and other
+/// control characters are replaced with spaces.
+fn comment_lines(text: &str) -> Vec<String> {
+    text.replace("\r\n", "\n")
+        .split(['\n', '\r', '\u{2028}', '\u{2029}'])
+        .map(|line| {
+            line.chars()
+                .map(|c| if c.is_control() && c != '\t' { ' ' } else { c })
+                .collect()
+        })
+        .collect()
+}
+
+/// Push a character of a Python string, escaping control characters
+fn push_py_char(out: &mut String, c: char) {
+    match c {
+        '\u{2028}' => out.push_str("\\u2028"),
+        '\u{2029}' => out.push_str("\\u2029"),
+        c if c.is_control() => out.push_str(&format!("\\x{:02x}", c as u32)),
+        c => out.push(c),
+    }
+}
@@ -0,0 +1,7 @@
+//! Helpers shared by everything that emits source code
+//!
+//! Kept apart from `generator` so that `spec` (whose `TypeRef` renders its
+//! own type expressions) and the generators can both use them without `spec`
+//! depending on `generator`.
+
+pub mod emit;
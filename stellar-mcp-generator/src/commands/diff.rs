@@ -0,0 +1,131 @@
+//! Diff command - compares two contract specs and reports breaking changes
+
+use crate::error::{GeneratorError, Result};
+use crate::spec::diff::{Severity, SpecDiff};
+use crate::spec::snapshot::{contract_snapshot_path, SpecSnapshot, SNAPSHOT_DIR, SNAPSHOT_PATH};
+use crate::spec::{ContractSpec, SpecFetcher, SpecParser};
+use crate::NetworkConfig;
+use clap::Parser;
+use std::path::Path;
+
+/// Arguments for the diff command
+#[derive(Parser, Debug)]
+pub struct DiffArgs {
+    /// Old spec: WASM file, contract ID, spec snapshot (.json) or generated project directory
+    pub old: String,
+
+    /// New spec: WASM file, contract ID, spec snapshot (.json) or generated project directory
+    pub new: String,
+
+    /// Network used to fetch specs of contract IDs (testnet, mainnet, futurenet, local)
+    #[arg(long, short = 'n', default_value = "testnet")]
+    pub network: String,
+
+    /// Custom RPC URL (overrides network default)
+    #[arg(long)]
+    pub rpc_url: Option<String>,
+
+    /// Print the report as JSON (for CI)
+    #[arg(long)]
+    pub json: bool,
+
+    /// Exit with an error when breaking changes are found
+    #[arg(long)]
+    pub fail_on_breaking: bool,
+
+    /// Enable verbose output for debugging
+    #[arg(long, short = 'v')]
+    pub verbose: bool,
+}
+
+/// Execute the diff command
+pub async fn execute(args: DiffArgs) -> Result<()> {
+    let old = load_spec(&args.old, &args).await?;
+    let new = load_spec(&args.new, &args).await?;
+    let diff = SpecDiff::compare(&old, &new);
+
+    if args.json {
+        let report = serde_json::json!({
+            "old": args.old,
+            "new": args.new,
+            "breaking": diff.is_breaking(),
+            "changes": diff.changes,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("Comparing {} -> {}", args.old, args.new);
+        println!();
+        if diff.changes.is_empty() {
+            println!("No changes.");
+        }
+        for change in &diff.changes {
+            let label = match change.severity {
+                Severity::Breaking => "BREAKING    ",
+                Severity::NonBreaking => "non-breaking",
+            };
+            println!("  {}  {}", label, change.message);
+        }
+        println!();
+        println!(
+            "{} breaking, {} non-breaking change(s)",
+            diff.count(Severity::Breaking),
+            diff.count(Severity::NonBreaking)
+        );
+    }
+
+    if args.fail_on_breaking && diff.is_breaking() {
+        return Err(GeneratorError::BreakingChanges {
+            count: diff.count(Severity::Breaking),
+        });
+    }
+
+    Ok(())
+}
+
+/// Load a spec from a WASM file, spec snapshot, generated project or contract ID
+async fn load_spec(
+    source: &str,
+    args: &DiffArgs,
+) -> Result<ContractSpec> {
+    let path = Path::new(source);
+
+    if path.is_dir() {
+        let snapshot = path.join(SNAPSHOT_PATH);
+        if !snapshot.exists() && path.join(SNAPSHOT_DIR).is_dir() {
+            return Err(GeneratorError::InvalidInput(format!(
+                "'{}' serves several contracts. Compare one of them with {}/{}",
+                source,
+                source.trim_end_matches('/'),
+                contract_snapshot_path("<name>")
+            )));
+        }
+        if !snapshot.exists() {
+            return Err(GeneratorError::InvalidInput(format!(
+                "'{}' has no spec snapshot ({})",
+                source, SNAPSHOT_PATH
+            )));
+        }
+        return Ok(SpecSnapshot::load(&snapshot)?.spec);
+    }
+
+    if path.is_file() {
+        if path.extension().is_some_and(|ext| ext == "json") {
+            return Ok(SpecSnapshot::load(path)?.spec);
+        }
+        return SpecParser::parse_wasm_file(path);
+    }
+
+    if source.starts_with('C') && source.len() == 56 {
+        let rpc_url = match &args.rpc_url {
+            Some(url) => url.clone(),
+            None => NetworkConfig::from_name(&args.network)?.rpc_url,
+        };
+        let fetcher = SpecFetcher::with_verbose(&rpc_url, args.verbose)?;
+        return fetcher.fetch_spec(source).await;
+    }
+
+    Err(GeneratorError::InvalidInput(format!(
+        "'{}' is not a WASM file, spec snapshot, project directory or contract ID",
+        source
+    )))
+}
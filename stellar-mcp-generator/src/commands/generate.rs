@@ -1,10 +1,16 @@
 //! Generate command - creates MCP server from contract spec
 
-use crate::generator::McpGenerator;
-use crate::spec::SpecFetcher;
+use crate::config::{self, ProjectConfig, CONFIG_FILE_NAME};
+use crate::error::{GeneratorError, Result};
+use crate::generator::contracts;
+use crate::generator::merge::unified_diff;
+use crate::generator::update::{self, FileAction, PlannedFile, ProjectPlan, UpdateReport};
+use crate::generator::{BackendRegistry, ProjectOptions, ServedContract};
+use crate::spec::snapshot;
+use crate::spec::{classifier, ContractSpec, FunctionKind, SpecFetcher, SpecParser};
 use crate::NetworkConfig;
 use clap::Parser;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Arguments for the generate command
 #[derive(Parser, Debug)]
@@ -13,6 +19,11 @@ pub struct GenerateArgs {
     #[arg(long, short = 'c', value_name = "CONTRACT_ID")]
     pub contract_id: Option<String>,
 
+    /// Local contract WASM file to read the spec from (no network access needed).
+    /// The contract ID can then be omitted and set later via CONTRACT_ID in .env
+    #[arg(long, value_name = "PATH")]
+    pub wasm: Option<PathBuf>,
+
     /// Network to use (testnet, mainnet, futurenet, local)
     #[arg(long, short = 'n')]
     pub network: Option<String>,
@@ -29,7 +40,18 @@ pub struct GenerateArgs {
     #[arg(long, short = 'o')]
     pub output: Option<PathBuf>,
 
-    /// Contract name (used for tool naming, defaults to contract ID prefix)
+    /// Contract to serve, as <NAME>=<CONTRACT_ID|path.wasm>. Repeat to serve several
+    /// contracts from one server; their tools are then namespaced as <NAME>.<tool>
+    #[arg(
+        long = "contract",
+        value_name = "NAME=ID|WASM",
+        value_parser = parse_contract_arg,
+        conflicts_with_all = ["contract_id", "wasm"]
+    )]
+    pub contracts: Vec<ContractArg>,
+
+    /// Contract name (used for tool naming, defaults to contract ID prefix).
+    /// With several contracts, names the generated package instead
     #[arg(long)]
     pub name: Option<String>,
 
@@ -37,28 +59,117 @@ pub struct GenerateArgs {
     #[arg(long)]
     pub server_name: Option<String>,
 
-    /// Language to generate (typescript or python)
+    /// Language to generate (typescript, python, rust or go)
     #[arg(long, short = 'l')]
     pub lang: Option<String>,
 
+    /// Directory of Handlebars templates overriding the built-in ones, laid out
+    /// like the generator's templates/ directory (e.g. index.ts.hbs, python/server.py.hbs)
+    #[arg(long, value_name = "DIR")]
+    pub templates: Option<PathBuf>,
+
     /// Overwrite existing output directory
     #[arg(long)]
     pub force: bool,
 
+    /// Regenerate an existing project in place: overwrite only files the generator
+    /// still owns and three-way merge files you have edited
+    #[arg(long, conflicts_with = "force")]
+    pub update: bool,
+
+    /// List the files that would be created, modified or deleted in the output
+    /// directory, without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Like --dry-run, and also print a unified diff of every change against
+    /// the current contents of the output directory
+    #[arg(long)]
+    pub diff: bool,
+
     /// Enable verbose output for debugging
     #[arg(long, short = 'v')]
     pub verbose: bool,
 
     /// Generate a React frontend alongside the MCP server
-    #[arg(long)]
-    pub with_frontend: bool,
+    /// (--with-frontend=false overrides the config file)
+    #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true", value_name = "BOOL")]
+    pub with_frontend: Option<bool>,
+
+    /// Serve REST routes (POST /api/<tool>) and an OpenAPI document next to
+    /// /mcp (typescript and python only; --with-rest=false overrides the config file)
+    #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true", value_name = "BOOL")]
+    pub with_rest: Option<bool>,
+
+    /// Project configuration file (defaults to ./stellar-mcp.toml when present)
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Functions to treat as read-only, overriding simulation (comma-separated,
+    /// qualified as <contract>.<function> with several contracts)
+    #[arg(long, value_name = "FUNCTIONS", value_delimiter = ',')]
+    pub read_only: Vec<String>,
+
+    /// Functions to treat as state-changing, overriding simulation (comma-separated,
+    /// qualified as <contract>.<function> with several contracts)
+    #[arg(long, value_name = "FUNCTIONS", value_delimiter = ',')]
+    pub write: Vec<String>,
 }
 
-/// Execute the generate command
-pub async fn execute(args: GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
+/// A contract given with `--contract <NAME>=<CONTRACT_ID|path.wasm>` or as a
+/// `[[contracts]]` entry of stellar-mcp.toml
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractArg {
+    /// Contract name, used as the tool namespace
+    pub name: String,
+    /// Deployed contract ID
+    pub contract_id: Option<String>,
+    /// Local contract WASM file to read the spec from
+    pub wasm: Option<PathBuf>,
+}
+
+/// Parse `<NAME>=<CONTRACT_ID|path.wasm>`
+fn parse_contract_arg(value: &str) -> Result<ContractArg, String> {
+    let (name, source) = value
+        .split_once('=')
+        .filter(|(name, source)| !name.is_empty() && !source.is_empty())
+        .ok_or_else(|| format!("expected <NAME>=<CONTRACT_ID|path.wasm>, got '{}'", value))?;
+
+    let (contract_id, wasm) = if source.ends_with(".wasm") || Path::new(source).is_file() {
+        (None, Some(PathBuf::from(source)))
+    } else {
+        (Some(source.to_string()), None)
+    };
+    Ok(ContractArg {
+        name: name.to_string(),
+        contract_id,
+        wasm,
+    })
+}
+
+/// Execute the generate command with the built-in language backends
+pub async fn execute(args: GenerateArgs) -> Result<()> {
+    execute_with(args, &BackendRegistry::default()).await
+}
+
+/// Execute the generate command, looking `--lang` up in `registry`
+pub async fn execute_with(mut args: GenerateArgs, registry: &BackendRegistry) -> Result<()> {
+    // Load stellar-mcp.toml (explicit --config or auto-discovered); flags win over the file
+    let project_config = match &args.config {
+        Some(path) => Some(ProjectConfig::load(path)?),
+        None => ProjectConfig::discover()?,
+    };
+    if let Some(config) = &project_config {
+        let path = args.config.clone().unwrap_or_else(|| PathBuf::from(CONFIG_FILE_NAME));
+        println!("Using configuration from {}", path.display());
+        config.apply_to_args(&mut args);
+    }
+
     // Check if user provided ANY flags → Expert mode
     // If NO flags provided → Wizard mode
     let use_wizard = args.contract_id.is_none()
+        && args.wasm.is_none()
+        && args.contracts.is_empty()
         && args.network.is_none()
         && args.output.is_none()
         && args.lang.is_none()
@@ -70,7 +181,7 @@ pub async fn execute(args: GenerateArgs) -> Result<(), Box<dyn std::error::Error
     // Get configuration from wizard or flags
     let (contract_id, network_str, output, lang, name, server_name, rpc_url, network_passphrase, with_frontend) = if use_wizard {
         // Run wizard to get all configuration
-        let wizard_config = crate::wizard::run_wizard().await?;
+        let wizard_config = crate::wizard::run_wizard(&registry.names()).await?;
 
         (
             wizard_config.contract_id,
@@ -85,9 +196,18 @@ pub async fn execute(args: GenerateArgs) -> Result<(), Box<dyn std::error::Error
         )
     } else {
         // Expert mode: use provided flags with defaults
-        let contract_id = args.contract_id.clone().ok_or(
-            "Contract ID is required. Use --contract-id or run without flags for wizard mode."
-        )?;
+        // With --wasm the contract ID is optional (it can be supplied later via .env)
+        let contract_id = match (&args.contract_id, &args.wasm) {
+            (Some(id), _) => id.clone(),
+            (None, Some(_)) => String::new(),
+            (None, None) if !args.contracts.is_empty() => String::new(),
+            (None, None) => {
+                return Err(GeneratorError::InvalidInput(
+                    "Contract ID is required. Use --contract-id, --wasm, --contract, or run without flags for wizard mode."
+                        .to_string(),
+                ))
+            }
+        };
         let network_str = args.network.clone().unwrap_or_else(|| "testnet".to_string());
         let output = args.output.clone().unwrap_or_else(|| PathBuf::from("./mcp-server"));
         let lang = args.lang.clone().unwrap_or_else(|| "typescript".to_string());
@@ -102,207 +222,419 @@ pub async fn execute(args: GenerateArgs) -> Result<(), Box<dyn std::error::Error
             server_name,
             args.rpc_url.clone(),
             args.network_passphrase.clone(),
-            args.with_frontend,
+            args.with_frontend.unwrap_or(false),
         )
     };
 
     // Validate language
-    if lang != "typescript" && lang != "python" {
-        return Err(format!(
-            "Invalid language '{}'. Supported languages: typescript, python",
+    let backend = registry.get(&lang)?;
+    let with_rest = args.with_rest.unwrap_or(false);
+    if with_rest && !backend.supports_rest() {
+        return Err(GeneratorError::InvalidInput(format!(
+            "--with-rest is not supported for {} servers (use typescript or python)",
             lang
-        )
-        .into());
+        )));
     }
 
+    // Load the templates up front so a broken override fails before any network access
+    let templates = registry.template_renderer(args.templates.as_deref())?;
+
     println!("Stellar MCP Generator v{}", env!("CARGO_PKG_VERSION"));
     println!("========================================");
 
-    // Validate contract ID format
-    if !contract_id.starts_with('C') || contract_id.len() != 56 {
-        return Err(format!(
-            "Invalid contract ID '{}'. Contract IDs must start with 'C' and be 56 characters long.",
-            contract_id
-        )
-        .into());
+    // Contracts to serve: the --contract list (or [[contracts]]), otherwise the
+    // single contract given by --contract-id / --wasm / the wizard
+    let multi_source = !args.contracts.is_empty();
+    let sources = if multi_source {
+        args.contracts.clone()
+    } else {
+        vec![ContractArg {
+            name: name.clone().unwrap_or_default(),
+            contract_id: (!contract_id.is_empty()).then(|| contract_id.clone()),
+            wasm: args.wasm.clone(),
+        }]
+    };
+    let namespaced = sources.len() > 1;
+
+    // Validate contract ID format (may be empty when generating from a local WASM file)
+    for source in &sources {
+        if let Some(id) = &source.contract_id {
+            if !id.starts_with('C') || id.len() != 56 {
+                return Err(GeneratorError::InvalidStrkey {
+                    value: id.clone(),
+                    reason: "Contract IDs must start with 'C' and be 56 characters long.".to_string(),
+                });
+            }
+        }
+    }
+
+    // With several contracts, function overrides name their contract
+    if namespaced {
+        let names: Vec<&str> = sources.iter().map(|s| s.name.as_str()).collect();
+        config::check_contract_prefixes(args.read_only.iter().chain(&args.write), &names)
+            .map_err(GeneratorError::InvalidInput)?;
+        if let Some(config) = &project_config {
+            config.check_contract_prefixes(&names)?;
+        }
     }
 
     // Resolve network configuration
     let network = if let Some(rpc) = &rpc_url {
-        let passphrase = network_passphrase.clone().ok_or(
-            "Network passphrase is required when using custom RPC URL. Use --network-passphrase",
-        )?;
+        let passphrase = network_passphrase.clone().ok_or_else(|| {
+            GeneratorError::InvalidInput(
+                "Network passphrase is required when using custom RPC URL. Use --network-passphrase"
+                    .to_string(),
+            )
+        })?;
         NetworkConfig::custom(rpc.clone(), passphrase)
     } else {
         NetworkConfig::from_name(&network_str)?
     };
 
     println!("Network: {} ({})", network.name, network.rpc_url);
-    println!("Contract ID: {}", contract_id);
+    if multi_source {
+        for source in &sources {
+            match (&source.contract_id, &source.wasm) {
+                (Some(id), _) => println!("Contract {}: {}", source.name, id),
+                (None, Some(wasm)) => println!("Contract {}: {} (not deployed)", source.name, wasm.display()),
+                (None, None) => println!("Contract {}: (not set)", source.name),
+            }
+        }
+    } else {
+        if contract_id.is_empty() {
+            println!("Contract ID: (not set - configure CONTRACT_ID in .env after deploying)");
+        } else {
+            println!("Contract ID: {}", contract_id);
+        }
+        if let Some(wasm) = &args.wasm {
+            println!("WASM: {}", wasm.display());
+        }
+    }
     println!("Language: {}", lang);
     println!("Output: {}", output.display());
     println!();
 
-    // Check output directory
-    if output.exists() && !args.force {
-        return Err(format!(
-            "Output directory '{}' already exists. Use --force to overwrite.",
-            output.display()
-        )
-        .into());
+    // Check output directory (a dry run may look at any existing output)
+    let dry_run = args.dry_run || args.diff;
+    if args.update {
+        if !output.exists() {
+            return Err(GeneratorError::InvalidInput(format!(
+                "Output directory '{}' does not exist. Generate the project before using --update.",
+                output.display()
+            )));
+        }
+    } else if output.exists() && !args.force && !dry_run {
+        return Err(GeneratorError::OutputExists { path: output });
     }
 
     // Create output directory
-    std::fs::create_dir_all(&output)?;
+    if !dry_run {
+        std::fs::create_dir_all(&output)
+            .map_err(GeneratorError::io(format!("Failed to create '{}'", output.display())))?;
+    }
 
-    // Step 1: Fetch contract specification
-    println!("Fetching contract specification...");
-    let fetcher = SpecFetcher::with_verbose(&network.rpc_url, args.verbose)?;
-    let spec = fetcher.fetch_spec(&contract_id).await?;
+    let mut contracts = Vec::new();
+    for source in &sources {
+        if namespaced {
+            println!("Contract '{}':", source.name);
+        }
+        let contract_id = source.contract_id.clone().unwrap_or_default();
 
-    println!(
-        "  Found {} functions, {} types",
-        spec.functions.len(),
-        spec.types.len()
-    );
+        // Step 1: Fetch contract specification (from a local WASM file or the network)
+        // along with the WASM hash the generated server checks for upgrades
+        let (mut spec, wasm_hash) =
+            fetch_spec(&contract_id, source.wasm.as_deref(), &network, args.verbose).await?;
 
-    // Step 2: Generate MCP server
-    println!("Generating MCP server...");
+        println!(
+            "  Found {} functions, {} types",
+            spec.functions.len(),
+            spec.types.len()
+        );
 
-    // Priority for contract name:
-    // 1. --name CLI argument / wizard input
-    // 2. Contract metadata "name" key
-    // 3. First 8 chars of contract ID (fallback)
-    let contract_name = name.clone().or_else(|| {
-        spec.name.clone().map(|n| {
-            // Convert to kebab-case for file naming
-            n.chars()
-                .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
-                .collect::<String>()
-                .trim_matches('-')
-                .to_string()
-        })
-    }).unwrap_or_else(|| contract_id[..8].to_lowercase());
+        // Classify functions as read-only or state-changing: simulate them against
+        // the deployed contract, then apply the user's overrides
+        if !contract_id.is_empty() {
+            println!("Classifying functions by simulation...");
+            let fetcher = SpecFetcher::with_verbose(&network.rpc_url, args.verbose)?;
+            if let Err(e) = fetcher.classify_functions(&contract_id, &mut spec).await {
+                println!("  Warning: could not simulate functions ({}), treating them as state-changing", e);
+            }
+        }
 
-    if let Some(ref meta_name) = spec.name {
-        println!("  Contract name from metadata: {}", meta_name);
-    }
+        // Function selection and tool descriptions from stellar-mcp.toml
+        if namespaced {
+            classifier::apply_overrides(
+                &mut spec,
+                &config::names_for_contract(&args.read_only, &source.name),
+                &config::names_for_contract(&args.write, &source.name),
+            )?;
+            if let Some(config) = &project_config {
+                config.for_contract(&source.name).apply_to_spec(&mut spec)?;
+            }
+        } else {
+            classifier::apply_overrides(&mut spec, &args.read_only, &args.write)?;
+            if let Some(config) = &project_config {
+                config.apply_to_spec(&mut spec)?;
+            }
+        }
 
-    // Route to appropriate generator based on language
-    if lang == "typescript" {
-        let generator = McpGenerator::new(
-            &output,
-            &contract_name,
-            &server_name,
-            &contract_id,
-            &network,
+        let read_only_count = spec
+            .functions
+            .iter()
+            .filter(|f| f.kind == FunctionKind::ReadOnly)
+            .count();
+        println!(
+            "  {} read-only, {} state-changing functions",
+            read_only_count,
+            spec.functions.len() - read_only_count
         );
 
-        generator.generate(&spec, &args)?;
+        let name = if multi_source {
+            source.name.clone()
+        } else {
+            default_contract_name(name.as_deref(), &spec, &contract_id, args.wasm.as_deref())
+        };
+        contracts.push(ServedContract {
+            name,
+            contract_id,
+            spec,
+            wasm_hash,
+        });
+    }
+    if multi_source {
+        contracts::validate_names(&contracts)?;
+    }
+
+    // Step 2: Generate MCP server
+    println!("Generating MCP server...");
 
-        // Generate frontend if requested
-        if with_frontend {
-            println!();
-            println!("Generating React frontend...");
-            use crate::generator::FrontendGenerator;
+    // A multi-contract server is named after --name, or the server name
+    let project_name = if namespaced {
+        name.clone().unwrap_or_else(|| server_name.clone())
+    } else {
+        contracts[0].name.clone()
+    };
 
-            let frontend_gen = FrontendGenerator::new(
-                &output,
-                &server_name,
-                &network,
-            );
+    // Render the project in memory, then write it into the output directory
+    if with_frontend {
+        println!("Generating React frontend...");
+    }
+    let project = registry.render_project(&ProjectOptions {
+        lang: &lang,
+        project_name: &project_name,
+        server_name: &server_name,
+        network: &network,
+        contracts: &contracts,
+        templates: &templates,
+        with_frontend,
+        with_rest,
+    })?;
+
+    if dry_run {
+        println!();
+        println!("Dry run: changes to {} (nothing is written)", output.display());
+        let plan = update::plan_project(&project, &output, args.update)?;
+        print_plan(&plan, args.diff);
+        return Ok(());
+    }
 
-            frontend_gen.generate()?;
-        }
+    for path in project.paths() {
+        println!("  Generated {}", path.display());
+    }
 
+    println!();
+    if args.update {
+        println!("Updating {}...", output.display());
+    } else {
+        println!("Writing {}...", output.display());
+    }
+    let report = update::write_project(&project, &output, args.update)?;
+    print_report(&report, args.update);
+
+    let conflicts = report.conflicts();
+    if !conflicts.is_empty() {
         println!();
-        println!("MCP server generated successfully!");
-
-        if with_frontend {
-            println!();
-            println!("✓ MCP Server + React Frontend generated!");
-            println!();
-            println!("Next steps:");
-            println!();
-            println!("  MCP Server:");
-            println!("    1. cd {}", output.display());
-            println!("    2. pnpm install");
-            println!("    3. cp .env.example .env && edit .env");
-            println!("    4. pnpm run build");
-            println!("    5. USE_HTTP=true PORT=3000 pnpm start");
-            println!();
-            println!("  Frontend:");
-            println!("    1. cd {}/frontend", output.display());
-            println!("    2. pnpm install");
-            println!("    3. cp .env.example .env");
-            println!("    4. pnpm dev");
-            println!();
-            println!("  Then open http://localhost:5173 in your browser");
-        } else {
-            println!();
-            println!("Next steps:");
-            println!("  1. cd {}", output.display());
-            println!("  2. pnpm install");
-            println!("  3. cp .env.example .env && edit .env");
-            println!("  4. pnpm run build");
-            println!("  5. pnpm start");
-            println!();
-            println!("To use with Claude Desktop, add to claude_desktop_config.json:");
-            println!("  See {}/README.md for configuration", output.display());
+        println!("Resolve the conflicts, then rebuild the server:");
+        for path in &conflicts {
+            println!("  {}", path);
         }
+        return Err(GeneratorError::MergeConflicts {
+            count: conflicts.len(),
+        });
+    }
+
+    println!();
+    println!("MCP server generated successfully!");
+
+    let (indent, steps) = if with_frontend { ("    ", "  MCP Server:") } else { ("  ", "Next steps:") };
+    println!();
+    if with_frontend {
+        println!("✓ MCP Server + React Frontend generated!");
+        println!();
+        println!("Next steps:");
+        println!();
+    }
+    println!("{}", steps);
+    // REST routes are served in HTTP mode only
+    for (i, step) in backend.next_steps(&output, with_frontend || with_rest).iter().enumerate() {
+        println!("{}{}. {}", indent, i + 1, step);
+    }
+    if with_rest {
+        println!();
+        println!("{}REST API: POST http://localhost:3000/api/<tool>", indent);
+        println!("{}OpenAPI document: http://localhost:3000/openapi.json", indent);
+    }
+    if with_frontend {
+        println!();
+        println!("  Frontend:");
+        println!("    1. cd {}/frontend", output.display());
+        println!("    2. pnpm install");
+        println!("    3. cp .env.example .env");
+        println!("    4. pnpm dev");
+        println!();
+        println!("  Then open http://localhost:5173 in your browser");
     } else {
-        // Python generation
-        use crate::generator::PythonGenerator;
-
-        let generator = PythonGenerator::new(
-            &output,
-            &contract_name,
-            &server_name,
-            &contract_id,
-            &network,
-        );
+        println!();
+        println!("To use with Claude Desktop, add to claude_desktop_config.json:");
+        println!("  See {}/README.md for configuration", output.display());
+    }
+
+    Ok(())
+}
+
+/// Read a contract spec from a local WASM file, or fetch it from the network,
+/// along with the WASM hash the generated server checks for upgrades
+async fn fetch_spec(
+    contract_id: &str,
+    wasm: Option<&Path>,
+    network: &NetworkConfig,
+    verbose: bool,
+) -> Result<(ContractSpec, Option<String>)> {
+    if let Some(wasm) = wasm {
+        println!("Reading contract specification from {}...", wasm.display());
+        let wasm_bytes = std::fs::read(wasm)
+            .map_err(GeneratorError::io(format!("Failed to read WASM file '{}'", wasm.display())))?;
+        Ok((SpecParser::parse_wasm(&wasm_bytes)?, Some(snapshot::wasm_hash(&wasm_bytes))))
+    } else {
+        println!("Fetching contract specification...");
+        let fetcher = SpecFetcher::with_verbose(&network.rpc_url, verbose)?;
+        fetcher.fetch_spec_with_wasm_hash(contract_id).await
+    }
+}
 
-        generator.generate(&spec, &args)?;
+/// Name of a single-contract server, used for tool and file naming.
+///
+/// Priority:
+/// 1. --name CLI argument / wizard input
+/// 2. Contract metadata "name" key
+/// 3. First 8 chars of contract ID (fallback)
+/// 4. WASM file name (when generating from --wasm without a contract ID)
+fn default_contract_name(
+    name: Option<&str>,
+    spec: &ContractSpec,
+    contract_id: &str,
+    wasm: Option<&Path>,
+) -> String {
+    let to_file_name = |n: &str| {
+        // Convert to kebab-case for file naming
+        n.chars()
+            .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+            .collect::<String>()
+            .trim_matches('-')
+            .to_string()
+    };
 
-        // Generate frontend if requested
-        if with_frontend {
-            println!();
-            println!("Generating React frontend...");
-            use crate::generator::FrontendGenerator;
+    if let Some(ref meta_name) = spec.name {
+        println!("  Contract name from metadata: {}", meta_name);
+    }
 
-            let frontend_gen = FrontendGenerator::new(
-                &output,
-                &server_name,
-                &network,
-            );
+    name.map(str::to_string)
+        .or_else(|| spec.name.as_deref().map(to_file_name))
+        .unwrap_or_else(|| {
+            if !contract_id.is_empty() {
+                contract_id[..8].to_lowercase()
+            } else {
+                wasm.and_then(|w| w.file_stem())
+                    .map(|stem| to_file_name(&stem.to_string_lossy()))
+                    .unwrap_or_else(|| "contract".to_string())
+            }
+        })
+}
 
-            frontend_gen.generate()?;
+/// Print what happened to each file. Plain generation only lists counts;
+/// updates list every file that was not simply rewritten.
+fn print_report(report: &UpdateReport, update: bool) {
+    if update {
+        for (path, action) in &report.files {
+            match action {
+                FileAction::Merged => println!("  merged     {}", path),
+                FileAction::Conflict(n) => println!("  CONFLICT   {} ({} conflicting hunk(s))", path, n),
+                FileAction::Sidecar(sidecar) => {
+                    println!("  CONFLICT   {} (modified, new version written to {})", path, sidecar)
+                }
+                FileAction::Created => println!("  created    {}", path),
+                FileAction::Removed => println!("  removed    {}", path),
+                FileAction::KeptModified => println!("  kept       {} (no longer generated, but modified)", path),
+                FileAction::Updated | FileAction::Unchanged => {}
+            }
         }
+    }
+    println!(
+        "  {} file(s) written, {} unchanged",
+        report.count(|a| matches!(a, FileAction::Created | FileAction::Updated | FileAction::Merged)),
+        report.count(|a| matches!(a, FileAction::Unchanged))
+    );
+}
 
-        println!();
-        println!("MCP server generated successfully!");
-
-        if with_frontend {
-            println!();
-            println!("✓ Python MCP Server + React Frontend generated!");
-            println!();
-            println!("Next steps:");
-            println!();
-            println!("  MCP Server:");
-            println!("    1. cd {}", output.display());
-            println!("    2. uv sync  # Install dependencies");
-            println!("    3. cp .env.example .env && edit .env");
-            println!("    4. USE_HTTP=true PORT=3000 uv run python server.py");
-            println!();
-            println!("  Frontend:");
-            println!("    1. cd {}/frontend", output.display());
-            println!("    2. pnpm install");
-            println!("    3. cp .env.example .env");
-            println!("    4. pnpm dev");
-            println!();
-            println!("  Then open http://localhost:5173 in your browser");
+/// Print every file a dry run would create, modify or delete, followed by its
+/// unified diff when `diff` is set
+fn print_plan(plan: &ProjectPlan, diff: bool) {
+    let mut changed = 0;
+    for file in &plan.files {
+        let (verb, path, note) = match &file.action {
+            FileAction::Created => ("create", file.path.as_str(), String::new()),
+            FileAction::Updated => ("modify", file.path.as_str(), String::new()),
+            FileAction::Merged => ("modify", file.path.as_str(), " (merged with your changes)".to_string()),
+            FileAction::Conflict(n) => ("modify", file.path.as_str(), format!(" ({} conflicting hunk(s))", n)),
+            FileAction::Sidecar(sidecar) => {
+                ("create", sidecar.as_str(), format!(" (new version of modified {})", file.path))
+            }
+            FileAction::Removed => ("delete", file.path.as_str(), String::new()),
+            FileAction::Unchanged | FileAction::KeptModified => continue,
+        };
+        changed += 1;
+        println!("  {:<8}{}{}", verb, path, note);
+        if diff {
+            print!("{}", file_diff(file, path));
         }
     }
+    println!(
+        "  {} file(s) would change, {} unchanged",
+        changed,
+        plan.files.len() - changed
+    );
+}
 
-    Ok(())
+/// Unified diff of one planned change, written to `path`
+fn file_diff(file: &PlannedFile, path: &str) -> String {
+    // A sidecar is a new file next to the current one
+    let current = match file.action {
+        FileAction::Sidecar(_) => None,
+        _ => file.current.as_deref(),
+    };
+    let old = current.map(std::str::from_utf8).unwrap_or(Ok(""));
+    let new = file.content.as_deref().map(std::str::from_utf8).unwrap_or(Ok(""));
+    let (Ok(old), Ok(new)) = (old, new) else {
+        return format!("Binary files differ: {}\n", path);
+    };
+
+    let old_label = match current {
+        Some(_) => format!("a/{}", path),
+        None => "/dev/null".to_string(),
+    };
+    let new_label = match file.content {
+        Some(_) => format!("b/{}", path),
+        None => "/dev/null".to_string(),
+    };
+    unified_diff(old, new, &old_label, &new_label)
 }
@@ -1,4 +1,5 @@
 //! CLI commands for the MCP generator
 
+pub mod diff;
 pub mod generate;
 pub mod validate;
@@ -2,6 +2,7 @@
 
 use clap::Parser;
 use std::path::PathBuf;
+use crate::error::{GeneratorError, Result};
 
 /// Arguments for the validate command
 #[derive(Parser, Debug)]
@@ -24,12 +25,15 @@ pub struct ValidateArgs {
 }
 
 /// Execute the validate command
-pub async fn execute(args: ValidateArgs) -> Result<(), Box<dyn std::error::Error>> {
+pub async fn execute(args: ValidateArgs) -> Result<()> {
     println!("Validating MCP server at: {}", args.path.display());
 
     // Check if directory exists
     if !args.path.exists() {
-        return Err(format!("Directory '{}' does not exist", args.path.display()).into());
+        return Err(GeneratorError::InvalidInput(format!(
+            "Directory '{}' does not exist",
+            args.path.display()
+        )));
     }
 
     // Check required files
@@ -63,8 +67,12 @@ pub async fn execute(args: ValidateArgs) -> Result<(), Box<dyn std::error::Error
     // Check package.json for required dependencies
     let package_json_path = args.path.join("package.json");
     if package_json_path.exists() {
-        let content = std::fs::read_to_string(&package_json_path)?;
-        let package: serde_json::Value = serde_json::from_str(&content)?;
+        let content = std::fs::read_to_string(&package_json_path)
+            .map_err(GeneratorError::io("Failed to read package.json"))?;
+        let package: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| GeneratorError::ValidationFailed {
+                errors: vec![format!("Invalid package.json: {}", e)],
+            })?;
 
         let required_deps = vec![
             "@modelcontextprotocol/sdk",
@@ -164,7 +172,7 @@ pub async fn execute(args: ValidateArgs) -> Result<(), Box<dyn std::error::Error
             for error in &errors {
                 println!("  - {}", error);
             }
-            return Err("Validation failed with errors".into());
+            return Err(GeneratorError::ValidationFailed { errors });
         }
 
         println!();
@@ -0,0 +1,350 @@
+//! Declarative project configuration (`stellar-mcp.toml`)
+//!
+//! Holds every generation setting so servers can be regenerated reproducibly
+//! (e.g. in CI) without flags or the interactive wizard. `generate` picks up
+//! `stellar-mcp.toml` from the current directory automatically; command line
+//! flags take precedence over the file.
+
+use crate::commands::generate::{ContractArg, GenerateArgs};
+use crate::spec::ContractSpec;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::error::{GeneratorError, Result};
+use std::path::{Path, PathBuf};
+
+/// Default configuration file name, looked up in the current directory
+pub const CONFIG_FILE_NAME: &str = "stellar-mcp.toml";
+
+/// Project configuration read from `stellar-mcp.toml`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Network name (testnet, mainnet, futurenet, local)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    /// Custom RPC URL (overrides the network default)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc_url: Option<String>,
+    /// Network passphrase (required with a custom RPC URL)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_passphrase: Option<String>,
+    /// Language to generate (typescript, python, rust or go)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    /// Output directory, relative to the configuration file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<PathBuf>,
+    /// MCP server name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_name: Option<String>,
+    /// Directory of templates overriding the built-in ones, relative to the configuration file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub templates: Option<PathBuf>,
+    /// Contract to generate the server for
+    #[serde(skip_serializing_if = "is_default")]
+    pub contract: ContractConfig,
+    /// Contracts to serve from one server (`[[contracts]]`), instead of `[contract]`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub contracts: Vec<ContractConfig>,
+    /// Function selection and classification
+    #[serde(skip_serializing_if = "is_default")]
+    pub functions: FunctionsConfig,
+    /// Per-tool overrides, keyed by contract function name
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub tools: BTreeMap<String, ToolConfig>,
+    /// Frontend options
+    #[serde(skip_serializing_if = "is_default")]
+    pub frontend: FrontendConfig,
+    /// REST gateway options
+    #[serde(skip_serializing_if = "is_default")]
+    pub rest: RestConfig,
+}
+
+/// `[contract]` section, or one `[[contracts]]` entry
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContractConfig {
+    /// Deployed contract ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Local contract WASM file, relative to the configuration file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<PathBuf>,
+    /// Contract name used for tool and file naming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// `[functions]` section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FunctionsConfig {
+    /// Only generate tools for these functions (all functions when empty)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Never generate tools for these functions
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Functions to treat as read-only, overriding simulation
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub read_only: Vec<String>,
+    /// Functions to treat as state-changing, overriding simulation
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub write: Vec<String>,
+}
+
+/// `[tools.<function>]` section (`[tools."<contract>.<function>"]` with several contracts)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ToolConfig {
+    /// Tool description shown to agents (replaces the contract doc comment)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// `[frontend]` section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FrontendConfig {
+    /// Generate a React frontend alongside the MCP server
+    pub enabled: bool,
+}
+
+/// `[rest]` section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RestConfig {
+    /// Serve REST routes and an OpenAPI document next to `/mcp`
+    pub enabled: bool,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Function names that apply to `contract`, from names qualified as
+/// `<contract>.<function>`
+pub fn names_for_contract(names: &[String], contract: &str) -> Vec<String> {
+    names
+        .iter()
+        .filter_map(|name| {
+            let (prefix, function) = name.split_once('.')?;
+            (prefix == contract).then(|| function.to_string())
+        })
+        .collect()
+}
+
+/// Check that function names are qualified with one of the served contracts
+pub fn check_contract_prefixes<'a>(
+    names: impl IntoIterator<Item = &'a String>,
+    contracts: &[&str],
+) -> Result<(), String> {
+    for name in names {
+        match name.split_once('.') {
+            Some((prefix, _)) if contracts.contains(&prefix) => {}
+            Some((prefix, _)) => {
+                return Err(format!(
+                    "Unknown contract '{}' in '{}' (contracts: {})",
+                    prefix,
+                    name,
+                    contracts.join(", ")
+                ))
+            }
+            None => {
+                return Err(format!(
+                    "Function '{}' must be qualified with its contract (e.g. '{}.{}')",
+                    name, contracts[0], name
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+impl ProjectConfig {
+    /// Parse a configuration from TOML
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let config: Self = toml::from_str(content)
+            .map_err(|e| GeneratorError::InvalidInput(format!("Invalid {}: {}", CONFIG_FILE_NAME, e)))?;
+
+        if !config.contracts.is_empty() && !is_default(&config.contract) {
+            return Err(GeneratorError::InvalidInput(format!(
+                "Invalid {}: use either [contract] or [[contracts]], not both",
+                CONFIG_FILE_NAME
+            )));
+        }
+        for contract in &config.contracts {
+            if contract.name.is_none() || (contract.id.is_none() && contract.wasm.is_none()) {
+                return Err(GeneratorError::InvalidInput(format!(
+                    "Invalid {}: every [[contracts]] entry needs a name and an id or wasm",
+                    CONFIG_FILE_NAME
+                )));
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Serialize the configuration to TOML
+    pub fn to_toml(&self) -> Result<String> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// Load a configuration file. Relative paths in it are resolved against
+    /// the directory containing the file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(GeneratorError::io(format!("Failed to read config file '{}'", path.display())))?;
+        let mut config = Self::from_toml(&content)?;
+
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        config.output = config.output.map(|p| base.join(p));
+        config.templates = config.templates.map(|p| base.join(p));
+        config.contract.wasm = config.contract.wasm.map(|p| base.join(p));
+        for contract in &mut config.contracts {
+            contract.wasm = contract.wasm.take().map(|p| base.join(p));
+        }
+
+        Ok(config)
+    }
+
+    /// Load `stellar-mcp.toml` from the current directory, if present
+    pub fn discover() -> Result<Option<Self>> {
+        let path = Path::new(CONFIG_FILE_NAME);
+        if path.exists() {
+            Ok(Some(Self::load(path)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Write the configuration file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_toml()?)
+            .map_err(GeneratorError::io(format!("Failed to write config file '{}'", path.display())))?;
+        Ok(())
+    }
+
+    /// Fill every setting not given on the command line from the configuration
+    pub fn apply_to_args(&self, args: &mut GenerateArgs) {
+        fn fill<T: Clone>(arg: &mut Option<T>, value: &Option<T>) {
+            if arg.is_none() {
+                *arg = value.clone();
+            }
+        }
+
+        // The contract is taken as a whole: a contract given on the command
+        // line must not be paired with the file's WASM or name
+        let contract_given = !args.contracts.is_empty() || args.contract_id.is_some() || args.wasm.is_some();
+        if !contract_given {
+            args.contract_id = self.contract.id.clone();
+            args.wasm = self.contract.wasm.clone();
+            fill(&mut args.name, &self.contract.name);
+        }
+        fill(&mut args.network, &self.network);
+        fill(&mut args.rpc_url, &self.rpc_url);
+        fill(&mut args.network_passphrase, &self.network_passphrase);
+        fill(&mut args.lang, &self.lang);
+        fill(&mut args.output, &self.output);
+        fill(&mut args.server_name, &self.server_name);
+        fill(&mut args.templates, &self.templates);
+        fill(&mut args.with_frontend, &Some(self.frontend.enabled));
+        fill(&mut args.with_rest, &Some(self.rest.enabled));
+
+        if !contract_given && args.contract_id.is_none() && args.wasm.is_none() {
+            args.contracts = self
+                .contracts
+                .iter()
+                .map(|c| ContractArg {
+                    name: c.name.clone().unwrap_or_default(),
+                    contract_id: c.id.clone(),
+                    wasm: c.wasm.clone(),
+                })
+                .collect();
+        }
+
+        if args.read_only.is_empty() {
+            args.read_only = self.functions.read_only.clone();
+        }
+        if args.write.is_empty() {
+            args.write = self.functions.write.clone();
+        }
+    }
+
+    /// Function and tool settings for one contract of a multi-contract server.
+    ///
+    /// With several contracts, `[functions]` and `[tools]` refer to functions
+    /// as `<contract>.<function>`; this keeps the entries for `contract` with
+    /// the prefix removed, so `apply_to_spec` can be used on its spec.
+    pub fn for_contract(&self, contract: &str) -> Self {
+        let functions = FunctionsConfig {
+            include: names_for_contract(&self.functions.include, contract),
+            exclude: names_for_contract(&self.functions.exclude, contract),
+            read_only: names_for_contract(&self.functions.read_only, contract),
+            write: names_for_contract(&self.functions.write, contract),
+        };
+        let tools = self
+            .tools
+            .iter()
+            .filter_map(|(name, tool)| {
+                let (prefix, function) = name.split_once('.')?;
+                (prefix == contract).then(|| (function.to_string(), tool.clone()))
+            })
+            .collect();
+        Self {
+            functions,
+            tools,
+            ..self.clone()
+        }
+    }
+
+    /// Check that every `[functions]` and `[tools]` entry of a multi-contract
+    /// configuration names one of the served contracts
+    pub fn check_contract_prefixes(&self, contracts: &[&str]) -> Result<()> {
+        let referenced = self
+            .functions
+            .include
+            .iter()
+            .chain(&self.functions.exclude)
+            .chain(&self.functions.read_only)
+            .chain(&self.functions.write)
+            .chain(self.tools.keys());
+        check_contract_prefixes(referenced, contracts)
+            .map_err(|e| GeneratorError::InvalidInput(format!("{} in {}", e, CONFIG_FILE_NAME)))
+    }
+
+    /// Apply function selection and tool description overrides to the spec
+    pub fn apply_to_spec(&self, spec: &mut ContractSpec) -> Result<()> {
+        let referenced = self
+            .functions
+            .include
+            .iter()
+            .chain(&self.functions.exclude)
+            .chain(self.tools.keys());
+        for name in referenced {
+            if !spec.functions.iter().any(|f| &f.name == name) {
+                return Err(GeneratorError::InvalidInput(format!(
+                    "Unknown function '{}' in {}",
+                    name, CONFIG_FILE_NAME
+                )));
+            }
+        }
+
+        spec.functions.retain(|f| {
+            (self.functions.include.is_empty() || self.functions.include.contains(&f.name))
+                && !self.functions.exclude.contains(&f.name)
+        });
+
+        for func in &mut spec.functions {
+            if let Some(description) = self
+                .tools
+                .get(&func.name)
+                .and_then(|t| t.description.clone())
+            {
+                func.doc = Some(description);
+            }
+        }
+
+        Ok(())
+    }
+}
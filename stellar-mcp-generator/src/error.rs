@@ -0,0 +1,160 @@
+//! Error type of the generator library and the `stellar-mcp` CLI
+//!
+//! Every public function returns [`GeneratorError`], so embedders can match on
+//! the cause of a failure. [`GeneratorError::exit_code`] gives each cause the
+//! process exit code the CLI reports.
+
+use crate::generator::names::NameCollision;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Result alias used throughout the crate
+pub type Result<T, E = GeneratorError> = std::result::Result<T, E>;
+
+/// Errors reported while reading contract specs and generating projects
+#[derive(Debug, Error)]
+pub enum GeneratorError {
+    /// Invalid flags, `stellar-mcp.toml` settings or wizard input
+    #[error("{0}")]
+    InvalidInput(String),
+
+    /// A contract ID is not a valid contract strkey
+    #[error("Invalid contract ID '{value}': {reason}")]
+    InvalidStrkey { value: String, reason: String },
+
+    /// The network has no contract instance for the ID
+    #[error("Contract {contract_id} not found")]
+    ContractNotFound { contract_id: String },
+
+    /// The contract is a Stellar Asset Contract, which has no WASM
+    #[error("Contract {contract_id} is a Stellar Asset Contract and has no WASM")]
+    StellarAssetContract { contract_id: String },
+
+    /// The RPC server answered with a JSON-RPC error
+    #[error("RPC error: {code} - {message}")]
+    Rpc { code: i32, message: String },
+
+    /// The RPC server could not be reached, or its response could not be read
+    #[error("RPC request failed: {0}")]
+    Network(String),
+
+    /// The contract WASM, spec or spec snapshot could not be read
+    #[error("{0}")]
+    InvalidSpec(String),
+
+    /// Distinct spec names map to the same generated name (`generator::names`)
+    #[error(
+        "Generated names collide:\n{}\nExclude a function with [functions] exclude in stellar-mcp.toml, or rename it in the contract.",
+        .collisions.iter().map(|c| format!("  {}", c)).collect::<Vec<_>>().join("\n")
+    )]
+    NameCollisions { collisions: Vec<NameCollision> },
+
+    /// The output directory exists and neither `--force` nor `--update` was given
+    #[error(
+        "Output directory '{}' already exists. Use --force to overwrite or --update to regenerate in place.",
+        .path.display()
+    )]
+    OutputExists { path: PathBuf },
+
+    /// A template override is missing, unknown or does not parse
+    #[error("{0}")]
+    InvalidTemplate(String),
+
+    /// A template failed to render
+    #[error("Failed to render template '{name}': {message}")]
+    TemplateRender { name: String, message: String },
+
+    /// `--update` left files with merge conflicts
+    #[error("{count} file(s) could not be merged automatically")]
+    MergeConflicts { count: usize },
+
+    /// `diff --fail-on-breaking` found breaking changes
+    #[error("{count} breaking change(s) detected")]
+    BreakingChanges { count: usize },
+
+    /// `validate` found errors in a generated project
+    #[error("Validation failed with errors")]
+    ValidationFailed { errors: Vec<String> },
+
+    /// Reading wizard input from the terminal failed
+    #[error("Prompt failed: {0}")]
+    Prompt(#[from] dialoguer::Error),
+
+    /// Serializing a manifest, snapshot or report failed
+    #[error("Serialization failed: {0}")]
+    Serialization(String),
+
+    /// Reading or writing a file failed
+    #[error("{context}: {source}")]
+    Io {
+        context: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl GeneratorError {
+    /// Process exit code the CLI uses for this error
+    ///
+    /// | Code | Error |
+    /// | ---- | ----- |
+    /// | 1    | `Io`, `Serialization` |
+    /// | 2    | `InvalidInput`, `InvalidStrkey` |
+    /// | 3    | `ContractNotFound`, `StellarAssetContract` |
+    /// | 4    | `Rpc`, `Network` |
+    /// | 5    | `InvalidSpec`, `NameCollisions` |
+    /// | 6    | `OutputExists` |
+    /// | 7    | `InvalidTemplate`, `TemplateRender` |
+    /// | 8    | `MergeConflicts` |
+    /// | 9    | `BreakingChanges` |
+    /// | 10   | `ValidationFailed` |
+    /// | 11   | `Prompt` |
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Io { .. } | Self::Serialization(_) => 1,
+            Self::InvalidInput(_) | Self::InvalidStrkey { .. } => 2,
+            Self::ContractNotFound { .. } | Self::StellarAssetContract { .. } => 3,
+            Self::Rpc { .. } | Self::Network(_) => 4,
+            Self::InvalidSpec(_) | Self::NameCollisions { .. } => 5,
+            Self::OutputExists { .. } => 6,
+            Self::InvalidTemplate(_) | Self::TemplateRender { .. } => 7,
+            Self::MergeConflicts { .. } => 8,
+            Self::BreakingChanges { .. } => 9,
+            Self::ValidationFailed { .. } => 10,
+            Self::Prompt(_) => 11,
+        }
+    }
+
+    /// Build an `Io` error described by `context`, e.g. `Failed to write 'x'`
+    pub(crate) fn io(context: impl Into<String>) -> impl FnOnce(std::io::Error) -> Self {
+        let context = context.into();
+        move |source| Self::Io { context, source }
+    }
+}
+
+impl From<std::io::Error> for GeneratorError {
+    fn from(source: std::io::Error) -> Self {
+        Self::Io {
+            context: "I/O error".to_string(),
+            source,
+        }
+    }
+}
+
+impl From<serde_json::Error> for GeneratorError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serialization(e.to_string())
+    }
+}
+
+impl From<toml::ser::Error> for GeneratorError {
+    fn from(e: toml::ser::Error) -> Self {
+        Self::Serialization(e.to_string())
+    }
+}
+
+impl From<reqwest::Error> for GeneratorError {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Network(e.to_string())
+    }
+}
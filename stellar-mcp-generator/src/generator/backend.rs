@@ -0,0 +1,386 @@
+//! Target language backends
+//!
+//! A backend turns the served contracts into a server project in one
+//! language: it maps contract types to the language's types, lays out and
+//! renders the project's files from its templates, checks the names it would
+//! generate, and tells the user how to run the result. `render_project` and
+//! `stellar mcp generate` look backends up by `--lang` in a
+//! [`BackendRegistry`]. The default registry holds the built-in `typescript`,
+//! `python`, `rust` and `go` backends; another crate can register its own and
+//! run the CLI with that registry (`commands::generate::execute_with`).
+
+use super::names::{self, NameCollision};
+use super::project::{GeneratedProject, ProjectOptions};
+use super::templates::{TemplateRenderer, GO_TEMPLATES, PYTHON_TEMPLATES, RUST_TEMPLATES, TYPESCRIPT_TEMPLATES};
+use super::tool_manifest::ToolNaming;
+use super::{
+    go_generator, python_bindings, rust_generator, ts_bindings, FrontendGenerator, GoGenerator, McpGenerator,
+    PythonGenerator, RustGenerator, ServedContract,
+};
+use crate::error::{GeneratorError, Result};
+use crate::spec::TypeRef;
+use crate::NetworkConfig;
+use serde::Serialize;
+use std::path::Path;
+
+/// A target language for generated servers
+pub trait TargetBackend: Send + Sync {
+    /// Language name, as passed to `--lang`
+    fn name(&self) -> &str;
+
+    /// Default templates, keyed by name. Names are shared by every backend
+    /// in a registry, so they should be prefixed with the language
+    /// (`python/server.py`).
+    fn templates(&self) -> &[(&'static str, &'static str)];
+
+    /// How a contract type is written in the generated code. Renderers map
+    /// every parameter, output and field type through it.
+    fn type_name(&self, type_ref: &TypeRef) -> String;
+
+    /// Generated names that would collide in this language's code. Reported
+    /// as `GeneratorError::NameCollisions` before anything is rendered.
+    fn find_collisions(&self, _contracts: &[ServedContract]) -> Vec<NameCollision> {
+        Vec::new()
+    }
+
+    /// How the tools and their arguments are named, in `tools.json` and the
+    /// OpenAPI document as in the server itself
+    fn tool_naming(&self) -> ToolNaming {
+        ToolNaming::Kebab
+    }
+
+    /// Whether `render` honours `ProjectOptions::with_rest`: REST routes
+    /// calling the tools, next to `/mcp`, and an OpenAPI document
+    fn supports_rest(&self) -> bool {
+        false
+    }
+
+    /// Render every file of the server project (without the frontend)
+    fn render(&self, options: &ProjectOptions) -> Result<GeneratedProject>;
+
+    /// Steps to install and run the server generated in `output`. With `http`
+    /// the server is started over HTTP, as the generated frontend expects.
+    fn next_steps(&self, output: &Path, http: bool) -> Vec<String>;
+}
+
+/// Backends available to `render_project` and `generate`, keyed by language
+pub struct BackendRegistry {
+    backends: Vec<Box<dyn TargetBackend>>,
+}
+
+impl BackendRegistry {
+    /// Registry without any backend
+    pub fn empty() -> Self {
+        Self { backends: Vec::new() }
+    }
+
+    /// Add a backend, replacing any backend of the same language
+    pub fn register(&mut self, backend: impl TargetBackend + 'static) {
+        self.backends.retain(|b| b.name() != backend.name());
+        self.backends.push(Box::new(backend));
+    }
+
+    /// Backend for `lang`
+    pub fn get(&self, lang: &str) -> Result<&dyn TargetBackend> {
+        self.backends
+            .iter()
+            .find(|b| b.name() == lang)
+            .map(Box::as_ref)
+            .ok_or_else(|| {
+                GeneratorError::InvalidInput(format!(
+                    "Invalid language '{}'. Supported languages: {}",
+                    lang,
+                    self.names().join(", ")
+                ))
+            })
+    }
+
+    /// Languages, in registration order
+    pub fn names(&self) -> Vec<&str> {
+        self.backends.iter().map(|b| b.name()).collect()
+    }
+
+    /// Template renderer with every backend's default templates, overridden
+    /// by the `.hbs` files in `overrides` (`--templates`)
+    pub fn template_renderer(&self, overrides: Option<&Path>) -> Result<TemplateRenderer> {
+        let renderer =
+            TemplateRenderer::with_templates(self.backends.iter().flat_map(|b| b.templates().iter().copied()))?;
+        match overrides {
+            Some(dir) => renderer.override_from(dir),
+            None => Ok(renderer),
+        }
+    }
+
+    /// Render every file of a server project (its spec snapshots and its
+    /// frontend included) with the backend for `options.lang`, without
+    /// writing anything
+    pub fn render_project(&self, options: &ProjectOptions) -> Result<GeneratedProject> {
+        let backend = self.get(options.lang)?;
+        if options.with_rest && !backend.supports_rest() {
+            return Err(GeneratorError::InvalidInput(format!(
+                "--with-rest is not supported for {} servers",
+                backend.name()
+            )));
+        }
+
+        let collisions = backend.find_collisions(options.contracts);
+        if !collisions.is_empty() {
+            return Err(GeneratorError::NameCollisions { collisions });
+        }
+
+        let mut project = backend.render(options)?;
+        // Record the spec the server is generated from (used by `diff` and the
+        // drift check), one snapshot per contract for multi-contract servers
+        let namespaced = options.contracts.len() > 1;
+        for contract in options.contracts {
+            project.add(contract.snapshot_path(namespaced), contract.snapshot(&options.network.name).to_json()?);
+        }
+        if options.with_frontend {
+            project.extend(FrontendGenerator::new(options.server_name, options.network).render()?);
+        }
+        Ok(project)
+    }
+}
+
+/// The built-in `typescript`, `python`, `rust` and `go` backends
+impl Default for BackendRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register(TypeScriptBackend);
+        registry.register(PythonBackend);
+        registry.register(RustBackend);
+        registry.register(GoBackend);
+        registry
+    }
+}
+
+/// Data shared by the templates of every backend: names, network settings
+/// and each contract's ID and environment variable
+pub fn project_data(
+    project_name: &str,
+    server_name: &str,
+    network: &NetworkConfig,
+    contracts: &[ServedContract],
+) -> serde_json::Value {
+    let namespaced = contracts.len() > 1;
+    let contracts: Vec<serde_json::Value> = contracts
+        .iter()
+        .map(|c| {
+            serde_json::json!({
+                "name": c.name,
+                "env_var": c.env_var(namespaced),
+                "contract_id": c.contract_id,
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "project_name": project_name,
+        "server_name": server_name,
+        "namespaced": namespaced,
+        "network_name": network.name,
+        "network_cli": network.name.to_lowercase(),
+        "rpc_url": network.rpc_url,
+        "network_passphrase": network.network_passphrase,
+        "contracts": contracts,
+    })
+}
+
+/// Render each `(template, path)` of `files` with the same data
+pub fn render_template_files<T: Serialize>(
+    project: &mut GeneratedProject,
+    templates: &TemplateRenderer,
+    files: &[(&str, &str)],
+    data: &T,
+) -> Result<()> {
+    for (template, path) in files {
+        project.add(*path, templates.render(template, data)?);
+    }
+    Ok(())
+}
+
+/// TypeScript server on the MCP TypeScript SDK (`McpGenerator`)
+pub struct TypeScriptBackend;
+
+impl TargetBackend for TypeScriptBackend {
+    fn name(&self) -> &str {
+        "typescript"
+    }
+
+    fn templates(&self) -> &[(&'static str, &'static str)] {
+        TYPESCRIPT_TEMPLATES
+    }
+
+    /// Type of the generated bindings (`Option<string>`)
+    fn type_name(&self, type_ref: &TypeRef) -> String {
+        ts_bindings::binding_type(type_ref)
+    }
+
+    fn find_collisions(&self, contracts: &[ServedContract]) -> Vec<NameCollision> {
+        names::typescript_collisions(contracts)
+    }
+
+    fn supports_rest(&self) -> bool {
+        true
+    }
+
+    fn render(&self, options: &ProjectOptions) -> Result<GeneratedProject> {
+        McpGenerator::new(
+            options.project_name,
+            options.server_name,
+            options.network,
+            options.contracts,
+            options.templates,
+        )
+        .with_rest(options.with_rest)
+        .render()
+    }
+
+    fn next_steps(&self, output: &Path, http: bool) -> Vec<String> {
+        vec![
+            format!("cd {}", output.display()),
+            "pnpm install".to_string(),
+            "cp .env.example .env && edit .env".to_string(),
+            "pnpm run build".to_string(),
+            if http { "USE_HTTP=true PORT=3000 pnpm start" } else { "pnpm start" }.to_string(),
+        ]
+    }
+}
+
+/// Python server on FastMCP (`PythonGenerator`)
+pub struct PythonBackend;
+
+impl TargetBackend for PythonBackend {
+    fn name(&self) -> &str {
+        "python"
+    }
+
+    fn templates(&self) -> &[(&'static str, &'static str)] {
+        PYTHON_TEMPLATES
+    }
+
+    /// Type hint of the generated bindings (`Optional[str]`)
+    fn type_name(&self, type_ref: &TypeRef) -> String {
+        python_bindings::binding_type(type_ref)
+    }
+
+    fn find_collisions(&self, contracts: &[ServedContract]) -> Vec<NameCollision> {
+        names::python_collisions(contracts)
+    }
+
+    fn tool_naming(&self) -> ToolNaming {
+        ToolNaming::Python
+    }
+
+    fn supports_rest(&self) -> bool {
+        true
+    }
+
+    fn render(&self, options: &ProjectOptions) -> Result<GeneratedProject> {
+        PythonGenerator::new(
+            options.project_name,
+            options.server_name,
+            options.network,
+            options.contracts,
+            options.templates,
+        )
+        .with_rest(options.with_rest)
+        .render()
+    }
+
+    fn next_steps(&self, output: &Path, http: bool) -> Vec<String> {
+        vec![
+            format!("cd {}", output.display()),
+            "uv sync  # Install dependencies".to_string(),
+            "cp .env.example .env && edit .env".to_string(),
+            if http { "USE_HTTP=true PORT=3000 uv run python server.py" } else { "uv run mcp install server.py" }
+                .to_string(),
+        ]
+    }
+}
+
+/// Rust server on the MCP Rust SDK (`RustGenerator`)
+pub struct RustBackend;
+
+impl TargetBackend for RustBackend {
+    fn name(&self) -> &str {
+        "rust"
+    }
+
+    fn templates(&self) -> &[(&'static str, &'static str)] {
+        RUST_TEMPLATES
+    }
+
+    /// `spec::Type` expression the server converts the JSON value with
+    /// (`Type::Vec(&Type::Address)`)
+    fn type_name(&self, type_ref: &TypeRef) -> String {
+        rust_generator::rust_type(type_ref)
+    }
+
+    fn find_collisions(&self, contracts: &[ServedContract]) -> Vec<NameCollision> {
+        names::rust_collisions(contracts)
+    }
+
+    fn render(&self, options: &ProjectOptions) -> Result<GeneratedProject> {
+        RustGenerator::new(
+            options.project_name,
+            options.server_name,
+            options.network,
+            options.contracts,
+            options.templates,
+        )
+        .render()
+    }
+
+    fn next_steps(&self, output: &Path, http: bool) -> Vec<String> {
+        vec![
+            format!("cd {}", output.display()),
+            "cp .env.example .env && edit .env".to_string(),
+            "cargo build --release".to_string(),
+            if http { "USE_HTTP=true PORT=3000 cargo run --release" } else { "cargo run --release" }.to_string(),
+        ]
+    }
+}
+
+/// Go server on the MCP Go SDK (`GoGenerator`)
+pub struct GoBackend;
+
+impl TargetBackend for GoBackend {
+    fn name(&self) -> &str {
+        "go"
+    }
+
+    fn templates(&self) -> &[(&'static str, &'static str)] {
+        GO_TEMPLATES
+    }
+
+    /// `Type` expression the server converts the JSON value with
+    /// (`TVec(TAddress)`)
+    fn type_name(&self, type_ref: &TypeRef) -> String {
+        go_generator::go_type(type_ref)
+    }
+
+    fn find_collisions(&self, contracts: &[ServedContract]) -> Vec<NameCollision> {
+        names::go_collisions(contracts)
+    }
+
+    fn render(&self, options: &ProjectOptions) -> Result<GeneratedProject> {
+        GoGenerator::new(
+            options.project_name,
+            options.server_name,
+            options.network,
+            options.contracts,
+            options.templates,
+        )
+        .render()
+    }
+
+    fn next_steps(&self, output: &Path, http: bool) -> Vec<String> {
+        vec![
+            format!("cd {}", output.display()),
+            "cp .env.example .env && edit .env".to_string(),
+            "go mod tidy  # Resolve dependencies".to_string(),
+            if http { "USE_HTTP=true PORT=3000 go run ." } else { "go run ." }.to_string(),
+        ]
+    }
+}
@@ -0,0 +1,101 @@
+//! Contracts served by a generated MCP server
+//!
+//! A server usually targets one contract. When it serves several (e.g. a token,
+//! an AMM and a vault), each contract's tools are namespaced with its name
+//! (`token.transfer`, `amm.swap`) and its ID is read from its own environment
+//! variable (`TOKEN_CONTRACT_ID`), while the signing and submission tools are
+//! shared by all of them.
+
+use crate::error::{GeneratorError, Result};
+use crate::spec::snapshot::{contract_snapshot_path, SpecSnapshot, SNAPSHOT_PATH};
+use crate::spec::ContractSpec;
+
+/// One contract a generated server exposes tools for
+#[derive(Debug, Clone)]
+pub struct ServedContract {
+    /// Contract name, used for file names and as the tool namespace
+    pub name: String,
+    /// Deployed contract ID (empty when generated from a WASM file before deployment)
+    pub contract_id: String,
+    /// Parsed and classified contract spec
+    pub spec: ContractSpec,
+    /// Hash of the WASM the spec was read from, checked against the deployed contract
+    pub wasm_hash: Option<String>,
+}
+
+impl ServedContract {
+    /// Tool name for this contract: `tool` as-is when the server has a single
+    /// contract, `<name>.<tool>` otherwise
+    pub fn tool_name(&self, tool: &str, namespaced: bool) -> String {
+        if namespaced {
+            format!("{}.{}", self.name, tool)
+        } else {
+            tool.to_string()
+        }
+    }
+
+    /// Environment variable holding the contract ID (`CONTRACT_ID`, or
+    /// `<NAME>_CONTRACT_ID` when the server has several contracts)
+    pub fn env_var(&self, namespaced: bool) -> String {
+        if namespaced {
+            format!("{}_CONTRACT_ID", self.identifier().to_uppercase())
+        } else {
+            "CONTRACT_ID".to_string()
+        }
+    }
+
+    /// Project path of the contract's spec snapshot (`.stellar-mcp/spec.json`,
+    /// or `.stellar-mcp/specs/<name>.json` when the server has several contracts)
+    pub fn snapshot_path(&self, namespaced: bool) -> String {
+        if namespaced {
+            contract_snapshot_path(&self.name)
+        } else {
+            SNAPSHOT_PATH.to_string()
+        }
+    }
+
+    /// Snapshot of the spec the server is generated from, on `network`
+    pub fn snapshot(&self, network: &str) -> SpecSnapshot {
+        SpecSnapshot {
+            generator_version: env!("CARGO_PKG_VERSION").to_string(),
+            contract_id: (!self.contract_id.is_empty()).then(|| self.contract_id.clone()),
+            network: Some(network.to_string()),
+            wasm_hash: self.wasm_hash.clone(),
+            spec: self.spec.clone(),
+        }
+    }
+
+    /// Contract name as a snake_case identifier (`my-token` → `my_token`)
+    pub fn identifier(&self) -> String {
+        self.name.replace('-', "_")
+    }
+}
+
+/// Check that contract names can be used as tool namespaces, file and module
+/// names: lowercase letters, digits, `-` and `_`, starting with a letter, and
+/// unique within the server
+pub fn validate_names(contracts: &[ServedContract]) -> Result<()> {
+    for (i, contract) in contracts.iter().enumerate() {
+        let name = &contract.name;
+        let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid {
+            return Err(GeneratorError::InvalidInput(format!(
+                "Invalid contract name '{}'. Use lowercase letters, digits, '-' and '_', starting with a letter.",
+                name
+            )));
+        }
+        if contracts[..i]
+            .iter()
+            .any(|other| other.identifier() == contract.identifier())
+        {
+            return Err(GeneratorError::InvalidInput(format!(
+                "Contract name '{}' is used more than once",
+                name
+            )));
+        }
+    }
+    Ok(())
+}
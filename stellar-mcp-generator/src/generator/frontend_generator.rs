@@ -1,88 +1,72 @@
 //! Frontend generator for React application
 
+use super::project::GeneratedProject;
 use crate::NetworkConfig;
-use std::fs;
-use std::path::Path;
+use crate::error::Result;
 
 /// Frontend generator for React + use-mcp application
 pub struct FrontendGenerator<'a> {
-    output_dir: &'a Path,
     server_name: &'a str,
-    network: &'a NetworkConfig,
 }
 
 impl<'a> FrontendGenerator<'a> {
-    /// Create a new frontend generator
+    /// Create a new frontend generator. The frontend reads its network from
+    /// the environment at runtime, so `network` is not used.
     pub fn new(
-        output_dir: &'a Path,
         server_name: &'a str,
-        network: &'a NetworkConfig,
+        _network: &'a NetworkConfig,
     ) -> Self {
         Self {
-            output_dir,
             server_name,
-            network,
         }
     }
 
-    /// Generate the frontend application
-    pub fn generate(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let frontend_dir = self.output_dir.join("frontend");
-
-        // Create directory structure
-        self.create_directories(&frontend_dir)?;
+    /// Render every file of the frontend application (under `frontend/`),
+    /// without writing anything
+    pub fn render(&self) -> Result<GeneratedProject> {
+        let mut project = GeneratedProject::new();
 
         // Generate files
-        self.generate_package_json(&frontend_dir)?;
-        self.generate_tsconfig(&frontend_dir)?;
-        self.generate_vite_config(&frontend_dir)?;
-        self.generate_index_html(&frontend_dir)?;
-        self.generate_vite_env_dts(&frontend_dir)?;
+        self.generate_package_json(&mut project)?;
+        self.generate_tsconfig(&mut project)?;
+        self.generate_vite_config(&mut project)?;
+        self.generate_index_html(&mut project)?;
+        self.generate_vite_env_dts(&mut project)?;
 
         // Server files
-        self.generate_server_index(&frontend_dir)?;
-        self.generate_chat_route(&frontend_dir)?;
+        self.generate_server_index(&mut project)?;
+        self.generate_chat_route(&mut project)?;
 
         // Client files
-        self.generate_app_tsx(&frontend_dir)?;
-        self.generate_main_tsx(&frontend_dir)?;
-        self.generate_index_css(&frontend_dir)?;
-        self.generate_mcp_client(&frontend_dir)?;
+        self.generate_app_tsx(&mut project)?;
+        self.generate_main_tsx(&mut project)?;
+        self.generate_index_css(&mut project)?;
+        self.generate_mcp_client(&mut project)?;
 
         // Components
-        self.generate_theme_provider(&frontend_dir)?;
-        self.generate_mode_toggle(&frontend_dir)?;
-        self.generate_chat_interface(&frontend_dir)?;
-        self.generate_message_list(&frontend_dir)?;
-        self.generate_chat_input(&frontend_dir)?;
-        self.generate_suggested_actions(&frontend_dir)?;
-        self.generate_auth_mode_selector(&frontend_dir)?;
-        self.generate_wallet_connector(&frontend_dir)?;
-        self.generate_read_operation_card(&frontend_dir)?;
-        self.generate_write_operation_card(&frontend_dir)?;
-        self.generate_secret_key_sign_card(&frontend_dir)?;
-        self.generate_transaction_executor(&frontend_dir)?;
-        self.generate_contract_tools(&frontend_dir)?;
-        self.generate_tool_executor(&frontend_dir)?;
+        self.generate_theme_provider(&mut project)?;
+        self.generate_mode_toggle(&mut project)?;
+        self.generate_chat_interface(&mut project)?;
+        self.generate_message_list(&mut project)?;
+        self.generate_chat_input(&mut project)?;
+        self.generate_suggested_actions(&mut project)?;
+        self.generate_auth_mode_selector(&mut project)?;
+        self.generate_wallet_connector(&mut project)?;
+        self.generate_read_operation_card(&mut project)?;
+        self.generate_write_operation_card(&mut project)?;
+        self.generate_secret_key_sign_card(&mut project)?;
+        self.generate_transaction_executor(&mut project)?;
+        self.generate_contract_tools(&mut project)?;
+        self.generate_tool_executor(&mut project)?;
 
         // Config files
-        self.generate_env_example(&frontend_dir)?;
-        self.generate_readme(&frontend_dir)?;
-
-        println!("  ✓ Generated React frontend in frontend/");
-
-        Ok(())
-    }
+        self.generate_env_example(&mut project)?;
+        self.generate_readme(&mut project)?;
 
-    fn create_directories(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
-        fs::create_dir_all(base.join("src/client/lib"))?;
-        fs::create_dir_all(base.join("src/client/components"))?;
-        fs::create_dir_all(base.join("src/server/routes"))?;
-        fs::create_dir_all(base.join("public"))?;
-        Ok(())
+        Ok(project)
     }
 
-    fn generate_package_json(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_package_json(&self, project: &mut GeneratedProject) -> Result<()> {
         let deps = serde_json::json!({
             "@ai-sdk/anthropic": "^1.0.13",
             "@ai-sdk/mcp": "^0.0.11",
@@ -141,13 +125,12 @@ impl<'a> FrontendGenerator<'a> {
         });
 
         let content = serde_json::to_string_pretty(&package_json)?;
-        fs::write(base.join("package.json"), content)?;
+        project.add("frontend/package.json", content);
 
-        println!("    Generated frontend/package.json");
         Ok(())
     }
 
-    fn generate_tsconfig(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_tsconfig(&self, project: &mut GeneratedProject) -> Result<()> {
         let tsconfig = serde_json::json!({
             "compilerOptions": {
                 "target": "ES2020",
@@ -171,7 +154,7 @@ impl<'a> FrontendGenerator<'a> {
         });
 
         let content = serde_json::to_string_pretty(&tsconfig)?;
-        fs::write(base.join("tsconfig.json"), content)?;
+        project.add("frontend/tsconfig.json", content);
 
         // Also create tsconfig.node.json for Vite config
         let tsconfig_node = serde_json::json!({
@@ -186,13 +169,12 @@ impl<'a> FrontendGenerator<'a> {
         });
 
         let node_content = serde_json::to_string_pretty(&tsconfig_node)?;
-        fs::write(base.join("tsconfig.node.json"), node_content)?;
+        project.add("frontend/tsconfig.node.json", node_content);
 
-        println!("    Generated frontend/tsconfig.json");
         Ok(())
     }
 
-    fn generate_vite_config(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_vite_config(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { defineConfig } from 'vite'
 import react from '@vitejs/plugin-react'
 import tailwindcss from '@tailwindcss/vite'
@@ -220,12 +202,11 @@ export default defineConfig({
   },
 })
 "#;
-        fs::write(base.join("vite.config.ts"), content)?;
-        println!("    Generated frontend/vite.config.ts");
+        project.add("frontend/vite.config.ts", content);
         Ok(())
     }
 
-    fn generate_vite_env_dts(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_vite_env_dts(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"/// <reference types="vite/client" />
 
 interface ImportMetaEnv {
@@ -237,12 +218,11 @@ interface ImportMeta {
   readonly env: ImportMetaEnv;
 }
 "#;
-        fs::write(base.join("src/vite-env.d.ts"), content)?;
-        println!("    Generated frontend/src/vite-env.d.ts");
+        project.add("frontend/src/vite-env.d.ts", content);
         Ok(())
     }
 
-    fn generate_index_html(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_index_html(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = format!(r#"<!doctype html>
 <html lang="en">
   <head>
@@ -257,12 +237,11 @@ interface ImportMeta {
   </body>
 </html>
 "#, self.server_name);
-        fs::write(base.join("index.html"), content)?;
-        println!("    Generated frontend/index.html");
+        project.add("frontend/index.html", content);
         Ok(())
     }
 
-    fn generate_main_tsx(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_main_tsx(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import React from 'react'
 import ReactDOM from 'react-dom/client'
 import App from './App.tsx'
@@ -277,12 +256,11 @@ ReactDOM.createRoot(document.getElementById('root')!).render(
   </React.StrictMode>,
 )
 "#;
-        fs::write(base.join("src/client/main.tsx"), content)?;
-        println!("    Generated frontend/src/client/main.tsx");
+        project.add("frontend/src/client/main.tsx", content);
         Ok(())
     }
 
-    fn generate_index_css(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_index_css(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"@import "tailwindcss";
 
 @theme {
@@ -392,12 +370,11 @@ ReactDOM.createRoot(document.getElementById('root')!).render(
   }
 }
 "#;
-        fs::write(base.join("src/client/index.css"), content)?;
-        println!("    Generated frontend/src/client/index.css");
+        project.add("frontend/src/client/index.css", content);
         Ok(())
     }
 
-    fn generate_app_tsx(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_app_tsx(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = format!(r#"import {{ useState, useEffect }} from 'react';
 import {{ ChatInterface }} from './components/ChatInterface';
 import {{ WalletConnector }} from './components/WalletConnector';
@@ -495,12 +472,11 @@ export default function App() {{
   );
 }}
 "#, self.server_name);
-        fs::write(base.join("src/client/App.tsx"), content)?;
-        println!("    Generated frontend/src/App.tsx");
+        project.add("frontend/src/client/App.tsx", content);
         Ok(())
     }
 
-    fn generate_mcp_client(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_mcp_client(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useState, useEffect } from 'react';
 import { experimental_createMCPClient as createMCPClient } from '@ai-sdk/mcp';
 
@@ -571,12 +547,11 @@ export function useMcpClient() {
   };
 }
 "#;
-        fs::write(base.join("src/client/lib/mcp-client.ts"), content)?;
-        println!("    Generated frontend/src/client/lib/mcp-client.ts");
+        project.add("frontend/src/client/lib/mcp-client.ts", content);
         Ok(())
     }
 
-    fn generate_theme_provider(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_theme_provider(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { createContext, useContext, useEffect, useState } from "react";
 
 type Theme = "dark" | "light" | "system";
@@ -650,12 +625,11 @@ export const useTheme = () => {
   return context;
 };
 "#;
-        fs::write(base.join("src/client/components/ThemeProvider.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/ThemeProvider.tsx");
+        project.add("frontend/src/client/components/ThemeProvider.tsx", content);
         Ok(())
     }
 
-    fn generate_mode_toggle(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_mode_toggle(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useTheme } from "./ThemeProvider"
 import { motion, AnimatePresence } from "framer-motion"
 
@@ -725,12 +699,11 @@ export function ModeToggle() {
   )
 }
 "#;
-        fs::write(base.join("src/client/components/ModeToggle.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/ModeToggle.tsx");
+        project.add("frontend/src/client/components/ModeToggle.tsx", content);
         Ok(())
     }
 
-    fn generate_suggested_actions(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_suggested_actions(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useState } from 'react';
 import { motion, AnimatePresence } from 'framer-motion';
 
@@ -1034,12 +1007,11 @@ function generatePrompt(tool: Tool): string {
   return name;
 }
 "#;
-        fs::write(base.join("src/client/components/SuggestedActions.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/SuggestedActions.tsx");
+        project.add("frontend/src/client/components/SuggestedActions.tsx", content);
         Ok(())
     }
 
-    fn generate_read_operation_card(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_read_operation_card(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useState } from 'react';
 import { motion, AnimatePresence } from 'framer-motion';
 
@@ -1515,12 +1487,11 @@ export function SignAndSubmitResultCard({ success, txResult }: SignAndSubmitResu
   );
 }
 "#;
-        fs::write(base.join("src/client/components/ReadOperationCard.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/ReadOperationCard.tsx");
+        project.add("frontend/src/client/components/ReadOperationCard.tsx", content);
         Ok(())
     }
 
-    fn generate_write_operation_card(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_write_operation_card(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useState } from 'react';
 
 interface WriteOperationCardProps {
@@ -1749,12 +1720,11 @@ export function WriteOperationCard({
   );
 }
 "#;
-        fs::write(base.join("src/client/components/WriteOperationCard.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/WriteOperationCard.tsx");
+        project.add("frontend/src/client/components/WriteOperationCard.tsx", content);
         Ok(())
     }
 
-    fn generate_transaction_executor(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_transaction_executor(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import {
   StellarWalletsKit,
   WalletNetwork,
@@ -1959,12 +1929,11 @@ export async function executeTransaction({
   }
 }
 "#;
-        fs::write(base.join("src/client/components/TransactionExecutor.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/TransactionExecutor.tsx");
+        project.add("frontend/src/client/components/TransactionExecutor.tsx", content);
         Ok(())
     }
 
-    fn generate_secret_key_sign_card(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_secret_key_sign_card(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useState } from 'react';
 import { motion, AnimatePresence } from 'framer-motion';
 
@@ -2202,12 +2171,11 @@ export function SecretKeySignCard({
   );
 }
 "#;
-        fs::write(base.join("src/client/components/SecretKeySignCard.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/SecretKeySignCard.tsx");
+        project.add("frontend/src/client/components/SecretKeySignCard.tsx", content);
         Ok(())
     }
 
-    fn generate_env_example(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_env_example(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"# ========== AI Provider Configuration ==========
 # Choose your AI provider: 'openai' or 'anthropic'
 AI_PROVIDER=openai
@@ -2233,12 +2201,11 @@ VITE_MCP_SERVER_URL=http://localhost:3000/mcp
 # API endpoint URL (where the AI chat backend runs)
 VITE_API_URL=http://localhost:3001/api/chat
 "#;
-        fs::write(base.join(".env.example"), content)?;
-        println!("    Generated frontend/.env.example");
+        project.add("frontend/.env.example", content);
         Ok(())
     }
 
-    fn generate_auth_mode_selector(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_auth_mode_selector(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { motion } from 'framer-motion';
 
 interface AuthModeSelectorProps {
@@ -2320,12 +2287,11 @@ export function AuthModeSelector({ mode, onModeChange }: AuthModeSelectorProps)
   );
 }
 "#;
-        fs::write(base.join("src/client/components/AuthModeSelector.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/AuthModeSelector.tsx");
+        project.add("frontend/src/client/components/AuthModeSelector.tsx", content);
         Ok(())
     }
 
-    fn generate_wallet_connector(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_wallet_connector(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useState } from 'react';
 import { StellarWalletsKit, WalletNetwork, allowAllModules, ISupportedWallet } from '@creit.tech/stellar-wallets-kit';
 
@@ -2388,12 +2354,11 @@ export function WalletConnector({ walletAddress, onConnect, onDisconnect }: Wall
   );
 }
 "#;
-        fs::write(base.join("src/client/components/WalletConnector.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/WalletConnector.tsx");
+        project.add("frontend/src/client/components/WalletConnector.tsx", content);
         Ok(())
     }
 
-    fn generate_contract_tools(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_contract_tools(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useState } from 'react';
 import { ToolExecutor } from './ToolExecutor';
 
@@ -2444,12 +2409,11 @@ export function ContractTools({
   );
 }
 "#;
-        fs::write(base.join("src/client/components/ContractTools.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/ContractTools.tsx");
+        project.add("frontend/src/client/components/ContractTools.tsx", content);
         Ok(())
     }
 
-    fn generate_tool_executor(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_tool_executor(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useState } from 'react';
 import { useMcpClient } from '../lib/mcp-client';
 import { StellarWalletsKit, WalletNetwork, allowAllModules } from '@creit.tech/stellar-wallets-kit';
@@ -2486,6 +2450,12 @@ export function ToolExecutor({
       const toolResult = await executeTool(tool.name, params);
       const { xdr, simulationResult } = toolResult;
 
+      // Read-only tools return the decoded result directly: nothing to sign
+      if (!xdr) {
+        setResult(toolResult);
+        return;
+      }
+
       if (authMode === 'secret') {
         // Secret Key Mode: Use sign-and-submit MCP tool
         const submitResult = await executeTool('sign-and-submit', {
@@ -2593,14 +2563,13 @@ export function ToolExecutor({
   );
 }
 "#;
-        fs::write(base.join("src/client/components/ToolExecutor.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/ToolExecutor.tsx");
+        project.add("frontend/src/client/components/ToolExecutor.tsx", content);
         Ok(())
     }
 
     // ========== SERVER FILES ==========
 
-    fn generate_server_index(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_server_index(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import express from 'express';
 import cors from 'cors';
 import dotenv from 'dotenv';
@@ -2652,12 +2621,11 @@ app.listen(PORT, () => {
   console.log(`   Chat endpoint: http://localhost:${PORT}/api/chat`);
 });
 "#;
-        fs::write(base.join("src/server/index.ts"), content)?;
-        println!("    Generated frontend/src/server/index.ts");
+        project.add("frontend/src/server/index.ts", content);
         Ok(())
     }
 
-    fn generate_chat_route(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_chat_route(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r##"import { Router } from 'express';
 import { openai } from '@ai-sdk/openai';
 import { anthropic } from '@ai-sdk/anthropic';
@@ -3227,14 +3195,13 @@ chatRouter.post('/chat', async (req, res) => {
 });
 
 "##;
-        fs::write(base.join("src/server/routes/chat.ts"), content)?;
-        println!("    Generated frontend/src/server/routes/chat.ts");
+        project.add("frontend/src/server/routes/chat.ts", content);
         Ok(())
     }
 
     // ========== CLIENT CHAT COMPONENTS ==========
 
-    fn generate_chat_interface(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_chat_interface(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { useChat } from 'ai/react';
 import { useState, useEffect } from 'react';
 import { MessageList } from './MessageList';
@@ -3547,12 +3514,11 @@ export function ChatInterface({ authMode, walletAddress }: ChatInterfaceProps) {
   );
 }
 "#;
-        fs::write(base.join("src/client/components/ChatInterface.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/ChatInterface.tsx");
+        project.add("frontend/src/client/components/ChatInterface.tsx", content);
         Ok(())
     }
 
-    fn generate_message_list(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_message_list(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import ReactMarkdown from 'react-markdown';
 import remarkGfm from 'remark-gfm';
 import { Message } from 'ai';
@@ -3784,12 +3750,11 @@ export function MessageList({ messages, walletAddress, isLoading, authMode: _aut
   );
 }
 "#;
-        fs::write(base.join("src/client/components/MessageList.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/MessageList.tsx");
+        project.add("frontend/src/client/components/MessageList.tsx", content);
         Ok(())
     }
 
-    fn generate_chat_input(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_chat_input(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = r#"import { FormEvent, ChangeEvent } from 'react';
 
 interface ChatInputProps {
@@ -3876,12 +3841,11 @@ export function ChatInput({
   );
 }
 "#;
-        fs::write(base.join("src/client/components/ChatInput.tsx"), content)?;
-        println!("    Generated frontend/src/client/components/ChatInput.tsx");
+        project.add("frontend/src/client/components/ChatInput.tsx", content);
         Ok(())
     }
 
-    fn generate_readme(&self, base: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_readme(&self, project: &mut GeneratedProject) -> Result<()> {
         let content = format!(r#"# {} Frontend
 
 AI-powered React frontend for interacting with the {} Stellar smart contract via MCP.
@@ -4121,8 +4085,7 @@ pnpm typecheck        # Check TypeScript errors
 
 [stellar-mcp-generator](https://github.com/stellar/stellar-mcp-generator)
 "#, self.server_name, self.server_name);
-        fs::write(base.join("README.md"), content)?;
-        println!("    Generated frontend/README.md");
+        project.add("frontend/README.md", content);
         Ok(())
     }
 }
@@ -0,0 +1,295 @@
+//! Go MCP Server generator implementation
+//!
+//! The generated server is a single `main` package on the MCP Go SDK. Like
+//! the Rust server, it registers the tools listed in `tools.json`, which it
+//! embeds, validates each call against the tool's JSON Schema and converts
+//! the arguments to Soroban values following the contract spec, emitted as
+//! tables in `contracts.go` (`spec.go` expressions like `TOption(TAddress)`).
+
+use super::backend::{project_data, render_template_files, GoBackend, TargetBackend};
+use super::contracts::ServedContract;
+use super::project::GeneratedProject;
+use super::python_bindings::is_tuple_struct;
+use super::rust_generator::{events_catalogue, events_uri, to_rust_package_name};
+use super::template_data::to_kebab_case;
+use super::templates::TemplateRenderer;
+use super::tool_manifest::{render_tool_manifest, TOOL_MANIFEST_PATH};
+use crate::codegen::emit::go_string;
+use crate::error::Result;
+use crate::spec::{EventDataFormat, FieldSpec, FunctionKind, TypeDef, TypeRef};
+use crate::NetworkConfig;
+use std::collections::HashSet;
+
+/// Project files rendered as-is from a template with the project data:
+/// `(template, path)`
+const TEMPLATE_FILES: &[(&str, &str)] = &[
+    ("go/go.mod", "go.mod"),
+    ("go/env.example", ".env.example"),
+    ("go/Dockerfile", "Dockerfile"),
+    ("go/dockerignore", ".dockerignore"),
+];
+
+/// Files of the server that don't depend on the contracts: `(template, path)`
+const SOURCE_FILES: &[(&str, &str)] = &[
+    ("go/main.go", "main.go"),
+    ("go/server.go", "server.go"),
+    ("go/spec.go", "spec.go"),
+    ("go/scval.go", "scval.go"),
+    ("go/rpc.go", "rpc.go"),
+    ("go/transaction.go", "transaction.go"),
+    ("go/contract_info.go", "contract_info.go"),
+    ("go/errors.go", "errors.go"),
+];
+
+/// Go MCP Server generator
+pub struct GoGenerator<'a> {
+    /// Project name (the contract name for single-contract servers)
+    project_name: &'a str,
+    server_name: &'a str,
+    network: &'a NetworkConfig,
+    /// Contracts the server exposes tools for
+    contracts: &'a [ServedContract],
+    templates: &'a TemplateRenderer,
+}
+
+impl<'a> GoGenerator<'a> {
+    /// Create a new Go generator
+    pub fn new(
+        project_name: &'a str,
+        server_name: &'a str,
+        network: &'a NetworkConfig,
+        contracts: &'a [ServedContract],
+        templates: &'a TemplateRenderer,
+    ) -> Self {
+        Self {
+            project_name,
+            server_name,
+            network,
+            contracts,
+            templates,
+        }
+    }
+
+    /// Render every file of the Go MCP server, without writing anything
+    pub fn render(&self) -> Result<GeneratedProject> {
+        let mut project = GeneratedProject::new();
+
+        let data = self.create_template_data();
+        render_template_files(&mut project, self.templates, SOURCE_FILES, &data)?;
+        project.add("contracts.go", self.templates.render("go/contracts.go", &self.create_contracts_data()?)?);
+        project.add("README.md", self.templates.render("go/README.md", &data)?);
+
+        let mut project_data = project_data(self.project_name, self.server_name, self.network, self.contracts);
+        project_data["package_name"] = data["package_name"].clone();
+        render_template_files(&mut project, self.templates, TEMPLATE_FILES, &project_data)?;
+        project.add(TOOL_MANIFEST_PATH, render_tool_manifest(&GoBackend, self.contracts));
+
+        Ok(project)
+    }
+
+    /// Whether tools are namespaced by contract (servers with several contracts)
+    fn namespaced(&self) -> bool {
+        self.contracts.len() > 1
+    }
+
+    /// Data for the server sources and the README: names, network settings
+    /// and each contract's tools with their types
+    fn create_template_data(&self) -> serde_json::Value {
+        let namespaced = self.namespaced();
+        let contracts: Vec<_> = self.contracts.iter().map(|contract| {
+            let spec = &contract.spec;
+            let field = |f: &FieldSpec| serde_json::json!({
+                "name": f.name,
+                "type_name": f.type_ref.to_typescript(),
+            });
+            let functions: Vec<_> = spec.functions.iter().map(|f| {
+                let inputs: Vec<_> = f.inputs.iter().map(|input| serde_json::json!({
+                    "name": input.name,
+                    "type_name": input.type_ref.to_typescript(),
+                    "doc": input.doc,
+                })).collect();
+                serde_json::json!({
+                    "name": f.name,
+                    "tool_name": contract.tool_name(&to_kebab_case(&f.name), namespaced),
+                    "doc": f.doc.clone().unwrap_or_else(|| format!("Call {} function", f.name)),
+                    "inputs": inputs,
+                    "output_type": f.output.as_ref().unwrap_or(&TypeRef::Void).to_typescript(),
+                    "read_only": f.kind == FunctionKind::ReadOnly,
+                })
+            }).collect();
+            let events: Vec<_> = spec.events.iter().map(|event| serde_json::json!({
+                "name": event.name,
+                "tool_name": contract.tool_name(&format!("get-{}-events", to_kebab_case(&event.name)), namespaced),
+                "doc": event.doc,
+                "topics": event.topics.iter().map(field).collect::<Vec<_>>(),
+                "data": event.data.iter().map(field).collect::<Vec<_>>(),
+            })).collect();
+
+            serde_json::json!({
+                "name": contract.name,
+                "contract_id": contract.contract_id,
+                "env_var": contract.env_var(namespaced),
+                "tool_prefix": contract.tool_name("", namespaced),
+                "events_uri": events_uri(contract, namespaced),
+                "functions": functions,
+                "events": events,
+            })
+        }).collect();
+        let names: Vec<&str> = self.contracts.iter().map(|c| c.name.as_str()).collect();
+
+        serde_json::json!({
+            "contract_name": self.project_name,
+            "contract_names": names.join(", "),
+            "package_name": to_rust_package_name(self.project_name),
+            "server_name": self.server_name,
+            "network_name": self.network.name,
+            "rpc_url": self.network.rpc_url,
+            "network_passphrase": self.network.network_passphrase,
+            "multi": self.namespaced(),
+            "contracts": contracts,
+            "version": env!("CARGO_PKG_VERSION"),
+        })
+    }
+
+    /// Data for `contracts.go`: every value is a Go expression
+    fn create_contracts_data(&self) -> Result<serde_json::Value> {
+        let namespaced = self.namespaced();
+        let contracts = self
+            .contracts
+            .iter()
+            .map(|contract| {
+                let spec = &contract.spec;
+                let types: Vec<_> = spec.types.iter().map(|t| serde_json::json!({
+                    "name": go_string(&t.name),
+                    "def": go_type_def(&t.definition),
+                })).collect();
+                let functions: Vec<_> = spec.functions.iter().map(|f| serde_json::json!({
+                    "name": go_string(&f.name),
+                    "inputs": go_fields(f.inputs.iter().map(|p| (p.name.as_str(), &p.type_ref))),
+                    "output": GoBackend.type_name(f.output.as_ref().unwrap_or(&TypeRef::Void)),
+                    "read_only": f.kind == FunctionKind::ReadOnly,
+                })).collect();
+                let events: Vec<_> = spec.events.iter().map(|event| {
+                    let prefix_topics: Vec<String> = event.prefix_topics.iter().map(|t| go_string(t)).collect();
+                    serde_json::json!({
+                        "name": go_string(&event.name),
+                        "prefix_topics": format!("[]string{{{}}}", prefix_topics.join(", ")),
+                        "topics": go_fields(event.topics.iter().map(|f| (f.name.as_str(), &f.type_ref))),
+                        "data": go_fields(event.data.iter().map(|f| (f.name.as_str(), &f.type_ref))),
+                        "data_format": match event.data_format {
+                            EventDataFormat::SingleValue => "DataSingleValue",
+                            EventDataFormat::Vec => "DataVec",
+                            EventDataFormat::Map => "DataMap",
+                        },
+                    })
+                }).collect();
+                // A code declared by several error enums keeps the first case
+                let mut codes = HashSet::new();
+                let errors: Vec<_> = spec.error_cases().filter(|e| codes.insert(e.code)).map(|e| serde_json::json!({
+                    "code": e.code,
+                    "name": go_string(&e.name),
+                    "doc": go_string(e.doc.as_deref().map(str::trim).unwrap_or_default()),
+                })).collect();
+
+                Ok(serde_json::json!({
+                    "name": go_string(&contract.name),
+                    "env_var": go_string(&contract.env_var(namespaced)),
+                    "contract_id": go_string(&contract.contract_id),
+                    "info_tool": go_string(&contract.tool_name("contract-info", namespaced)),
+                    "events_uri": go_string(&events_uri(contract, namespaced)),
+                    "events_catalogue": go_string(&serde_json::to_string(&events_catalogue(contract, namespaced))?),
+                    "wasm_hash": go_string(contract.wasm_hash.as_deref().unwrap_or_default()),
+                    "types": types,
+                    "functions": functions,
+                    "events": events,
+                    "errors": errors,
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "network_name": go_string(&self.network.name),
+            "rpc_url": go_string(&self.network.rpc_url),
+            "network_passphrase": go_string(&self.network.network_passphrase),
+            "contracts": contracts,
+        }))
+    }
+}
+
+/// `Type` expression describing a type in the generated server
+/// (`TVec(TAddress)`)
+pub(crate) fn go_type(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Bool => "TBool".to_string(),
+        TypeRef::Void => "TVoid".to_string(),
+        // Status values travel as their code, like a u32
+        TypeRef::Status | TypeRef::U32 => "TU32".to_string(),
+        TypeRef::I32 => "TI32".to_string(),
+        TypeRef::U64 => "TU64".to_string(),
+        TypeRef::I64 => "TI64".to_string(),
+        TypeRef::Timepoint => "TTimepoint".to_string(),
+        TypeRef::Duration => "TDuration".to_string(),
+        TypeRef::U128 => "TU128".to_string(),
+        TypeRef::I128 => "TI128".to_string(),
+        TypeRef::U256 => "TU256".to_string(),
+        TypeRef::I256 => "TI256".to_string(),
+        TypeRef::Bytes => "TBytes".to_string(),
+        TypeRef::BytesN(n) => format!("TBytesN({})", n),
+        TypeRef::String => "TString".to_string(),
+        TypeRef::Symbol => "TSymbol".to_string(),
+        TypeRef::Address => "TAddress".to_string(),
+        TypeRef::Option(inner) => format!("TOption({})", go_type(inner)),
+        TypeRef::Result { ok, .. } => format!("TResult({})", go_type(ok)),
+        TypeRef::Vec(inner) => format!("TVec({})", go_type(inner)),
+        TypeRef::Map { key, value } => format!("TMap({}, {})", go_type(key), go_type(value)),
+        TypeRef::Tuple(types) => {
+            let types: Vec<String> = types.iter().map(go_type).collect();
+            format!("TTuple({})", types.join(", "))
+        }
+        TypeRef::Custom(name) => format!("TCustom({})", go_string(name)),
+    }
+}
+
+/// `TypeDef` expression for a contract type
+fn go_type_def(definition: &TypeDef) -> String {
+    match definition {
+        TypeDef::Struct { fields } if is_tuple_struct(fields) => {
+            let types: Vec<String> = fields.iter().map(|f| GoBackend.type_name(&f.type_ref)).collect();
+            format!("TupleStructDef({})", types.join(", "))
+        }
+        TypeDef::Struct { fields } => {
+            let fields: Vec<String> = fields.iter().map(|f| go_field(&f.name, &f.type_ref)).collect();
+            format!("StructDef({})", fields.join(", "))
+        }
+        TypeDef::Enum { variants } => {
+            let variants: Vec<String> =
+                variants.iter().map(|v| format!("Variant{{{}, {}}}", go_string(&v.name), v.value)).collect();
+            format!("EnumDef({})", variants.join(", "))
+        }
+        TypeDef::Union { cases } => {
+            let cases: Vec<String> = cases
+                .iter()
+                .map(|c| {
+                    let payload = match &c.type_ref {
+                        Some(type_ref) => format!("Payload({})", GoBackend.type_name(type_ref)),
+                        None => "nil".to_string(),
+                    };
+                    format!("Case{{{}, {}}}", go_string(&c.name), payload)
+                })
+                .collect();
+            format!("UnionDef({})", cases.join(", "))
+        }
+    }
+}
+
+/// `Field` expression for a named value
+fn go_field(name: &str, type_ref: &TypeRef) -> String {
+    format!("Field{{{}, {}}}", go_string(name), GoBackend.type_name(type_ref))
+}
+
+/// `[]Field` expression for named values
+fn go_fields<'t>(fields: impl Iterator<Item = (&'t str, &'t TypeRef)>) -> String {
+    let fields: Vec<String> = fields.map(|(name, type_ref)| format!("{{{}, {}}}", go_string(name), GoBackend.type_name(type_ref))).collect();
+    format!("[]Field{{{}}}", fields.join(", "))
+}
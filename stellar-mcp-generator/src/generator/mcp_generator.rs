@@ -1,31 +1,29 @@
 //! MCP Server generator implementation
 
+use super::backend::{project_data, render_template_files, TypeScriptBackend};
+use super::contracts::ServedContract;
+use super::openapi::{render_openapi_document, OPENAPI_PATH};
+use super::project::GeneratedProject;
 use super::template_data::*;
-use crate::commands::generate::GenerateArgs;
-use crate::spec::{ContractSpec, TypeDef, TypeRef};
+use super::templates::TemplateRenderer;
+use super::tool_manifest::{render_tool_manifest, TOOL_MANIFEST_PATH};
+use super::ts_bindings::render_ts_bindings;
+use crate::codegen::emit::{
+    ts_comment_text, ts_identifier, ts_json, ts_object_key, ts_property, ts_string, ts_string_body,
+};
+use crate::error::Result;
+use crate::spec::json_types;
+use crate::spec::{
+    ContractSpec, EventDataFormat, EventSpec, FunctionKind, TypeDef, TypeRef, TypeSpec, UnionCase,
+};
 use crate::NetworkConfig;
-use std::fs;
-use std::path::Path;
+use std::collections::HashSet;
 
 /// Convert a TypeRef to a Zod schema string for use in outputSchema.
 /// Unlike `TypeRef::to_zod()`, Custom types are prefixed with the `schemas.`
 /// namespace used in generated `index.ts` (e.g. `schemas.TokenConfigSchema`).
 pub fn output_zod_for_type(type_ref: &TypeRef) -> String {
-    match type_ref {
-        TypeRef::Custom(name) => format!("schemas.{}Schema", name),
-        TypeRef::Option(inner) => format!("{}.nullable()", output_zod_for_type(inner)),
-        TypeRef::Vec(inner) => format!("z.array({})", output_zod_for_type(inner)),
-        TypeRef::Map { key, value } => {
-            format!("z.map({}, {})", output_zod_for_type(key), output_zod_for_type(value))
-        }
-        TypeRef::Tuple(types) => {
-            let zods: Vec<String> = types.iter().map(|t| output_zod_for_type(t)).collect();
-            format!("z.tuple([{}])", zods.join(", "))
-        }
-        TypeRef::Result { ok, .. } => output_zod_for_type(ok),
-        // All primitive types are fine as-is from to_zod()
-        _ => type_ref.to_zod(),
-    }
+    json_types::zod(&type_ref.to_json_schema(), "schemas.")
 }
 
 /// Build the Zod raw shape string for the `outputSchema` of a generated tool.
@@ -44,1888 +42,872 @@ pub fn output_schema_raw_shape(output: &Option<TypeRef>) -> String {
     }
 }
 
-/// MCP Server generator
-pub struct McpGenerator<'a> {
-    output_dir: &'a Path,
-    contract_name: &'a str,
-    server_name: &'a str,
-    contract_id: &'a str,
-    network: &'a NetworkConfig,
+/// Build the Zod schema declaration for a contract union type.
+/// Cases are discriminated on `tag` to match the official bindings format:
+/// unit cases carry only the tag, tuple cases carry a `values` tuple typed
+/// from the case payload (e.g. `{ tag: 'Ed25519', values: [<hex>] }`).
+pub fn union_schema(type_name: &str, cases: &[UnionCase]) -> String {
+    let type_spec = TypeSpec {
+        name: type_name.to_string(),
+        doc: None,
+        definition: TypeDef::Union { cases: cases.to_vec() },
+    };
+    let mut content = format!(
+        "export const {}Schema = z.discriminatedUnion('tag', [\n",
+        to_pascal_case(type_name)
+    );
+    for case in type_spec.to_json_schema()["oneOf"].as_array().into_iter().flatten() {
+        content.push_str(&format!("  {},\n", json_types::zod(case, "")));
+    }
+    content.push_str("]);\n\n");
+    content
 }
 
-impl<'a> McpGenerator<'a> {
-    /// Create a new generator
-    pub fn new(
-        output_dir: &'a Path,
-        contract_name: &'a str,
-        server_name: &'a str,
-        contract_id: &'a str,
-        network: &'a NetworkConfig,
-    ) -> Self {
-        Self {
-            output_dir,
-            contract_name,
-            server_name,
-            contract_id,
-            network,
+/// Zod schema for a decoded event field.
+/// Event values are normalised to plain JSON before validation (bigint → string,
+/// bytes → hex, Map → object), so maps become records and custom types, whose
+/// `scValToNative` shape differs from the input schemas, stay `z.unknown()`.
+fn event_field_zod(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Custom(_) | TypeRef::Map { .. } => "z.unknown()".to_string(),
+        TypeRef::Void => "z.null()".to_string(),
+        TypeRef::Option(inner) => format!("{}.nullable()", event_field_zod(inner)),
+        TypeRef::Vec(inner) => format!("z.array({})", event_field_zod(inner)),
+        TypeRef::Tuple(types) => {
+            let zods: Vec<String> = types.iter().map(event_field_zod).collect();
+            format!("z.tuple([{}])", zods.join(", "))
         }
+        TypeRef::Result { ok, .. } => event_field_zod(ok),
+        _ => type_ref.to_zod(),
     }
+}
 
-    /// Generate the MCP server
-    pub fn generate(
-        &self,
-        spec: &ContractSpec,
-        args: &GenerateArgs,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        // Create directory structure
-        self.create_directories()?;
-
-        // Generate official Stellar TypeScript bindings first
-        self.generate_official_bindings()?;
-
-        // Generate files
-        self.generate_index_ts(spec, args)?;
-        self.generate_tools_ts(spec)?;
-        self.generate_schemas_ts(spec)?;
-        self.generate_lib_files(args)?;
-        self.generate_deploy_wallet(args)?;
-        self.generate_package_json(args)?;
-        self.generate_tsconfig()?;
-        self.generate_env_example(args)?;
-        self.generate_dockerfile()?;
-        self.generate_dockerignore()?;
-        self.generate_vercel_json()?;
-        self.generate_readme(spec, args)?;
-
-        Ok(())
-    }
-
-    fn create_directories(&self) -> Result<(), Box<dyn std::error::Error>> {
-        fs::create_dir_all(self.output_dir.join("src/tools"))?;
-        fs::create_dir_all(self.output_dir.join("src/schemas"))?;
-        fs::create_dir_all(self.output_dir.join("src/lib"))?;
-        fs::create_dir_all(self.output_dir.join("src/bindings"))?;
-        // Note: src/types removed - using official Stellar bindings instead
-        Ok(())
+/// `nativeToScVal` type hint for a topic parameter that can be used as a
+/// `getEvents` filter. Returns `None` for types that are matched with `*`.
+pub(crate) fn event_topic_scval_type(type_ref: &TypeRef) -> Option<&'static str> {
+    match type_ref {
+        TypeRef::Bool => Some("bool"),
+        TypeRef::U32 => Some("u32"),
+        TypeRef::I32 => Some("i32"),
+        TypeRef::U64 => Some("u64"),
+        TypeRef::I64 => Some("i64"),
+        TypeRef::U128 => Some("u128"),
+        TypeRef::I128 => Some("i128"),
+        TypeRef::U256 => Some("u256"),
+        TypeRef::I256 => Some("i256"),
+        TypeRef::String => Some("string"),
+        TypeRef::Symbol => Some("symbol"),
+        TypeRef::Address => Some("address"),
+        _ => None,
     }
+}
 
-    /// Generate official Stellar TypeScript bindings using stellar CLI
-    fn generate_official_bindings(&self) -> Result<(), Box<dyn std::error::Error>> {
-        use std::process::Command;
-
-        println!("  Generating official Stellar TypeScript bindings...");
-
-        let bindings_dir = self.output_dir.join("src/bindings");
-
-        // Call stellar contract bindings typescript
-        let output = Command::new("stellar")
-            .arg("contract")
-            .arg("bindings")
-            .arg("typescript")
-            .arg("--contract-id")
-            .arg(self.contract_id)
-            .arg("--output-dir")
-            .arg(&bindings_dir)
-            .arg("--overwrite")
-            .arg("--rpc-url")
-            .arg(&self.network.rpc_url)
-            .arg("--network-passphrase")
-            .arg(&self.network.network_passphrase)
-            .output()?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("Failed to generate Stellar bindings: {}", stderr).into());
-        }
-
-        println!("  ✓ Generated official bindings in src/bindings/");
-
-        Ok(())
+/// `dataFormat` of an event in the event catalogues
+pub(crate) fn event_data_format_str(format: EventDataFormat) -> &'static str {
+    match format {
+        EventDataFormat::SingleValue => "single-value",
+        EventDataFormat::Vec => "vec",
+        EventDataFormat::Map => "map",
     }
+}
 
-    fn generate_index_ts(
-        &self,
-        spec: &ContractSpec,
-        args: &GenerateArgs,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let functions: Vec<FunctionTemplateData> = spec
-            .functions
-            .iter()
-            .map(|f| FunctionTemplateData {
-                name: f.name.clone(),
-                name_kebab: to_kebab_case(&f.name),
-                name_camel: to_camel_case(&f.name),
-                doc: f.doc.clone().unwrap_or_else(|| format!("Call {} function", f.name)),
-                inputs: f
-                    .inputs
+/// Custom types whose MCP input shape differs from what the official bindings
+/// expect, i.e. types that (transitively) contain integers wider than 32 bits,
+/// bytes, options or enums. Computed as a fixed point so recursive types work.
+pub(crate) fn converted_types(types: &[TypeSpec]) -> HashSet<String> {
+    let mut converted = HashSet::new();
+    loop {
+        let mut changed = false;
+        for type_spec in types {
+            if converted.contains(&type_spec.name) {
+                continue;
+            }
+            let needs_conversion = match &type_spec.definition {
+                TypeDef::Struct { fields } => fields
                     .iter()
-                    .enumerate()
-                    .map(|(i, p)| InputTemplateData {
-                        name: p.name.clone(),
-                        name_camel: to_camel_case(&p.name),
-                        doc: p.doc.clone().unwrap_or_default(),
-                        ts_type: p.type_ref.to_typescript(),
-                        zod_type: p.type_ref.to_zod(),
-                        is_last: i == f.inputs.len() - 1,
-                    })
-                    .collect(),
-                has_inputs: !f.inputs.is_empty(),
-                output_type: f
-                    .output
-                    .as_ref()
-                    .map(|t| t.to_typescript())
-                    .unwrap_or_else(|| "void".to_string()),
-                has_output: f.output.is_some(),
-                output_zod: output_schema_raw_shape(&f.output),
-            })
-            .collect();
-
-        let content = self.render_index_template(&functions, args)?;
-        fs::write(self.output_dir.join("src/index.ts"), content)?;
-
-        println!("  Generated src/index.ts");
-        Ok(())
-    }
-
-    fn render_index_template(
-        &self,
-        functions: &[FunctionTemplateData],
-        _args: &GenerateArgs,
-    ) -> Result<String, Box<dyn std::error::Error>> {
-        let mut content = String::new();
-
-        // Imports (shebang added by esbuild via --banner)
-        content.push_str("import { McpServer } from '@modelcontextprotocol/sdk/server/mcp.js';\n");
-        content.push_str("import { StdioServerTransport } from '@modelcontextprotocol/sdk/server/stdio.js';\n");
-        content.push_str("import { StreamableHTTPServerTransport } from '@modelcontextprotocol/sdk/server/streamableHttp.js';\n");
-        content.push_str("import { z } from 'zod';\n");
-        content.push_str("import express from 'express';\n");
-        content.push_str("import cors from 'cors';\n");
-        content.push_str(&format!("import * as tools from './tools/{}.js';\n", self.contract_name));
-        content.push_str(&format!("import * as schemas from './schemas/{}.js';\n", self.contract_name));
-
-        // Import transaction helpers
-        content.push_str("import { submitTransaction } from './lib/submit.js';\n");
-        content.push_str("import { prepareTransactionForWallet, signTransaction } from './lib/utils.js';\n");
-        content.push_str("import { signAndSendWithPasskey } from './lib/passkey.js';\n");
-        content.push_str("import { log, jsonStringify } from './lib/logger.js';\n");
-        content.push_str("import { formatToolError } from './lib/errors.js';\n");
-        content.push_str("\n");
-
-        // Server configuration
-        content.push_str("// Configuration from environment\n");
-        content.push_str(&format!("const CONTRACT_ID = process.env.CONTRACT_ID || '{}';\n", self.contract_id));
-        content.push_str(&format!("const RPC_URL = process.env.RPC_URL || '{}';\n", self.network.rpc_url));
-        content.push_str(&format!("const NETWORK_PASSPHRASE = process.env.NETWORK_PASSPHRASE || '{}';\n", self.network.network_passphrase));
-        content.push_str("\n");
-
-        // jsonStringify and log are imported from ./lib/logger.js
-        // formatToolError is imported from ./lib/errors.js
-
-
-        // Factory function to create MCP server instance
-        content.push_str("// Factory function to create and configure an MCP server instance\n");
-        content.push_str("function createMcpServer(): McpServer {\n");
-        content.push_str("  const server = new McpServer({\n");
-        content.push_str(&format!("    name: '{}-mcp',\n", self.server_name));
-        content.push_str("    version: '1.0.0',\n");
-        content.push_str("  });\n\n");
-        content.push_str("  // Register all tools on this server instance\n");
-        content.push_str("  registerTools(server);\n\n");
-        content.push_str("  return server;\n");
-        content.push_str("}\n\n");
-
-        // Start registerTools function
-        content.push_str("// Function to register all tools on a server instance\n");
-        content.push_str("function registerTools(server: McpServer): void {\n\n");
-
-        // Generate tool registrations
-        for func in functions {
-            content.push_str(&format!("// Tool: {}\n", func.name));
-            content.push_str("server.registerTool(\n");
-            content.push_str(&format!("  '{}',\n", func.name_kebab));
-            // Escape newlines and single quotes for JavaScript string
-            let escaped_doc = func.doc
-                .replace('\\', "\\\\")
-                .replace('\'', "\\'")
-                .replace('\n', "\\n");
-
-            // Config object
-            content.push_str("  {\n");
-            content.push_str(&format!("    description: '{}',\n", escaped_doc));
-
-            // inputSchema
-            if func.has_inputs {
-                content.push_str("    inputSchema: {\n");
-                for input in &func.inputs {
-                    // If zod_type references a custom schema (ends with Schema and starts with uppercase),
-                    // prefix with schemas. namespace
-                    let zod_ref = if input.zod_type.ends_with("Schema") &&
-                        input.zod_type.chars().next().map(|c| c.is_uppercase()).unwrap_or(false) {
-                        format!("schemas.{}", input.zod_type)
-                    } else {
-                        input.zod_type.clone()
-                    };
-                    // Use snake_case field names to match official Stellar bindings
-                    content.push_str(&format!(
-                        "      {}: {}.describe('{}'),\n",
-                        input.name,
-                        zod_ref,
-                        input.doc.replace('\'', "\\'")
-                    ));
-                }
-                content.push_str("    },\n");
-            } else {
-                content.push_str("    inputSchema: {},\n");
+                    .any(|f| convert_expr(&f.type_ref, "v", &converted, 0).is_some()),
+                TypeDef::Enum { variants } => !variants.is_empty(),
+                TypeDef::Union { cases } => cases.iter().any(|c| {
+                    c.type_ref
+                        .as_ref()
+                        .is_some_and(|t| convert_expr(t, "v", &converted, 0).is_some())
+                }),
+            };
+            if needs_conversion {
+                converted.insert(type_spec.name.clone());
+                changed = true;
             }
-
-            // outputSchema (raw shape, not wrapped in z.object())
-            content.push_str(&format!("    outputSchema: {},\n", func.output_zod));
-            content.push_str("  },\n");
-
-            // Handler
-            content.push_str("  async (params) => {\n");
-            content.push_str(&format!("    log('{}', 'info', 'called', params);\n", func.name_kebab));
-            content.push_str("    try {\n");
-            content.push_str(&format!(
-                "      const result = await tools.{}(params, {{\n",
-                func.name_camel
-            ));
-            content.push_str("        contractId: CONTRACT_ID,\n");
-            content.push_str("        rpcUrl: RPC_URL,\n");
-            content.push_str("        networkPassphrase: NETWORK_PASSPHRASE,\n");
-            content.push_str("      });\n");
-            content.push_str(&format!("      log('{}', 'info', 'success', result);\n", func.name_kebab));
-            content.push_str("\n");
-            content.push_str("      return {\n");
-            content.push_str("        content: [{\n");
-            content.push_str("          type: 'text',\n");
-            content.push_str("          text: jsonStringify(result, 2),\n");
-            content.push_str("        }],\n");
-            content.push_str("        structuredContent: JSON.parse(jsonStringify(result)),\n");
-            content.push_str("      };\n");
-            content.push_str("    } catch (error) {\n");
-            content.push_str(&format!("      return formatToolError('{}', error);\n", func.name_kebab));
-            content.push_str("    }\n");
-            content.push_str("  }\n");
-            content.push_str(");\n\n");
         }
-
-        // Sign and submit tool
-        content.push_str("// Tool: sign-and-submit\n");
-        content.push_str("server.registerTool(\n");
-        content.push_str("  'sign-and-submit',\n");
-        content.push_str("  {\n");
-        content.push_str("    description: 'Sign a transaction XDR and submit to the network. Use walletContractId for passkey smart wallet signing (requires WALLET_SIGNER_SECRET env var), or secretKey for regular keypair signing. secretKey is always required as fee payer.',\n");
-        content.push_str("    inputSchema: {\n");
-        content.push_str("      xdr: z.string().describe('Transaction XDR to sign and submit'),\n");
-        content.push_str("      secretKey: z.string().optional().describe('Secret key for signing. For passkey flow, this becomes the fee payer secret.'),\n");
-        content.push_str("      walletContractId: z.string().optional().describe('Smart wallet contract ID for passkey signing (uses WALLET_SIGNER_SECRET from env, secretKey as fee payer)'),\n");
-        content.push_str("    },\n");
-        content.push_str("    outputSchema: { success: z.boolean(), result: z.unknown().optional() },\n");
-        content.push_str("  },\n");
-        content.push_str("  async ({ xdr, secretKey, walletContractId }) => {\n");
-        content.push_str("    log('sign-and-submit', 'info', 'called');\n");
-        content.push_str("    try {\n");
-
-        // Always validate secretKey first
-        content.push_str("      if (!secretKey) {\n");
-        content.push_str("        throw new Error('Either secretKey (for regular signing) or walletContractId (for passkey signing) is required');\n");
-        content.push_str("      }\n\n");
-
-        // Add passkey signing conditional - simplified, signAndSendWithPasskey handles everything
-        content.push_str("      // Use passkey signing if walletContractId is provided\n");
-        content.push_str("      if (walletContractId) {\n");
-        content.push_str("        // Passkey signing uses WALLET_SIGNER_SECRET from env for auth, secretKey as fee payer\n");
-        content.push_str("        const result = await signAndSendWithPasskey(xdr, walletContractId, secretKey);\n");
-        content.push_str("        log('sign-and-submit', 'info', 'success (passkey)', result);\n");
-        content.push_str("        const payload = { success: true, result };\n");
-        content.push_str("        return {\n");
-        content.push_str("          content: [{\n");
-        content.push_str("            type: 'text',\n");
-        content.push_str("            text: jsonStringify(payload),\n");
-        content.push_str("          }],\n");
-        content.push_str("          structuredContent: payload,\n");
-        content.push_str("        };\n");
-        content.push_str("      }\n\n");
-
-        // Use SDK-based signing and submission for regular flow
-        content.push_str("      // Regular signing: signAuthEntries + sign envelope + submit\n");
-        content.push_str("      const signedXdr = await signTransaction(xdr, secretKey);\n");
-        content.push_str("      const result = await submitTransaction(signedXdr);\n");
-        content.push_str("      log('sign-and-submit', 'info', 'success', result);\n");
-        content.push_str("      const payload = { success: true, result };\n");
-        content.push_str("      return {\n");
-        content.push_str("        content: [{\n");
-        content.push_str("          type: 'text',\n");
-        content.push_str("          text: jsonStringify(payload),\n");
-        content.push_str("        }],\n");
-        content.push_str("        structuredContent: payload,\n");
-        content.push_str("      };\n");
-        content.push_str("    } catch (error) {\n");
-        content.push_str("      return formatToolError('sign-and-submit', error);\n");
-        content.push_str("    }\n");
-        content.push_str("  }\n");
-        content.push_str(");\n\n");
-
-        // Prepare transaction tool (for wallet mode) - always included for external wallet support
-        content.push_str("// Tool: prepare-transaction\n");
-        content.push_str("server.registerTool(\n");
-        content.push_str("  'prepare-transaction',\n");
-        content.push_str("  {\n");
-        content.push_str("    description: 'Prepare transaction for wallet signing. Takes XDR with dummy sequence and returns wallet-ready XDR with fresh sequence. Use this when user wants to sign a transaction with their wallet.',\n");
-        content.push_str("    inputSchema: {\n");
-        content.push_str("      xdr: z.string().describe('Transaction XDR from contract function call'),\n");
-        content.push_str("      walletAddress: z.string().describe('Wallet public key (G...) to prepare transaction for'),\n");
-        content.push_str("      toolName: z.string().describe('Name of contract function being called'),\n");
-        content.push_str("      params: z.record(z.any()).optional().describe('Parameters passed to function'),\n");
-        content.push_str("      simulationResult: z.any().optional().describe('Simulation result from initial call'),\n");
-        content.push_str("    },\n");
-        content.push_str("    outputSchema: { walletReadyXdr: z.string(), preview: z.record(z.string(), z.unknown()) },\n");
-        content.push_str("  },\n");
-        content.push_str("  async ({ xdr, walletAddress, toolName, params, simulationResult }) => {\n");
-        content.push_str("    log('prepare-transaction', 'info', 'called', { walletAddress });\n");
-        content.push_str("    try {\n");
-        content.push_str("      const result = await prepareTransactionForWallet(xdr, walletAddress);\n");
-        content.push_str("      log('prepare-transaction', 'info', 'success');\n");
-        content.push_str("      const payload = {\n");
-        content.push_str("        walletReadyXdr: result.walletReadyXdr,\n");
-        content.push_str("        preview: {\n");
-        content.push_str("          toolName,\n");
-        content.push_str("          params,\n");
-        content.push_str("          simulationResult,\n");
-        content.push_str("          network: NETWORK_PASSPHRASE,\n");
-        content.push_str("        },\n");
-        content.push_str("      };\n");
-        content.push_str("      return {\n");
-        content.push_str("        content: [{\n");
-        content.push_str("          type: 'text',\n");
-        content.push_str("          text: jsonStringify(payload),\n");
-        content.push_str("        }],\n");
-        content.push_str("        structuredContent: payload,\n");
-        content.push_str("      };\n");
-        content.push_str("    } catch (error) {\n");
-        content.push_str("      return formatToolError('prepare-transaction', error);\n");
-        content.push_str("    }\n");
-        content.push_str("  }\n");
-        content.push_str(");\n\n");
-
-        // Prepare sign and submit tool (for secret key mode) - always included for frontend support
-        content.push_str("// Tool: prepare-sign-and-submit\n");
-        content.push_str("// This tool is used in SECRET KEY mode to prepare a transaction for signing.\n");
-        content.push_str("// It returns the XDR and metadata so the frontend can show the SecretKeySignCard.\n");
-        content.push_str("// The actual signing happens when the user calls sign-and-submit with their secret key.\n");
-        content.push_str("server.registerTool(\n");
-        content.push_str("  'prepare-sign-and-submit',\n");
-        content.push_str("  {\n");
-        content.push_str("    description: 'Prepare a write transaction for secret key signing. Call this when the user wants to execute a write operation (deploy, transfer, etc.) in SECRET KEY mode. Returns the XDR for the frontend to show the signing UI. After user provides their secret key, call sign-and-submit to complete the transaction.',\n");
-        content.push_str("    inputSchema: {\n");
-        content.push_str("      xdr: z.string().describe('Transaction XDR from contract function call'),\n");
-        content.push_str("      toolName: z.string().describe('Name of contract function being called (e.g., deploy-token, pause)'),\n");
-        content.push_str("      params: z.record(z.any()).optional().describe('Parameters passed to the contract function'),\n");
-        content.push_str("      simulationResult: z.any().optional().describe('Simulation result from the contract call'),\n");
-        content.push_str("    },\n");
-        content.push_str("    outputSchema: { readyForSigning: z.literal(true), xdr: z.string(), preview: z.record(z.string(), z.unknown()) },\n");
-        content.push_str("  },\n");
-        content.push_str("  async ({ xdr, toolName, params, simulationResult }) => {\n");
-        content.push_str("    log('prepare-sign-and-submit', 'info', 'called', { toolName });\n");
-        content.push_str("    try {\n");
-        content.push_str("      // Simply return the XDR and metadata for the frontend to display\n");
-        content.push_str("      // No actual signing happens here - that's done by sign-and-submit\n");
-        content.push_str("      const payload = {\n");
-        content.push_str("        readyForSigning: true as const,\n");
-        content.push_str("        xdr,\n");
-        content.push_str("        preview: {\n");
-        content.push_str("          toolName,\n");
-        content.push_str("          params,\n");
-        content.push_str("          simulationResult,\n");
-        content.push_str("          network: NETWORK_PASSPHRASE,\n");
-        content.push_str("        },\n");
-        content.push_str("      };\n");
-        content.push_str("      log('prepare-sign-and-submit', 'info', 'success');\n");
-        content.push_str("      return {\n");
-        content.push_str("        content: [{\n");
-        content.push_str("          type: 'text',\n");
-        content.push_str("          text: jsonStringify(payload),\n");
-        content.push_str("        }],\n");
-        content.push_str("        structuredContent: payload,\n");
-        content.push_str("      };\n");
-        content.push_str("    } catch (error) {\n");
-        content.push_str("      return formatToolError('prepare-sign-and-submit', error);\n");
-        content.push_str("    }\n");
-        content.push_str("  }\n");
-        content.push_str(");\n");
-
-        // Close registerTools function
-        content.push_str("} // End of registerTools function\n\n");
-
-        // Main function with dual transport support using StreamableHTTPServerTransport
-        content.push_str("// Start server with stdio or HTTP transport\n");
-        content.push_str("async function main() {\n");
-        content.push_str("  const useHttp = process.env.USE_HTTP === 'true';\n");
-        content.push_str("  const port = process.env.PORT ? parseInt(process.env.PORT) : 3000;\n\n");
-
-        content.push_str("  if (useHttp) {\n");
-        content.push_str("    // HTTP mode with StreamableHTTP transport - STATELESS mode\n");
-        content.push_str("    // Each request creates a new server/transport pair\n");
-        content.push_str("    const RATE_LIMIT = parseInt(process.env.RATE_LIMIT ?? '100', 10);\n");
-        content.push_str("    const windowMs = 60_000;\n");
-        content.push_str("    const ipWindows = new Map<string, { count: number; resetAt: number }>();\n\n");
-        content.push_str("    function consumeRateLimit(ip: string): boolean {\n");
-        content.push_str("      const now = Date.now();\n");
-        content.push_str("      const entry = ipWindows.get(ip);\n");
-        content.push_str("      if (!entry || now >= entry.resetAt) {\n");
-        content.push_str("        ipWindows.set(ip, { count: 1, resetAt: now + windowMs });\n");
-        content.push_str("        return true;\n");
-        content.push_str("      }\n");
-        content.push_str("      if (entry.count >= RATE_LIMIT) return false;\n");
-        content.push_str("      entry.count++;\n");
-        content.push_str("      return true;\n");
-        content.push_str("    }\n\n");
-        content.push_str("    // Clean up stale rate-limit entries every 5 minutes\n");
-        content.push_str("    setInterval(() => {\n");
-        content.push_str("      const now = Date.now();\n");
-        content.push_str("      for (const [ip, entry] of ipWindows) {\n");
-        content.push_str("        if (now >= entry.resetAt) ipWindows.delete(ip);\n");
-        content.push_str("      }\n");
-        content.push_str("    }, 5 * 60_000);\n\n");
-        content.push_str("    const CORS_ORIGINS = (process.env.CORS_ORIGINS ?? '*').split(',').map((s: string) => s.trim());\n");
-        content.push_str("    const app = express();\n");
-        content.push_str("    app.use(cors({\n");
-        content.push_str("      origin: (origin: string | undefined, cb: (err: Error | null, allow?: boolean) => void) => {\n");
-        content.push_str("        if (CORS_ORIGINS.includes('*') || !origin || CORS_ORIGINS.includes(origin)) cb(null, true);\n");
-        content.push_str("        else cb(new Error('Not allowed by CORS'));\n");
-        content.push_str("      },\n");
-        content.push_str("      methods: ['GET', 'POST', 'DELETE', 'OPTIONS'],\n");
-        content.push_str("      allowedHeaders: ['Content-Type', 'Accept', 'mcp-session-id'],\n");
-        content.push_str("      exposedHeaders: ['mcp-session-id'],\n");
-        content.push_str("    }));\n");
-        content.push_str("    app.use(express.json());\n\n");
-
-        content.push_str("    // Health check — always accessible, not rate limited\n");
-        content.push_str("    app.get('/health', (_req, res) => {\n");
-        content.push_str("      res.json({ status: 'ok' });\n");
-        content.push_str("    });\n\n");
-
-        content.push_str("    // Rate limiting middleware for MCP endpoints\n");
-        content.push_str("    app.use('/mcp', (req, res, next) => {\n");
-        content.push_str("      const ip = (req.headers['x-forwarded-for'] as string)?.split(',')[0]?.trim() || req.socket.remoteAddress || 'unknown';\n");
-        content.push_str("      if (!consumeRateLimit(ip)) {\n");
-        content.push_str("        res.writeHead(429, {\n");
-        content.push_str("          'Content-Type': 'application/json',\n");
-        content.push_str("          'Retry-After': '60',\n");
-        content.push_str("          'X-RateLimit-Limit': String(RATE_LIMIT),\n");
-        content.push_str("        });\n");
-        content.push_str("        res.end(JSON.stringify({ error: 'Too Many Requests', retryAfter: 60 }));\n");
-        content.push_str("        return;\n");
-        content.push_str("      }\n");
-        content.push_str("      next();\n");
-        content.push_str("    });\n\n");
-
-        // POST endpoint - handles all MCP requests in stateless mode
-        content.push_str("    // POST endpoint - handles all MCP requests in stateless mode\n");
-        content.push_str("    app.post('/mcp', async (req, res) => {\n");
-        content.push_str("      console.error(`[MCP] POST request - stateless mode`);\n\n");
-        content.push_str("      try {\n");
-        content.push_str("        // Create a fresh transport and server for EVERY request (true stateless)\n");
-        content.push_str("        const transport = new StreamableHTTPServerTransport({\n");
-        content.push_str("          sessionIdGenerator: undefined, // STATELESS - no sessions\n");
-        content.push_str("        });\n\n");
-        content.push_str("        const server = createMcpServer();\n");
-        content.push_str("        await server.connect(transport);\n\n");
-        content.push_str("        // Handle the request\n");
-        content.push_str("        await transport.handleRequest(req, res, req.body);\n");
-        content.push_str("      } catch (error) {\n");
-        content.push_str("        console.error('[MCP] POST error:', error);\n");
-        content.push_str("        if (!res.headersSent) {\n");
-        content.push_str("          res.status(500).json({\n");
-        content.push_str("            jsonrpc: '2.0',\n");
-        content.push_str("            error: { code: -32603, message: 'Internal server error' },\n");
-        content.push_str("            id: null\n");
-        content.push_str("          });\n");
-        content.push_str("        }\n");
-        content.push_str("      }\n");
-        content.push_str("    });\n\n");
-
-        // GET endpoint - return 405 to signal stateless mode (no persistent SSE stream)
-        content.push_str("    // GET endpoint - return 405 (stateless mode has no persistent SSE stream)\n");
-        content.push_str("    // StreamableHTTPClientTransport handles 405 gracefully: skips GET stream,\n");
-        content.push_str("    // operates in pure stateless POST mode.  A closing SSE stream confuses\n");
-        content.push_str("    // clients into reconnection loops and causes 'SSE error: undefined'.\n");
-        content.push_str("    app.get('/mcp', (_req, res) => {\n");
-        content.push_str("      res.status(405).json({ error: 'Method Not Allowed: server runs in stateless HTTP mode' });\n");
-        content.push_str("    });\n\n");
-
-        // DELETE endpoint
-        content.push_str("    // DELETE endpoint\n");
-        content.push_str("    app.delete('/mcp', async (_req, res) => {\n");
-        content.push_str("      console.error(`[MCP] DELETE request - no-op in stateless mode`);\n");
-        content.push_str("      res.status(200).json({\n");
-        content.push_str("        jsonrpc: '2.0',\n");
-        content.push_str("        result: {},\n");
-        content.push_str("        id: null\n");
-        content.push_str("      });\n");
-        content.push_str("    });\n\n");
-
-        content.push_str("    const httpServer = app.listen(port, () => {\n");
-        content.push_str(&format!("      console.error('{}-mcp MCP server running on HTTP port ' + port);\n", self.server_name));
-        content.push_str("      console.error('Mode: STATELESS (no sessions)');\n");
-        content.push_str("      console.error('Rate limit: ' + RATE_LIMIT + ' req/min per IP');\n");
-        content.push_str("      console.error('CORS origins: ' + CORS_ORIGINS.join(', '));\n");
-        content.push_str("      console.error('Health check: http://localhost:' + port + '/health');\n");
-        content.push_str("      console.error('MCP endpoint: http://localhost:' + port + '/mcp');\n");
-        content.push_str("    });\n");
-        content.push_str("    // Graceful shutdown\n");
-        content.push_str("    const shutdown = (signal: string) => {\n");
-        content.push_str("      console.error('\\n' + signal + ' received — shutting down gracefully');\n");
-        content.push_str("      httpServer.close(() => {\n");
-        content.push_str("        console.error('HTTP server closed');\n");
-        content.push_str("        process.exit(0);\n");
-        content.push_str("      });\n");
-        content.push_str("      setTimeout(() => { console.error('Forcing exit'); process.exit(1); }, 10_000).unref();\n");
-        content.push_str("    };\n");
-        content.push_str("    process.on('SIGTERM', () => shutdown('SIGTERM'));\n");
-        content.push_str("    process.on('SIGINT',  () => shutdown('SIGINT'));\n");
-        content.push_str("  } else {\n");
-        content.push_str("    // Stdio mode (default for Claude Desktop)\n");
-        content.push_str("    const server = createMcpServer();\n");
-        content.push_str("    const transport = new StdioServerTransport();\n");
-        content.push_str("    await server.connect(transport);\n");
-        content.push_str(&format!("    console.error('{}-mcp MCP server running on stdio');\n", self.server_name));
-        content.push_str("  }\n");
-        content.push_str("}\n\n");
-
-        content.push_str("main().catch((error) => {\n");
-        content.push_str("  console.error('Fatal error:', error);\n");
-        content.push_str("  process.exit(1);\n");
-        content.push_str("});\n");
-
-        Ok(content)
+        if !changed {
+            return converted;
+        }
     }
+}
 
-    fn generate_tools_ts(&self, spec: &ContractSpec) -> Result<(), Box<dyn std::error::Error>> {
-        let mut content = String::new();
-
-        content.push_str("// Generated tool handlers using official Stellar bindings\n");
-        content.push_str("import { rpc } from '@stellar/stellar-sdk';\n");
-        content.push_str("import { Client } from '../bindings/dist/index.js';\n");
-        content.push_str("import type * as ContractTypes from '../bindings/dist/index.js';\n");
-        content.push_str("\n");
-
-        content.push_str("export interface ContractConfig {\n");
-        content.push_str("  contractId: string;\n");
-        content.push_str("  rpcUrl: string;\n");
-        content.push_str("  networkPassphrase: string;\n");
-        content.push_str("}\n\n");
-
-        // Helper to create client
-        content.push_str("// Helper to create contract client\n");
-        content.push_str("function createClient(config: ContractConfig): Client {\n");
-        content.push_str("  return new Client(config);\n");
-        content.push_str("}\n\n");
-
-        // Helper to convert types for Stellar SDK
-        content.push_str("// Helper to convert types for Stellar SDK:\n");
-        content.push_str("// - null → undefined (for Option<T>)\n");
-        content.push_str("// - hex strings → Buffer (for Bytes/BytesN fields like 'salt', '*_hash', etc.)\n");
-        content.push_str("// - numeric strings → bigint (for i128/u128 fields like '*_supply', 'cap', 'amount', etc.)\n");
-        content.push_str("function convertNullToUndefined<T>(obj: T): T {\n");
-        content.push_str("  if (obj === null || obj === undefined) return undefined as any;\n");
-        content.push_str("  if (typeof obj !== 'object') return obj;\n");
-        content.push_str("  if (Array.isArray(obj)) return obj.map(convertNullToUndefined) as any;\n");
-        content.push_str("  \n");
-        content.push_str("  const result: any = {};\n");
-        content.push_str("  for (const [key, value] of Object.entries(obj)) {\n");
-        content.push_str("    // Convert null to undefined\n");
-        content.push_str("    if (value === null) {\n");
-        content.push_str("      result[key] = undefined;\n");
-        content.push_str("    }\n");
-        content.push_str("    // Convert hex strings to Buffer for known Bytes fields\n");
-        content.push_str("    else if (typeof value === 'string' && \n");
-        content.push_str("            (key === 'salt' || key.endsWith('_hash') || key.endsWith('_wasm')) &&\n");
-        content.push_str("            /^[0-9a-fA-F]+$/.test(value)) {\n");
-        content.push_str("      result[key] = Buffer.from(value, 'hex');\n");
-        content.push_str("    }\n");
-        content.push_str("    // Convert numeric strings to bigint for known i128/u128 fields\n");
-        content.push_str("    else if (typeof value === 'string' && \n");
-        content.push_str("            (key.includes('supply') || key === 'cap' || key === 'amount' || key === 'balance' || \n");
-        content.push_str("             key === 'value' || key.includes('_amount') || key.includes('_balance')) &&\n");
-        content.push_str("            /^-?[0-9]+$/.test(value)) {\n");
-        content.push_str("      result[key] = BigInt(value);\n");
-        content.push_str("    }\n");
-        content.push_str("    // Recursively convert nested objects\n");
-        content.push_str("    else if (typeof value === 'object') {\n");
-        content.push_str("      result[key] = convertNullToUndefined(value);\n");
-        content.push_str("    }\n");
-        content.push_str("    else {\n");
-        content.push_str("      result[key] = value;\n");
-        content.push_str("    }\n");
-        content.push_str("  }\n");
-        content.push_str("  return result;\n");
-        content.push_str("}\n\n");
-
-        // Generate typed functions that use the official Client
-        for func in &spec.functions {
-            // Helper function to check if a type needs conversion
-            fn needs_conversion(type_ref: &TypeRef) -> bool {
-                matches!(type_ref,
-                    TypeRef::Bytes |
-                    TypeRef::BytesN(_) |
-                    TypeRef::Option(_) |
-                    TypeRef::Custom(_) // Custom types might contain Option/Bytes fields
-                )
+fn convert_expr(
+    type_ref: &TypeRef,
+    expr: &str,
+    converted: &HashSet<String>,
+    depth: usize,
+) -> Option<String> {
+    match type_ref {
+        TypeRef::U64
+        | TypeRef::I64
+        | TypeRef::Timepoint
+        | TypeRef::Duration
+        | TypeRef::U128
+        | TypeRef::I128
+        | TypeRef::U256
+        | TypeRef::I256 => Some(format!("BigInt({})", expr)),
+        TypeRef::Bytes | TypeRef::BytesN(_) => Some(format!("Buffer.from({}, 'hex')", expr)),
+        TypeRef::Option(inner) => {
+            let inner_expr = convert_expr(inner, expr, converted, depth)
+                .unwrap_or_else(|| expr.to_string());
+            Some(format!("({} == null ? undefined : {})", expr, inner_expr))
+        }
+        TypeRef::Result { ok, .. } => convert_expr(ok, expr, converted, depth),
+        TypeRef::Vec(inner) => {
+            let item = format!("v{}", depth);
+            convert_expr(inner, &item, converted, depth + 1)
+                .map(|inner_expr| format!("{}.map(({}: any) => {})", expr, item, inner_expr))
+        }
+        TypeRef::Map { key, value } => {
+            // Maps arrive as `[key, value]` pairs; the bindings take a `Map`
+            let (k, v) = (format!("k{}", depth), format!("v{}", depth));
+            let key_expr = convert_expr(key, &k, converted, depth + 1);
+            let value_expr = convert_expr(value, &v, converted, depth + 1);
+            if key_expr.is_none() && value_expr.is_none() {
+                return Some(format!("new Map({})", expr));
             }
-
-            let needs_type_conversion = func.inputs.iter().any(|p| needs_conversion(&p.type_ref));
-
-            let param_str = if func.inputs.is_empty() {
-                "{}".to_string()
-            } else if needs_type_conversion {
-                // Build an object with type conversions
-                let fields: Vec<String> = func
-                    .inputs
-                    .iter()
-                    .map(|p| {
-                        match &p.type_ref {
-                            TypeRef::Bytes | TypeRef::BytesN(_) => {
-                                format!("{}: Buffer.from(params.{}, 'hex')", p.name, p.name)
-                            }
-                            TypeRef::Option(_) => {
-                                // Convert null to undefined for Stellar's Option<T>
-                                format!("{}: params.{} === null ? undefined : params.{}", p.name, p.name, p.name)
-                            }
-                            TypeRef::Custom(_) => {
-                                // For custom types, convert null to undefined recursively
-                                format!("{}: convertNullToUndefined(params.{})", p.name, p.name)
-                            }
-                            _ => format!("{}: params.{}", p.name, p.name)
-                        }
-                    })
-                    .collect();
-                format!("{{\n    {}\n  }}", fields.join(",\n    "))
-            } else {
-                "params".to_string()
-            };
-
-            let param_type_str = if func.inputs.is_empty() {
-                "{}".to_string()
-            } else {
-                // Use ContractTypes for parameter types
-                let params: Vec<String> = func
-                    .inputs
-                    .iter()
-                    .map(|p| {
-                        let ts_type = match &p.type_ref {
-                            TypeRef::Custom(name) => format!("ContractTypes.{}", name),
-                            _ => p.type_ref.to_typescript(),
-                        };
-                        format!("{}: {}", p.name, ts_type)
-                    })
-                    .collect();
-                format!("{{ {} }}", params.join(", "))
-            };
-
-            content.push_str(&format!(
-                "/**\n * {}\n */\n",
-                func.doc.as_ref().unwrap_or(&func.name)
-            ));
-            content.push_str(&format!(
-                "export async function {}(\n",
-                to_camel_case(&func.name)
-            ));
-            content.push_str(&format!("  params: {},\n", param_type_str));
-            content.push_str("  config: ContractConfig\n");
-            content.push_str("): Promise<{ xdr: string; simulationResult?: any }> {\n");
-
-            content.push_str("  const client = createClient(config);\n\n");
-
-            content.push_str(&format!("  // Call {} using official bindings\n", func.name));
-            content.push_str(&format!("  const assembled = await client.{}({});\n\n", func.name, param_str));
-
-            content.push_str("  // assembled.result contains the simulated result\n");
-            content.push_str("  return {\n");
-            content.push_str("    xdr: assembled.built!.toXDR(),\n");
-            content.push_str("    simulationResult: assembled.result,\n");
-            content.push_str("  };\n");
-            content.push_str("}\n\n");
+            Some(format!(
+                "new Map({}.map(([{}, {}]: [any, any]) => [{}, {}]))",
+                expr,
+                k,
+                v,
+                key_expr.unwrap_or_else(|| k.clone()),
+                value_expr.unwrap_or_else(|| v.clone())
+            ))
         }
+        TypeRef::Tuple(types) => {
+            let items: Vec<Option<String>> = types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| convert_expr(t, &format!("{}[{}]", expr, i), converted, depth))
+                .collect();
+            if items.iter().all(Option::is_none) {
+                return None;
+            }
+            let items: Vec<String> = items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| item.unwrap_or_else(|| format!("{}[{}]", expr, i)))
+                .collect();
+            Some(format!("[{}]", items.join(", ")))
+        }
+        TypeRef::Custom(name) if converted.contains(name) => {
+            Some(format!("convert{}({})", to_pascal_case(name), expr))
+        }
+        _ => None,
+    }
+}
 
-        fs::write(
-            self.output_dir.join(&format!("src/tools/{}.ts", self.contract_name)),
-            content,
-        )?;
+/// Build the JavaScript expression converting an MCP tool argument (plain
+/// JSON validated by the Zod schemas) into the value the official bindings
+/// expect: numeric strings → bigint for 64-bit and wider integers, hex →
+/// `Buffer` for `Bytes`/`BytesN`, `null` → `undefined` for options,
+/// `[key, value]` pairs → `Map` and variant names → values for enums. Nested vecs, maps, tuples and custom
+/// types are walked using the converters from `ts_type_converters`.
+/// Returns `None` when the value can be passed through unchanged.
+pub fn ts_convert_expr(type_ref: &TypeRef, expr: &str, types: &[TypeSpec]) -> Option<String> {
+    convert_expr(type_ref, expr, &converted_types(types), 0)
+}
 
-        println!("  Generated src/tools/{}.ts", self.contract_name);
-        Ok(())
-    }
+/// Generate a `convert<Type>` function for every contract type that needs
+/// argument conversion (see `ts_convert_expr`).
+pub fn ts_type_converters(types: &[TypeSpec]) -> String {
+    let converted = converted_types(types);
+    let mut content = String::new();
 
-    fn generate_schemas_ts(&self, spec: &ContractSpec) -> Result<(), Box<dyn std::error::Error>> {
-        let mut content = String::new();
-
-        content.push_str("// Generated Zod schemas matching official Stellar bindings\n");
-        content.push_str("import { z } from 'zod';\n\n");
-
-        // Generate schemas for custom types with snake_case field names
-        for type_spec in &spec.types {
-            match &type_spec.definition {
-                TypeDef::Struct { fields } => {
-                    content.push_str(&format!(
-                        "export const {}Schema = z.object({{\n",
-                        to_pascal_case(&type_spec.name)
-                    ));
-                    for field in fields {
-                        // Use snake_case field names to match official bindings
-                        content.push_str(&format!(
-                            "  {}: {},\n",
-                            field.name, // Keep original snake_case name
-                            field.type_ref.to_zod()
-                        ));
-                    }
-                    content.push_str("});\n\n");
-                }
-                TypeDef::Enum { variants } => {
-                    let variant_names: Vec<String> =
-                        variants.iter().map(|v| format!("'{}'", v.name)).collect();
-                    content.push_str(&format!(
-                        "export const {}Schema = z.enum([{}]);\n\n",
-                        to_pascal_case(&type_spec.name),
-                        variant_names.join(", ")
-                    ));
-                }
-                TypeDef::Union { cases } => {
-                    // Use 'tag' instead of 'type' to match official binding format
-                    // Accept objects with just 'tag', values field is added automatically
-                    content.push_str(&format!(
-                        "export const {}Schema = z.union([\n",
-                        to_pascal_case(&type_spec.name)
-                    ));
-                    for case in cases {
+    for type_spec in types.iter().filter(|t| converted.contains(&t.name)) {
+        content.push_str(&format!(
+            "function convert{}(v: any): any {{\n",
+            to_pascal_case(&type_spec.name)
+        ));
+        match &type_spec.definition {
+            TypeDef::Struct { fields } => {
+                content.push_str("  return {\n    ...v,\n");
+                for field in fields {
+                    let access = ts_property("v", &field.name);
+                    if let Some(field_expr) = convert_expr(&field.type_ref, &access, &converted, 0) {
                         content.push_str(&format!(
-                            "  z.object({{ tag: z.literal('{}') }}).transform(v => ({{ ...v, values: undefined as void }})),\n",
-                            case.name
+                            "    {}: {},\n",
+                            ts_object_key(&field.name),
+                            field_expr
                         ));
                     }
-                    content.push_str("]) as any;\n\n");
                 }
+                content.push_str("  };\n");
             }
-        }
-
-        // Generate schemas for function parameters with snake_case field names
-        for func in &spec.functions {
-            if !func.inputs.is_empty() {
+            TypeDef::Enum { variants } => {
+                let values: Vec<String> = variants
+                    .iter()
+                    .map(|v| format!("{}: {}", ts_string(&v.name), v.value))
+                    .collect();
                 content.push_str(&format!(
-                    "export const {}ParamsSchema = z.object({{\n",
-                    to_pascal_case(&func.name)
+                    "  const values: Record<string, number> = {{ {} }};\n",
+                    values.join(", ")
                 ));
-                for input in &func.inputs {
-                    // Use snake_case field names to match official bindings
-                    content.push_str(&format!(
-                        "  {}: {},\n",
-                        input.name, // Keep original snake_case name
-                        input.type_ref.to_zod()
-                    ));
+                content.push_str("  return typeof v === 'string' && v in values ? values[v] : v;\n");
+            }
+            TypeDef::Union { cases } => {
+                content.push_str("  switch (v.tag) {\n");
+                for case in cases {
+                    let Some(payload) = &case.type_ref else { continue };
+                    if let Some(values) = convert_expr(payload, "v.values", &converted, 0) {
+                        content.push_str(&format!("    case {}:\n", ts_string(&case.name)));
+                        content.push_str(&format!("      return {{ tag: v.tag, values: {} }};\n", values));
+                    }
                 }
-                content.push_str("});\n\n");
+                content.push_str("    default:\n      return v;\n  }\n");
             }
         }
-
-        fs::write(
-            self.output_dir.join(&format!("src/schemas/{}.ts", self.contract_name)),
-            content,
-        )?;
-
-        println!("  Generated src/schemas/{}.ts", self.contract_name);
-        Ok(())
+        content.push_str("}\n\n");
     }
 
-    // Custom type generation removed - using official Stellar bindings instead
-
-    fn generate_lib_files(&self, _args: &GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
-        // Logger utility — structured logging to stderr (MCP convention)
-        let logger_content = r#"// Structured logger — writes to stderr (MCP convention: stdout is for protocol)
-
-/** Serialize BigInt values — Soroban uses i128/u128 which become BigInt in JS */
-export const jsonStringify = (obj: unknown, space?: number): string =>
-  JSON.stringify(obj, (_, v) => (typeof v === 'bigint' ? v.toString() : v), space);
-
-/** Structured logger — writes to stderr with ISO timestamps */
-export function log(tool: string, level: 'info' | 'error' | 'debug', message: string, data?: unknown): void {
-  const ts = new Date().toISOString();
-  const prefix = `${ts} [${tool}] ${level.toUpperCase()}:`;
-  if (data !== undefined) {
-    console.error(prefix, message, typeof data === 'string' ? data : jsonStringify(data));
-  } else {
-    console.error(prefix, message);
-  }
+    content
 }
-"#;
-        fs::write(self.output_dir.join("src/lib/logger.ts"), logger_content)?;
-        println!("  Generated src/lib/logger.ts");
-
-        // Error parsing utility — Soroban-specific error parsing + formatToolError
-        let errors_content = r#"// Soroban error parsing and MCP error formatting
-import { log, jsonStringify } from './logger.js';
-
-/** Common Soroban host error codes → human-readable descriptions */
-const SOROBAN_ERROR_HINTS: Record<string, string> = {
-  'Error(Storage, ExistingValue)': 'An entry with this key already exists (e.g. contract deployed with same salt)',
-  'Error(Auth, InvalidAction)': 'Authorization failed — the signer does not have permission for this action',
-  'Error(Budget, Exceeded)': 'Transaction budget exceeded — the operation is too expensive',
-  'Error(Value, InvalidInput)': 'Invalid input value — check parameter types and ranges',
-  'Error(Object, MissingValue)': 'Required value not found — a referenced entry does not exist',
-  'Error(WasmVm, Trapped)': 'Contract execution trapped — likely a panic or assertion failure in the contract',
-};
 
-/**
- * Parse Soroban simulation errors into structured, AI-friendly output.
- * Extracts human-readable reasons from diagnostic event logs.
- */
-function parseSorobanError(error: Error): {
-  error: string;
-  reason: string;
-  diagnosticEvents: string[];
-  hint?: string;
-  raw: string;
-} {
-  const msg = error.message;
-
-  // Extract the error code like Error(Storage, ExistingValue)
-  const codeMatch = msg.match(/Error\([A-Za-z]+,\s*[A-Za-z]+\)/);
-  const errorCode = codeMatch?.[0] ?? 'Unknown';
-
-  // Extract human-readable data strings from diagnostic events
-  const diagnosticEvents: string[] = [];
-  const dataMatches = msg.matchAll(/data:(?:"([^"]+)"|\[([^\]]+)\])/g);
-  for (const m of dataMatches) {
-    const text = m[1] ?? m[2];
-    if (text && !text.startsWith('escalating')) {
-      diagnosticEvents.push(text);
-    }
-  }
-
-  // Extract the escalation reason as a fallback
-  const escalationMatch = msg.match(/data:"(escalating[^"]+)"/);
-  if (escalationMatch) {
-    diagnosticEvents.push(escalationMatch[1]);
-  }
-
-  // Build a concise reason from the diagnostic events
-  const reason = diagnosticEvents[0] ?? `Soroban host error: ${errorCode}`;
-
-  return {
-    error: errorCode,
-    reason,
-    diagnosticEvents,
-    hint: SOROBAN_ERROR_HINTS[errorCode],
-    raw: msg,
-  };
+/// Identifiers a contract's tools use in the generated `src/index.ts`
+struct IndexNames {
+    /// Namespace the contract's tool handlers are imported as
+    tools: String,
+    /// Namespace the contract's Zod schemas are imported as
+    schemas: String,
+    /// Constant (and environment variable) holding the contract ID
+    contract_id: String,
+    /// Constant holding the spec snapshot used for drift checks
+    snapshot: String,
 }
 
-/** Format any tool error into a structured MCP error response */
-export function formatToolError(toolName: string, error: unknown): {
-  content: Array<{ type: 'text'; text: string }>;
-  isError: true;
-} {
-  const err = error instanceof Error ? error : new Error(String(error));
-  const isSorobanError = err.message.includes('HostError:') || err.message.includes('SimulationFailed');
-
-  let body: Record<string, unknown>;
-  if (isSorobanError) {
-    body = parseSorobanError(err);
-  } else {
-    body = {
-      error: 'Tool execution failed',
-      reason: err.message,
-    };
-  }
+/// Project files rendered as-is from a template with the project data:
+/// `(template, path)`
+const TEMPLATE_FILES: &[(&str, &str)] = &[
+    ("deploy-wallet.ts", "deploy-wallet.ts"),
+    ("tsconfig.json", "tsconfig.json"),
+    ("env.example", ".env.example"),
+    ("Dockerfile", "Dockerfile"),
+    ("dockerignore", ".dockerignore"),
+    ("vercel.json", "vercel.json"),
+];
 
-  log(toolName, 'error', body.reason as string);
-  return {
-    content: [{ type: 'text', text: jsonStringify(body, 2) }],
-    isError: true,
-  };
+/// MCP Server generator
+pub struct McpGenerator<'a> {
+    /// Project name (the contract name for single-contract servers)
+    project_name: &'a str,
+    server_name: &'a str,
+    network: &'a NetworkConfig,
+    /// Contracts the server exposes tools for
+    contracts: &'a [ServedContract],
+    templates: &'a TemplateRenderer,
+    /// Serve REST routes and `openapi.json` next to `/mcp`
+    with_rest: bool,
 }
-"#;
-        fs::write(self.output_dir.join("src/lib/errors.ts"), errors_content)?;
-        println!("  Generated src/lib/errors.ts");
 
-        // Transaction utility
-        let tx_content = r#"// Transaction utilities
-import { xdr, scValToNative } from '@stellar/stellar-sdk';
+impl<'a> McpGenerator<'a> {
+    /// Create a new generator
+    pub fn new(
+        project_name: &'a str,
+        server_name: &'a str,
+        network: &'a NetworkConfig,
+        contracts: &'a [ServedContract],
+        templates: &'a TemplateRenderer,
+    ) -> Self {
+        Self {
+            project_name,
+            server_name,
+            network,
+            contracts,
+            templates,
+            with_rest: false,
+        }
+    }
 
-export function parseTransactionResult(resultMetaXdr: string): any {
-  const meta = xdr.TransactionMeta.fromXDR(resultMetaXdr, 'base64');
-  const v3 = meta.v3();
-  const sorobanMeta = v3.sorobanMeta();
+    /// Also serve the contract functions as REST routes (`POST /api/<tool>`)
+    /// described by an OpenAPI document
+    pub fn with_rest(mut self, with_rest: bool) -> Self {
+        self.with_rest = with_rest;
+        self
+    }
 
-  if (!sorobanMeta) {
-    return null;
-  }
+    /// Render every file of the MCP server, without writing anything
+    pub fn render(&self) -> Result<GeneratedProject> {
+        let mut project = GeneratedProject::new();
 
-  const returnValue = sorobanMeta.returnValue();
-  return scValToNative(returnValue);
-}
-"#;
-        fs::write(self.output_dir.join("src/lib/transaction.ts"), tx_content)?;
-
-        // Always generate submit.ts (simple transaction submission)
-        let submit_content = r#"// Transaction submission using Stellar SDK
-import {
-  TransactionBuilder,
-  rpc,
-  scValToNative,
-} from '@stellar/stellar-sdk';
-
-const NETWORK_PASSPHRASE = process.env.NETWORK_PASSPHRASE || 'Test SDF Network ; September 2015';
-const RPC_URL = process.env.RPC_URL || 'https://soroban-testnet.stellar.org';
-
-export interface SubmitResult {
-  hash: string;
-  status: string;
-  parsedResult?: unknown;
-  resultMetaXdr?: string;
-}
+        // Generate the contract bindings, tool handlers and schemas of every contract
+        for contract in self.contracts {
+            self.generate_bindings(&mut project, contract)?;
+            self.generate_tools_ts(&mut project, contract)?;
+            self.generate_schemas_ts(&mut project, contract)?;
+        }
 
-/**
- * Submit a signed transaction to the Stellar network
- *
- * NOTE: This function expects the transaction to already be signed!
- * Use this after signing with signAuthEntries + sign in the MCP tool.
- *
- * @param signedXdr - Signed transaction XDR
- * @returns Submission result with hash and parsed response
- */
-export async function submitTransaction(
-  signedXdr: string
-): Promise<SubmitResult> {
-  const server = new rpc.Server(RPC_URL, { allowHttp: true });
-
-  // Parse the signed transaction
-  const tx = TransactionBuilder.fromXDR(signedXdr, NETWORK_PASSPHRASE);
-
-  // Submit to network
-  const response = await server.sendTransaction(tx);
-
-  if (response.status !== 'PENDING') {
-    const errorMessage =
-      (response as any).errorResult?.toXDR?.('base64') ||
-      (response as any).errorResultXdr ||
-      JSON.stringify(response);
-    throw new Error(`Transaction failed: ${response.status} - ${errorMessage}`);
-  }
-
-  // Poll for result using SDK's pollTransaction
-  const txResult = await server.pollTransaction(response.hash, {
-    sleepStrategy: () => 500,
-    attempts: 60, // 30 seconds total
-  });
-
-  // Parse result if available
-  let parsedResult;
-  let resultMetaXdrString: string | undefined;
-
-  // Type guard: only SUCCESS and FAILED have resultMetaXdr
-  if (txResult.status === 'SUCCESS' || txResult.status === 'FAILED') {
-    // Now TypeScript knows txResult has resultMetaXdr
-    resultMetaXdrString = txResult.resultMetaXdr.toXDR('base64');
-
-    if (txResult.status === 'SUCCESS') {
-      try {
-        // Try to get return value - handle different meta versions
-        const meta = txResult.resultMetaXdr;
-        const metaSwitch = meta.switch();
-
-        if (metaSwitch.value === 3) {
-          const sorobanMeta = meta.v3().sorobanMeta();
-          if (sorobanMeta) {
-            parsedResult = scValToNative(sorobanMeta.returnValue());
-          }
+        // Generate files
+        self.generate_index_ts(&mut project)?;
+        self.generate_lib_files(&mut project)?;
+        self.generate_package_json(&mut project)?;
+        render_template_files(&mut project, self.templates, TEMPLATE_FILES, &self.project_data())?;
+        project.add(TOOL_MANIFEST_PATH, render_tool_manifest(&TypeScriptBackend, self.contracts));
+        if self.with_rest {
+            project.add(OPENAPI_PATH, render_openapi_document(&TypeScriptBackend, self.server_name, self.contracts));
         }
-      } catch {
-        // Result parsing failed, but transaction succeeded
-        parsedResult = 'Transaction succeeded (result parsing unavailable)';
-      }
+        self.generate_readme(&mut project)?;
+
+        Ok(project)
     }
-  }
-
-  return {
-    hash: response.hash,
-    status: txResult.status,
-    parsedResult,
-    resultMetaXdr: resultMetaXdrString,
-  };
-}
-"#;
-        fs::write(self.output_dir.join("src/lib/submit.ts"), submit_content)?;
-        println!("  Generated src/lib/submit.ts");
-
-        // Always generate utils.ts with signing helper (rebuild pattern - no Client dependency)
-        let utils_content = r#"// Signing utilities
-import {
-  Keypair,
-  rpc,
-  authorizeEntry,
-  TransactionBuilder,
-  Operation,
-} from '@stellar/stellar-sdk';
-
-const RPC_URL = process.env.RPC_URL || 'https://soroban-testnet.stellar.org';
-const NETWORK_PASSPHRASE =
-  process.env.NETWORK_PASSPHRASE || 'Test SDF Network ; September 2015';
-
-/**
- * Result of preparing a transaction for wallet signing
- */
-export interface PrepareTransactionResult {
-  walletReadyXdr: string;
-  simulationResult: {
-    minResourceFee: string;
-    cost: {
-      cpuInsns: string;
-      memBytes: string;
-    };
-    latestLedger: number;
-  };
-}
 
-/**
- * Prepare a transaction for wallet signing by re-simulating with the wallet's address
- *
- * This is needed because the original XDR was built with a dummy/null source account.
- * For wallet signing, we need to:
- * 1. Parse the original transaction to get the contract call details
- * 2. Rebuild the transaction with the wallet's public key as source
- * 3. Re-simulate to get fresh auth entries, footprint and resources
- * 4. The simulation will return auth entries that the wallet needs to sign
- *
- * NOTE: This approach works because `assembleTransaction` pulls the auth entries
- * from the simulation response. The simulation generates auth entries based on
- * what `require_auth` calls are made during the simulated execution. Since the
- * function args contain the deployer address (not the source account), the auth
- * entries will be for that deployer address - which should match the wallet.
- *
- * IMPORTANT: The deployer address in the function args MUST match the wallet address.
- * If they don't match, the simulation will generate auth entries for the wrong address
- * and the wallet won't be able to sign them.
- *
- * @param xdr - Original transaction XDR (built without publicKey)
- * @param walletAddress - The wallet's public key (G...) to prepare transaction for
- * @returns Wallet-ready XDR and simulation result
- */
-export async function prepareTransactionForWallet(
-  originalXdr: string,
-  walletAddress: string
-): Promise<PrepareTransactionResult> {
-  const server = new rpc.Server(RPC_URL, { allowHttp: true });
-
-  // Step 1: Parse original transaction
-  const originalTx = TransactionBuilder.fromXDR(originalXdr, NETWORK_PASSPHRASE);
-  const operation = originalTx.operations[0] as Operation.InvokeHostFunction;
-
-  // Step 2: Get fresh account for the wallet (this gives us a fresh sequence number)
-  const sourceAccount = await server.getAccount(walletAddress);
-
-  // Step 3: Rebuild transaction with wallet as source, but WITHOUT auth entries
-  // Let the simulation generate fresh auth entries
-  const rebuiltTx = new TransactionBuilder(sourceAccount, {
-    fee: originalTx.fee,
-    networkPassphrase: NETWORK_PASSPHRASE,
-  })
-    .addOperation(
-      Operation.invokeHostFunction({
-        func: operation.func,
-        auth: [], // Empty auth - let simulation fill it in
-      })
-    )
-    .setTimeout(30)
-    .build();
-
-  // Step 4: Simulate to get fresh auth entries and footprint/resources
-  const simResponse = await server.simulateTransaction(rebuiltTx);
-  if (rpc.Api.isSimulationError(simResponse)) {
-    throw new Error(`Simulation failed: ${simResponse.error}`);
-  }
-
-  // Step 5: Assemble with simulation data
-  // This pulls auth entries from simResponse.result.auth
-  const finalTx = rpc.assembleTransaction(rebuiltTx, simResponse).build();
-
-  // Extract simulation result info
-  const successResponse = simResponse as rpc.Api.SimulateTransactionSuccessResponse;
-
-  // Extract cost information, being defensive since types don't specify cost/mem usage directly
-  let cpuInsns = '0';
-  let memBytes = '0';
-  if (successResponse.result && typeof (successResponse.result as any).cost === "object") {
-    cpuInsns = (successResponse.result as any).cost?.cpuInsns ?? '0';
-    memBytes = (successResponse.result as any).cost?.memBytes ?? '0';
-  }
-
-  return {
-    walletReadyXdr: finalTx.toXDR(),
-    simulationResult: {
-      minResourceFee: successResponse.minResourceFee || '0',
-      cost: {
-        cpuInsns,
-        memBytes,
-      },
-      latestLedger: successResponse.latestLedger,
-    },
-  };
-}
+    /// Whether tools are namespaced by contract (servers with several contracts)
+    fn namespaced(&self) -> bool {
+        self.contracts.len() > 1
+    }
 
-/**
- * Sign auth entries and optionally the transaction envelope
- *
- * This function applies the "rebuild with fresh sequence" pattern:
- * 1. Parse XDR directly (no Client dependency)
- * 2. Sign auth entries using SDK's authorizeEntry()
- * 3. Fetch fresh account (fresh sequence number)
- * 4. Rebuild transaction with fresh sequence
- * 5. Re-simulate for fresh footprint/resources
- * 6. Sign envelope and return
- *
- * @param xdr - Unsigned transaction XDR
- * @param secretKey - Secret key for signing
- * @param signEnvelope - If true, also signs the transaction envelope (default: true)
- * @returns Signed XDR (with auth entries, and optionally envelope signature)
- */
-export async function signTransaction(
-  xdr: string,
-  secretKey: string,
-  signEnvelope: boolean = true
-): Promise<string> {
-  const keypair = Keypair.fromSecret(secretKey);
-  const server = new rpc.Server(RPC_URL, { allowHttp: true });
-
-  // Step 1: Parse original transaction
-  const originalTx = TransactionBuilder.fromXDR(xdr, NETWORK_PASSPHRASE);
-  const operation = originalTx.operations[0] as Operation.InvokeHostFunction;
-
-  // Step 2: Get current ledger for auth expiration
-  const ledgerSeq = (await server.getLatestLedger()).sequence;
-  const validUntilLedger = ledgerSeq + 100;
-
-  // Step 3: Sign auth entries that need signing by this keypair
-  const signedAuth: typeof operation.auth = [];
-  if (operation.auth) {
-    for (const entry of operation.auth) {
-      const creds = entry.credentials();
-      // Check if this is an address credential that matches our keypair
-      if (creds.switch().name === 'sorobanCredentialsAddress') {
-        try {
-          const address = creds.address().address();
-          const accountId = address.accountId();
-          if (accountId) {
-            const pubKeyHex = accountId.ed25519()?.toString('hex');
-            const keypairHex = keypair.rawPublicKey().toString('hex');
-            if (pubKeyHex === keypairHex) {
-              // Sign this auth entry (authorizeEntry is async!)
-              const signed = await authorizeEntry(
-                entry,
-                keypair,
-                validUntilLedger,
-                NETWORK_PASSPHRASE
-              );
-              signedAuth.push(signed);
-              continue;
-            }
-          }
-        } catch {
-          // If we can't parse the address, just keep the entry as-is
+    /// Bindings module of a contract, relative to `src/bindings`
+    fn bindings_module(&self, contract: &ServedContract) -> String {
+        if self.namespaced() {
+            contract.name.clone()
+        } else {
+            "index".to_string()
         }
-      }
-      // Keep entry unchanged if we don't need to sign it
-      signedAuth.push(entry);
     }
-  }
-
-  // Step 4: Fetch fresh account (current sequence number)
-  const sourceAccount = await server.getAccount(keypair.publicKey());
-
-  // Step 5: Rebuild transaction with fresh sequence, preserving signed auth
-  const rebuiltTx = new TransactionBuilder(sourceAccount, {
-    fee: originalTx.fee,
-    networkPassphrase: NETWORK_PASSPHRASE,
-  })
-    .addOperation(
-      Operation.invokeHostFunction({
-        func: operation.func,
-        auth: signedAuth,
-      })
-    )
-    .setTimeout(30)
-    .build();
-
-  // Step 6: Simulate to get fresh footprint/resources
-  const simResponse = await server.simulateTransaction(rebuiltTx);
-  if (rpc.Api.isSimulationError(simResponse)) {
-    throw new Error(`Simulation failed: ${simResponse.error}`);
-  }
-
-  // Step 7: Assemble with simulation data
-  const finalTx = rpc.assembleTransaction(rebuiltTx, simResponse).build();
-
-  // Step 8: Optionally sign envelope
-  if (signEnvelope) {
-    finalTx.sign(keypair);
-  }
-
-  return finalTx.toXDR();
-}
-"#;
-        fs::write(self.output_dir.join("src/lib/utils.ts"), utils_content)?;
-        println!("  Generated src/lib/utils.ts");
-
-        // Always generate passkey.ts with rebuild pattern
-        let passkey_content = r#"// PasskeyKit integration
-import { PasskeyKit, PasskeyClient } from 'passkey-kit';
-import {
-  Keypair,
-  TransactionBuilder,
-  rpc,
-  Operation,
-} from '@stellar/stellar-sdk';
-
-// Configuration from environment (read once at module level)
-const RPC_URL = process.env.RPC_URL || 'https://soroban-testnet.stellar.org';
-const NETWORK_PASSPHRASE =
-  process.env.NETWORK_PASSPHRASE || 'Test SDF Network ; September 2015';
-
-/**
- * Get a PasskeyKit instance configured for a specific wallet contract
- *
- * @param walletContractId - Smart wallet contract ID
- * @returns Configured PasskeyKit instance
- */
-export function getPasskeyWallet(walletContractId: string): PasskeyKit {
-  const WALLET_WASM_HASH = process.env.WALLET_WASM_HASH;
-
-  if (!WALLET_WASM_HASH) {
-    throw new Error(
-      'Passkey signing requires WALLET_WASM_HASH environment variable'
-    );
-  }
-
-  const passkeyKit = new PasskeyKit({
-    rpcUrl: RPC_URL,
-    networkPassphrase: NETWORK_PASSPHRASE,
-    walletWasmHash: WALLET_WASM_HASH,
-  });
-
-  // Set the wallet to the specific smart wallet contract
-  passkeyKit.wallet = new PasskeyClient({
-    contractId: walletContractId,
-    rpcUrl: RPC_URL,
-    networkPassphrase: NETWORK_PASSPHRASE,
-  });
-
-  return passkeyKit;
-}
 
-/**
- * Sign and submit transaction using passkey wallet
- *
- * This function handles the sequence number staleness issue by:
- * 1. Having PasskeyKit sign the auth entries (which are sequence-independent)
- * 2. Rebuilding the transaction with a fresh sequence number
- * 3. Re-simulating for fresh footprint/resources
- * 4. Signing the envelope and submitting
- *
- * Environment variables required:
- * - WALLET_WASM_HASH: Smart wallet WASM hash
- * - WALLET_SIGNER_SECRET: Secret key for wallet signer (signs auth entries)
- *
- * @param xdr - Transaction XDR to sign and submit
- * @param walletContractId - Smart wallet contract ID
- * @param feePayerSecret - Secret key for fee payer (signs envelope)
- * @returns Submission result
- */
-export async function signAndSendWithPasskey(
-  xdr: string,
-  walletContractId: string,
-  feePayerSecret: string
-): Promise<any> {
-  const WALLET_SIGNER_SECRET = process.env.WALLET_SIGNER_SECRET;
-
-  if (!WALLET_SIGNER_SECRET) {
-    throw new Error(
-      'Passkey signing requires WALLET_SIGNER_SECRET environment variable'
-    );
-  }
-
-  const walletSignerKeypair = Keypair.fromSecret(WALLET_SIGNER_SECRET);
-  const feePayerKeypair = feePayerSecret
-    ? Keypair.fromSecret(feePayerSecret)
-    : walletSignerKeypair;
-  const server = new rpc.Server(RPC_URL, { allowHttp: true });
-  const passkeyWallet = getPasskeyWallet(walletContractId);
-
-  // Step 1: PasskeyKit signs auth entries using wallet signer
-  // Auth entries sign the invocation payload, NOT the transaction envelope
-  // This means they are sequence-number independent
-  const assembledTx = await passkeyWallet.sign(xdr, {
-    keypair: walletSignerKeypair,
-  });
-
-  // Step 2: Extract operation with signed auth entries
-  const builtTx = assembledTx.built!;
-  const operation = builtTx.operations[0] as Operation.InvokeHostFunction;
-
-  // Step 3: Fetch fresh account for fee payer (current sequence number)
-  const sourceAccount = await server.getAccount(feePayerKeypair.publicKey());
-
-  // Step 4: Rebuild transaction with fresh sequence, preserving signed auth
-  const rebuiltTx = new TransactionBuilder(sourceAccount, {
-    fee: builtTx.fee,
-    networkPassphrase: NETWORK_PASSPHRASE,
-  })
-    .addOperation(
-      Operation.invokeHostFunction({
-        func: operation.func,
-        auth: operation.auth, // Signed auth entries preserved!
-      })
-    )
-    .setTimeout(30)
-    .build();
-
-  // Step 5: Simulate to get fresh footprint/resources
-  const simResponse = await server.simulateTransaction(rebuiltTx);
-
-  if (rpc.Api.isSimulationError(simResponse)) {
-    throw new Error(`Simulation failed: ${simResponse.error}`);
-  }
-
-  // Step 6: Assemble with simulation data
-  const assembledRebuilt = rpc
-    .assembleTransaction(rebuiltTx, simResponse)
-    .build();
-
-  // Step 7: Sign envelope with fee payer and submit
-  assembledRebuilt.sign(feePayerKeypair);
-  const response = await server.sendTransaction(assembledRebuilt);
-
-  if (response.status !== 'PENDING') {
-    const errorMessage =
-      (response as any).errorResult?.toXDR?.('base64') ||
-      (response as any).errorResultXdr ||
-      JSON.stringify(response);
-    throw new Error(`Transaction failed: ${response.status} - ${errorMessage}`);
-  }
-
-  // Step 8: Poll for result
-  const txResult = await server.pollTransaction(response.hash, {
-    sleepStrategy: () => 500,
-    attempts: 60,
-  });
-
-  return {
-    hash: response.hash,
-    status: txResult.status,
-    parsedResult: txResult.status === 'SUCCESS' ? txResult : undefined,
-  };
-}
-"#;
-        fs::write(self.output_dir.join("src/lib/passkey.ts"), passkey_content)?;
-        println!("  Generated src/lib/passkey.ts");
+    fn index_names(&self, contract: &ServedContract) -> IndexNames {
+        if !self.namespaced() {
+            return IndexNames {
+                tools: "tools".to_string(),
+                schemas: "schemas".to_string(),
+                contract_id: "CONTRACT_ID".to_string(),
+                snapshot: "SPEC_SNAPSHOT".to_string(),
+            };
+        }
+        let camel = to_camel_case(&contract.identifier());
+        IndexNames {
+            tools: format!("{}Tools", camel),
+            schemas: format!("{}Schemas", camel),
+            contract_id: contract.env_var(true),
+            snapshot: format!("{}_SPEC_SNAPSHOT", contract.identifier().to_uppercase()),
+        }
+    }
+
+    /// Snapshot file the contract's spec is recorded in
+    fn snapshot_path(&self, contract: &ServedContract) -> String {
+        if self.namespaced() {
+            crate::spec::snapshot::contract_snapshot_path(&contract.name)
+        } else {
+            crate::spec::snapshot::SNAPSHOT_PATH.to_string()
+        }
+    }
+
+    /// Generate the contract bindings (types and typed `Client`) from the spec
+    fn generate_bindings(&self, project: &mut GeneratedProject, contract: &ServedContract) -> Result<()> {
+        let content = render_ts_bindings(&contract.spec, &contract.contract_id, self.network);
+        let path = format!("src/bindings/{}.ts", self.bindings_module(contract));
+        project.add(&path, content);
 
-        println!("  Generated src/lib/transaction.ts");
         Ok(())
     }
 
-    fn generate_package_json(&self, _args: &GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
-        let deps = serde_json::json!({
-            "@modelcontextprotocol/sdk": "^1.24.3",
-            "@stellar/stellar-sdk": "~14.4.0",
-            "zod": "^3.23.0",
-            "dotenv": "^16.4.0",
-            "passkey-kit": "^0.10.19",
-            "passkey-kit-sdk": "^0.7.2",
-            "express": "^4.18.2",
-            "cors": "^2.8.5"
+    fn generate_index_ts(&self, project: &mut GeneratedProject) -> Result<()> {
+        let contracts: Vec<serde_json::Value> = self
+            .contracts
+            .iter()
+            .map(|contract| self.index_contract_data(contract))
+            .collect();
+        let data = serde_json::json!({
+            "server_name": ts_string_body(self.server_name),
+            "rpc_url": ts_string_body(&self.network.rpc_url),
+            "network_passphrase": ts_string_body(&self.network.network_passphrase),
+            "network_name": ts_string_body(&self.network.name),
+            "generator_version": env!("CARGO_PKG_VERSION"),
+            "namespaced": self.namespaced(),
+            "with_rest": self.with_rest,
+            "contracts": contracts,
         });
 
-        let scripts = serde_json::json!({
-            "build:bindings": "cd src/bindings && pnpm install && pnpm build",
-            "build:server": "esbuild src/index.ts --bundle --platform=node --target=node20 --format=esm --outfile=dist/index.js --external:@modelcontextprotocol/sdk --external:@stellar/stellar-sdk --external:zod --external:dotenv --external:express --external:cors --banner:js=\"#!/usr/bin/env node\"",
-            "build": "pnpm run build:bindings && pnpm run build:server",
-            "start": "node dist/index.js",
-            "start:http": "USE_HTTP=true PORT=3000 node dist/index.js",
-            "dev": "esbuild src/index.ts --bundle --platform=node --target=node20 --format=esm --outfile=dist/index.js --external:@modelcontextprotocol/sdk --external:@stellar/stellar-sdk --external:zod --external:dotenv --external:express --external:cors --banner:js=\"#!/usr/bin/env node\" --watch",
-            "deploy-passkey": "tsx deploy-wallet.ts",
-            "typecheck": "tsc --noEmit"
-        });
+        let content = self.templates.render("index.ts", &data)?;
+        project.add("src/index.ts", content);
 
-        let dev_deps = serde_json::json!({
-            "@types/node": "^22.0.0",
-            "@types/express": "^4.17.17",
-            "@types/cors": "^2.8.13",
-            "typescript": "^5.5.0",
-            "esbuild": "^0.24.0",
-            "tsx": "^4.19.0"
-        });
+        Ok(())
+    }
 
-        let package_json = serde_json::json!({
-            "name": format!("{}-mcp-server", self.contract_name),
-            "version": "1.0.0",
-            "description": format!("MCP server for {} Soroban contract", self.contract_name),
-            "type": "module",
-            "main": "dist/index.js",
-            "bin": {
-                format!("{}-mcp", self.contract_name): "./dist/index.js"
-            },
-            "scripts": scripts,
-            "dependencies": deps,
-            "devDependencies": dev_deps,
-            "engines": {
-                "node": ">=20"
-            }
-        });
+    /// Data for one contract in `src/index.ts`: the identifiers its tools use,
+    /// a tool per function and event, the events resource and the
+    /// contract-info drift check
+    fn index_contract_data(&self, contract: &ServedContract) -> serde_json::Value {
+        let namespaced = self.namespaced();
+        let names = self.index_names(contract);
+        let spec = &contract.spec;
 
-        let content = serde_json::to_string_pretty(&package_json)?;
-        fs::write(self.output_dir.join("package.json"), content)?;
+        let functions: Vec<serde_json::Value> = spec
+            .functions
+            .iter()
+            .map(|f| {
+                let inputs: Vec<serde_json::Value> = f
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        // Custom schemas (e.g. `TokenConfigSchema`) live in the contract's schemas namespace
+                        let refs = format!("{}.", names.schemas);
+                        let mut zod = json_types::zod(&input.type_ref.to_json_schema(), &refs);
+                        if input.type_ref.is_optional() {
+                            zod.push_str(".optional()");
+                        }
+                        serde_json::json!({
+                            "name": input.name,
+                            "zod": zod,
+                            "description": ts_string_body(input.doc.as_deref().unwrap_or("")),
+                            "type_ref": input.type_ref,
+                        })
+                    })
+                    .collect();
 
-        println!("  Generated package.json");
-        Ok(())
+                // outputSchema is a raw shape, not wrapped in z.object()
+                let output_schema = match f.kind {
+                    FunctionKind::ReadOnly => "{ result: z.unknown() }".to_string(),
+                    FunctionKind::Write => output_schema_raw_shape(&f.output),
+                };
+                let output_schema = if namespaced {
+                    output_schema.replace("schemas.", &format!("{}.", names.schemas))
+                } else {
+                    output_schema
+                };
+                let doc = f.doc.clone().unwrap_or_else(|| format!("Call {} function", f.name));
+
+                serde_json::json!({
+                    "name": f.name,
+                    "fn_name": ts_identifier(&to_camel_case(&f.name)),
+                    "tool_name": contract.tool_name(&to_kebab_case(&f.name), namespaced),
+                    "description": ts_string_body(&doc),
+                    "inputs": inputs,
+                    "has_inputs": !f.inputs.is_empty(),
+                    "output": f.output,
+                    "output_schema": output_schema,
+                    "read_only": f.kind == FunctionKind::ReadOnly,
+                })
+            })
+            .collect();
+
+        // Event query tools (read-only, backed by RPC getEvents)
+        let shape = |fields: &[crate::spec::FieldSpec]| -> String {
+            let entries: Vec<String> = fields
+                .iter()
+                .map(|f| format!("{}: {}", f.name, event_field_zod(&f.type_ref)))
+                .collect();
+            format!("z.object({{ {} }})", entries.join(", "))
+        };
+        let events: Vec<serde_json::Value> = spec
+            .events
+            .iter()
+            .map(|event| {
+                let description = match &event.doc {
+                    Some(doc) => format!("Query {} events emitted by the contract. {}", event.name, doc),
+                    None => format!("Query {} events emitted by the contract.", event.name),
+                };
+                let filters: Vec<serde_json::Value> = event
+                    .topics
+                    .iter()
+                    .filter(|topic| event_topic_scval_type(&topic.type_ref).is_some())
+                    .map(|topic| {
+                        let doc = topic
+                            .doc
+                            .clone()
+                            .unwrap_or_else(|| format!("Only return events whose {} topic matches", topic.name));
+                        serde_json::json!({
+                            "name": topic.name,
+                            "zod": topic.type_ref.to_zod(),
+                            "description": ts_string_body(&doc),
+                            "type_ref": topic.type_ref,
+                        })
+                    })
+                    .collect();
+
+                serde_json::json!({
+                    "name": event.name,
+                    "tool_name": contract.tool_name(&format!("get-{}-events", to_kebab_case(&event.name)), namespaced),
+                    "description": ts_string_body(&description),
+                    "filters": filters,
+                    "topics_schema": shape(&event.topics),
+                    "data_schema": shape(&event.data),
+                })
+            })
+            .collect();
+
+        let (events_resource, events_uri) = if namespaced {
+            (format!("{}-contract-events", contract.name), format!("contract://{}/events", contract.name))
+        } else {
+            ("contract-events".to_string(), "contract://events".to_string())
+        };
+
+        serde_json::json!({
+            "name": contract.name,
+            "contract_id": contract.contract_id,
+            "tools_ns": names.tools,
+            "schemas_ns": names.schemas,
+            "id_const": names.contract_id,
+            "snapshot_const": names.snapshot,
+            "snapshot_path": self.snapshot_path(contract),
+            "wasm_hash": contract.wasm_hash,
+            "info_tool": contract.tool_name("contract-info", namespaced),
+            "events_resource": events_resource,
+            "events_uri": events_uri,
+            "functions": functions,
+            "events": events,
+        })
     }
 
-    fn generate_tsconfig(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let tsconfig = serde_json::json!({
-            "compilerOptions": {
-                "target": "ES2022",
-                "module": "NodeNext",
-                "moduleResolution": "NodeNext",
-                "lib": ["ES2022"],
-                "outDir": "./dist",
-                "rootDir": "./src",
-                "strict": true,
-                "esModuleInterop": true,
-                "skipLibCheck": true,
-                "forceConsistentCasingInFileNames": true,
-                "declaration": true,
-                "declarationMap": true,
-                "sourceMap": true
-            },
-            "include": ["src/**/*"],
-            "exclude": ["node_modules", "dist"]
+    fn generate_tools_ts(&self, project: &mut GeneratedProject, contract: &ServedContract) -> Result<()> {
+        let spec = &contract.spec;
+        let converted = converted_types(&spec.types);
+
+        // Typed functions calling the official Client
+        let functions: Vec<serde_json::Value> = spec
+            .functions
+            .iter()
+            .map(|func| {
+                let conversions: Vec<Option<String>> = func
+                    .inputs
+                    .iter()
+                    .map(|p| convert_expr(&p.type_ref, &format!("params.{}", p.name), &converted, 0))
+                    .collect();
+
+                let params_expr = if func.inputs.is_empty() {
+                    "{}".to_string()
+                } else if conversions.iter().any(Option::is_some) {
+                    // Build an object with type conversions
+                    let fields: Vec<String> = func
+                        .inputs
+                        .iter()
+                        .zip(&conversions)
+                        .map(|(p, conversion)| match conversion {
+                            Some(expr) => format!("{}: {}", p.name, expr),
+                            None => format!("{}: params.{}", p.name, p.name),
+                        })
+                        .collect();
+                    format!("{{\n    {}\n  }}", fields.join(",\n    "))
+                } else {
+                    "params".to_string()
+                };
+
+                let param_type = if func.inputs.is_empty() {
+                    "{}".to_string()
+                } else {
+                    // Use ContractTypes for parameter types
+                    let params: Vec<String> = func
+                        .inputs
+                        .iter()
+                        .map(|p| {
+                            let ts_type = json_types::typescript(&p.type_ref.to_json_schema(), "ContractTypes.");
+                            let optional = if p.type_ref.is_optional() { "?" } else { "" };
+                            format!("{}{}: {}", p.name, optional, ts_type)
+                        })
+                        .collect();
+                    format!("{{ {} }}", params.join(", "))
+                };
+
+                serde_json::json!({
+                    "name": func.name,
+                    "fn_name": ts_identifier(&to_camel_case(&func.name)),
+                    "doc": ts_comment_text(func.doc.as_ref().unwrap_or(&func.name), " * "),
+                    "param_type": param_type,
+                    "params_expr": params_expr,
+                    "read_only": func.kind == FunctionKind::ReadOnly,
+                })
+            })
+            .collect();
+
+        let data = serde_json::json!({
+            "bindings_module": self.bindings_module(contract),
+            // Spec-driven converters from MCP arguments (JSON) to binding values
+            "converters": ts_type_converters(&spec.types).trim_end(),
+            "functions": functions,
+            "events": Self::event_functions_data(&spec.events),
+            "events_json": Self::events_catalogue(&spec.events),
+            "errors_json": Self::errors_table(spec),
         });
 
-        let content = serde_json::to_string_pretty(&tsconfig)?;
-        fs::write(self.output_dir.join("tsconfig.json"), content)?;
+        let content = self.templates.render("tools.ts", &data)?;
+        project.add(format!("src/tools/{}.ts", contract.name), content);
 
-        println!("  Generated tsconfig.json");
         Ok(())
     }
 
-    fn generate_env_example(&self, _args: &GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
-        let mut content = String::new();
-
-        content.push_str("# Contract configuration\n");
-        content.push_str(&format!("CONTRACT_ID={}\n", self.contract_id));
-        content.push_str(&format!("RPC_URL={}\n", self.network.rpc_url));
-        content.push_str(&format!("NETWORK_PASSPHRASE=\"{}\"\n", self.network.network_passphrase));
-        content.push_str("\n");
-
-        content.push_str("# PasskeyKit configuration (for smart wallet signing)\n");
-        content.push_str("WALLET_WASM_HASH=your_wallet_wasm_hash_here\n");
-        content.push_str("WALLET_CONTRACT_ID=your_wallet_contract_id_here\n");
-        content.push_str("WALLET_SIGNER_SECRET=your_wallet_signer_secret_here\n");
-        content.push_str("\n");
-        content.push_str("# HTTP transport (set to \"true\" to enable HTTP mode instead of stdio)\n");
-        content.push_str("# USE_HTTP=true\n");
-        content.push_str("# PORT=3000\n");
-        content.push_str("\n");
-        content.push_str("# Rate limiting (requests per minute per IP, HTTP mode only)\n");
-        content.push_str("# RATE_LIMIT=100\n");
-        content.push_str("\n");
-        content.push_str("# CORS (comma-separated origins, or * for all — HTTP mode only)\n");
-        content.push_str("# CORS_ORIGINS=https://myapp.example.com,https://staging.example.com\n");
-
-        fs::write(self.output_dir.join(".env.example"), content)?;
-
-        println!("  Generated .env.example");
-        Ok(())
+    /// Contract error codes exported as `CONTRACT_ERRORS`, as a JSON literal.
+    /// A code declared by several error enums keeps the first case.
+    fn errors_table(spec: &ContractSpec) -> String {
+        let mut table = serde_json::Map::new();
+        for error in spec.error_cases() {
+            table.entry(error.code.to_string()).or_insert_with(|| {
+                let mut info = serde_json::json!({ "name": error.name });
+                if let Some(doc) = &error.doc {
+                    info["doc"] = doc.trim().into();
+                }
+                info
+            });
+        }
+        ts_json(&serde_json::Value::Object(table))
     }
 
-    fn generate_deploy_wallet(&self, _args: &GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
-        let content = format!(r#"#!/usr/bin/env tsx
-/**
- * Deploy a PasskeyKit smart wallet contract using PasskeyClient
- *
- * Usage: tsx deploy-wallet.ts <wasm_hash>
- */
-
-import {{ Keypair }} from '@stellar/stellar-sdk';
-import {{ basicNodeSigner }} from '@stellar/stellar-sdk/contract';
-import {{ Buffer }} from 'buffer';
-
-// Import the generated wallet client
-import {{ Client as PasskeyClient }} from 'passkey-kit-sdk';
-
-async function deployWallet() {{
-  console.log('🚀 Deploying PasskeyKit Wallet Contract\n');
-
-  // Get WASM hash from args or use default
-  const wasmHash = process.argv[2];
-  if (!wasmHash) {{
-    console.error('❌ Error: WASM hash required');
-    console.error('Usage: pnpm deploy-passkey <wasm_hash>');
-    console.error('\nTo get WASM hash, first upload the wallet WASM:');
-    console.error('  stellar contract upload --wasm wallet.wasm --source your-key --network testnet');
-    process.exit(1);
-  }}
-
-  // Get deployer keypair from environment or stellar CLI
-  const secretKey = process.env.DEPLOYER_SECRET || 'YOUR_DEPLOYER_SECRET_KEY';
-  if (secretKey === 'YOUR_DEPLOYER_SECRET_KEY') {{
-    console.error('❌ Error: DEPLOYER_SECRET environment variable not set');
-    console.error('Set it with: export DEPLOYER_SECRET=SXXXXXXXXXXXXXXX');
-    process.exit(1);
-  }}
-
-  const deployer = Keypair.fromSecret(secretKey);
-
-  console.log('Deployer:', deployer.publicKey());
-  console.log('Network: {}');
-  console.log('RPC: {}');
-  console.log('WASM Hash:', wasmHash);
-  console.log();
-
-  try {{
-    // Create a dummy Ed25519 signer for testing (instead of real passkey)
-    const dummyKey = Keypair.random();
-    const dummyPublicKey = dummyKey.rawPublicKey();
-
-    console.log('📦 Deploying wallet contract with Ed25519 signer...');
-    console.log('Signer Public Key:', dummyKey.publicKey());
-    console.log();
-
-    // Deploy using PasskeyClient.deploy
-    const assembledTx = await PasskeyClient.deploy(
-      {{
-        signer: {{
-          tag: 'Ed25519',
-          values: [
-            dummyPublicKey,
-            [undefined], // SignerExpiration - no expiration
-            [undefined], // SignerLimits - no limits
-            {{ tag: 'Persistent', values: undefined }}, // SignerStorage
-          ]
-        }}
-      }},
-      {{
-        rpcUrl: '{}',
-        networkPassphrase: '{}',
-        wasmHash: Buffer.from(wasmHash, 'hex'),
-        publicKey: deployer.publicKey(),
-        salt: Buffer.from(Keypair.random().rawPublicKey()), // Random salt
-        timeoutInSeconds: 30,
-      }}
-    );
+    /// Contract event catalogue exported as `CONTRACT_EVENTS`, as a JSON literal
+    fn events_catalogue(events: &[EventSpec]) -> String {
+        let catalogue: serde_json::Value = events
+            .iter()
+            .map(|event| {
+                let fields = |fields: &[crate::spec::FieldSpec]| -> Vec<serde_json::Value> {
+                    fields
+                        .iter()
+                        .map(|f| serde_json::json!({ "name": f.name, "type": f.type_ref.to_typescript() }))
+                        .collect()
+                };
+                serde_json::json!({
+                    "name": event.name,
+                    "tool": format!("get-{}-events", to_kebab_case(&event.name)),
+                    "doc": event.doc,
+                    "prefixTopics": event.prefix_topics,
+                    "topics": fields(&event.topics),
+                    "data": fields(&event.data),
+                    "dataFormat": event_data_format_str(event.data_format),
+                })
+            })
+            .collect();
 
-    const contractId = assembledTx.result.options.contractId;
+        ts_json(&catalogue)
+    }
 
-    console.log('Contract ID (pre-simulation):', contractId);
-    console.log('Signing and sending transaction...');
+    /// Data for the typed query function of each event: its parameters, the
+    /// topic filter expressions and how to decode topics and data
+    fn event_functions_data(events: &[EventSpec]) -> Vec<serde_json::Value> {
+        events
+            .iter()
+            .map(|event| {
+                let mut param_fields = vec![
+                    "startLedger?: number".to_string(),
+                    "cursor?: string".to_string(),
+                    "limit?: number".to_string(),
+                ];
+                let mut topic_exprs: Vec<String> = event
+                    .prefix_topics
+                    .iter()
+                    .map(|t| format!("nativeToScVal({}, {{ type: 'symbol' }}).toXDR('base64')", ts_string(t)))
+                    .collect();
+                for topic in &event.topics {
+                    match event_topic_scval_type(&topic.type_ref) {
+                        Some(scval_type) => {
+                            // 64-bit and wider integers arrive as strings from the Zod schema
+                            let is_bigint = matches!(
+                                topic.type_ref,
+                                TypeRef::U64 | TypeRef::I64 | TypeRef::U128 | TypeRef::I128 | TypeRef::U256 | TypeRef::I256
+                            );
+                            let value = if is_bigint {
+                                format!("BigInt(params.{})", topic.name)
+                            } else {
+                                format!("params.{}", topic.name)
+                            };
+                            param_fields.push(format!("{}?: {}", topic.name, topic.type_ref.to_typescript()));
+                            let hint = if scval_type == "bool" {
+                                String::new()
+                            } else {
+                                format!(", {{ type: '{}' }}", scval_type)
+                            };
+                            topic_exprs.push(format!(
+                                "params.{} != null ? nativeToScVal({}{}).toXDR('base64') : '*'",
+                                topic.name, value, hint
+                            ));
+                        }
+                        None => topic_exprs.push("'*'".to_string()),
+                    }
+                }
 
-    // Sign and send
-    await assembledTx.sign({{
-      signTransaction: basicNodeSigner(deployer, '{}').signTransaction
-    }});
+                let names = |fields: &[crate::spec::FieldSpec]| -> String {
+                    let quoted: Vec<String> = fields.iter().map(|f| ts_string(&f.name)).collect();
+                    format!("[{}]", quoted.join(", "))
+                };
+
+                serde_json::json!({
+                    "name": event.name,
+                    "doc": ts_comment_text(
+                        &event.doc.clone().unwrap_or_else(|| format!("Query {} events", event.name)),
+                        " * ",
+                    ),
+                    "param_fields": param_fields.join("; "),
+                    "topic_exprs": topic_exprs,
+                    "topic_names": names(&event.topics),
+                    "data_names": names(&event.data),
+                    "data_format": event_data_format_str(event.data_format),
+                })
+            })
+            .collect()
+    }
 
-    const result = await assembledTx.send();
+    fn generate_schemas_ts(&self, project: &mut GeneratedProject, contract: &ServedContract) -> Result<()> {
+        let spec = &contract.spec;
 
-    console.log('\n✅ Wallet Deployed Successfully!\n');
-    console.log('Wallet Contract ID:', contractId);
-    console.log('Transaction Hash:', result.hash);
-    console.log('Signer Public Key:', dummyKey.publicKey());
-    console.log('Signer Secret Key:', dummyKey.secret());
+        // Zod schema of a struct field or function parameter, from its JSON Schema
+        let field = |name: &str, type_ref: &TypeRef| {
+            let mut zod = json_types::zod(&type_ref.to_json_schema(), "");
+            if type_ref.is_optional() {
+                zod.push_str(".optional()");
+            }
+            serde_json::json!({ "name": name, "type_ref": type_ref, "zod": zod })
+        };
 
-    console.log('\n📝 Add these to your .env file:');
-    console.log(`WALLET_WASM_HASH=${{wasmHash}}`);
-    console.log(`WALLET_CONTRACT_ID=${{contractId}}`);
-    console.log(`WALLET_SIGNER_SECRET=${{dummyKey.secret()}}`);
+        // Schemas for custom types, keeping the snake_case field names of the official bindings
+        let types: Vec<serde_json::Value> = spec
+            .types
+            .iter()
+            .map(|type_spec| {
+                let fields: Vec<serde_json::Value> = match &type_spec.definition {
+                    TypeDef::Struct { fields } => fields.iter().map(|f| field(&f.name, &f.type_ref)).collect(),
+                    _ => Vec::new(),
+                };
+                let variants = match &type_spec.definition {
+                    TypeDef::Enum { variants } => {
+                        let names: Vec<String> = variants.iter().map(|v| ts_string(&v.name)).collect();
+                        names.join(", ")
+                    }
+                    _ => String::new(),
+                };
+                let union = match &type_spec.definition {
+                    TypeDef::Union { cases } => union_schema(&type_spec.name, cases).trim_end().to_string(),
+                    _ => String::new(),
+                };
+                serde_json::json!({
+                    "name": type_spec.name,
+                    "is_struct": matches!(type_spec.definition, TypeDef::Struct { .. }),
+                    "is_enum": matches!(type_spec.definition, TypeDef::Enum { .. }),
+                    "is_union": matches!(type_spec.definition, TypeDef::Union { .. }),
+                    "fields": fields,
+                    "variants": variants,
+                    "union_schema": union,
+                })
+            })
+            .collect();
 
-  }} catch (error) {{
-    console.error('\n❌ Deployment failed:', error);
-    if (error instanceof Error) {{
-      console.error('Error message:', error.message);
-      console.error('Stack:', error.stack);
-    }}
-    process.exit(1);
-  }}
-}}
+        // Schemas for function parameters
+        let functions: Vec<serde_json::Value> = spec
+            .functions
+            .iter()
+            .map(|f| {
+                let inputs: Vec<serde_json::Value> =
+                    f.inputs.iter().map(|p| field(&p.name, &p.type_ref)).collect();
+                serde_json::json!({ "name": f.name, "inputs": inputs })
+            })
+            .collect();
 
-deployWallet();
-"#, self.network.network_passphrase, self.network.rpc_url, self.network.rpc_url, self.network.network_passphrase, self.network.network_passphrase);
+        let data = serde_json::json!({ "types": types, "functions": functions });
+        let content = self.templates.render("schemas.ts", &data)?;
+        project.add(format!("src/schemas/{}.ts", contract.name), content);
 
-        fs::write(self.output_dir.join("deploy-wallet.ts"), content)?;
-        println!("  Generated deploy-wallet.ts");
         Ok(())
     }
 
-    fn generate_dockerfile(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let content = include_str!("../../templates/Dockerfile.hbs");
-        fs::write(self.output_dir.join("Dockerfile"), content)?;
-        println!("  Generated Dockerfile");
-        Ok(())
-    }
+    fn generate_lib_files(&self, project: &mut GeneratedProject) -> Result<()> {
+        // Logger (structured logging to stderr), Soroban error formatting,
+        // transaction helpers, signing utilities, PasskeyKit integration and
+        // the spec drift check
+        let data = serde_json::json!({});
+        for name in ["logger", "errors", "transaction", "submit", "utils", "passkey", "contract-info"] {
+            let path = format!("src/lib/{}.ts", name);
+            let content = self.templates.render(&format!("lib/{}.ts", name), &data)?;
+            project.add(&path, content);
+        }
 
-    fn generate_dockerignore(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let content = "node_modules\ndist\n.env\n.env.local\n*.log\n.git\n.DS_Store\n";
-        fs::write(self.output_dir.join(".dockerignore"), content)?;
-        println!("  Generated .dockerignore");
         Ok(())
     }
 
-    fn generate_vercel_json(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let content = include_str!("../../templates/vercel.json.hbs");
-        fs::write(self.output_dir.join("vercel.json"), content)?;
-        println!("  Generated vercel.json");
+    fn generate_package_json(&self, project: &mut GeneratedProject) -> Result<()> {
+        let data = serde_json::json!({ "project_name": self.project_name });
+        let content = self.templates.render("package.json", &data)?;
+        project.add("package.json", content);
+
         Ok(())
     }
 
-    fn generate_readme(
-        &self,
-        spec: &ContractSpec,
-        _args: &GenerateArgs,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let mut content = String::new();
-
-        content.push_str(&format!("# {} MCP Server\n\n", to_pascal_case(self.contract_name)));
-        content.push_str(&format!(
-            "Auto-generated MCP server for the {} contract on Stellar {}.\n\n",
-            self.contract_name, self.network.name
-        ));
-
-        content.push_str("## Contract Information\n\n");
-        content.push_str(&format!("- **Contract ID**: `{}`\n", self.contract_id));
-        content.push_str(&format!("- **Network**: {}\n", self.network.name));
-        content.push_str(&format!("- **RPC URL**: {}\n\n", self.network.rpc_url));
-
-        content.push_str("## Requirements\n\n");
-        content.push_str("- **Node.js 20+** recommended\n");
-        content.push_str("- For Node.js 18.x users experiencing SSL certificate errors, add `NODE_TLS_REJECT_UNAUTHORIZED=0` to environment variables (development only)\n\n");
+    fn generate_readme(&self, project: &mut GeneratedProject) -> Result<()> {
+        let namespaced = self.namespaced();
+        let mut data = self.project_data();
 
-        content.push_str("## Available Tools\n\n");
-        for func in &spec.functions {
-            // Create collapsible dropdown for each tool
-            content.push_str(&format!("<details>\n<summary><code>{}</code></summary>\n\n", to_kebab_case(&func.name)));
-
-            if let Some(doc) = &func.doc {
-                content.push_str(&format!("{}\n\n", doc));
-            }
+        let contracts: Vec<serde_json::Value> = self
+            .contracts
+            .iter()
+            .map(|contract| {
+                let functions: Vec<serde_json::Value> = contract
+                    .spec
+                    .functions
+                    .iter()
+                    .map(|func| {
+                        let inputs: Vec<serde_json::Value> = func
+                            .inputs
+                            .iter()
+                            .map(|input| {
+                                serde_json::json!({
+                                    "name": input.name,
+                                    "type_ref": input.type_ref,
+                                    "description": input.doc.as_deref().unwrap_or("No description"),
+                                })
+                            })
+                            .collect();
+                        serde_json::json!({
+                            "name": func.name,
+                            "tool_name": contract.tool_name(&to_kebab_case(&func.name), namespaced),
+                            "doc": func.doc,
+                            "read_only": func.kind == FunctionKind::ReadOnly,
+                            "inputs": inputs,
+                            "output": func.output,
+                        })
+                    })
+                    .collect();
+                let events: Vec<serde_json::Value> = contract
+                    .spec
+                    .events
+                    .iter()
+                    .map(|event| {
+                        let tool_name = format!("get-{}-events", to_kebab_case(&event.name));
+                        serde_json::json!({
+                            "name": event.name,
+                            "tool_name": contract.tool_name(&tool_name, namespaced),
+                            "doc": event.doc,
+                            "topics": event.topics,
+                            "data": event.data,
+                        })
+                    })
+                    .collect();
 
-            if !func.inputs.is_empty() {
-                content.push_str("**Parameters:**\n\n");
-                for input in &func.inputs {
-                    content.push_str(&format!(
-                        "- `{}` ({}): {}\n",
-                        input.name,
-                        input.type_ref.to_typescript(),
-                        input.doc.as_ref().unwrap_or(&"No description".to_string())
-                    ));
-                }
-                content.push_str("\n");
-            }
+                serde_json::json!({
+                    "name": contract.name,
+                    "contract_id": contract.contract_id,
+                    "env_var": contract.env_var(namespaced),
+                    "wasm_hash": contract.wasm_hash,
+                    "snapshot_path": self.snapshot_path(contract),
+                    "functions": functions,
+                    "events": events,
+                })
+            })
+            .collect();
+        let names: Vec<&str> = self.contracts.iter().map(|c| c.name.as_str()).collect();
 
-            if let Some(output) = &func.output {
-                content.push_str(&format!("**Returns:** `{}`\n\n", output.to_typescript()));
-            }
+        data["contracts"] = serde_json::Value::Array(contracts);
+        data["contract_names"] = names.join(", ").into();
+        data["example_tool"] = self.contracts[0].tool_name("contract-info", true).into();
+        data["has_events"] = self.contracts.iter().any(|c| !c.spec.events.is_empty()).into();
 
-            content.push_str("</details>\n\n");
-        }
+        let content = self.templates.render("README.md", &data)?;
+        project.add("README.md", content);
 
-        // Environment Variables section
-        content.push_str("## Environment Variables\n\n");
-        content.push_str("Create a `.env` file in the project root with the following variables:\n\n");
-        content.push_str("### Core Contract Configuration\n\n");
-        content.push_str("```bash\n");
-        content.push_str(&format!("CONTRACT_ID={}\n", self.contract_id));
-        content.push_str(&format!("RPC_URL={}\n", self.network.rpc_url));
-        content.push_str(&format!("NETWORK_PASSPHRASE={}\n", self.network.network_passphrase));
-        content.push_str("```\n\n");
-
-        content.push_str("### PasskeyKit Configuration (Optional)\n\n");
-        content.push_str("For passkey-based transaction signing:\n\n");
-        content.push_str("```bash\n");
-        content.push_str("WALLET_WASM_HASH=your_wallet_wasm_hash_here\n");
-        content.push_str("WALLET_CONTRACT_ID=your_wallet_contract_id_here\n");
-        content.push_str("WALLET_SIGNER_SECRET=your_wallet_signer_secret_here\n");
-        content.push_str("```\n\n");
-
-        // Deployment section
-        content.push_str("## Deploying a PasskeyKit Wallet\n\n");
-        content.push_str("To enable passkey-based signing, deploy a PasskeyKit wallet contract:\n\n");
-        content.push_str("### 1. Build or obtain the wallet WASM\n\n");
-        content.push_str("```bash\n");
-        content.push_str("# Option 1: Build from passkey-kit source\n");
-        content.push_str("cd /path/to/passkey-kit/contracts\n");
-        content.push_str("make build\n");
-        content.push_str("cp out/smart_wallet.optimized.wasm ./wallet.wasm\n\n");
-        content.push_str("# Option 2: Use pre-built WASM\n");
-        content.push_str("# Download from passkey-kit releases\n");
-        content.push_str("```\n\n");
-
-        content.push_str("### 2. Upload WASM to network\n\n");
-        content.push_str("```bash\n");
-        content.push_str("stellar contract upload \\\n");
-        content.push_str("  --wasm wallet.wasm \\\n");
-        content.push_str("  --source your-keypair-alias \\\n");
-        content.push_str(&format!("  --network {}\n", self.network.name.to_lowercase()));
-        content.push_str("\n# Save the WASM hash from output\n");
-        content.push_str("```\n\n");
-
-        content.push_str("### 3. Deploy wallet using the script\n\n");
-        content.push_str("```bash\n");
-        content.push_str("# Set your deployer secret\n");
-        content.push_str("export DEPLOYER_SECRET=SXXXXXXXXXXXXXXX\n\n");
-        content.push_str("# Deploy with the WASM hash from step 2\n");
-        content.push_str("pnpm deploy-passkey <WASM_HASH>\n");
-        content.push_str("```\n\n");
-
-        content.push_str("The script will output the wallet contract ID and signer credentials. Add these to your `.env` file.\n\n");
-
-        // Quick Start section
-        content.push_str("## Quick Start\n\n");
-        content.push_str("```bash\n");
-        content.push_str("# Install dependencies\n");
-        content.push_str("pnpm install\n\n");
-        content.push_str("# Configure environment\n");
-        content.push_str("cp .env.example .env\n");
-        content.push_str("# Edit .env with your values\n\n");
-        content.push_str("# Build\n");
-        content.push_str("pnpm run build\n\n");
-        content.push_str("# Start server\n");
-        content.push_str("pnpm start\n");
-        content.push_str("```\n\n");
-
-        // HTTP Transport section
-        content.push_str("## HTTP Transport\n\n");
-        content.push_str("Run as an HTTP server instead of stdio (useful for remote deployments):\n\n");
-        content.push_str("```bash\n");
-        content.push_str("# Start with HTTP transport\n");
-        content.push_str("USE_HTTP=true PORT=3000 pnpm start\n\n");
-        content.push_str("# Or use the convenience script\n");
-        content.push_str("pnpm start:http\n");
-        content.push_str("```\n\n");
-        content.push_str("The HTTP server exposes:\n");
-        content.push_str("- `POST /mcp` — Streamable HTTP MCP endpoint\n");
-        content.push_str("- `GET /health` — Health check\n\n");
-
-        // Rate Limiting section
-        content.push_str("### Rate Limiting\n\n");
-        content.push_str("When running in HTTP mode, requests to `/mcp` are rate-limited per IP address.\n\n");
-        content.push_str("| Variable | Default | Description |\n");
-        content.push_str("|----------|---------|-------------|\n");
-        content.push_str("| `RATE_LIMIT` | `100` | Max requests per IP per 60-second window |\n\n");
-        content.push_str("Exceeding the limit returns HTTP 429 with a `Retry-After` header.\n\n");
-
-        // Docker Deployment section
-        content.push_str("## Docker Deployment\n\n");
-        content.push_str("A `Dockerfile` is included for containerized deployment:\n\n");
-        content.push_str("```bash\n");
-        content.push_str("# Build the image\n");
-        content.push_str(&format!("docker build -t {}-mcp .\n\n", self.contract_name));
-        content.push_str("# Run the container\n");
-        content.push_str(&format!("docker run -d \\\n"));
-        content.push_str("  --name mcp-server \\\n");
-        content.push_str("  -p 3000:3000 \\\n");
-        content.push_str(&format!("  -e CONTRACT_ID={} \\\n", self.contract_id));
-        content.push_str(&format!("  -e RPC_URL={} \\\n", self.network.rpc_url));
-        content.push_str(&format!("  -e NETWORK_PASSPHRASE='{}' \\\n", self.network.network_passphrase));
-        content.push_str("  -e RATE_LIMIT=100 \\\n");
-        content.push_str(&format!("  {}-mcp\n", self.contract_name));
-        content.push_str("```\n\n");
-        content.push_str("The container runs in HTTP mode by default on port 3000 with a built-in health check.\n\n");
-
-        // Vercel Deployment section
-        content.push_str("## Vercel Deployment\n\n");
-        content.push_str("A `vercel.json` is included for serverless deployment:\n\n");
-        content.push_str("1. Install the Vercel CLI: `npm i -g vercel`\n");
-        content.push_str("2. Set environment variables:\n");
-        content.push_str("   ```bash\n");
-        content.push_str(&format!("   vercel env add CONTRACT_ID  # {}\n", self.contract_id));
-        content.push_str(&format!("   vercel env add RPC_URL      # {}\n", self.network.rpc_url));
-        content.push_str("   vercel env add NETWORK_PASSPHRASE\n");
-        content.push_str("   vercel env add USE_HTTP      # true\n");
-        content.push_str("   ```\n");
-        content.push_str("3. Deploy:\n");
-        content.push_str("   ```bash\n");
-        content.push_str("   vercel --prod\n");
-        content.push_str("   ```\n\n");
-
-        // Claude Desktop Configuration
-        content.push_str("## Claude Desktop Configuration\n\n");
-        content.push_str("Add to `~/Library/Application Support/Claude/claude_desktop_config.json`:\n\n");
-
-        // Basic configuration without passkey
-        content.push_str("### Basic Configuration (Standard Keypair Signing)\n\n");
-        content.push_str("```json\n");
-        content.push_str("{\n");
-        content.push_str("  \"mcpServers\": {\n");
-        content.push_str(&format!("    \"{}\": {{\n", self.contract_name));
-        content.push_str("      \"command\": \"node\",\n");
-        content.push_str("      \"args\": [\"/absolute/path/to/this/project/dist/index.js\"],\n");
-        content.push_str("      \"env\": {\n");
-        content.push_str(&format!("        \"CONTRACT_ID\": \"{}\",\n", self.contract_id));
-        content.push_str(&format!("        \"RPC_URL\": \"{}\",\n", self.network.rpc_url));
-        content.push_str(&format!("        \"NETWORK_PASSPHRASE\": \"{}\"\n", self.network.network_passphrase));
-        content.push_str("      }\n");
-        content.push_str("    }\n");
-        content.push_str("  }\n");
-        content.push_str("}\n");
-        content.push_str("```\n\n");
-
-        // Configuration with passkey support
-        content.push_str("### With PasskeyKit Support\n\n");
-        content.push_str("If you want to use passkey-based signing, add `WALLET_WASM_HASH`:\n\n");
-        content.push_str("```json\n");
-        content.push_str("{\n");
-        content.push_str("  \"mcpServers\": {\n");
-        content.push_str(&format!("    \"{}\": {{\n", self.contract_name));
-        content.push_str("      \"command\": \"node\",\n");
-        content.push_str("      \"args\": [\"/absolute/path/to/this/project/dist/index.js\"],\n");
-        content.push_str("      \"env\": {\n");
-        content.push_str(&format!("        \"CONTRACT_ID\": \"{}\",\n", self.contract_id));
-        content.push_str(&format!("        \"RPC_URL\": \"{}\",\n", self.network.rpc_url));
-        content.push_str(&format!("        \"NETWORK_PASSPHRASE\": \"{}\",\n", self.network.network_passphrase));
-        content.push_str("        \"WALLET_WASM_HASH\": \"your_wallet_wasm_hash_here\"\n");
-        content.push_str("      }\n");
-        content.push_str("    }\n");
-        content.push_str("  }\n");
-        content.push_str("}\n");
-        content.push_str("```\n\n");
-
-        content.push_str("**Important Notes:**\n");
-        content.push_str("- Replace `/absolute/path/to/this/project/` with the actual absolute path to this directory\n");
-        content.push_str("- For passkey support: Replace `your_wallet_wasm_hash_here` with your deployed wallet WASM hash (get this from `pnpm deploy-passkey`)\n");
-        content.push_str("- Build the project first with `pnpm run build` before starting Claude Desktop\n");
-        content.push_str("- Restart Claude Desktop after making configuration changes\n");
-        content.push_str("- If using Node.js 18.x and encountering SSL errors, add `\"NODE_TLS_REJECT_UNAUTHORIZED\": \"0\"` to the `env` object (development only)\n\n");
-
-        // Transaction Signing section
-        content.push_str("## Transaction Signing\n\n");
-        content.push_str("This MCP server supports two transaction signing methods:\n\n");
-        content.push_str("### 1. Standard Keypair Signing\n\n");
-        content.push_str("Use your Stellar secret key directly:\n");
-        content.push_str("- Signs authorization entries\n");
-        content.push_str("- Signs transaction envelope\n");
-        content.push_str("- Submits to network\n\n");
-        content.push_str("### 2. PasskeyKit Smart Wallet Signing\n\n");
-        content.push_str("Use a deployed PasskeyKit wallet for enhanced security:\n");
-        content.push_str("- Signs authorization entries with keypair\n");
-        content.push_str("- Signs envelope with smart wallet contract\n");
-        content.push_str("- Supports passkey-based authentication\n\n");
-        content.push_str("To use passkey signing, provide the `walletContractId` parameter to the `sign-and-submit` tool.\n\n");
-
-        content.push_str("## Generated by\n\n");
-        content.push_str("[stellar-mcp-generator](https://github.com/stellar/stellar-mcp-generator)\n\n");
-        content.push_str("This MCP server was auto-generated from the Stellar smart contract and includes production-ready transaction signing with support for both standard keypairs and PasskeyKit smart wallets.\n");
-
-        fs::write(self.output_dir.join("README.md"), content)?;
-
-        println!("  Generated README.md");
         Ok(())
     }
+
+    /// Settings shared by the project-level templates (configuration files,
+    /// deployment files and the README)
+    fn project_data(&self) -> serde_json::Value {
+        let mut data = project_data(self.project_name, self.server_name, self.network, self.contracts);
+        data["with_rest"] = self.with_rest.into();
+        data
+    }
 }
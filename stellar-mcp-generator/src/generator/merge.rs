@@ -0,0 +1,293 @@
+//! Line-based three-way merge for regenerating projects in place
+//!
+//! `base` is the content the generator wrote last time, `ours` is the file as
+//! the user left it and `theirs` is the freshly generated content. Changes made
+//! on only one side are taken as-is; overlapping changes become conflicts
+//! marked with `<<<<<<<` / `=======` / `>>>>>>>`.
+//!
+//! The same line matching backs [`unified_diff`], used by `generate --diff`.
+
+/// Marker opening a user-owned region, followed by the region name
+pub const CUSTOM_BEGIN: &str = "stellar-mcp:custom-begin";
+
+/// Marker closing a user-owned region, followed by the region name
+pub const CUSTOM_END: &str = "stellar-mcp:custom-end";
+
+/// Result of a three-way merge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeResult {
+    /// Merged content, with conflict markers where both sides changed
+    pub content: String,
+    /// Number of conflicting hunks
+    pub conflicts: usize,
+}
+
+/// Merge the user's changes (`base` → `ours`) into newly generated content (`theirs`)
+pub fn merge3(base: &str, ours: &str, theirs: &str) -> MergeResult {
+    let base_lines = split_lines(base);
+    let our_lines = split_lines(ours);
+    let their_lines = split_lines(theirs);
+
+    let ours_by_base = matching_lines(&base_lines, &our_lines);
+    let theirs_by_base = matching_lines(&base_lines, &their_lines);
+
+    let mut content = String::new();
+    let mut conflicts = 0;
+    let (mut i, mut j, mut k) = (0, 0, 0);
+
+    loop {
+        // Next base line kept unchanged on both sides (a stable anchor)
+        let anchor =
+            (i..base_lines.len()).find_map(|b| match (ours_by_base[b], theirs_by_base[b]) {
+                (Some(o), Some(t)) => Some((b, o, t)),
+                _ => None,
+            });
+        let (b_end, o_end, t_end) =
+            anchor.unwrap_or((base_lines.len(), our_lines.len(), their_lines.len()));
+
+        let base_chunk = &base_lines[i..b_end];
+        let our_chunk = &our_lines[j..o_end];
+        let their_chunk = &their_lines[k..t_end];
+
+        if our_chunk == base_chunk {
+            push_lines(&mut content, their_chunk);
+        } else if their_chunk == base_chunk || our_chunk == their_chunk {
+            push_lines(&mut content, our_chunk);
+        } else {
+            conflicts += 1;
+            content.push_str("<<<<<<< yours\n");
+            push_lines_terminated(&mut content, our_chunk);
+            content.push_str("=======\n");
+            push_lines_terminated(&mut content, their_chunk);
+            content.push_str(">>>>>>> generated\n");
+        }
+
+        match anchor {
+            Some((b, o, t)) => {
+                content.push_str(base_lines[b]);
+                i = b + 1;
+                j = o + 1;
+                k = t + 1;
+            }
+            None => break,
+        }
+    }
+
+    MergeResult { content, conflicts }
+}
+
+/// Lines of context around each hunk of a unified diff
+const DIFF_CONTEXT: usize = 3;
+
+/// One line of a diff: kept, removed from `old` or added from `new`
+#[derive(Clone, Copy)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Unified diff (as `diff -u` prints it) turning `old` into `new`, with the
+/// given file labels. Empty when the contents are equal.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+    let matches = matching_lines(&old_lines, &new_lines);
+
+    let mut lines = Vec::new();
+    let mut j = 0;
+    for (i, line) in old_lines.iter().enumerate() {
+        match matches[i] {
+            Some(m) => {
+                lines.extend(new_lines[j..m].iter().map(|l| DiffLine::Added(l)));
+                lines.push(DiffLine::Same(line));
+                j = m + 1;
+            }
+            None => lines.push(DiffLine::Removed(line)),
+        }
+    }
+    lines.extend(new_lines[j..].iter().map(|l| DiffLine::Added(l)));
+
+    let changed: Vec<usize> = (0..lines.len())
+        .filter(|&n| !matches!(lines[n], DiffLine::Same(_)))
+        .collect();
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("--- {}\n+++ {}\n", old_label, new_label);
+    let mut start = 0;
+    while start < changed.len() {
+        // Changes closer than twice the context share a hunk
+        let mut end = start;
+        while end + 1 < changed.len() && changed[end + 1] - changed[end] <= 2 * DIFF_CONTEXT {
+            end += 1;
+        }
+        let from = changed[start].saturating_sub(DIFF_CONTEXT);
+        let to = (changed[end] + DIFF_CONTEXT + 1).min(lines.len());
+        push_hunk(&mut out, &lines, from, to);
+        start = end + 1;
+    }
+    out
+}
+
+/// Append the hunk covering `lines[from..to]`
+fn push_hunk(out: &mut String, lines: &[DiffLine], from: usize, to: usize) {
+    let counts = |range: &[DiffLine]| {
+        range.iter().fold((0, 0), |(old, new), line| match line {
+            DiffLine::Same(_) => (old + 1, new + 1),
+            DiffLine::Removed(_) => (old + 1, new),
+            DiffLine::Added(_) => (old, new + 1),
+        })
+    };
+    let (old_before, new_before) = counts(&lines[..from]);
+    let (old_count, new_count) = counts(&lines[from..to]);
+    // An empty range is numbered after the line it follows
+    let first = |before: usize, count: usize| if count == 0 { before } else { before + 1 };
+
+    out.push_str(&format!(
+        "@@ -{},{} +{},{} @@\n",
+        first(old_before, old_count),
+        old_count,
+        first(new_before, new_count),
+        new_count
+    ));
+    for line in &lines[from..to] {
+        let (prefix, text) = match line {
+            DiffLine::Same(text) => (' ', text),
+            DiffLine::Removed(text) => ('-', text),
+            DiffLine::Added(text) => ('+', text),
+        };
+        out.push(prefix);
+        out.push_str(text);
+        if !text.ends_with('\n') {
+            out.push_str("\n\\ No newline at end of file\n");
+        }
+    }
+}
+
+/// Copy the body of every `stellar-mcp:custom-begin <name>` region in `current`
+/// into the region of the same name in `generated`, so code users add inside
+/// marked regions always survives regeneration.
+pub fn carry_custom_regions(current: &str, generated: &str) -> String {
+    let current_lines = split_lines(current);
+    let generated_lines = split_lines(generated);
+    let mut result = String::new();
+    let mut i = 0;
+
+    while i < generated_lines.len() {
+        let line = generated_lines[i];
+        result.push_str(line);
+        i += 1;
+        let Some(name) = marker_name(line, CUSTOM_BEGIN) else {
+            continue;
+        };
+        let Some(body) = region_body(&current_lines, name) else {
+            continue;
+        };
+        // A begin marker without its end marker does not delimit a region:
+        // keep the generated lines rather than dropping the rest of the file
+        let Some(len) = region_len(&generated_lines[i..], name) else {
+            continue;
+        };
+
+        // Skip the generated body and emit the user's instead
+        push_lines(&mut result, body);
+        i += len;
+    }
+
+    result
+}
+
+fn region_body<'a>(lines: &'a [&'a str], name: &str) -> Option<&'a [&'a str]> {
+    let start = lines
+        .iter()
+        .position(|l| marker_name(l, CUSTOM_BEGIN) == Some(name))?
+        + 1;
+    let len = region_len(&lines[start..], name)?;
+    Some(&lines[start..start + len])
+}
+
+/// Number of lines before the `custom-end` marker of region `name`
+fn region_len(lines: &[&str], name: &str) -> Option<usize> {
+    lines.iter().position(|l| marker_name(l, CUSTOM_END) == Some(name))
+}
+
+fn marker_name<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    let (_, rest) = line.split_once(marker)?;
+    rest.split_whitespace().next()
+}
+
+fn split_lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+fn push_lines(out: &mut String, lines: &[&str]) {
+    for line in lines {
+        out.push_str(line);
+    }
+}
+
+/// Like `push_lines`, but makes sure the chunk ends with a newline so the
+/// following conflict marker starts on its own line
+fn push_lines_terminated(out: &mut String, lines: &[&str]) {
+    push_lines(out, lines);
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+/// For every line of `base`, the index of the matching line in `other` along a
+/// longest common subsequence (`None` if the line was changed or removed)
+fn matching_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; base.len()];
+
+    // Common prefix and suffix are matched directly, the LCS only covers the middle
+    let prefix = base.iter().zip(other).take_while(|(a, b)| a == b).count();
+    let suffix = base[prefix..]
+        .iter()
+        .rev()
+        .zip(other[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    for (i, m) in matches.iter_mut().enumerate().take(prefix) {
+        *m = Some(i);
+    }
+    for s in 0..suffix {
+        matches[base.len() - 1 - s] = Some(other.len() - 1 - s);
+    }
+
+    let a = &base[prefix..base.len() - suffix];
+    let b = &other[prefix..other.len() - suffix];
+    if a.is_empty() || b.is_empty() {
+        return matches;
+    }
+
+    // lengths[i][j] = LCS length of a[i..] and b[j..]
+    let width = b.len() + 1;
+    let mut lengths = vec![0u32; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i * width + j] = if a[i] == b[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            matches[prefix + i] = Some(prefix + j);
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    matches
+}
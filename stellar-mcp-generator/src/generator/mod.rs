@@ -1,17 +1,36 @@
 //! MCP server generator module
 
+pub mod backend;
+pub mod contracts;
 mod mcp_generator;
 mod python_generator;
+mod rust_generator;
+mod go_generator;
 pub mod pydantic_schemas;
+pub mod python_bindings;
 mod frontend_generator;
+pub mod merge;
+pub mod names;
+pub mod openapi;
+mod project;
 mod template_data;
 mod templates;
+pub mod tool_manifest;
+pub mod ts_bindings;
+pub mod update;
 
+pub use backend::{BackendRegistry, TargetBackend};
+pub use contracts::ServedContract;
 pub use mcp_generator::McpGenerator;
 pub use python_generator::PythonGenerator;
+pub use rust_generator::RustGenerator;
+pub use go_generator::GoGenerator;
 pub use frontend_generator::FrontendGenerator;
+pub use project::{render_project, GeneratedProject, ProjectOptions};
 
 // Re-export specific items to avoid ambiguous glob exports
-pub use template_data::{to_kebab_case, to_camel_case, to_pascal_case};
+pub use template_data::{to_kebab_case, to_camel_case, to_pascal_case, to_snake_case};
 pub use templates::TemplateRenderer;
-pub use mcp_generator::{output_zod_for_type, output_schema_raw_shape};
+pub use mcp_generator::{
+    output_zod_for_type, output_schema_raw_shape, ts_convert_expr, ts_type_converters, union_schema,
+};
@@ -0,0 +1,352 @@
+//! Collision checks for names generated from contract specs
+//!
+//! Tool, function, schema and parameter names in a generated server are
+//! derived from spec names by case conversion, so distinct spec names can end
+//! up with the same generated name (`get_x` and `getX` both become the tool
+//! `get-x`), or with a name the generated code already uses (a function
+//! `sign_and_submit`, a type `Client`). The server would then register a tool
+//! twice or silently shadow one of its own definitions.
+//!
+//! [`find_collisions`] walks every scope of the code `render_project` would
+//! generate for a language and reports each name with more than one source.
+//! Renaming tools behind the user's back would break the agents and configs
+//! that call them, so collisions are errors ([`check_collisions`]): the fix is
+//! to exclude one of the functions or rename it in the contract.
+
+use super::backend::BackendRegistry;
+use super::contracts::ServedContract;
+use super::mcp_generator::{converted_types, event_topic_scval_type};
+use super::python_generator::to_python_identifier;
+use super::tool_manifest::ToolNaming;
+use super::template_data::{to_camel_case, to_kebab_case, to_pascal_case};
+use crate::codegen::emit::ts_identifier;
+use crate::error::{GeneratorError, Result};
+use crate::spec::TypeDef;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Tools every generated server registers, whatever the contract
+const BUILT_IN_TOOLS: &[&str] = &["sign-and-submit", "prepare-transaction", "prepare-sign-and-submit"];
+
+/// Top-level names of `src/tools/<contract>.ts` that don't come from the spec
+const TS_TOOLS_MODULE: &[&str] = &[
+    "rpc", "nativeToScVal", "scValToNative", "Client", "ContractTypes", "ContractConfig",
+    "createClient", "CONTRACT_EVENTS", "EventQuery", "DecodedEvent", "EventsPage", "toJsonValue",
+    "queryEvents", "ContractErrors", "CONTRACT_ERRORS",
+];
+
+/// Top-level names of the TypeScript bindings that don't come from the spec
+const TS_BINDINGS_MODULE: &[&str] = &[
+    "AssembledTransaction", "ContractClient", "ContractClientOptions", "MethodOptions",
+    "ContractSpec", "i32", "i64", "i128", "i256", "Option", "Result", "u32", "u64", "u128", "u256",
+    "networks", "Errors", "Client", "specEntries",
+];
+
+/// Inputs every TypeScript (and Rust) event tool has besides its topic filters
+const TS_EVENT_INPUTS: &[&str] = &["startLedger", "cursor", "limit"];
+
+/// Top-level names of `server.py` that don't come from the spec
+const PY_SERVER_MODULE: &[&str] = &[
+    "os", "sys", "asyncio", "json", "FastMCP", "Context", "Optional", "Any", "Dict", "List",
+    "Tuple", "load_dotenv", "Address", "sign_transaction", "prepare_transaction_for_wallet",
+    "submit_transaction", "get_contract_info", "format_tool_error", "mcp", "ON_SPEC_DRIFT", "check_spec_drift",
+    "sign_and_submit", "prepare_transaction", "prepare_sign_and_submit", "health_check",
+];
+
+/// Top-level names of the Python bindings that don't come from the spec
+const PY_BINDINGS_MODULE: &[&str] = &[
+    "annotations", "dataclass", "IntEnum", "Any", "Dict", "List", "Optional", "Tuple", "Address",
+    "scval", "xdr", "AssembledTransactionAsync", "ContractClientAsync", "ERRORS", "ClientAsync",
+];
+
+/// Top-level names of a Python `schemas.py` that don't come from the spec
+const PY_SCHEMAS_MODULE: &[&str] = &[
+    "BaseModel", "Field", "ConfigDict", "Optional", "List", "Dict", "Tuple", "Union", "Literal",
+    "Address",
+];
+
+/// Parameters every Python tool function has besides the contract's
+const PY_TOOL_PARAMS: &[&str] = &["ctx"];
+
+/// Parameters every Python event tool function has besides its topic filters
+const PY_EVENT_PARAMS: &[&str] = &["start_ledger", "cursor", "limit", "ctx"];
+
+/// A generated name with more than one source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameCollision {
+    /// Where the name is defined: `tool names`, a file, or a function's parameters
+    pub scope: String,
+    /// The generated name
+    pub name: String,
+    /// What each definition is generated from, e.g. "function `get_x`" or "built-in"
+    pub sources: Vec<String>,
+}
+
+impl fmt::Display for NameCollision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: '{}' is generated from {}", self.scope, self.name, self.sources.join(" and "))
+    }
+}
+
+/// Names defined in one scope of the generated code, with their sources
+struct Scope {
+    name: String,
+    names: BTreeMap<String, Vec<String>>,
+}
+
+impl Scope {
+    fn new(name: impl Into<String>, built_in: &[&str]) -> Self {
+        let mut scope = Self {
+            name: name.into(),
+            names: BTreeMap::new(),
+        };
+        for name in built_in {
+            scope.add(name.to_string(), "built-in".to_string());
+        }
+        scope
+    }
+
+    fn add(&mut self, name: String, source: String) {
+        let sources = self.names.entry(name).or_default();
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    fn collisions(self) -> impl Iterator<Item = NameCollision> {
+        let scope = self.name;
+        self.names
+            .into_iter()
+            .filter(|(_, sources)| sources.len() > 1)
+            .map(move |(name, sources)| NameCollision {
+                scope: scope.clone(),
+                name,
+                sources,
+            })
+    }
+}
+
+/// Spec items of one contract, labelled the way `stellar-mcp.toml` names them
+/// (`transfer`, or `token.transfer` on a multi-contract server)
+struct Labels<'a> {
+    contract: &'a ServedContract,
+    namespaced: bool,
+}
+
+impl Labels<'_> {
+    fn of(&self, kind: &str, name: &str) -> String {
+        if self.namespaced {
+            format!("{} `{}.{}`", kind, self.contract.name, name)
+        } else {
+            format!("{} `{}`", kind, name)
+        }
+    }
+}
+
+/// Every generated name of a `lang` project for `contracts` that more than one
+/// definition maps to, as checked by the `registry` backend for `lang`.
+/// Fails with `GeneratorError::InvalidInput` if no backend is registered for it.
+pub fn find_collisions(registry: &BackendRegistry, lang: &str, contracts: &[ServedContract]) -> Result<Vec<NameCollision>> {
+    Ok(registry.get(lang)?.find_collisions(contracts))
+}
+
+/// Fail with [`GeneratorError::NameCollisions`] if any generated names collide
+pub fn check_collisions(registry: &BackendRegistry, lang: &str, contracts: &[ServedContract]) -> Result<()> {
+    let collisions = find_collisions(registry, lang, contracts)?;
+    if collisions.is_empty() {
+        Ok(())
+    } else {
+        Err(GeneratorError::NameCollisions { collisions })
+    }
+}
+
+pub(crate) fn typescript_collisions(contracts: &[ServedContract]) -> Vec<NameCollision> {
+    let namespaced = contracts.len() > 1;
+    let mut tools = Scope::new("tool names", BUILT_IN_TOOLS);
+    let mut scopes = Vec::new();
+
+    for contract in contracts {
+        let labels = Labels { contract, namespaced };
+        let spec = &contract.spec;
+        let bindings_module = if namespaced { contract.name.as_str() } else { "index" };
+
+        tools.add(contract.tool_name("contract-info", namespaced), "built-in".to_string());
+        for func in &spec.functions {
+            tools.add(contract.tool_name(&to_kebab_case(&func.name), namespaced), labels.of("function", &func.name));
+        }
+        for event in &spec.events {
+            let tool = format!("get-{}-events", to_kebab_case(&event.name));
+            tools.add(contract.tool_name(&tool, namespaced), labels.of("event", &event.name));
+        }
+
+        let mut module = Scope::new(format!("src/tools/{}.ts", contract.name), TS_TOOLS_MODULE);
+        for func in &spec.functions {
+            module.add(ts_identifier(&to_camel_case(&func.name)), labels.of("function", &func.name));
+        }
+        for event in &spec.events {
+            module.add(format!("get{}Events", to_pascal_case(&event.name)), labels.of("event", &event.name));
+        }
+        let converted = converted_types(&spec.types);
+        for type_spec in spec.types.iter().filter(|t| converted.contains(&t.name)) {
+            module.add(format!("convert{}", to_pascal_case(&type_spec.name)), labels.of("type", &type_spec.name));
+        }
+        scopes.push(module);
+
+        let mut schemas = Scope::new(format!("src/schemas/{}.ts", contract.name), &["z"]);
+        for type_spec in &spec.types {
+            schemas.add(format!("{}Schema", to_pascal_case(&type_spec.name)), labels.of("type", &type_spec.name));
+        }
+        for func in spec.functions.iter().filter(|f| !f.inputs.is_empty()) {
+            schemas.add(format!("{}ParamsSchema", to_pascal_case(&func.name)), labels.of("function", &func.name));
+        }
+        scopes.push(schemas);
+
+        let mut bindings = Scope::new(format!("src/bindings/{}.ts", bindings_module), TS_BINDINGS_MODULE);
+        for type_spec in &spec.types {
+            bindings.add(type_spec.name.clone(), labels.of("type", &type_spec.name));
+        }
+        scopes.push(bindings);
+
+        for event in &spec.events {
+            let tool = contract.tool_name(&format!("get-{}-events", to_kebab_case(&event.name)), namespaced);
+            let mut inputs = Scope::new(format!("src/index.ts: inputs of {}", tool), TS_EVENT_INPUTS);
+            for topic in event.topics.iter().filter(|t| event_topic_scval_type(&t.type_ref).is_some()) {
+                inputs.add(topic.name.clone(), format!("topic `{}`", topic.name));
+            }
+            scopes.push(inputs);
+        }
+    }
+
+    std::iter::once(tools)
+        .chain(scopes)
+        .flat_map(Scope::collisions)
+        .collect()
+}
+
+/// The Rust server looks tools up by name and reads event filters from the
+/// same arguments as the paging inputs; its Rust identifiers don't come from
+/// the spec.
+pub(crate) fn rust_collisions(contracts: &[ServedContract]) -> Vec<NameCollision> {
+    let namespaced = contracts.len() > 1;
+    let mut tools = Scope::new("tool names", BUILT_IN_TOOLS);
+    let mut scopes = Vec::new();
+
+    for contract in contracts {
+        let labels = Labels { contract, namespaced };
+        let spec = &contract.spec;
+
+        tools.add(contract.tool_name("contract-info", namespaced), "built-in".to_string());
+        for func in &spec.functions {
+            tools.add(contract.tool_name(&to_kebab_case(&func.name), namespaced), labels.of("function", &func.name));
+        }
+        for event in &spec.events {
+            let tool = contract.tool_name(&format!("get-{}-events", to_kebab_case(&event.name)), namespaced);
+            tools.add(tool.clone(), labels.of("event", &event.name));
+
+            let mut inputs = Scope::new(format!("tools.json: inputs of {}", tool), TS_EVENT_INPUTS);
+            for topic in event.topics.iter().filter(|t| event_topic_scval_type(&t.type_ref).is_some()) {
+                inputs.add(topic.name.clone(), format!("topic `{}`", topic.name));
+            }
+            scopes.push(inputs);
+        }
+    }
+
+    std::iter::once(tools)
+        .chain(scopes)
+        .flat_map(Scope::collisions)
+        .collect()
+}
+
+/// The Go server is laid out like the Rust one: tools registered from
+/// `tools.json` and no identifiers taken from the spec
+pub(crate) fn go_collisions(contracts: &[ServedContract]) -> Vec<NameCollision> {
+    rust_collisions(contracts)
+}
+
+pub(crate) fn python_collisions(contracts: &[ServedContract]) -> Vec<NameCollision> {
+    let namespaced = contracts.len() > 1;
+    let mut tools = Scope::new("tool names", BUILT_IN_TOOLS);
+    let mut server = Scope::new("server.py", PY_SERVER_MODULE);
+    let mut scopes = Vec::new();
+
+    for contract in contracts {
+        let labels = Labels { contract, namespaced };
+        let spec = &contract.spec;
+        let (prefix, package) = if namespaced {
+            (format!("{}_", contract.identifier()), format!("src/{}", contract.identifier()))
+        } else {
+            (String::new(), "src".to_string())
+        };
+
+        tools.add(contract.tool_name(ToolNaming::Python.contract_info_tool(), namespaced), "built-in".to_string());
+        for name in ["client", "config", "contract_info"] {
+            server.add(format!("{}{}", prefix, name), "built-in".to_string());
+        }
+        server.add(format!("{}SPEC_SNAPSHOT", prefix.to_uppercase()), "built-in".to_string());
+        if !spec.events.is_empty() {
+            server.add(format!("{}CONTRACT_EVENTS", prefix.to_uppercase()), "built-in".to_string());
+            server.add(format!("{}contract_events", prefix), "built-in".to_string());
+        }
+        if namespaced {
+            server.add(format!("{}_contract", contract.identifier()), "built-in".to_string());
+            server.add(format!("{}_schemas", contract.identifier()), "built-in".to_string());
+        }
+
+        let mut methods = Scope::new(format!("{}/bindings.py: ClientAsync methods", package), &[]);
+        for func in &spec.functions {
+            let name = to_python_identifier(&func.name);
+            let function = format!("{}{}", prefix, name);
+            let source = labels.of("function", &func.name);
+            tools.add(contract.tool_name(&name, namespaced), source.clone());
+            server.add(function.clone(), source.clone());
+            methods.add(name, source);
+
+            let mut params = Scope::new(format!("server.py: parameters of {}", function), PY_TOOL_PARAMS);
+            for input in &func.inputs {
+                params.add(to_python_identifier(&input.name), format!("parameter `{}`", input.name));
+            }
+            scopes.push(params);
+        }
+        scopes.push(methods);
+
+        for event in &spec.events {
+            let source = labels.of("event", &event.name);
+            let tool = ToolNaming::Python.event_tool(&event.name);
+            tools.add(contract.tool_name(&tool, namespaced), source.clone());
+            let function = format!("{}{}", prefix, tool);
+            server.add(function.clone(), source);
+
+            let mut params = Scope::new(format!("server.py: parameters of {}", function), PY_EVENT_PARAMS);
+            for topic in event.topics.iter().filter(|t| event_topic_scval_type(&t.type_ref).is_some()) {
+                params.add(to_python_identifier(&topic.name), format!("topic `{}`", topic.name));
+            }
+            scopes.push(params);
+        }
+
+        let mut schemas = Scope::new(format!("{}/schemas.py", package), PY_SCHEMAS_MODULE);
+        let mut bindings = Scope::new(format!("{}/bindings.py", package), PY_BINDINGS_MODULE);
+        for type_spec in &spec.types {
+            let source = labels.of("type", &type_spec.name);
+            bindings.add(type_spec.name.clone(), source.clone());
+            schemas.add(type_spec.name.clone(), source.clone());
+            schemas.add(format!("{}Schema", type_spec.name), source.clone());
+            schemas.add(format!("{}_to_bindings", type_spec.name.to_lowercase()), source.clone());
+            if let TypeDef::Union { cases } = &type_spec.definition {
+                if cases.iter().any(|c| c.type_ref.is_some()) {
+                    for case in cases {
+                        schemas.add(format!("{}_{}", type_spec.name, case.name), source.clone());
+                    }
+                }
+            }
+        }
+        scopes.push(schemas);
+        scopes.push(bindings);
+    }
+
+    [tools, server]
+        .into_iter()
+        .chain(scopes)
+        .flat_map(Scope::collisions)
+        .collect()
+}
+
@@ -0,0 +1,274 @@
+//! OpenAPI document of a generated server's REST gateway
+//!
+//! With `--with-rest`, TypeScript and Python servers serve `POST /api/<tool>`
+//! next to `/mcp`: one route per contract function, plus the transaction
+//! tools (`sign-and-submit`, `prepare-transaction`, `prepare-sign-and-submit`).
+//! Each route calls the MCP tool of the same name with the JSON request body
+//! as its arguments. The OpenAPI 3.1 document describing those routes is
+//! derived from the tool manifest (`tools.json`), so request and response
+//! bodies use the same JSON Schema as the tools, with the contract types
+//! hoisted into `components/schemas`.
+
+use super::backend::TargetBackend;
+use super::contracts::ServedContract;
+use super::tool_manifest::{tool_manifest, ToolNaming};
+use crate::spec::json_schema::DEFS_PREFIX;
+use serde_json::{json, Map, Value};
+
+/// Path of the OpenAPI document in a generated project (served at `GET /openapi.json`)
+pub const OPENAPI_PATH: &str = "openapi.json";
+
+/// Prefix of REST routes: a tool is called with `POST /api/<tool name>`
+pub const REST_PREFIX: &str = "/api/";
+
+/// OpenAPI document of the REST gateway a `backend` server serves for `contracts`
+pub fn openapi_document(backend: &dyn TargetBackend, server_name: &str, contracts: &[ServedContract]) -> Value {
+    let namespaced = contracts.len() > 1;
+    let manifest = tool_manifest(backend, contracts);
+    let mut paths = Map::new();
+    let mut schemas = Map::new();
+
+    for tool in manifest["tools"].as_array().into_iter().flatten() {
+        // Event tools stay MCP-only: REST routes cover contract functions
+        if tool.get("function").is_none() {
+            continue;
+        }
+        let contract = tool["contract"].as_str().unwrap_or_default();
+        // Contracts of a multi-contract server may declare types of the same name
+        let prefix = if namespaced { format!("{}.", contract) } else { String::new() };
+        let input = hoist_defs(tool["inputSchema"].clone(), &prefix, &mut schemas);
+        let output = hoist_defs(tool["outputSchema"].clone(), &prefix, &mut schemas);
+        let name = tool["name"].as_str().unwrap_or_default();
+        paths.insert(
+            format!("{}{}", REST_PREFIX, name),
+            operation(name, contract, &tool["description"], input, output),
+        );
+    }
+
+    for (name, description, input, output) in transaction_tools(backend.tool_naming()) {
+        paths.insert(
+            format!("{}{}", REST_PREFIX, name),
+            operation(name, "transactions", &json!(description), input, output),
+        );
+    }
+
+    let names: Vec<&str> = contracts.iter().map(|c| c.name.as_str()).collect();
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": format!("{}-mcp REST API", server_name),
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": format!(
+                "REST gateway to the MCP tools of {}. Each route calls the tool of the same name \
+                 with the request body as its arguments. Write functions return a transaction XDR \
+                 to sign and submit with /api/sign-and-submit.",
+                names.join(", ")
+            ),
+        },
+        "servers": [{ "url": "http://localhost:3000", "description": "Server started with USE_HTTP=true PORT=3000" }],
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+            "responses": error_responses(),
+        },
+    })
+}
+
+/// `openapi.json` content: the document as pretty-printed JSON
+pub fn render_openapi_document(backend: &dyn TargetBackend, server_name: &str, contracts: &[ServedContract]) -> String {
+    format!("{:#}\n", openapi_document(backend, server_name, contracts))
+}
+
+/// `POST` operation calling the tool `name`
+fn operation(name: &str, tag: &str, description: &Value, input: Value, output: Value) -> Value {
+    json!({
+        "post": {
+            "operationId": name,
+            "tags": [tag],
+            "description": description,
+            "requestBody": {
+                "required": true,
+                "content": { "application/json": { "schema": input } },
+            },
+            "responses": {
+                "200": {
+                    "description": "Tool result",
+                    "content": { "application/json": { "schema": output } },
+                },
+                "400": { "$ref": "#/components/responses/InvalidRequest" },
+                "422": { "$ref": "#/components/responses/ToolFailed" },
+                "429": { "$ref": "#/components/responses/RateLimited" },
+            },
+        },
+    })
+}
+
+/// Move the `$defs` of a tool schema into `schemas` (as `<prefix><type>`),
+/// pointing its references at `#/components/schemas`
+fn hoist_defs(mut schema: Value, prefix: &str, schemas: &mut Map<String, Value>) -> Value {
+    if let Some(Value::Object(defs)) = schema.as_object_mut().and_then(|s| s.remove("$defs")) {
+        for (name, mut def) in defs {
+            rewrite_refs(&mut def, prefix);
+            schemas.insert(format!("{}{}", prefix, name), def);
+        }
+    }
+    rewrite_refs(&mut schema, prefix);
+    schema
+}
+
+fn rewrite_refs(schema: &mut Value, prefix: &str) {
+    match schema {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get_mut("$ref") {
+                if let Some(name) = reference.strip_prefix(DEFS_PREFIX) {
+                    *reference = format!("#/components/schemas/{}{}", prefix, name);
+                }
+            }
+            object.values_mut().for_each(|v| rewrite_refs(v, prefix));
+        }
+        Value::Array(items) => items.iter_mut().for_each(|v| rewrite_refs(v, prefix)),
+        _ => {}
+    }
+}
+
+/// Name, description, input and output schemas of the transaction tools, as
+/// the TypeScript server (`ToolNaming::Kebab`) or the Python server
+/// (`ToolNaming::Python`) defines them. Python names their arguments in
+/// snake_case, signs with a secret key only and returns the prepared
+/// transaction as `{transaction, data}`.
+fn transaction_tools(naming: ToolNaming) -> Vec<(&'static str, &'static str, Value, Value)> {
+    let python = naming == ToolNaming::Python;
+    let arg = |camel: &'static str, snake: &'static str| naming.builtin_argument(camel, snake);
+    let string = |description: &str| json!({ "type": "string", "description": description });
+
+    let mut sign_input = json!({
+        "type": "object",
+        "properties": {
+            "xdr": string("Transaction XDR to sign and submit"),
+            arg("secretKey", "secret_key"): string("Secret key (S...) to sign with and pay the fee"),
+        },
+        "required": ["xdr"],
+    });
+    if !python {
+        sign_input["properties"]["walletContractId"] =
+            string("Smart wallet contract ID for passkey signing (uses WALLET_SIGNER_SECRET, secretKey as fee payer)");
+    }
+    let sign_output = json!({
+        "type": "object",
+        "properties": {
+            "success": { "type": "boolean" },
+            "result": { "description": "Submitted transaction status and result" },
+        },
+        "required": ["success"],
+    });
+
+    let preview_input = |wallet: bool| {
+        let mut input = json!({
+            "type": "object",
+            "properties": {
+                "xdr": string("Transaction XDR from a contract function call"),
+                arg("toolName", "tool_name"): string("Name of the contract function tool that built the transaction"),
+                "params": { "type": "object", "description": "Arguments passed to the tool" },
+                arg("simulationResult", "simulation_result"): { "description": "Simulation result returned by the tool" },
+            },
+            "required": ["xdr", arg("toolName", "tool_name")],
+        });
+        if wallet {
+            input["properties"][arg("walletAddress", "wallet_address")] =
+                string("Wallet public key (G...) to prepare the transaction for");
+            input["required"] = json!(["xdr", arg("walletAddress", "wallet_address"), arg("toolName", "tool_name")]);
+        }
+        input
+    };
+    let preview = json!({ "type": "object", "description": "Tool name, arguments, simulation result and network" });
+    let prepare_output = if python {
+        json!({
+            "type": "object",
+            "properties": {
+                "transaction": {
+                    "type": "object",
+                    "properties": { "xdr": { "type": "string" }, "network": { "type": "string" } },
+                    "required": ["xdr", "network"],
+                },
+                "data": preview,
+            },
+            "required": ["transaction", "data"],
+        })
+    } else {
+        json!({
+            "type": "object",
+            "properties": { "walletReadyXdr": { "type": "string" }, "preview": preview },
+            "required": ["walletReadyXdr", "preview"],
+        })
+    };
+    let ready_output = json!({
+        "type": "object",
+        "properties": {
+            "readyForSigning": { "const": true },
+            "xdr": { "type": "string" },
+            "preview": preview,
+        },
+        "required": ["readyForSigning", "xdr", "preview"],
+    });
+
+    vec![
+        (
+            "sign-and-submit",
+            "Sign a transaction XDR and submit it to the network",
+            sign_input,
+            sign_output,
+        ),
+        (
+            "prepare-transaction",
+            "Prepare a transaction for wallet signing: returns wallet-ready XDR with a fresh sequence number",
+            preview_input(true),
+            prepare_output,
+        ),
+        (
+            "prepare-sign-and-submit",
+            "Prepare a write transaction for secret key signing: returns the XDR and a preview to show before sign-and-submit",
+            preview_input(false),
+            ready_output,
+        ),
+    ]
+}
+
+/// Error responses shared by every operation. Tool failures carry the same
+/// JSON body as MCP tool errors.
+fn error_responses() -> Value {
+    let error = |properties: Value| {
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": { "type": "object", "properties": properties, "required": ["error"] },
+                },
+            },
+        })
+    };
+    let mut invalid = error(json!({ "error": { "type": "string" }, "reason": { "type": "string" } }));
+    invalid["description"] = json!("The request body is not a JSON object");
+    let mut failed = error(json!({
+        "error": { "type": "string", "description": "Soroban error code, or 'Tool execution failed'" },
+        "reason": { "type": "string" },
+        "contractError": {
+            "type": "object",
+            "properties": {
+                "code": { "type": "integer" },
+                "name": { "type": "string" },
+                "doc": { "type": "string" },
+            },
+            "required": ["code"],
+        },
+        "hint": { "type": "string" },
+        "raw": { "type": "string" },
+    }));
+    failed["description"] = json!("The tool failed: arguments that do not match the request schema, or a failed simulation or submission");
+    let mut limited = error(json!({ "error": { "type": "string" } }));
+    limited["description"] = json!("Rate limit exceeded (RATE_LIMIT requests per minute per IP)");
+
+    json!({
+        "InvalidRequest": invalid,
+        "ToolFailed": failed,
+        "RateLimited": limited,
+    })
+}
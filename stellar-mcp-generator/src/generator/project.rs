@@ -0,0 +1,76 @@
+//! In-memory generated projects
+//!
+//! Generators render every file of a project into a [`GeneratedProject`]
+//! without touching the filesystem. Writing it out is a separate step:
+//! [`super::update::write_project`] writes the files with a generation
+//! manifest and three-way merging of user edits.
+
+use super::{BackendRegistry, ServedContract, TemplateRenderer};
+use crate::error::Result;
+use crate::NetworkConfig;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Files of a generated project, keyed by path relative to the project root
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeneratedProject {
+    pub files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl GeneratedProject {
+    /// Create an empty project
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file, replacing any earlier content at the same path
+    pub fn add(&mut self, path: impl Into<PathBuf>, content: impl Into<Vec<u8>>) {
+        self.files.insert(path.into(), content.into());
+    }
+
+    /// Add every file of `other`, replacing files at the same paths
+    pub fn extend(&mut self, other: GeneratedProject) {
+        self.files.extend(other.files);
+    }
+
+    /// Content of a file
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&[u8]> {
+        self.files.get(path.as_ref()).map(Vec::as_slice)
+    }
+
+    /// Content of a UTF-8 text file
+    pub fn get_str(&self, path: impl AsRef<Path>) -> Option<&str> {
+        self.get(path).and_then(|content| std::str::from_utf8(content).ok())
+    }
+
+    /// Paths of all files, in order
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+}
+
+/// Settings for rendering a complete project with [`render_project`]
+pub struct ProjectOptions<'a> {
+    /// Server language: the name of a backend (`typescript`, `python`, `rust`, `go`)
+    pub lang: &'a str,
+    /// Project name (the contract name for single-contract servers)
+    pub project_name: &'a str,
+    /// MCP server name
+    pub server_name: &'a str,
+    pub network: &'a NetworkConfig,
+    /// Contracts the server exposes tools for
+    pub contracts: &'a [ServedContract],
+    pub templates: &'a TemplateRenderer,
+    /// Also render the React frontend under `frontend/`
+    pub with_frontend: bool,
+    /// Also serve REST routes and `openapi.json` next to `/mcp` (backends
+    /// with `TargetBackend::supports_rest`)
+    pub with_rest: bool,
+}
+
+/// Render every file of an MCP server project (its spec snapshots and its
+/// frontend included) with the built-in backends, without writing anything. Fails with `NameCollisions`
+/// if spec names would collide in the generated code.
+pub fn render_project(options: &ProjectOptions) -> Result<GeneratedProject> {
+    BackendRegistry::default().render_project(options)
+}
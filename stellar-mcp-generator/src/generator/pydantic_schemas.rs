@@ -1,5 +1,8 @@
 //! Pydantic schema generation for Python MCP servers
 
+use super::python_bindings;
+use crate::codegen::emit::{py_comment, py_docstring, py_identifier, py_string};
+use crate::spec::json_types;
 use crate::spec::types::{ContractSpec, EnumVariant, FieldSpec, TypeDef, TypeRef, TypeSpec, UnionCase};
 
 /// Check if a TypeRef uses Address type (recursively)
@@ -14,16 +17,24 @@ fn type_uses_address(type_ref: &TypeRef) -> bool {
     }
 }
 
+/// Attribute name of a struct field on its Pydantic schema. Keywords and
+/// names Pydantic would treat as private (tuple struct fields `0`, `1`, ...
+/// become `_0`, `_1`) are renamed; the field then keeps its spec name as alias.
+pub fn schema_attr(field_name: &str) -> String {
+    let attr = py_identifier(field_name);
+    if attr.starts_with('_') {
+        format!("field{}", attr)
+    } else {
+        attr
+    }
+}
+
 /// Generate Pydantic schema class for a struct type
 fn generate_struct_schema(name: &str, fields: &[FieldSpec], doc: &Option<String>) -> String {
     let mut output = String::new();
 
-    // Add docstring if available
-    if let Some(d) = doc {
-        output.push_str(&format!("    \"\"\"{}\"\"\"\n", d.replace('"', r#"\""#)));
-    }
-
     output.push_str(&format!("class {}Schema(BaseModel):\n", name));
+    output.push_str(&py_docstring(doc.as_deref(), "    "));
 
     if fields.is_empty() {
         output.push_str("    pass\n");
@@ -33,32 +44,34 @@ fn generate_struct_schema(name: &str, fields: &[FieldSpec], doc: &Option<String>
     // Generate field definitions
     for field in fields {
         let field_doc = field.doc.as_deref().unwrap_or("");
-        let field_def = field
-            .type_ref
-            .to_pydantic_field(&field.name, field_doc, true);
+        let attr = schema_attr(&field.name);
+        let mut field_def = field.type_ref.to_pydantic_field(&attr, field_doc, true);
+        if attr != field.name {
+            // Accept and report the field under its spec name
+            field_def.pop();
+            field_def.push_str(&format!(", alias={})", py_string(&field.name)));
+        }
         output.push_str(&format!("    {}\n", field_def));
     }
 
     output
 }
 
-/// Generate Pydantic schema for an enum type
+/// Generate Pydantic schema for an enum type: its variant names, as in the
+/// JSON Schema of the type
 fn generate_enum_schema(name: &str, variants: &[EnumVariant], doc: &Option<String>) -> String {
+    let type_spec = TypeSpec {
+        name: name.to_string(),
+        doc: None,
+        definition: TypeDef::Enum { variants: variants.to_vec() },
+    };
     let mut output = String::new();
 
-    // Add docstring if available
-    if let Some(d) = doc {
-        output.push_str(&format!("    \"\"\"{}\"\"\"\n", d.replace('"', r#"\""#)));
-    }
-
-    // Extract variant names for Literal type
-    let variant_names: Vec<String> = variants.iter().map(|v| format!("\"{}\"", v.name)).collect();
-
-    output.push_str(&format!("class {}Schema(BaseModel):\n", name));
-    output.push_str("    model_config = ConfigDict(frozen=True)\n");
+    output.push_str(&py_comment(doc.as_deref(), ""));
     output.push_str(&format!(
-        "    tag: Literal[{}]\n",
-        variant_names.join(", ")
+        "{}Schema = {}\n",
+        name,
+        json_types::pydantic(&type_spec.to_json_schema())
     ));
 
     output
@@ -68,20 +81,16 @@ fn generate_enum_schema(name: &str, variants: &[EnumVariant], doc: &Option<Strin
 fn generate_union_schema(name: &str, cases: &[UnionCase], doc: &Option<String>) -> String {
     let mut output = String::new();
 
-    // Add docstring if available
-    if let Some(d) = doc {
-        output.push_str(&format!("    \"\"\"{}\"\"\"\n", d.replace('"', r#"\""#)));
-    }
-
     // Check if this is a simple enum (all cases are unit variants)
     let is_simple_enum = cases.iter().all(|c| c.type_ref.is_none());
 
     if is_simple_enum {
         // Generate as simple enum
         let variant_names: Vec<String> =
-            cases.iter().map(|c| format!("\"{}\"", c.name)).collect();
+            cases.iter().map(|c| py_string(&c.name)).collect();
 
         output.push_str(&format!("class {}Schema(BaseModel):\n", name));
+        output.push_str(&py_docstring(doc.as_deref(), "    "));
         output.push_str("    model_config = ConfigDict(frozen=True)\n");
         output.push_str(&format!(
             "    tag: Literal[{}]\n",
@@ -96,11 +105,12 @@ fn generate_union_schema(name: &str, cases: &[UnionCase], doc: &Option<String>)
             let variant_class = format!("{}_{}", name, case.name);
             output.push_str(&format!("class {}(BaseModel):\n", variant_class));
             output.push_str("    model_config = ConfigDict(frozen=True)\n");
-            output.push_str(&format!("    tag: Literal[\"{}\"] = \"{}\"\n", case.name, case.name));
+            let tag = py_string(&case.name);
+            output.push_str(&format!("    tag: Literal[{}] = {}\n", tag, tag));
 
             if let Some(type_ref) = &case.type_ref {
                 let case_doc = case.doc.as_deref().unwrap_or("");
-                let field_def = type_ref.to_pydantic_field("value", case_doc, true);
+                let field_def = type_ref.to_pydantic_field("values", case_doc, true);
                 output.push_str(&format!("    {}\n", field_def));
             }
 
@@ -123,7 +133,9 @@ fn generate_union_schema(name: &str, cases: &[UnionCase], doc: &Option<String>)
 pub fn generate_pydantic_schemas(spec: &ContractSpec) -> String {
     let mut output = String::new();
 
-    // Add imports
+    // Add imports. Annotations are resolved lazily, since schemas can
+    // reference types defined later in the spec.
+    output.push_str("from __future__ import annotations\n\n");
     output.push_str("from pydantic import BaseModel, Field, ConfigDict\n");
     output.push_str("from typing import Optional, List, Dict, Tuple, Union, Literal\n");
 
@@ -131,7 +143,10 @@ pub fn generate_pydantic_schemas(spec: &ContractSpec) -> String {
     let uses_address = spec.types.iter().any(|type_spec| {
         match &type_spec.definition {
             TypeDef::Struct { fields } => fields.iter().any(|f| type_uses_address(&f.type_ref)),
-            _ => false,
+            TypeDef::Union { cases } => cases
+                .iter()
+                .any(|c| c.type_ref.as_ref().is_some_and(type_uses_address)),
+            TypeDef::Enum { .. } => false,
         }
     });
 
@@ -142,10 +157,10 @@ pub fn generate_pydantic_schemas(spec: &ContractSpec) -> String {
     // Add binding class imports if we have types
     if !spec.types.is_empty() {
         let type_names: Vec<String> = spec.types.iter().map(|t| t.name.clone()).collect();
-        output.push_str(&format!("from .bindings.bindings import {}\n", type_names.join(", ")));
+        output.push_str(&format!("from .bindings import {}\n", type_names.join(", ")));
     }
 
-    output.push_str("\n");
+    output.push('\n');
 
     // Generate schema for each custom type
     for type_spec in &spec.types {
@@ -178,6 +193,75 @@ pub fn generate_pydantic_schemas(spec: &ContractSpec) -> String {
     output
 }
 
+/// Python expression converting a Pydantic value (`expr`) into the native value
+/// the bindings expect, following the JSON Schema of the type: 64-bit and wider
+/// integers arrive as strings, bytes as hex, maps as `[key, value]` pairs and
+/// addresses as strkeys. Returns `expr` unchanged when no conversion is needed.
+pub fn schema_to_binding_expr(type_ref: &TypeRef, expr: &str) -> String {
+    to_binding(type_ref, expr, 0)
+}
+
+fn to_binding(type_ref: &TypeRef, expr: &str, depth: usize) -> String {
+    match type_ref {
+        TypeRef::U64
+        | TypeRef::I64
+        | TypeRef::Timepoint
+        | TypeRef::Duration
+        | TypeRef::U128
+        | TypeRef::I128
+        | TypeRef::U256
+        | TypeRef::I256 => format!("int({})", expr),
+        TypeRef::Bytes | TypeRef::BytesN(_) => format!("bytes.fromhex({})", expr),
+        TypeRef::Address => format!("Address({})", expr),
+        TypeRef::Custom(name) => format!("{}_to_bindings({})", name.to_lowercase(), expr),
+        TypeRef::Result { ok, .. } => to_binding(ok, expr, depth),
+        TypeRef::Option(inner) => {
+            let converted = to_binding(inner, expr, depth);
+            if converted == expr {
+                converted
+            } else {
+                format!("(None if {} is None else {})", expr, converted)
+            }
+        }
+        TypeRef::Vec(inner) => {
+            let item = format!("item{}", depth);
+            let converted = to_binding(inner, &item, depth + 1);
+            if converted == item {
+                expr.to_string()
+            } else {
+                format!("[{} for {} in {}]", converted, item, expr)
+            }
+        }
+        TypeRef::Map { key, value } => {
+            let (k, v) = (format!("key{}", depth), format!("value{}", depth));
+            let (key_expr, value_expr) =
+                (to_binding(key, &k, depth + 1), to_binding(value, &v, depth + 1));
+            if key_expr == k && value_expr == v {
+                format!("dict({})", expr)
+            } else {
+                format!("{{{}: {} for {}, {} in {}}}", key_expr, value_expr, k, v, expr)
+            }
+        }
+        TypeRef::Tuple(types) => {
+            let items: Vec<String> = types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| to_binding(t, &format!("{}[{}]", expr, i), depth))
+                .collect();
+            let unchanged = items
+                .iter()
+                .enumerate()
+                .all(|(i, item)| *item == format!("{}[{}]", expr, i));
+            if unchanged {
+                expr.to_string()
+            } else {
+                format!("({}{})", items.join(", "), if items.len() == 1 { "," } else { "" })
+            }
+        }
+        _ => expr.to_string(),
+    }
+}
+
 /// Generate conversion helper functions between Pydantic models and binding classes
 pub fn generate_conversion_helpers(spec: &ContractSpec) -> String {
     let mut output = String::new();
@@ -187,99 +271,58 @@ pub fn generate_conversion_helpers(spec: &ContractSpec) -> String {
     for type_spec in &spec.types {
         let type_name = &type_spec.name;
 
+        output.push_str(&format!(
+            "def {}_to_bindings(schema: {}Schema) -> {}:\n",
+            type_name.to_lowercase(),
+            type_name,
+            type_name
+        ));
+        output.push_str("    \"\"\"Convert Pydantic schema to binding class\"\"\"\n");
+
         match &type_spec.definition {
-            TypeDef::Struct { fields } => {
-                // Generate to_bindings method
+            TypeDef::Struct { fields }
+                if !fields.is_empty() && fields.iter().all(|f| f.name.parse::<u32>().is_ok()) =>
+            {
+                // Tuple structs wrap their fields in a single `value` tuple
+                let items: Vec<String> = fields
+                    .iter()
+                    .map(|f| {
+                        schema_to_binding_expr(&f.type_ref, &format!("schema.{}", schema_attr(&f.name)))
+                    })
+                    .collect();
                 output.push_str(&format!(
-                    "def {}_to_bindings(schema: {}Schema) -> {}:\n",
-                    type_name.to_lowercase(),
+                    "    return {}(value=({}{}))\n\n",
                     type_name,
-                    type_name
+                    items.join(", "),
+                    if items.len() == 1 { "," } else { "" }
                 ));
-                output.push_str("    \"\"\"Convert Pydantic schema to binding class\"\"\"\n");
+            }
+            TypeDef::Struct { fields } => {
                 output.push_str(&format!("    return {}(\n", type_name));
-
                 for field in fields {
-                    let field_name = &field.name;
-                    // Handle type conversion based on field type
-                    let conversion = match &field.type_ref {
-                        crate::spec::types::TypeRef::Address => {
-                            format!("Address(schema.{})", field_name)
-                        }
-                        crate::spec::types::TypeRef::Custom(custom_name) => {
-                            format!(
-                                "{}_to_bindings(schema.{})",
-                                custom_name.to_lowercase(),
-                                field_name
-                            )
-                        }
-                        crate::spec::types::TypeRef::BytesN(_) => {
-                            // BytesN (fixed-size bytes) comes as hex string, convert to bytes
-                            format!("bytes.fromhex(schema.{}) if isinstance(schema.{}, str) else schema.{}", field_name, field_name, field_name)
-                        }
-                        crate::spec::types::TypeRef::String => {
-                            // Check if the binding expects bytes (common for name/symbol fields)
-                            // We encode strings to UTF-8 bytes if needed
-                            format!("schema.{}.encode('utf-8') if isinstance(schema.{}, str) else schema.{}", field_name, field_name, field_name)
-                        }
-                        crate::spec::types::TypeRef::Option(inner) => {
-                            match **inner {
-                                crate::spec::types::TypeRef::Address => {
-                                    format!("Address(schema.{}) if schema.{} else None", field_name, field_name)
-                                }
-                                crate::spec::types::TypeRef::Custom(ref custom_name) => {
-                                    format!(
-                                        "{}_to_bindings(schema.{}) if schema.{} else None",
-                                        custom_name.to_lowercase(),
-                                        field_name,
-                                        field_name
-                                    )
-                                }
-                                crate::spec::types::TypeRef::U128
-                                | crate::spec::types::TypeRef::I128
-                                | crate::spec::types::TypeRef::U256
-                                | crate::spec::types::TypeRef::I256 => {
-                                    format!("int(schema.{}) if schema.{} else None", field_name, field_name)
-                                }
-                                _ => format!("schema.{}", field_name),
-                            }
-                        }
-                        crate::spec::types::TypeRef::Vec(inner) => {
-                            match **inner {
-                                crate::spec::types::TypeRef::Custom(ref custom_name) => {
-                                    format!(
-                                        "[{}_to_bindings(item) for item in schema.{}]",
-                                        custom_name.to_lowercase(),
-                                        field_name
-                                    )
-                                }
-                                _ => format!("schema.{}", field_name),
-                            }
-                        }
-                        crate::spec::types::TypeRef::U128
-                        | crate::spec::types::TypeRef::I128
-                        | crate::spec::types::TypeRef::U256
-                        | crate::spec::types::TypeRef::I256 => {
-                            format!("int(schema.{})", field_name)
-                        }
-                        _ => format!("schema.{}", field_name),
-                    };
-                    output.push_str(&format!("        {}={},\n", field_name, conversion));
+                    output.push_str(&format!(
+                        "        {}={},\n",
+                        python_bindings::field_attr(field),
+                        schema_to_binding_expr(&field.type_ref, &format!("schema.{}", schema_attr(&field.name)))
+                    ));
                 }
-
                 output.push_str("    )\n\n");
             }
-            TypeDef::Enum { .. } | TypeDef::Union { .. } => {
-                // For enums/unions, generate conversion using Kind enum
-                output.push_str(&format!(
-                    "def {}_to_bindings(schema: {}Schema) -> {}:\n",
-                    type_name.to_lowercase(),
-                    type_name,
-                    type_name
-                ));
-                output.push_str("    \"\"\"Convert Pydantic schema to binding class\"\"\"\n");
-                output.push_str(&format!("    from .bindings.bindings import {}Kind\n", type_name));
-                output.push_str(&format!("    return {}(kind={}Kind(schema.tag))\n\n", type_name, type_name));
+            TypeDef::Enum { .. } => {
+                output.push_str(&format!("    return {}[schema]\n\n", type_name));
+            }
+            TypeDef::Union { cases } => {
+                for case in cases {
+                    if let Some(type_ref) = &case.type_ref {
+                        output.push_str(&format!("    if schema.tag == {}:\n", py_string(&case.name)));
+                        output.push_str(&format!(
+                            "        return {}(tag=schema.tag, value={})\n",
+                            type_name,
+                            schema_to_binding_expr(type_ref, "schema.values")
+                        ));
+                    }
+                }
+                output.push_str(&format!("    return {}(tag=schema.tag)\n\n", type_name));
             }
         }
     }
@@ -290,7 +333,7 @@ pub fn generate_conversion_helpers(spec: &ContractSpec) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::spec::types::{TypeRef, FieldSpec, TypeSpec, TypeDef, ContractSpec};
+    use crate::spec::types::{TypeRef, FieldSpec};
 
     #[test]
     fn test_generate_struct_schema() {
@@ -329,7 +372,6 @@ mod tests {
         ];
 
         let schema = generate_enum_schema("Status", &variants, &None);
-        assert!(schema.contains("class StatusSchema(BaseModel)"));
-        assert!(schema.contains("tag: Literal[\"Active\", \"Inactive\"]"));
+        assert_eq!(schema, "StatusSchema = Literal[\"Active\", \"Inactive\"]\n");
     }
 }
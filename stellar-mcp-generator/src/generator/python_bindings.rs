@@ -0,0 +1,448 @@
+//! Native Python contract bindings
+//!
+//! Renders `src/bindings.py` for generated Python servers straight from the
+//! parsed `ContractSpec`: a dataclass (or `IntEnum`) per contract UDT with
+//! `to_scval` / `from_scval`, the contract's error codes and a `ClientAsync`
+//! built on `stellar_sdk.contract.ContractClientAsync`. Every value is encoded
+//! from its spec type, so nothing has to guess whether a field holds `bytes`
+//! or `str`. This replaces the `stellar-contract-bindings` CLI.
+
+use super::backend::{PythonBackend, TargetBackend};
+use super::python_generator::to_python_identifier;
+use crate::codegen::emit::{py_docstring, py_identifier, py_string};
+use crate::spec::{ContractSpec, ErrorEnumSpec, FieldSpec, FunctionSpec, TypeDef, TypeRef, TypeSpec};
+
+/// Python type of the native value the bindings use for a contract type
+pub fn binding_type(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Bool => "bool".to_string(),
+        TypeRef::Void => "None".to_string(),
+        TypeRef::Status => "int".to_string(),
+        TypeRef::U32 | TypeRef::I32 | TypeRef::U64 | TypeRef::I64 => "int".to_string(),
+        TypeRef::U128 | TypeRef::I128 | TypeRef::U256 | TypeRef::I256 => "int".to_string(),
+        TypeRef::Timepoint | TypeRef::Duration => "int".to_string(),
+        TypeRef::Bytes | TypeRef::BytesN(_) => "bytes".to_string(),
+        TypeRef::String | TypeRef::Symbol => "str".to_string(),
+        TypeRef::Address => "Address".to_string(),
+        TypeRef::Option(inner) => format!("Optional[{}]", binding_type(inner)),
+        TypeRef::Result { ok, .. } => binding_type(ok),
+        TypeRef::Vec(inner) => format!("List[{}]", binding_type(inner)),
+        TypeRef::Map { key, value } => {
+            format!("Dict[{}, {}]", binding_type(key), binding_type(value))
+        }
+        TypeRef::Tuple(types) => {
+            let items: Vec<String> = types.iter().map(binding_type).collect();
+            format!("Tuple[{}]", items.join(", "))
+        }
+        TypeRef::Custom(name) => name.clone(),
+    }
+}
+
+/// Python expression encoding the native `value` as an `xdr.SCVal`
+pub fn encode_expr(type_ref: &TypeRef, value: &str) -> String {
+    encode(type_ref, value, 0)
+}
+
+/// Python expression decoding the `xdr.SCVal` in `val` into its native value
+pub fn decode_expr(type_ref: &TypeRef, val: &str) -> String {
+    decode(type_ref, val, 0)
+}
+
+fn encode(type_ref: &TypeRef, value: &str, depth: usize) -> String {
+    match type_ref {
+        TypeRef::Bool => format!("scval.to_bool({})", value),
+        TypeRef::Void => "scval.to_void()".to_string(),
+        TypeRef::U32 | TypeRef::Status => format!("scval.to_uint32({})", value),
+        TypeRef::I32 => format!("scval.to_int32({})", value),
+        TypeRef::U64 => format!("scval.to_uint64({})", value),
+        TypeRef::I64 => format!("scval.to_int64({})", value),
+        TypeRef::U128 => format!("scval.to_uint128({})", value),
+        TypeRef::I128 => format!("scval.to_int128({})", value),
+        TypeRef::U256 => format!("scval.to_uint256({})", value),
+        TypeRef::I256 => format!("scval.to_int256({})", value),
+        TypeRef::Timepoint => format!("scval.to_timepoint({})", value),
+        TypeRef::Duration => format!("scval.to_duration({})", value),
+        TypeRef::Bytes | TypeRef::BytesN(_) => format!("scval.to_bytes({})", value),
+        TypeRef::String => format!("scval.to_string({})", value),
+        TypeRef::Symbol => format!("scval.to_symbol({})", value),
+        TypeRef::Address => format!("scval.to_address({})", value),
+        TypeRef::Option(inner) => format!(
+            "(scval.to_void() if {} is None else {})",
+            value,
+            encode(inner, value, depth)
+        ),
+        TypeRef::Result { ok, .. } => encode(ok, value, depth),
+        TypeRef::Vec(inner) => {
+            let item = format!("item{}", depth);
+            format!(
+                "scval.to_vec([{} for {} in {}])",
+                encode(inner, &item, depth + 1),
+                item,
+                value
+            )
+        }
+        TypeRef::Map {
+            key,
+            value: map_value,
+        } => {
+            let (k, v) = (format!("key{}", depth), format!("value{}", depth));
+            format!(
+                "scval.to_map({{{}: {} for {}, {} in {}.items()}})",
+                encode(key, &k, depth + 1),
+                encode(map_value, &v, depth + 1),
+                k,
+                v,
+                value
+            )
+        }
+        TypeRef::Tuple(types) => {
+            let items: Vec<String> = types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| encode(t, &format!("{}[{}]", value, i), depth))
+                .collect();
+            format!("scval.to_vec([{}])", items.join(", "))
+        }
+        TypeRef::Custom(_) => format!("{}.to_scval()", value),
+    }
+}
+
+fn decode(type_ref: &TypeRef, val: &str, depth: usize) -> String {
+    match type_ref {
+        TypeRef::Bool => format!("scval.from_bool({})", val),
+        TypeRef::Void => format!("scval.from_void({})", val),
+        TypeRef::U32 | TypeRef::Status => format!("scval.from_uint32({})", val),
+        TypeRef::I32 => format!("scval.from_int32({})", val),
+        TypeRef::U64 => format!("scval.from_uint64({})", val),
+        TypeRef::I64 => format!("scval.from_int64({})", val),
+        TypeRef::U128 => format!("scval.from_uint128({})", val),
+        TypeRef::I128 => format!("scval.from_int128({})", val),
+        TypeRef::U256 => format!("scval.from_uint256({})", val),
+        TypeRef::I256 => format!("scval.from_int256({})", val),
+        TypeRef::Timepoint => format!("scval.from_timepoint({})", val),
+        TypeRef::Duration => format!("scval.from_duration({})", val),
+        TypeRef::Bytes | TypeRef::BytesN(_) => format!("scval.from_bytes({})", val),
+        // Soroban strings are byte strings; the bindings expose them as `str`
+        TypeRef::String => format!("scval.from_string({}).decode(\"utf-8\", \"replace\")", val),
+        TypeRef::Symbol => format!("scval.from_symbol({})", val),
+        TypeRef::Address => format!("scval.from_address({})", val),
+        TypeRef::Option(inner) => format!(
+            "(None if {}.type == xdr.SCValType.SCV_VOID else {})",
+            val,
+            decode(inner, val, depth)
+        ),
+        TypeRef::Result { ok, .. } => decode(ok, val, depth),
+        TypeRef::Vec(inner) => {
+            let item = format!("item{}", depth);
+            format!(
+                "[{} for {} in scval.from_vec({})]",
+                decode(inner, &item, depth + 1),
+                item,
+                val
+            )
+        }
+        TypeRef::Map { key, value } => {
+            let (k, v) = (format!("key{}", depth), format!("value{}", depth));
+            format!(
+                "{{{}: {} for {}, {} in scval.from_map({}).items()}}",
+                decode(key, &k, depth + 1),
+                decode(value, &v, depth + 1),
+                k,
+                v,
+                val
+            )
+        }
+        TypeRef::Tuple(types) => {
+            let items_var = format!("items{}", depth);
+            let items: Vec<String> = types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| decode(t, &format!("{}[{}]", items_var, i), depth + 1))
+                .collect();
+            format!(
+                "(lambda {}: ({}{}))(scval.from_vec({}))",
+                items_var,
+                items.join(", "),
+                if items.len() == 1 { "," } else { "" },
+                val
+            )
+        }
+        TypeRef::Custom(name) => format!("{}.from_scval({})", name, val),
+    }
+}
+
+/// Whether a struct is a tuple struct (fields named 0, 1, ...), encoded as a vector
+pub(crate) fn is_tuple_struct(fields: &[FieldSpec]) -> bool {
+    !fields.is_empty() && fields.iter().all(|f| f.name.parse::<u32>().is_ok())
+}
+
+/// Attribute name of a struct field on the generated dataclass
+pub fn field_attr(field: &FieldSpec) -> String {
+    py_identifier(&field.name)
+}
+
+/// Render the complete `src/bindings.py` module
+pub fn render_python_bindings(spec: &ContractSpec, contract_name: &str) -> String {
+    let mut content = String::new();
+
+    content.push_str("\"\"\"\n");
+    content.push_str(&format!(
+        "Contract bindings for {}, generated by stellar-mcp from the contract spec.\n\n",
+        contract_name
+    ));
+    content.push_str("Do not edit: changes are overwritten when the server is regenerated.\n");
+    content.push_str("\"\"\"\n\n");
+    content.push_str("from __future__ import annotations\n\n");
+    content.push_str("from dataclasses import dataclass\n");
+    content.push_str("from enum import IntEnum\n");
+    content.push_str("from typing import Any, Dict, List, Optional, Tuple\n\n");
+    content.push_str("from stellar_sdk import Address, scval, xdr\n");
+    content.push_str(
+        "from stellar_sdk.contract import AssembledTransactionAsync, ContractClientAsync\n",
+    );
+
+    for type_spec in &spec.types {
+        content.push_str("\n\n");
+        content.push_str(&render_type(type_spec));
+    }
+
+    content.push_str("\n\n");
+    content.push_str(&render_errors(&spec.errors));
+
+    content.push_str("\n\n");
+    content.push_str("class ClientAsync(ContractClientAsync):\n");
+    content.push_str(&format!(
+        "    \"\"\"Async client for the {} contract\"\"\"\n",
+        contract_name
+    ));
+    for func in &spec.functions {
+        content.push('\n');
+        content.push_str(&render_method(func));
+    }
+
+    content
+}
+
+fn render_type(type_spec: &TypeSpec) -> String {
+    let name = &type_spec.name;
+    let mut content = String::new();
+
+    match &type_spec.definition {
+        TypeDef::Struct { fields } if is_tuple_struct(fields) => {
+            let tuple = TypeRef::Tuple(fields.iter().map(|f| f.type_ref.clone()).collect());
+            content.push_str(&format!("@dataclass\nclass {}:\n", name));
+            content.push_str(&py_docstring(type_spec.doc.as_deref(), "    "));
+            content.push_str(&format!("    value: {}\n\n", PythonBackend.type_name(&tuple)));
+            content.push_str("    def to_scval(self) -> xdr.SCVal:\n");
+            content.push_str(&format!(
+                "        return {}\n\n",
+                encode_expr(&tuple, "self.value")
+            ));
+            content.push_str("    @classmethod\n");
+            content.push_str(&format!(
+                "    def from_scval(cls, val: xdr.SCVal) -> {}:\n",
+                name
+            ));
+            content.push_str(&format!(
+                "        return cls({})\n",
+                decode_expr(&tuple, "val")
+            ));
+        }
+        TypeDef::Struct { fields } => {
+            content.push_str(&format!("@dataclass\nclass {}:\n", name));
+            content.push_str(&py_docstring(type_spec.doc.as_deref(), "    "));
+            for field in fields {
+                content.push_str(&format!(
+                    "    {}: {}\n",
+                    field_attr(field),
+                    PythonBackend.type_name(&field.type_ref)
+                ));
+            }
+            if !fields.is_empty() {
+                content.push('\n');
+            }
+
+            // Struct fields are encoded as a map with symbol keys, which must be sorted
+            let mut sorted: Vec<&FieldSpec> = fields.iter().collect();
+            sorted.sort_by(|a, b| a.name.as_bytes().cmp(b.name.as_bytes()));
+            content.push_str("    def to_scval(self) -> xdr.SCVal:\n");
+            content.push_str("        return scval.to_struct({\n");
+            for field in &sorted {
+                content.push_str(&format!(
+                    "            {}: {},\n",
+                    py_string(&field.name),
+                    encode_expr(&field.type_ref, &format!("self.{}", field_attr(field)))
+                ));
+            }
+            content.push_str("        })\n\n");
+
+            content.push_str("    @classmethod\n");
+            content.push_str(&format!(
+                "    def from_scval(cls, val: xdr.SCVal) -> {}:\n",
+                name
+            ));
+            if fields.is_empty() {
+                content.push_str("        return cls()\n");
+            } else {
+                content.push_str("        fields = scval.from_struct(val)\n");
+                content.push_str("        return cls(\n");
+                for field in fields {
+                    content.push_str(&format!(
+                        "            {}={},\n",
+                        field_attr(field),
+                        decode_expr(&field.type_ref, &format!("fields[{}]", py_string(&field.name)))
+                    ));
+                }
+                content.push_str("        )\n");
+            }
+        }
+        TypeDef::Enum { variants } => {
+            content.push_str(&format!("class {}(IntEnum):\n", name));
+            content.push_str(&py_docstring(type_spec.doc.as_deref(), "    "));
+            for variant in variants {
+                content.push_str(&format!(
+                    "    {} = {}\n",
+                    py_identifier(&variant.name),
+                    variant.value
+                ));
+            }
+            if !variants.is_empty() {
+                content.push('\n');
+            }
+            content.push_str("    def to_scval(self) -> xdr.SCVal:\n");
+            content.push_str("        return scval.to_uint32(self.value)\n\n");
+            content.push_str("    @classmethod\n");
+            content.push_str(&format!(
+                "    def from_scval(cls, val: xdr.SCVal) -> {}:\n",
+                name
+            ));
+            content.push_str("        return cls(scval.from_uint32(val))\n");
+        }
+        TypeDef::Union { cases } => {
+            // Same shape as the Pydantic union schemas: a tag plus the case's value
+            content.push_str(&format!("@dataclass\nclass {}:\n", name));
+            content.push_str(&py_docstring(type_spec.doc.as_deref(), "    "));
+            content.push_str("    tag: str\n");
+            content.push_str("    value: Any = None\n\n");
+
+            content.push_str("    def to_scval(self) -> xdr.SCVal:\n");
+            for case in cases {
+                content.push_str(&format!("        if self.tag == {}:\n", py_string(&case.name)));
+                let tag = format!("scval.to_symbol({})", py_string(&case.name));
+                let values = match &case.type_ref {
+                    None => String::new(),
+                    Some(TypeRef::Tuple(items)) => items
+                        .iter()
+                        .enumerate()
+                        .map(|(i, t)| {
+                            format!(", {}", encode_expr(t, &format!("self.value[{}]", i)))
+                        })
+                        .collect(),
+                    Some(other) => format!(", {}", encode_expr(other, "self.value")),
+                };
+                content.push_str(&format!(
+                    "            return scval.to_vec([{}{}])\n",
+                    tag, values
+                ));
+            }
+            content.push_str(&format!(
+                "        raise ValueError(f\"Unknown {} variant: {{self.tag}}\")\n\n",
+                name
+            ));
+
+            content.push_str("    @classmethod\n");
+            content.push_str(&format!(
+                "    def from_scval(cls, val: xdr.SCVal) -> {}:\n",
+                name
+            ));
+            content.push_str("        items = scval.from_vec(val)\n");
+            content.push_str("        tag = scval.from_symbol(items[0])\n");
+            for case in cases {
+                content.push_str(&format!("        if tag == {}:\n", py_string(&case.name)));
+                let value = match &case.type_ref {
+                    None => String::new(),
+                    Some(TypeRef::Tuple(items)) => {
+                        let values: Vec<String> = items
+                            .iter()
+                            .enumerate()
+                            .map(|(i, t)| decode_expr(t, &format!("items[{}]", i + 1)))
+                            .collect();
+                        format!(
+                            ", ({}{})",
+                            values.join(", "),
+                            if values.len() == 1 { "," } else { "" }
+                        )
+                    }
+                    Some(other) => format!(", {}", decode_expr(other, "items[1]")),
+                };
+                content.push_str(&format!("            return cls(tag{})\n", value));
+            }
+            content.push_str(&format!(
+                "        raise ValueError(f\"Unknown {} variant: {{tag}}\")\n",
+                name
+            ));
+        }
+    }
+
+    content
+}
+
+fn render_errors(errors: &[ErrorEnumSpec]) -> String {
+    let mut content = String::from("# Contract error codes and their names\n");
+    if errors.is_empty() {
+        content.push_str("ERRORS: Dict[int, str] = {}\n");
+        return content;
+    }
+    content.push_str("ERRORS: Dict[int, str] = {\n");
+    for error in errors.iter().flat_map(|e| &e.cases) {
+        content.push_str(&format!("    {}: {},\n", error.code, py_string(&error.name)));
+    }
+    content.push_str("}\n");
+    content
+}
+
+fn render_method(func: &FunctionSpec) -> String {
+    let mut content = String::new();
+    let result_type = match &func.output {
+        None | Some(TypeRef::Void) => "None".to_string(),
+        Some(type_ref) => PythonBackend.type_name(type_ref),
+    };
+
+    let mut params = vec!["self".to_string()];
+    params.extend(func.inputs.iter().map(|p| {
+        format!(
+            "{}: {}",
+            to_python_identifier(&p.name),
+            PythonBackend.type_name(&p.type_ref)
+        )
+    }));
+    content.push_str(&format!(
+        "    async def {}(\n        {},\n    ) -> AssembledTransactionAsync[{}]:\n",
+        to_python_identifier(&func.name),
+        params.join(",\n        "),
+        result_type
+    ));
+    content.push_str(&py_docstring(func.doc.as_deref(), "        "));
+
+    content.push_str("        return await self.invoke(\n");
+    content.push_str(&format!("            {},\n", py_string(&func.name)));
+    content.push_str("            [\n");
+    for param in &func.inputs {
+        content.push_str(&format!(
+            "                {},\n",
+            encode_expr(&param.type_ref, &to_python_identifier(&param.name))
+        ));
+    }
+    content.push_str("            ],\n");
+    let parse = match &func.output {
+        None | Some(TypeRef::Void) => "None".to_string(),
+        Some(type_ref) => decode_expr(type_ref, "v"),
+    };
+    content.push_str(&format!(
+        "            parse_result_xdr_fn=lambda v: {},\n",
+        parse
+    ));
+    content.push_str("        )\n");
+
+    content
+}
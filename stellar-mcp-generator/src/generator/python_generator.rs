@@ -1,353 +1,469 @@
 //! Python MCP Server generator implementation
 
+use super::backend::{project_data, render_template_files, PythonBackend, TargetBackend};
+use super::contracts::ServedContract;
+use super::openapi::{render_openapi_document, OPENAPI_PATH};
+use super::project::GeneratedProject;
 use super::pydantic_schemas;
+use super::python_bindings;
 use super::template_data::*;
-use crate::commands::generate::GenerateArgs;
-use crate::spec::ContractSpec;
+use super::templates::TemplateRenderer;
+use super::tool_manifest::{render_tool_manifest, ToolNaming, TOOL_MANIFEST_PATH};
+use crate::codegen::emit::{py_docstring_text, py_identifier, py_string};
+use crate::error::Result;
+use crate::spec::json_types;
+use crate::spec::{ContractSpec, EventDataFormat};
 use crate::NetworkConfig;
-use handlebars::Handlebars;
-use std::fs;
-use std::path::Path;
+use std::collections::HashSet;
+
+/// Project files rendered as-is from a template with the project data:
+/// `(template, path)`
+const TEMPLATE_FILES: &[(&str, &str)] = &[
+    ("python/env.example", ".env.example"),
+    ("python/Dockerfile", "Dockerfile"),
+    ("python/dockerignore", ".dockerignore"),
+];
 
 /// Python MCP Server generator
 pub struct PythonGenerator<'a> {
-    output_dir: &'a Path,
-    contract_name: &'a str,
+    /// Project name (the contract name for single-contract servers)
+    project_name: &'a str,
     server_name: &'a str,
-    contract_id: &'a str,
     network: &'a NetworkConfig,
+    /// Contracts the server exposes tools for
+    contracts: &'a [ServedContract],
+    templates: &'a TemplateRenderer,
+    /// Serve REST routes and `openapi.json` next to `/mcp`
+    with_rest: bool,
 }
 
 impl<'a> PythonGenerator<'a> {
     /// Create a new Python generator
     pub fn new(
-        output_dir: &'a Path,
-        contract_name: &'a str,
+        project_name: &'a str,
         server_name: &'a str,
-        contract_id: &'a str,
         network: &'a NetworkConfig,
+        contracts: &'a [ServedContract],
+        templates: &'a TemplateRenderer,
     ) -> Self {
         Self {
-            output_dir,
-            contract_name,
+            project_name,
             server_name,
-            contract_id,
             network,
+            contracts,
+            templates,
+            with_rest: false,
         }
     }
 
-    /// Generate the Python MCP server
-    pub fn generate(
-        &self,
-        spec: &ContractSpec,
-        _args: &GenerateArgs,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        println!("  Generating Python MCP server...");
-
-        // Create directory structure
-        self.create_directories()?;
+    /// Also serve the contract functions as REST routes (`POST /api/<tool>`)
+    /// described by an OpenAPI document
+    pub fn with_rest(mut self, with_rest: bool) -> Self {
+        self.with_rest = with_rest;
+        self
+    }
 
-        // Generate official Stellar Python bindings first
-        self.generate_official_bindings()?;
+    /// Render every file of the Python MCP server, without writing anything
+    pub fn render(&self) -> Result<GeneratedProject> {
+        let mut project = GeneratedProject::new();
+
+        // Generate the bindings, Pydantic schemas and client of every contract
+        for contract in self.contracts {
+            self.generate_bindings(&mut project, contract)?;
+            self.generate_schemas_py(&mut project, contract)?;
+            self.generate_contract_client(&mut project, contract)?;
+            if self.namespaced() {
+                self.generate_init_py(&mut project, &contract.name, &format!("{}/__init__.py", self.package_dir(contract)))?;
+            }
+        }
 
         // Generate Python files
-        self.generate_schemas_py(spec)?;
-        self.generate_server_py(spec)?;
-        self.generate_contract_client(spec)?;
-        self.generate_init_py()?;
-        self.generate_lib_files()?;
-        self.generate_pyproject_toml()?;
-        self.generate_env_example()?;
-        self.generate_dockerfile()?;
-        self.generate_dockerignore()?;
-        self.generate_readme(spec)?;
-
-        println!("  Python MCP server generated!");
-        println!();
-        println!("  Next steps:");
-        println!("    1. cd {}", self.output_dir.display());
-        println!("    2. uv sync  # Install dependencies");
-        println!("    3. cp .env.example .env && edit .env");
-        println!("    4. uv run mcp install server.py");
+        self.generate_server_py(&mut project)?;
+        self.generate_init_py(&mut project, self.project_name, "src/__init__.py")?;
+        self.generate_lib_files(&mut project)?;
+        self.generate_pyproject_toml(&mut project)?;
+        let data = project_data(self.project_name, self.server_name, self.network, self.contracts);
+        render_template_files(&mut project, self.templates, TEMPLATE_FILES, &data)?;
+        project.add(TOOL_MANIFEST_PATH, render_tool_manifest(&PythonBackend, self.contracts));
+        if self.with_rest {
+            project.add(OPENAPI_PATH, render_openapi_document(&PythonBackend, self.server_name, self.contracts));
+        }
+        self.generate_readme(&mut project)?;
 
-        Ok(())
+        Ok(project)
     }
 
-    fn create_directories(&self) -> Result<(), Box<dyn std::error::Error>> {
-        fs::create_dir_all(self.output_dir.join("src"))?;
-        fs::create_dir_all(self.output_dir.join("src/bindings"))?;
-        fs::create_dir_all(self.output_dir.join("src/lib"))?;
-        Ok(())
+    /// Whether tools are namespaced by contract (servers with several contracts)
+    fn namespaced(&self) -> bool {
+        self.contracts.len() > 1
     }
 
-    /// Generate official Stellar Python bindings using stellar-contract-bindings CLI
-    fn generate_official_bindings(&self) -> Result<(), Box<dyn std::error::Error>> {
-        use std::process::Command;
-
-        println!("  Generating official Stellar Python bindings...");
-
-        let bindings_dir = self.output_dir.join("src/bindings");
-
-        // Try to call stellar-contract-bindings python
-        let output = Command::new("stellar-contract-bindings")
-            .arg("python")
-            .arg("--contract-id")
-            .arg(self.contract_id)
-            .arg("--rpc-url")
-            .arg(&self.network.rpc_url)
-            .arg("--output")
-            .arg(&bindings_dir)
-            .output();
-
-        match output {
-            Ok(result) => {
-                if !result.status.success() {
-                    let stderr = String::from_utf8_lossy(&result.stderr);
-
-                    // Check if it's a "command not found" type error
-                    if stderr.contains("not found") || stderr.contains("No such file") {
-                        return Err(self.bindings_not_installed_error());
-                    }
-
-                    return Err(format!("Failed to generate Stellar bindings: {}", stderr).into());
-                }
-                println!("  ✓ Generated official bindings in src/bindings/");
-                Ok(())
-            }
-            Err(e) => {
-                // Command failed to execute (likely not installed)
-                if e.kind() == std::io::ErrorKind::NotFound {
-                    Err(self.bindings_not_installed_error())
-                } else {
-                    Err(format!("Failed to execute stellar-contract-bindings: {}", e).into())
-                }
-            }
+    /// Directory holding a contract's bindings, schemas and client: `src`, or
+    /// one package per contract (`src/<name>`) for multi-contract servers
+    fn package_dir(&self, contract: &ServedContract) -> String {
+        if self.namespaced() {
+            format!("src/{}", contract.identifier())
+        } else {
+            "src".to_string()
         }
     }
 
-    fn bindings_not_installed_error(&self) -> Box<dyn std::error::Error> {
-        let msg = format!(
-            "\n❌ stellar-contract-bindings is not installed!\n\n\
-            The Python generator requires the 'stellar-contract-bindings' package.\n\n\
-            Install it with:\n\
-            \n\
-            Option 1 (using pip):\n\
-              pip install stellar-contract-bindings\n\
-            \n\
-            Option 2 (using uv - recommended):\n\
-              uv pip install stellar-contract-bindings\n\
-            \n\
-            Then run the generator again.\n\
-            \n\
-            Alternatively, you can generate bindings manually after generation:\n\
-              stellar-contract-bindings python \\\n\
-                --contract-id {} \\\n\
-                --rpc-url {} \\\n\
-                --output ./src/bindings\n",
-            self.contract_id,
-            self.network.rpc_url
-        );
-        msg.into()
-    }
-
-    fn generate_server_py(&self, spec: &ContractSpec) -> Result<(), Box<dyn std::error::Error>> {
-        let template = include_str!("../../templates/python/server.py.hbs");
-        let data = self.create_template_data(spec)?;
-
-        let mut hbs = Handlebars::new();
-        hbs.register_template_string("server", template)?;
-
-        let output = hbs.render("server", &data)?;
-        fs::write(self.output_dir.join("server.py"), output)?;
+    /// Generate the contract bindings (dataclasses, ScVal helpers and client) from the spec
+    fn generate_bindings(&self, project: &mut GeneratedProject, contract: &ServedContract) -> Result<()> {
+        let content = python_bindings::render_python_bindings(&contract.spec, &contract.name);
+        let path = format!("{}/bindings.py", self.package_dir(contract));
+        project.add(&path, content);
 
         Ok(())
     }
 
-    fn generate_contract_client(&self, spec: &ContractSpec) -> Result<(), Box<dyn std::error::Error>> {
-        let template = include_str!("../../templates/python/contract_client.py.hbs");
-        let data = self.create_template_data(spec)?;
+    fn generate_server_py(&self, project: &mut GeneratedProject) -> Result<()> {
+        let data = self.create_template_data()?;
+        let output = self.templates.render("python/server.py", &data)?;
+        project.add("server.py", output);
 
-        let mut hbs = Handlebars::new();
-        hbs.register_template_string("client", template)?;
+        Ok(())
+    }
 
-        let output = hbs.render("client", &data)?;
-        fs::write(self.output_dir.join("src/contract_client.py"), output)?;
+    fn generate_contract_client(&self, project: &mut GeneratedProject, contract: &ServedContract) -> Result<()> {
+        let data = self.create_contract_data(contract)?;
+        let output = self.templates.render("python/contract_client.py", &data)?;
+        project.add(format!("{}/contract_client.py", self.package_dir(contract)), output);
 
         Ok(())
     }
 
-    fn generate_init_py(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let template = include_str!("../../templates/python/init.py.hbs");
+    fn generate_init_py(&self, project: &mut GeneratedProject, name: &str, path: &str) -> Result<()> {
         let data = serde_json::json!({
-            "contract_name": self.contract_name,
+            "contract_name": name,
         });
-
-        let mut hbs = Handlebars::new();
-        hbs.register_template_string("init", template)?;
-
-        let output = hbs.render("init", &data)?;
-        fs::write(self.output_dir.join("src/__init__.py"), output)?;
+        let output = self.templates.render("python/init.py", &data)?;
+        project.add(path, output);
 
         Ok(())
     }
 
-    fn generate_schemas_py(&self, spec: &ContractSpec) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_schemas_py(&self, project: &mut GeneratedProject, contract: &ServedContract) -> Result<()> {
         // Generate Pydantic schemas
-        let schemas_content = pydantic_schemas::generate_pydantic_schemas(spec);
+        let schemas_content = pydantic_schemas::generate_pydantic_schemas(&contract.spec);
 
         // Generate conversion helpers
-        let conversions_content = pydantic_schemas::generate_conversion_helpers(spec);
+        let conversions_content = pydantic_schemas::generate_conversion_helpers(&contract.spec);
 
         // Combine both
         let output = format!("{}\n{}", schemas_content, conversions_content);
 
-        fs::write(self.output_dir.join("src/schemas.py"), output)?;
+        project.add(format!("{}/schemas.py", self.package_dir(contract)), output);
 
         Ok(())
     }
 
-    fn generate_pyproject_toml(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let template = include_str!("../../templates/python/pyproject.toml.hbs");
+    fn generate_pyproject_toml(&self, project: &mut GeneratedProject) -> Result<()> {
         let data = serde_json::json!({
-            "contract_name": self.contract_name,
-            "package_name": to_python_package_name(self.contract_name),
+            "contract_name": self.project_name,
+            "package_name": to_python_package_name(self.project_name),
         });
 
-        let mut hbs = Handlebars::new();
-        hbs.register_template_string("pyproject", template)?;
-
-        let output = hbs.render("pyproject", &data)?;
-        fs::write(self.output_dir.join("pyproject.toml"), output)?;
+        let output = self.templates.render("python/pyproject.toml", &data)?;
+        project.add("pyproject.toml", output);
 
         Ok(())
     }
 
-    fn generate_env_example(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let template = include_str!("../../templates/python/env.example.hbs");
-        let data = serde_json::json!({
-            "contract_id": self.contract_id,
-            "rpc_url": self.network.rpc_url,
-            "network_passphrase": self.network.network_passphrase,
-        });
-
-        let mut hbs = Handlebars::new();
-        hbs.register_template_string("env", template)?;
-
-        let output = hbs.render("env", &data)?;
-        fs::write(self.output_dir.join(".env.example"), output)?;
+    fn generate_readme(&self, project: &mut GeneratedProject) -> Result<()> {
+        let data = self.create_template_data()?;
+        let output = self.templates.render("python/README.md", &data)?;
+        project.add("README.md", output);
 
         Ok(())
     }
 
-    fn generate_dockerfile(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let content = include_str!("../../templates/python/Dockerfile.hbs");
-        fs::write(self.output_dir.join("Dockerfile"), content)?;
-        println!("  Generated Dockerfile");
-        Ok(())
-    }
+    fn generate_lib_files(&self, project: &mut GeneratedProject) -> Result<()> {
+        let data = serde_json::json!({});
+        for file in ["__init__.py", "utils.py", "submit.py", "contract_info.py", "errors.py"] {
+            let output = self.templates.render(&format!("python/lib/{}", file), &data)?;
+            project.add(format!("src/lib/{}", file), output);
+        }
 
-    fn generate_dockerignore(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let content = "__pycache__\n*.pyc\n.venv\n.env\n.env.local\n*.log\n.git\n.DS_Store\n";
-        fs::write(self.output_dir.join(".dockerignore"), content)?;
-        println!("  Generated .dockerignore");
         Ok(())
     }
 
-    fn generate_readme(&self, spec: &ContractSpec) -> Result<(), Box<dyn std::error::Error>> {
-        let template = include_str!("../../templates/python/README.md.hbs");
-        let data = self.create_template_data(spec)?;
-
-        let mut hbs = Handlebars::new();
-        hbs.register_template_string("readme", template)?;
-
-        let output = hbs.render("readme", &data)?;
-        fs::write(self.output_dir.join("README.md"), output)?;
+    /// Data for server.py and the README: every contract, plus the server settings
+    fn create_template_data(&self) -> Result<serde_json::Value> {
+        let contracts = self
+            .contracts
+            .iter()
+            .map(|c| self.create_contract_data(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        let names: Vec<&str> = self.contracts.iter().map(|c| c.name.as_str()).collect();
 
-        Ok(())
+        Ok(serde_json::json!({
+            "contract_name": self.project_name,
+            "contract_names": names.join(", "),
+            "package_name": to_python_package_name(self.project_name),
+            "server_name": self.server_name,
+            "network_name": self.network.name,
+            "rpc_url": self.network.rpc_url,
+            "network_passphrase": self.network.network_passphrase,
+            "multi": self.namespaced(),
+            "with_rest": self.with_rest,
+            "contracts": contracts,
+            "version": env!("CARGO_PKG_VERSION"),
+        }))
     }
 
-    fn generate_lib_files(&self) -> Result<(), Box<dyn std::error::Error>> {
-        // Generate lib/__init__.py
-        let init_template = include_str!("../../templates/python/lib/__init__.py.hbs");
-        fs::write(self.output_dir.join("src/lib/__init__.py"), init_template)?;
-
-        // Generate lib/utils.py
-        let utils_template = include_str!("../../templates/python/lib/utils.py.hbs");
-        fs::write(self.output_dir.join("src/lib/utils.py"), utils_template)?;
-
-        // Generate lib/submit.py
-        let submit_template = include_str!("../../templates/python/lib/submit.py.hbs");
-        fs::write(self.output_dir.join("src/lib/submit.py"), submit_template)?;
-
-        Ok(())
-    }
+    /// Data for one contract: its functions and events, and the names its
+    /// tools, client and configuration use in server.py
+    fn create_contract_data(&self, contract: &ServedContract) -> Result<serde_json::Value> {
+        let spec = &contract.spec;
+        let namespaced = self.namespaced();
+        let id = contract.identifier();
+
+        // In a multi-contract server.py, each contract's schemas and conversion
+        // helpers are referenced through its own module
+        let schemas_module = format!("{}_schemas", id);
+        let qualify = |expr: String| {
+            if namespaced {
+                qualify_contract_names(&expr, &schemas_module, spec)
+            } else {
+                expr
+            }
+        };
 
-    fn create_template_data(&self, spec: &ContractSpec) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
         let functions: Vec<_> = spec.functions.iter().map(|f| {
             let inputs: Vec<_> = f.inputs.iter().map(|input| {
-                let is_custom_type = matches!(input.type_ref, crate::spec::TypeRef::Custom(_));
-                let custom_type_name = if let crate::spec::TypeRef::Custom(name) = &input.type_ref {
-                    Some(name.clone())
+                let name_snake = to_python_identifier(&input.name);
+                let pydantic_type = qualify(input.type_ref.to_pydantic());
+                // Strings and integers carry the schema's constraints, so the
+                // tool rejects malformed values before converting them
+                let constraints = json_types::pydantic_constraints(&input.type_ref.to_json_schema());
+                let annotation = if constraints.is_empty() {
+                    pydantic_type.clone()
                 } else {
-                    None
+                    format!("Annotated[{}, Field({})]", pydantic_type, constraints.join(", "))
                 };
-
                 serde_json::json!({
                     "name": input.name,
-                    "name_snake": to_snake_case(&input.name),
-                    "py_type": map_type_to_python(&input.type_ref),
-                    "pydantic_type": input.type_ref.to_pydantic(),
-                    "doc": input.doc.as_deref().unwrap_or(""),
-                    "is_custom_type": is_custom_type,
-                    "custom_type_name": custom_type_name,
-                    "conversion_function": custom_type_name.as_ref().map(|name| format!("{}_to_bindings", name.to_lowercase())),
+                    "convert_expr": qualify(pydantic_schemas::schema_to_binding_expr(&input.type_ref, &name_snake)),
+                    "name_snake": name_snake,
+                    "py_type": PythonBackend.type_name(&input.type_ref),
+                    "pydantic_type": pydantic_type,
+                    "annotation": annotation,
+                    "optional": input.type_ref.is_optional(),
+                    "doc": py_docstring_text(input.doc.as_deref().unwrap_or("")),
                 })
             }).collect();
+            // Options default to None; parameters become keyword-only when a
+            // required one follows an option
+            let keyword_only = f.inputs.iter()
+                .skip_while(|input| !input.type_ref.is_optional())
+                .any(|input| !input.type_ref.is_optional());
 
             serde_json::json!({
                 "name": f.name,
-                "name_snake": to_snake_case(&f.name),
-                "doc": f.doc.as_deref().unwrap_or(""),
+                "name_snake": to_python_identifier(&f.name),
+                "doc": py_docstring_text(f.doc.as_deref().unwrap_or("")),
                 "inputs": inputs,
                 "has_inputs": !f.inputs.is_empty(),
+                "keyword_only": keyword_only,
+                "read_only": f.kind == crate::spec::FunctionKind::ReadOnly,
+            })
+        }).collect();
+
+        let events: Vec<_> = spec.events.iter().map(|event| {
+            let fields = |fields: &[crate::spec::FieldSpec]| -> Vec<serde_json::Value> {
+                fields.iter().map(|f| serde_json::json!({
+                    "name": f.name,
+                    "py_type": PythonBackend.type_name(&f.type_ref),
+                })).collect()
+            };
+            let quoted = |fields: &[crate::spec::FieldSpec]| -> String {
+                let names: Vec<String> = fields.iter().map(|f| py_string(&f.name)).collect();
+                format!("[{}]", names.join(", "))
+            };
+
+            let mut filters = Vec::new();
+            let mut topic_exprs: Vec<String> = event.prefix_topics.iter()
+                .map(|t| format!("scval.to_symbol({}).to_xdr()", py_string(t)))
+                .collect();
+            for topic in &event.topics {
+                let py_name = to_python_identifier(&topic.name);
+                match topic_scval_expr(&topic.type_ref, &py_name) {
+                    Some(expr) => {
+                        topic_exprs.push(format!("{}.to_xdr() if {} is not None else \"*\"", expr, py_name));
+                        filters.push(serde_json::json!({
+                            "name": topic.name,
+                            "py_name": py_name,
+                            "py_type": topic.type_ref.to_pydantic(),
+                        }));
+                    }
+                    None => topic_exprs.push("\"*\"".to_string()),
+                }
+            }
+
+            serde_json::json!({
+                "name": event.name,
+                "name_snake": to_snake_case(&event.name),
+                "tool": ToolNaming::Python.event_tool(&event.name),
+                "doc": py_docstring_text(event.doc.as_deref().unwrap_or("")),
+                "filters": filters,
+                "topic_exprs": topic_exprs,
+                "topic_names": quoted(&event.topics),
+                "data_names": quoted(&event.data),
+                "data_format": match event.data_format {
+                    EventDataFormat::SingleValue => "single-value",
+                    EventDataFormat::Vec => "vec",
+                    EventDataFormat::Map => "map",
+                },
+                "topics": fields(&event.topics),
+                "data": fields(&event.data),
             })
         }).collect();
 
+        // Event catalogue served by the contract://events resource, embedded as a
+        // Python string literal
+        let catalogue: Vec<_> = spec.events.iter().map(|event| {
+            let fields = |fields: &[crate::spec::FieldSpec]| -> Vec<serde_json::Value> {
+                fields.iter().map(|f| serde_json::json!({
+                    "name": f.name,
+                    "type": f.type_ref.to_pydantic(),
+                })).collect()
+            };
+            serde_json::json!({
+                "name": event.name,
+                "tool": contract.tool_name(&ToolNaming::Python.event_tool(&event.name), namespaced),
+                "doc": event.doc,
+                "prefixTopics": event.prefix_topics,
+                "topics": fields(&event.topics),
+                "data": fields(&event.data),
+            })
+        }).collect();
+        let events_json = py_string(&serde_json::to_string(&catalogue)?);
+
+        let (prefix, module, snapshot_path) = if namespaced {
+            (
+                format!("{}_", id),
+                format!("{}_contract.", id),
+                crate::spec::snapshot::contract_snapshot_path(&contract.name),
+            )
+        } else {
+            (String::new(), String::new(), crate::spec::snapshot::SNAPSHOT_PATH.to_string())
+        };
+
         Ok(serde_json::json!({
-            "contract_name": self.contract_name,
-            "package_name": to_python_package_name(self.contract_name),
-            "contract_id": self.contract_id,
+            "name": contract.name,
+            "module": id,
+            "contract_name": contract.name,
+            "contract_id": contract.contract_id,
+            "env_var": contract.env_var(namespaced),
+            "tool_prefix": contract.tool_name("", namespaced),
+            "fn_prefix": prefix,
+            "client_module": module,
+            "client_var": format!("{}client", prefix),
+            "config_var": format!("{}config", prefix),
+            "snapshot_var": format!("{}SPEC_SNAPSHOT", prefix.to_uppercase()),
+            "snapshot_path": snapshot_path,
+            "events_var": format!("{}CONTRACT_EVENTS", prefix.to_uppercase()),
+            "events_uri": if namespaced { format!("contract://{}/events", contract.name) } else { "contract://events".to_string() },
+            "events_resource": if namespaced { format!("{}-contract-events", contract.name) } else { "contract-events".to_string() },
             "server_name": self.server_name,
             "network_name": self.network.name,
-            "rpc_url": self.network.rpc_url,
-            "network_passphrase": self.network.network_passphrase,
             "functions": functions,
+            "events": events,
+            "events_json": events_json,
+            "contract_errors": contract_errors_literal(spec),
             "version": env!("CARGO_PKG_VERSION"),
+            "wasm_hash": contract.wasm_hash,
         }))
     }
 }
 
-/// Convert string to snake_case
-fn to_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    let mut prev_is_lower = false;
+/// `CONTRACT_ERRORS` dict literal mapping the contract's error codes to their
+/// name and doc. A code declared by several error enums keeps the first case.
+fn contract_errors_literal(spec: &ContractSpec) -> String {
+    let mut codes = HashSet::new();
+    let mut content = String::new();
+    for error in spec.error_cases().filter(|e| codes.insert(e.code)) {
+        content.push_str(&format!("    {}: {{\"name\": {}", error.code, py_string(&error.name)));
+        if let Some(doc) = &error.doc {
+            content.push_str(&format!(", \"doc\": {}", py_string(doc.trim())));
+        }
+        content.push_str("},\n");
+    }
+    if content.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{\n{}}}", content)
+    }
+}
 
-    for (i, c) in s.chars().enumerate() {
-        if c.is_uppercase() {
-            if i > 0 && prev_is_lower {
-                result.push('_');
+/// Prefix the Pydantic schema classes and conversion helpers of a contract's
+/// types in a Python expression with the module they are imported as
+/// (`TokenInfoSchema` → `token_schemas.TokenInfoSchema`), so the tools of
+/// several contracts can live in one server.py without name clashes
+fn qualify_contract_names(expr: &str, module: &str, spec: &ContractSpec) -> String {
+    let names: HashSet<String> = spec
+        .types
+        .iter()
+        .flat_map(|t| [format!("{}Schema", t.name), format!("{}_to_bindings", t.name.to_lowercase())])
+        .collect();
+
+    let mut result = String::new();
+    let mut ident = String::new();
+    let mut prev = None;
+    let flush = |ident: &mut String, result: &mut String, prev: Option<char>| {
+        if !ident.is_empty() {
+            if prev != Some('.') && names.contains(ident.as_str()) {
+                result.push_str(module);
+                result.push('.');
             }
-            result.push(c.to_ascii_lowercase());
-            prev_is_lower = false;
+            result.push_str(ident);
+            ident.clear();
+        }
+    };
+    for c in expr.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            ident.push(c);
         } else {
+            flush(&mut ident, &mut result, prev);
             result.push(c);
-            prev_is_lower = c.is_lowercase();
+            prev = Some(c);
         }
     }
-
+    flush(&mut ident, &mut result, prev);
     result
 }
 
+/// Convert a parameter name to a snake_case Python identifier, suffixing
+/// reserved keywords with `_` (e.g. a `from` topic becomes `from_`)
+pub(crate) fn to_python_identifier(s: &str) -> String {
+    py_identifier(&to_snake_case(s))
+}
+
+/// `stellar_sdk.scval` expression encoding an event topic filter value.
+/// Returns `None` for topic types that can't be used as filters (matched with `*`).
+fn topic_scval_expr(type_ref: &crate::spec::TypeRef, var: &str) -> Option<String> {
+    use crate::spec::TypeRef;
+
+    let expr = match type_ref {
+        TypeRef::Bool => format!("scval.to_bool({})", var),
+        TypeRef::U32 => format!("scval.to_uint32({})", var),
+        TypeRef::I32 => format!("scval.to_int32({})", var),
+        // 64-bit and wider integers are passed as strings (see TypeRef::to_json_schema)
+        TypeRef::U64 => format!("scval.to_uint64(int({}))", var),
+        TypeRef::I64 => format!("scval.to_int64(int({}))", var),
+        TypeRef::U128 => format!("scval.to_uint128(int({}))", var),
+        TypeRef::I128 => format!("scval.to_int128(int({}))", var),
+        TypeRef::U256 => format!("scval.to_uint256(int({}))", var),
+        TypeRef::I256 => format!("scval.to_int256(int({}))", var),
+        TypeRef::String => format!("scval.to_string({})", var),
+        TypeRef::Symbol => format!("scval.to_symbol({})", var),
+        TypeRef::Address => format!("scval.to_address({})", var),
+        _ => return None,
+    };
+    Some(expr)
+}
+
 /// Convert string to Python package name (PEP 508 compliant)
 /// Replaces spaces and invalid characters with hyphens, converts to lowercase
 fn to_python_package_name(s: &str) -> String {
@@ -368,45 +484,3 @@ fn to_python_package_name(s: &str) -> String {
     // Remove leading/trailing hyphens
     result.trim_matches('-').to_string()
 }
-
-/// Map Soroban type to Python type hint
-/// For MCP tool signatures, we need types that Pydantic can generate JSON Schema for.
-/// Custom types (dataclasses from stellar-contract-bindings) can't be used directly,
-/// so we use Dict[str, Any] and convert them in the function body.
-fn map_type_to_python(type_ref: &crate::spec::TypeRef) -> String {
-    use crate::spec::TypeRef;
-
-    match type_ref {
-        TypeRef::U32 | TypeRef::I32 | TypeRef::U64 | TypeRef::I64
-        | TypeRef::U128 | TypeRef::I128 | TypeRef::U256 | TypeRef::I256 => "int".to_string(),
-        TypeRef::Bool => "bool".to_string(),
-        TypeRef::String | TypeRef::Symbol => "str".to_string(),
-        TypeRef::Address => "str".to_string(), // Address as string
-        TypeRef::Bytes | TypeRef::BytesN(_) => "bytes".to_string(),
-        TypeRef::Vec(inner) => {
-            // Check if inner type is custom (complex type)
-            if matches!(inner.as_ref(), TypeRef::Custom(_)) {
-                "List[Dict[str, Any]]".to_string()
-            } else {
-                format!("List[{}]", map_type_to_python(inner))
-            }
-        }
-        TypeRef::Option(inner) => {
-            // Check if inner type is custom (complex type)
-            if matches!(inner.as_ref(), TypeRef::Custom(_)) {
-                "Optional[Dict[str, Any]]".to_string()
-            } else {
-                format!("Optional[{}]", map_type_to_python(inner))
-            }
-        }
-        TypeRef::Map { key, value } => format!("Dict[{}, {}]", map_type_to_python(key), map_type_to_python(value)),
-        TypeRef::Tuple(types) => {
-            let type_strs: Vec<_> = types.iter().map(|t| map_type_to_python(t)).collect();
-            format!("Tuple[{}]", type_strs.join(", "))
-        }
-        // Custom types (structs/enums from contract) → use Dict for Pydantic compatibility
-        // The convert_mcp_params helper will handle conversion to actual dataclass types
-        TypeRef::Custom(_name) => "Dict[str, Any]".to_string(),
-        _ => "Any".to_string(), // Fallback
-    }
-}
@@ -0,0 +1,352 @@
+//! Rust MCP Server generator implementation
+//!
+//! The generated server is a single binary on the MCP Rust SDK (rmcp). Its
+//! tools are the ones listed in `tools.json`, which it embeds; each tool call
+//! is validated and converted to Soroban values against the contract spec,
+//! emitted as static tables in `src/contracts.rs` (`spec::Type` expressions
+//! like `Type::Option(&Type::Address)`).
+
+use super::backend::{project_data, render_template_files, RustBackend, TargetBackend};
+use super::contracts::ServedContract;
+use super::mcp_generator::event_data_format_str;
+use super::project::GeneratedProject;
+use super::python_bindings::is_tuple_struct;
+use super::template_data::to_kebab_case;
+use super::templates::TemplateRenderer;
+use super::tool_manifest::{render_tool_manifest, TOOL_MANIFEST_PATH};
+use crate::codegen::emit::rs_string;
+use crate::error::Result;
+use crate::spec::{EventDataFormat, FieldSpec, FunctionKind, TypeDef, TypeRef};
+use crate::NetworkConfig;
+use std::collections::HashSet;
+
+/// Project files rendered as-is from a template with the project data:
+/// `(template, path)`
+const TEMPLATE_FILES: &[(&str, &str)] = &[
+    ("rust/env.example", ".env.example"),
+    ("rust/Dockerfile", "Dockerfile"),
+    ("rust/dockerignore", ".dockerignore"),
+];
+
+/// Modules of the server that don't depend on the contracts: `(template, path)`
+const SOURCE_FILES: &[(&str, &str)] = &[
+    ("rust/main.rs", "src/main.rs"),
+    ("rust/server.rs", "src/server.rs"),
+    ("rust/spec.rs", "src/spec.rs"),
+    ("rust/scval.rs", "src/scval.rs"),
+    ("rust/rpc.rs", "src/rpc.rs"),
+    ("rust/transaction.rs", "src/transaction.rs"),
+    ("rust/contract_info.rs", "src/contract_info.rs"),
+    ("rust/errors.rs", "src/errors.rs"),
+];
+
+/// Rust MCP Server generator
+pub struct RustGenerator<'a> {
+    /// Project name (the contract name for single-contract servers)
+    project_name: &'a str,
+    server_name: &'a str,
+    network: &'a NetworkConfig,
+    /// Contracts the server exposes tools for
+    contracts: &'a [ServedContract],
+    templates: &'a TemplateRenderer,
+}
+
+impl<'a> RustGenerator<'a> {
+    /// Create a new Rust generator
+    pub fn new(
+        project_name: &'a str,
+        server_name: &'a str,
+        network: &'a NetworkConfig,
+        contracts: &'a [ServedContract],
+        templates: &'a TemplateRenderer,
+    ) -> Self {
+        Self {
+            project_name,
+            server_name,
+            network,
+            contracts,
+            templates,
+        }
+    }
+
+    /// Render every file of the Rust MCP server, without writing anything
+    pub fn render(&self) -> Result<GeneratedProject> {
+        let mut project = GeneratedProject::new();
+
+        let data = self.create_template_data();
+        render_template_files(&mut project, self.templates, SOURCE_FILES, &data)?;
+        project.add("src/contracts.rs", self.templates.render("rust/contracts.rs", &self.create_contracts_data()?)?);
+        project.add("Cargo.toml", self.templates.render("rust/Cargo.toml", &data)?);
+        project.add("README.md", self.templates.render("rust/README.md", &data)?);
+
+        let mut project_data = project_data(self.project_name, self.server_name, self.network, self.contracts);
+        project_data["package_name"] = data["package_name"].clone();
+        render_template_files(&mut project, self.templates, TEMPLATE_FILES, &project_data)?;
+        project.add(TOOL_MANIFEST_PATH, render_tool_manifest(&RustBackend, self.contracts));
+
+        Ok(project)
+    }
+
+    /// Whether tools are namespaced by contract (servers with several contracts)
+    fn namespaced(&self) -> bool {
+        self.contracts.len() > 1
+    }
+
+    /// Data for the server sources, Cargo.toml and the README: names, network
+    /// settings and each contract's tools with their types
+    fn create_template_data(&self) -> serde_json::Value {
+        let namespaced = self.namespaced();
+        let contracts: Vec<_> = self.contracts.iter().map(|contract| {
+            let spec = &contract.spec;
+            let field = |f: &FieldSpec| serde_json::json!({
+                "name": f.name,
+                "type_name": f.type_ref.to_typescript(),
+            });
+            let functions: Vec<_> = spec.functions.iter().map(|f| {
+                let inputs: Vec<_> = f.inputs.iter().map(|input| serde_json::json!({
+                    "name": input.name,
+                    "type_name": input.type_ref.to_typescript(),
+                    "doc": input.doc,
+                })).collect();
+                serde_json::json!({
+                    "name": f.name,
+                    "tool_name": contract.tool_name(&to_kebab_case(&f.name), namespaced),
+                    "doc": f.doc.clone().unwrap_or_else(|| format!("Call {} function", f.name)),
+                    "inputs": inputs,
+                    "output_type": f.output.as_ref().unwrap_or(&TypeRef::Void).to_typescript(),
+                    "read_only": f.kind == FunctionKind::ReadOnly,
+                })
+            }).collect();
+            let events: Vec<_> = spec.events.iter().map(|event| serde_json::json!({
+                "name": event.name,
+                "tool_name": contract.tool_name(&format!("get-{}-events", to_kebab_case(&event.name)), namespaced),
+                "doc": event.doc,
+                "topics": event.topics.iter().map(field).collect::<Vec<_>>(),
+                "data": event.data.iter().map(field).collect::<Vec<_>>(),
+            })).collect();
+
+            serde_json::json!({
+                "name": contract.name,
+                "contract_id": contract.contract_id,
+                "env_var": contract.env_var(namespaced),
+                "tool_prefix": contract.tool_name("", namespaced),
+                "events_uri": events_uri(contract, namespaced),
+                "functions": functions,
+                "events": events,
+            })
+        }).collect();
+        let names: Vec<&str> = self.contracts.iter().map(|c| c.name.as_str()).collect();
+
+        serde_json::json!({
+            "contract_name": self.project_name,
+            "contract_names": names.join(", "),
+            "package_name": to_rust_package_name(self.project_name),
+            "server_name": self.server_name,
+            "network_name": self.network.name,
+            "rpc_url": self.network.rpc_url,
+            "network_passphrase": self.network.network_passphrase,
+            "multi": self.namespaced(),
+            "contracts": contracts,
+            "version": env!("CARGO_PKG_VERSION"),
+        })
+    }
+
+    /// Data for `src/contracts.rs`: every value is a Rust expression
+    fn create_contracts_data(&self) -> Result<serde_json::Value> {
+        let namespaced = self.namespaced();
+        let contracts = self
+            .contracts
+            .iter()
+            .map(|contract| {
+                let spec = &contract.spec;
+                let types: Vec<_> = spec.types.iter().map(|t| serde_json::json!({
+                    "name": rs_string(&t.name),
+                    "def": rust_type_def(&t.definition),
+                })).collect();
+                let functions: Vec<_> = spec.functions.iter().map(|f| serde_json::json!({
+                    "name": rs_string(&f.name),
+                    "inputs": rust_fields(f.inputs.iter().map(|p| (p.name.as_str(), &p.type_ref))),
+                    "output": RustBackend.type_name(f.output.as_ref().unwrap_or(&TypeRef::Void)),
+                    "read_only": f.kind == FunctionKind::ReadOnly,
+                })).collect();
+                let events: Vec<_> = spec.events.iter().map(|event| {
+                    let prefix_topics: Vec<String> = event.prefix_topics.iter().map(|t| rs_string(t)).collect();
+                    serde_json::json!({
+                        "name": rs_string(&event.name),
+                        "prefix_topics": format!("&[{}]", prefix_topics.join(", ")),
+                        "topics": rust_fields(event.topics.iter().map(|f| (f.name.as_str(), &f.type_ref))),
+                        "data": rust_fields(event.data.iter().map(|f| (f.name.as_str(), &f.type_ref))),
+                        "data_format": match event.data_format {
+                            EventDataFormat::SingleValue => "SingleValue",
+                            EventDataFormat::Vec => "Vec",
+                            EventDataFormat::Map => "Map",
+                        },
+                    })
+                }).collect();
+                // A code declared by several error enums keeps the first case
+                let mut codes = HashSet::new();
+                let errors: Vec<_> = spec.error_cases().filter(|e| codes.insert(e.code)).map(|e| serde_json::json!({
+                    "code": e.code,
+                    "name": rs_string(&e.name),
+                    "doc": match &e.doc {
+                        Some(doc) => format!("Some({})", rs_string(doc.trim())),
+                        None => "None".to_string(),
+                    },
+                })).collect();
+
+                Ok(serde_json::json!({
+                    "name": rs_string(&contract.name),
+                    "env_var": rs_string(&contract.env_var(namespaced)),
+                    "contract_id": rs_string(&contract.contract_id),
+                    "info_tool": rs_string(&contract.tool_name("contract-info", namespaced)),
+                    "events_uri": rs_string(&events_uri(contract, namespaced)),
+                    "events_catalogue": rs_string(&serde_json::to_string(&events_catalogue(contract, namespaced))?),
+                    "wasm_hash": match &contract.wasm_hash {
+                        Some(hash) => format!("Some({})", rs_string(hash)),
+                        None => "None".to_string(),
+                    },
+                    "types": types,
+                    "functions": functions,
+                    "events": events,
+                    "errors": errors,
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "network_name": rs_string(&self.network.name),
+            "rpc_url": rs_string(&self.network.rpc_url),
+            "network_passphrase": rs_string(&self.network.network_passphrase),
+            "contracts": contracts,
+        }))
+    }
+}
+
+/// URI of the resource listing a contract's events
+pub(super) fn events_uri(contract: &ServedContract, namespaced: bool) -> String {
+    if namespaced {
+        format!("contract://{}/events", contract.name)
+    } else {
+        "contract://events".to_string()
+    }
+}
+
+/// Event catalogue served by the events resource. Field types are the JSON
+/// Schema of their values.
+pub(super) fn events_catalogue(contract: &ServedContract, namespaced: bool) -> serde_json::Value {
+    contract
+        .spec
+        .events
+        .iter()
+        .map(|event| {
+            let fields = |fields: &[FieldSpec]| -> Vec<serde_json::Value> {
+                fields
+                    .iter()
+                    .map(|f| serde_json::json!({ "name": f.name, "type": f.type_ref.to_json_schema() }))
+                    .collect()
+            };
+            serde_json::json!({
+                "name": event.name,
+                "tool": contract.tool_name(&format!("get-{}-events", to_kebab_case(&event.name)), namespaced),
+                "doc": event.doc,
+                "prefixTopics": event.prefix_topics,
+                "topics": fields(&event.topics),
+                "data": fields(&event.data),
+                "dataFormat": event_data_format_str(event.data_format),
+            })
+        })
+        .collect()
+}
+
+/// `spec::Type` expression describing a type in the generated server
+/// (`Type::Vec(&Type::Address)`)
+pub(crate) fn rust_type(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Bool => "Type::Bool".to_string(),
+        TypeRef::Void => "Type::Void".to_string(),
+        // Status values travel as their code, like a u32
+        TypeRef::Status | TypeRef::U32 => "Type::U32".to_string(),
+        TypeRef::I32 => "Type::I32".to_string(),
+        TypeRef::U64 => "Type::U64".to_string(),
+        TypeRef::I64 => "Type::I64".to_string(),
+        TypeRef::Timepoint => "Type::Timepoint".to_string(),
+        TypeRef::Duration => "Type::Duration".to_string(),
+        TypeRef::U128 => "Type::U128".to_string(),
+        TypeRef::I128 => "Type::I128".to_string(),
+        TypeRef::U256 => "Type::U256".to_string(),
+        TypeRef::I256 => "Type::I256".to_string(),
+        TypeRef::Bytes => "Type::Bytes".to_string(),
+        TypeRef::BytesN(n) => format!("Type::BytesN({})", n),
+        TypeRef::String => "Type::String".to_string(),
+        TypeRef::Symbol => "Type::Symbol".to_string(),
+        TypeRef::Address => "Type::Address".to_string(),
+        TypeRef::Option(inner) => format!("Type::Option(&{})", rust_type(inner)),
+        TypeRef::Result { ok, .. } => format!("Type::Result(&{})", rust_type(ok)),
+        TypeRef::Vec(inner) => format!("Type::Vec(&{})", rust_type(inner)),
+        TypeRef::Map { key, value } => format!("Type::Map(&{}, &{})", rust_type(key), rust_type(value)),
+        TypeRef::Tuple(types) => {
+            let types: Vec<String> = types.iter().map(rust_type).collect();
+            format!("Type::Tuple(&[{}])", types.join(", "))
+        }
+        TypeRef::Custom(name) => format!("Type::Custom({})", rs_string(name)),
+    }
+}
+
+/// `spec::TypeDef` expression for a contract type
+fn rust_type_def(definition: &TypeDef) -> String {
+    match definition {
+        TypeDef::Struct { fields } if is_tuple_struct(fields) => {
+            let types: Vec<String> = fields.iter().map(|f| RustBackend.type_name(&f.type_ref)).collect();
+            format!("TypeDef::TupleStruct(&[{}])", types.join(", "))
+        }
+        TypeDef::Struct { fields } => {
+            format!("TypeDef::Struct({})", rust_fields(fields.iter().map(|f| (f.name.as_str(), &f.type_ref))))
+        }
+        TypeDef::Enum { variants } => {
+            let variants: Vec<String> =
+                variants.iter().map(|v| format!("({}, {})", rs_string(&v.name), v.value)).collect();
+            format!("TypeDef::Enum(&[{}])", variants.join(", "))
+        }
+        TypeDef::Union { cases } => {
+            let cases: Vec<String> = cases
+                .iter()
+                .map(|c| {
+                    let payload = match &c.type_ref {
+                        Some(type_ref) => format!("Some({})", RustBackend.type_name(type_ref)),
+                        None => "None".to_string(),
+                    };
+                    format!("({}, {})", rs_string(&c.name), payload)
+                })
+                .collect();
+            format!("TypeDef::Union(&[{}])", cases.join(", "))
+        }
+    }
+}
+
+/// `&[Field]` expression for named values
+fn rust_fields<'t>(fields: impl Iterator<Item = (&'t str, &'t TypeRef)>) -> String {
+    let fields: Vec<String> = fields
+        .map(|(name, type_ref)| format!("Field {{ name: {}, ty: {} }}", rs_string(name), RustBackend.type_name(type_ref)))
+        .collect();
+    format!("&[{}]", fields.join(", "))
+}
+
+/// Cargo package name: lowercase letters, digits and `-`. Also names the
+/// Go module and binary.
+pub(super) fn to_rust_package_name(s: &str) -> String {
+    let mut result = String::new();
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            result.push(c.to_ascii_lowercase());
+        } else if !result.is_empty() && !result.ends_with('-') {
+            result.push('-');
+        }
+    }
+    let result = result.trim_end_matches('-');
+    if result.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        format!("{}-mcp-server", result)
+    } else {
+        format!("mcp-server-{}", result).trim_end_matches('-').to_string()
+    }
+}
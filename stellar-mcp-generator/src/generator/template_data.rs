@@ -1,143 +1,5 @@
-//! Template data structures for Handlebars rendering
-
-use serde::Serialize;
-
-/// Data for the main index.ts template
-#[derive(Serialize)]
-pub struct IndexTemplateData {
-    pub server_name: String,
-    pub contract_id: String,
-    pub contract_name: String,
-    pub rpc_url: String,
-    pub network_passphrase: String,
-    pub functions: Vec<FunctionTemplateData>,
-    pub with_launchtube: bool,
-    pub with_passkey: bool,
-}
-
-/// Function data for templates
-#[derive(Serialize)]
-pub struct FunctionTemplateData {
-    pub name: String,
-    pub name_kebab: String,
-    pub name_camel: String,
-    pub doc: String,
-    pub inputs: Vec<InputTemplateData>,
-    pub has_inputs: bool,
-    pub output_type: String,
-    pub has_output: bool,
-    /// Zod raw shape string for the registerTool outputSchema (e.g. `{ xdr: z.string() }`)
-    pub output_zod: String,
-}
-
-/// Input parameter data for templates
-#[derive(Serialize)]
-pub struct InputTemplateData {
-    pub name: String,
-    pub name_camel: String,
-    pub doc: String,
-    pub ts_type: String,
-    pub zod_type: String,
-    pub is_last: bool,
-}
-
-/// Data for the schema template
-#[derive(Serialize)]
-pub struct SchemaTemplateData {
-    pub contract_name: String,
-    pub functions: Vec<FunctionSchemaData>,
-    pub types: Vec<TypeSchemaData>,
-}
-
-/// Function schema data
-#[derive(Serialize)]
-pub struct FunctionSchemaData {
-    pub name: String,
-    pub name_pascal: String,
-    pub inputs: Vec<InputTemplateData>,
-    pub has_inputs: bool,
-}
-
-/// Custom type schema data
-#[derive(Serialize)]
-pub struct TypeSchemaData {
-    pub name: String,
-    pub name_pascal: String,
-    pub fields: Vec<FieldSchemaData>,
-    pub is_enum: bool,
-    pub enum_variants: Vec<EnumVariantData>,
-}
-
-/// Field schema data
-#[derive(Serialize)]
-pub struct FieldSchemaData {
-    pub name: String,
-    pub name_camel: String,
-    pub ts_type: String,
-    pub zod_type: String,
-    pub is_last: bool,
-}
-
-/// Enum variant data
-#[derive(Serialize)]
-pub struct EnumVariantData {
-    pub name: String,
-    pub value: u32,
-    pub is_last: bool,
-}
-
-/// Data for package.json template
-#[derive(Serialize)]
-pub struct PackageJsonData {
-    pub name: String,
-    pub description: String,
-    pub version: String,
-    pub with_launchtube: bool,
-    pub with_passkey: bool,
-}
-
-/// Data for .env.example template
-#[derive(Serialize)]
-pub struct EnvExampleData {
-    pub contract_id: String,
-    pub rpc_url: String,
-    pub network_passphrase: String,
-    pub with_launchtube: bool,
-    pub with_passkey: bool,
-}
-
-/// Data for README template
-#[derive(Serialize)]
-pub struct ReadmeTemplateData {
-    pub server_name: String,
-    pub contract_id: String,
-    pub contract_name: String,
-    pub network_name: String,
-    pub functions: Vec<FunctionDocData>,
-    pub with_launchtube: bool,
-    pub with_passkey: bool,
-}
-
-/// Function documentation data
-#[derive(Serialize)]
-pub struct FunctionDocData {
-    pub name: String,
-    pub name_kebab: String,
-    pub doc: String,
-    pub inputs: Vec<InputDocData>,
-    pub output_type: String,
-}
-
-/// Input documentation data
-#[derive(Serialize)]
-pub struct InputDocData {
-    pub name: String,
-    pub ts_type: String,
-    pub doc: String,
-    pub required: bool,
-}
-
-// Helper functions for name conversion
+//! Name conversion helpers used by the generators and exposed to templates as
+//! Handlebars helpers (see `templates.rs`)
 
 /// Convert to kebab-case
 pub fn to_kebab_case(s: &str) -> String {
@@ -194,3 +56,24 @@ pub fn to_pascal_case(s: &str) -> String {
     }
     result
 }
+
+/// Convert to snake_case
+pub fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    let mut prev_is_lower = false;
+
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 && prev_is_lower {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+            prev_is_lower = false;
+        } else {
+            result.push(c);
+            prev_is_lower = c.is_lowercase();
+        }
+    }
+
+    result
+}
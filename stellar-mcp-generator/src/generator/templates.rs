@@ -1,162 +1,290 @@
 //! Template rendering using Handlebars
+//!
+//! Every project file a backend writes is rendered from a named template. The
+//! defaults are compiled into the binary (each backend lists its own, see
+//! `TargetBackend::templates`); a template directory (`--templates <dir>`)
+//! can override any of them with a `<name>.hbs` file laid out like this
+//! crate's `templates/` directory, e.g. `index.ts.hbs` or
+//! `python/server.py.hbs`. Templates can include each other as partials
+//! (`{{> lib/logger.ts}}`) and use the case conversion and type mapping
+//! helpers registered here.
 
-use handlebars::Handlebars;
+use super::template_data::{to_camel_case, to_kebab_case, to_pascal_case, to_snake_case};
+use crate::spec::TypeRef;
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderErrorReason,
+};
 use serde::Serialize;
-use std::error::Error;
+use crate::error::{GeneratorError, Result};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// Default templates of the TypeScript server, keyed by name (path relative
+/// to `templates/`, without `.hbs`)
+pub const TYPESCRIPT_TEMPLATES: &[(&str, &str)] = &[
+    ("index.ts", include_str!("../../templates/index.ts.hbs")),
+    ("tools.ts", include_str!("../../templates/tools.ts.hbs")),
+    ("schemas.ts", include_str!("../../templates/schemas.ts.hbs")),
+    ("lib/logger.ts", include_str!("../../templates/lib/logger.ts.hbs")),
+    ("lib/errors.ts", include_str!("../../templates/lib/errors.ts.hbs")),
+    ("lib/transaction.ts", include_str!("../../templates/lib/transaction.ts.hbs")),
+    ("lib/submit.ts", include_str!("../../templates/lib/submit.ts.hbs")),
+    ("lib/utils.ts", include_str!("../../templates/lib/utils.ts.hbs")),
+    ("lib/passkey.ts", include_str!("../../templates/lib/passkey.ts.hbs")),
+    ("lib/contract-info.ts", include_str!("../../templates/lib/contract-info.ts.hbs")),
+    ("deploy-wallet.ts", include_str!("../../templates/deploy-wallet.ts.hbs")),
+    ("package.json", include_str!("../../templates/package.json.hbs")),
+    ("tsconfig.json", include_str!("../../templates/tsconfig.json.hbs")),
+    ("env.example", include_str!("../../templates/env.example.hbs")),
+    ("Dockerfile", include_str!("../../templates/Dockerfile.hbs")),
+    ("dockerignore", include_str!("../../templates/dockerignore.hbs")),
+    ("vercel.json", include_str!("../../templates/vercel.json.hbs")),
+    ("README.md", include_str!("../../templates/README.md.hbs")),
+];
+
+/// Default templates of the Python server
+pub const PYTHON_TEMPLATES: &[(&str, &str)] = &[
+    ("python/server.py", include_str!("../../templates/python/server.py.hbs")),
+    ("python/contract_client.py", include_str!("../../templates/python/contract_client.py.hbs")),
+    ("python/init.py", include_str!("../../templates/python/init.py.hbs")),
+    ("python/lib/__init__.py", include_str!("../../templates/python/lib/__init__.py.hbs")),
+    ("python/lib/utils.py", include_str!("../../templates/python/lib/utils.py.hbs")),
+    ("python/lib/submit.py", include_str!("../../templates/python/lib/submit.py.hbs")),
+    ("python/lib/contract_info.py", include_str!("../../templates/python/lib/contract_info.py.hbs")),
+    ("python/lib/errors.py", include_str!("../../templates/python/lib/errors.py.hbs")),
+    ("python/pyproject.toml", include_str!("../../templates/python/pyproject.toml.hbs")),
+    ("python/env.example", include_str!("../../templates/python/env.example.hbs")),
+    ("python/Dockerfile", include_str!("../../templates/python/Dockerfile.hbs")),
+    ("python/dockerignore", include_str!("../../templates/python/dockerignore.hbs")),
+    ("python/README.md", include_str!("../../templates/python/README.md.hbs")),
+];
+
+/// Default templates of the Rust server
+pub const RUST_TEMPLATES: &[(&str, &str)] = &[
+    ("rust/main.rs", include_str!("../../templates/rust/main.rs.hbs")),
+    ("rust/server.rs", include_str!("../../templates/rust/server.rs.hbs")),
+    ("rust/contracts.rs", include_str!("../../templates/rust/contracts.rs.hbs")),
+    ("rust/spec.rs", include_str!("../../templates/rust/spec.rs.hbs")),
+    ("rust/scval.rs", include_str!("../../templates/rust/scval.rs.hbs")),
+    ("rust/rpc.rs", include_str!("../../templates/rust/rpc.rs.hbs")),
+    ("rust/transaction.rs", include_str!("../../templates/rust/transaction.rs.hbs")),
+    ("rust/contract_info.rs", include_str!("../../templates/rust/contract_info.rs.hbs")),
+    ("rust/errors.rs", include_str!("../../templates/rust/errors.rs.hbs")),
+    ("rust/Cargo.toml", include_str!("../../templates/rust/Cargo.toml.hbs")),
+    ("rust/env.example", include_str!("../../templates/rust/env.example.hbs")),
+    ("rust/Dockerfile", include_str!("../../templates/rust/Dockerfile.hbs")),
+    ("rust/dockerignore", include_str!("../../templates/rust/dockerignore.hbs")),
+    ("rust/README.md", include_str!("../../templates/rust/README.md.hbs")),
+];
+
+/// Default templates of the Go server
+pub const GO_TEMPLATES: &[(&str, &str)] = &[
+    ("go/main.go", include_str!("../../templates/go/main.go.hbs")),
+    ("go/server.go", include_str!("../../templates/go/server.go.hbs")),
+    ("go/contracts.go", include_str!("../../templates/go/contracts.go.hbs")),
+    ("go/spec.go", include_str!("../../templates/go/spec.go.hbs")),
+    ("go/scval.go", include_str!("../../templates/go/scval.go.hbs")),
+    ("go/rpc.go", include_str!("../../templates/go/rpc.go.hbs")),
+    ("go/transaction.go", include_str!("../../templates/go/transaction.go.hbs")),
+    ("go/contract_info.go", include_str!("../../templates/go/contract_info.go.hbs")),
+    ("go/errors.go", include_str!("../../templates/go/errors.go.hbs")),
+    ("go/go.mod", include_str!("../../templates/go/go.mod.hbs")),
+    ("go/env.example", include_str!("../../templates/go/env.example.hbs")),
+    ("go/Dockerfile", include_str!("../../templates/go/Dockerfile.hbs")),
+    ("go/dockerignore", include_str!("../../templates/go/dockerignore.hbs")),
+    ("go/README.md", include_str!("../../templates/go/README.md.hbs")),
+];
 
 /// Template renderer using Handlebars
-pub struct TemplateRenderer<'a> {
-    handlebars: Handlebars<'a>,
+pub struct TemplateRenderer {
+    handlebars: Handlebars<'static>,
+    /// Names of the templates that can be rendered and overridden
+    names: BTreeSet<String>,
 }
 
-impl<'a> TemplateRenderer<'a> {
-    /// Create a new template renderer with embedded templates
-    pub fn new() -> Result<Self, Box<dyn Error>> {
+impl TemplateRenderer {
+    /// Create a new template renderer with the built-in backends' templates
+    pub fn new() -> Result<Self> {
+        Self::with_templates(builtin_templates())
+    }
+
+    /// Create a renderer with the given default templates, keyed by name
+    pub fn with_templates<'t>(templates: impl IntoIterator<Item = (&'t str, &'t str)>) -> Result<Self> {
         let mut handlebars = Handlebars::new();
+        register_helpers(&mut handlebars);
 
-        // Embedded templates (compiled into binary)
-        handlebars.register_template_string("index", include_str!("../../templates/index.ts.hbs"))?;
-        handlebars.register_template_string("tools", include_str!("../../templates/tools.ts.hbs"))?;
-        handlebars.register_template_string("schemas", include_str!("../../templates/schemas.ts.hbs"))?;
-        handlebars.register_template_string("types", include_str!("../../templates/types.ts.hbs"))?;
-        handlebars.register_template_string("launchtube", include_str!("../../templates/launchtube.ts.hbs"))?;
-        handlebars.register_template_string("passkey", include_str!("../../templates/passkey.ts.hbs"))?;
-        handlebars.register_template_string("transaction", include_str!("../../templates/transaction.ts.hbs"))?;
-        handlebars.register_template_string("package_json", include_str!("../../templates/package.json.hbs"))?;
-        handlebars.register_template_string("tsconfig", include_str!("../../templates/tsconfig.json.hbs"))?;
-        handlebars.register_template_string("env_example", include_str!("../../templates/env.example.hbs"))?;
-        handlebars.register_template_string("readme", include_str!("../../templates/README.md.hbs"))?;
-        handlebars.register_template_string("vercel", include_str!("../../templates/vercel.json.hbs"))?;
-
-        Ok(Self { handlebars })
+        let mut names = BTreeSet::new();
+        for (name, template) in templates {
+            handlebars
+                .register_template_string(name, template)
+                .map_err(|e| GeneratorError::InvalidTemplate(format!("Invalid template '{}': {}", name, e)))?;
+            names.insert(name.to_string());
+        }
+
+        Ok(Self { handlebars, names })
     }
 
-    /// Render a template with the given data
-    pub fn render<T: Serialize>(&self, template_name: &str, data: &T) -> Result<String, Box<dyn Error>> {
-        Ok(self.handlebars.render(template_name, data)?)
+    /// Create a renderer whose built-in templates are overridden by the `.hbs`
+    /// files in `dir` (see [`Self::override_from`])
+    pub fn with_overrides(dir: &Path) -> Result<Self> {
+        Self::new()?.override_from(dir)
     }
-}
 
-/// Data for the index.ts template
-#[derive(Serialize)]
-pub struct IndexData {
-    pub contract_name: String,
-    pub contract_id: String,
-    pub server_name: String,
-    pub rpc_url: String,
-    pub network_passphrase: String,
-    pub functions: Vec<FunctionData>,
-    pub with_launchtube: bool,
-    pub with_passkey: bool,
-}
+    /// Override templates with the `.hbs` files in `dir`. Files that do not
+    /// match a template name are rejected, so a typo does not silently fall
+    /// back to the default.
+    pub fn override_from(self, dir: &Path) -> Result<Self> {
+        let mut renderer = self;
+        if !dir.is_dir() {
+            return Err(GeneratorError::InvalidTemplate(format!(
+                "Template directory '{}' not found",
+                dir.display()
+            )));
+        }
 
-/// Data for a function in templates
-#[derive(Serialize)]
-pub struct FunctionData {
-    pub name: String,
-    pub name_kebab: String,
-    pub name_camel: String,
-    pub name_pascal: String,
-    pub doc: String,
-    pub inputs: Vec<InputData>,
-    pub has_inputs: bool,
-    pub output_type: String,
-    pub has_output: bool,
-}
+        for entry in walkdir::WalkDir::new(dir).sort_by_file_name() {
+            let entry = entry.map_err(|e| GeneratorError::InvalidTemplate(e.to_string()))?;
+            let path = entry.path();
+            if !entry.file_type().is_file() || path.extension().is_none_or(|ext| ext != "hbs") {
+                continue;
+            }
+            let name = path
+                .strip_prefix(dir)
+                .expect("walkdir entries are inside the walked directory")
+                .with_extension("")
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if !renderer.names.contains(&name) {
+                return Err(GeneratorError::InvalidTemplate(format!(
+                    "Unknown template '{}' in '{}'. Templates: {}",
+                    path.display(),
+                    dir.display(),
+                    renderer.template_names().collect::<Vec<_>>().join(", ")
+                )));
+            }
+            let content = fs::read_to_string(path)
+                .map_err(GeneratorError::io(format!("Failed to read template '{}'", path.display())))?;
+            renderer
+                .handlebars
+                .register_template_string(&name, content)
+                .map_err(|e| {
+                    GeneratorError::InvalidTemplate(format!("Invalid template '{}': {}", path.display(), e))
+                })?;
+        }
 
-/// Data for a function input
-#[derive(Serialize)]
-pub struct InputData {
-    pub name: String,
-    pub name_camel: String,
-    pub doc: String,
-    pub ts_type: String,
-    pub zod_type: String,
-    pub is_address: bool,
-}
+        Ok(renderer)
+    }
 
-/// Data for the tools.ts template
-#[derive(Serialize)]
-pub struct ToolsData {
-    pub contract_name: String,
-    pub functions: Vec<FunctionData>,
-}
+    /// Render a template with the given data
+    pub fn render<T: Serialize>(
+        &self,
+        template_name: &str,
+        data: &T,
+    ) -> Result<String> {
+        self.handlebars
+            .render(template_name, data)
+            .map_err(|e| GeneratorError::TemplateRender {
+                name: template_name.to_string(),
+                message: e.to_string(),
+            })
+    }
 
-/// Data for the schemas.ts template
-#[derive(Serialize)]
-pub struct SchemasData {
-    pub contract_name: String,
-    pub types: Vec<TypeData>,
-    pub functions: Vec<FunctionData>,
+    /// Names of the templates that can be overridden
+    pub fn template_names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
 }
 
-/// Data for custom types
-#[derive(Serialize)]
-pub struct TypeData {
-    pub name: String,
-    pub name_pascal: String,
-    pub doc: Option<String>,
-    pub is_struct: bool,
-    pub is_enum: bool,
-    pub is_union: bool,
-    pub fields: Vec<FieldData>,
-    pub variants: Vec<VariantData>,
-    pub cases: Vec<CaseData>,
+/// Default templates of the built-in backends
+pub fn builtin_templates() -> impl Iterator<Item = (&'static str, &'static str)> {
+    TYPESCRIPT_TEMPLATES.iter().chain(PYTHON_TEMPLATES).chain(RUST_TEMPLATES).chain(GO_TEMPLATES).copied()
 }
 
-/// Data for struct fields
-#[derive(Serialize)]
-pub struct FieldData {
-    pub name: String,
-    pub name_camel: String,
-    pub doc: Option<String>,
-    pub ts_type: String,
-    pub zod_type: String,
-}
+/// Register the helpers available to every template:
+///
+/// - case conversion: `{{kebab_case name}}`, `{{camel_case name}}`,
+///   `{{pascal_case name}}`, `{{snake_case name}}`, `{{upper_case name}}`
+/// - type mapping, for the `type_ref` of parameters, outputs and fields:
+///   `{{ts_type type_ref}}`, `{{zod_type type_ref}}`, `{{pydantic_type type_ref}}`
+fn register_helpers(handlebars: &mut Handlebars<'static>) {
+    let case_helpers: [(&'static str, CaseFn); 5] = [
+        ("kebab_case", to_kebab_case),
+        ("camel_case", to_camel_case),
+        ("pascal_case", to_pascal_case),
+        ("snake_case", to_snake_case),
+        ("upper_case", str::to_uppercase),
+    ];
+    for (name, convert) in case_helpers {
+        handlebars.register_helper(name, Box::new(CaseHelper { name, convert }));
+    }
 
-/// Data for enum variants
-#[derive(Serialize)]
-pub struct VariantData {
-    pub name: String,
-    pub doc: Option<String>,
-    pub value: u32,
+    let type_helpers: [(&'static str, TypeFn); 3] = [
+        ("ts_type", TypeRef::to_typescript),
+        ("zod_type", TypeRef::to_zod),
+        ("pydantic_type", TypeRef::to_pydantic),
+    ];
+    for (name, convert) in type_helpers {
+        handlebars.register_helper(name, Box::new(TypeHelper { name, convert }));
+    }
 }
 
-/// Data for union cases
-#[derive(Serialize)]
-pub struct CaseData {
-    pub name: String,
-    pub doc: Option<String>,
-    pub has_value: bool,
-    pub ts_type: String,
-    pub zod_type: String,
+type CaseFn = fn(&str) -> String;
+type TypeFn = fn(&TypeRef) -> String;
+
+/// Helper converting a string argument, e.g. `{{pascal_case name}}`
+struct CaseHelper {
+    name: &'static str,
+    convert: CaseFn,
 }
 
-/// Data for the types.ts template
-#[derive(Serialize)]
-pub struct TypesData {
-    pub contract_name: String,
-    pub types: Vec<TypeData>,
+impl HelperDef for CaseHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let value = h
+            .param(0)
+            .ok_or(RenderErrorReason::ParamNotFoundForIndex(self.name, 0))?;
+        let text = value
+            .value()
+            .as_str()
+            .ok_or(RenderErrorReason::InvalidParamType("string"))?;
+        out.write(&(self.convert)(text))?;
+        Ok(())
+    }
 }
 
-/// Data for lib templates
-#[derive(Serialize)]
-pub struct LibData {
-    pub contract_name: String,
-    pub network_passphrase: String,
+/// Helper mapping a serialized `TypeRef` to a target language type, e.g.
+/// `{{ts_type type_ref}}`
+struct TypeHelper {
+    name: &'static str,
+    convert: TypeFn,
 }
 
-/// Data for README template
-#[derive(Serialize)]
-pub struct ReadmeData {
-    pub server_name_pascal: String,
-    pub contract_name: String,
-    pub contract_id: String,
-    pub network_name: String,
-    pub rpc_url: String,
-    pub network_passphrase: String,
-    pub output_dir: String,
-    pub functions: Vec<FunctionData>,
+impl HelperDef for TypeHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> HelperResult {
+        let value = h
+            .param(0)
+            .ok_or(RenderErrorReason::ParamNotFoundForIndex(self.name, 0))?;
+        let type_ref: TypeRef = serde_json::from_value(value.value().clone())
+            .map_err(|_| RenderErrorReason::InvalidParamType("TypeRef"))?;
+        out.write(&(self.convert)(&type_ref))?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,259 @@
+//! Language-neutral manifest of a generated server's contract tools
+//!
+//! Every generated project gets a `tools.json` listing the tools generated from
+//! its contract specs (one per function and event) with the JSON Schema of
+//! their input and output. Types come from `TypeRef::to_json_schema`, the same
+//! schema the server's Zod or Pydantic validation is derived from, and each
+//! schema embeds the `$defs` it references, so agents, tests and other tooling
+//! can validate calls without reading the server's code. Tool and argument
+//! names are the ones the server registers, which differ by language
+//! (`get-balance` vs `get_balance`, `from` vs `from_`): each backend picks
+//! its [`ToolNaming`].
+
+use super::backend::TargetBackend;
+use super::contracts::ServedContract;
+use super::mcp_generator::event_topic_scval_type;
+use super::python_generator::to_python_identifier;
+use super::template_data::{to_kebab_case, to_snake_case};
+use crate::spec::json_schema::{describe, object_schema, with_defs};
+use crate::spec::{EventSpec, FieldSpec, FunctionKind, FunctionSpec, TypeRef, TypeSpec};
+use serde_json::{json, Value};
+
+/// Path of the manifest in a generated project
+pub const TOOL_MANIFEST_PATH: &str = "tools.json";
+
+/// Manifest of the contract tools a `backend` server generates for `contracts`
+pub fn tool_manifest(backend: &dyn TargetBackend, contracts: &[ServedContract]) -> Value {
+    let namespaced = contracts.len() > 1;
+    let naming = backend.tool_naming();
+    let mut tools = Vec::new();
+
+    for contract in contracts {
+        let types = &contract.spec.types;
+        for function in &contract.spec.functions {
+            let name = contract.tool_name(&naming.function_tool(&function.name), namespaced);
+            tools.push(function_tool(&name, &contract.name, function, naming, types));
+        }
+        for event in &contract.spec.events {
+            let name = contract.tool_name(&naming.event_tool(&event.name), namespaced);
+            tools.push(event_tool(&name, &contract.name, event, naming, types));
+        }
+    }
+
+    json!({
+        "generatorVersion": env!("CARGO_PKG_VERSION"),
+        "language": backend.name(),
+        "tools": tools,
+    })
+}
+
+/// `tools.json` content: the manifest as pretty-printed JSON
+pub fn render_tool_manifest(backend: &dyn TargetBackend, contracts: &[ServedContract]) -> String {
+    format!("{:#}\n", tool_manifest(backend, contracts))
+}
+
+/// How a server names its tools and arguments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolNaming {
+    /// Kebab-case tools, spec argument names and camelCase built-in
+    /// arguments (`get-balance`, `get-transfer-events`, `from`, `startLedger`),
+    /// as in TypeScript
+    Kebab,
+    /// Python identifiers for function tools and arguments, snake_case event
+    /// tools and built-in arguments (`get_balance`, `get_transfer_events`,
+    /// `from_`, `start_ledger`)
+    Python,
+}
+
+impl ToolNaming {
+    /// Tool name of a contract function (before any contract namespace)
+    pub fn function_tool(self, function: &str) -> String {
+        match self {
+            ToolNaming::Kebab => to_kebab_case(function),
+            ToolNaming::Python => to_python_identifier(function),
+        }
+    }
+
+    /// Tool name of a contract event's query tool (before any contract namespace)
+    pub fn event_tool(self, event: &str) -> String {
+        match self {
+            ToolNaming::Kebab => format!("get-{}-events", to_kebab_case(event)),
+            ToolNaming::Python => format!("get_{}_events", to_snake_case(event)),
+        }
+    }
+
+    /// Tool name of the built-in contract info tool (before any contract namespace)
+    pub fn contract_info_tool(self) -> &'static str {
+        match self {
+            ToolNaming::Kebab => "contract-info",
+            ToolNaming::Python => "contract_info",
+        }
+    }
+
+    /// Argument name of a function parameter or event topic
+    pub fn argument(self, name: &str) -> String {
+        match self {
+            ToolNaming::Kebab => name.to_string(),
+            ToolNaming::Python => to_python_identifier(name),
+        }
+    }
+
+    /// Name of an argument the generator adds itself (`startLedger` / `start_ledger`)
+    pub fn builtin_argument(self, camel: &'static str, snake: &'static str) -> &'static str {
+        match self {
+            ToolNaming::Kebab => camel,
+            ToolNaming::Python => snake,
+        }
+    }
+}
+
+fn function_tool(
+    name: &str,
+    contract: &str,
+    function: &FunctionSpec,
+    naming: ToolNaming,
+    types: &[TypeSpec],
+) -> Value {
+    let read_only = function.kind == FunctionKind::ReadOnly;
+    let input = object_schema(
+        function
+            .inputs
+            .iter()
+            .map(|p| (naming.argument(&p.name), &p.type_ref, p.doc.as_deref())),
+    );
+
+    let output = if read_only {
+        let result = function.output.as_ref().unwrap_or(&TypeRef::Void);
+        let mut result = result.to_json_schema();
+        describe(&mut result, Some("Value returned by the simulated call"));
+        json!({
+            "type": "object",
+            "properties": { "result": result },
+            "required": ["result"],
+        })
+    } else {
+        json!({
+            "type": "object",
+            "properties": {
+                "xdr": { "type": "string", "description": "Transaction XDR (base64) to sign and submit" },
+                "simulationResult": { "description": "Value returned by the simulated call" },
+            },
+            "required": ["xdr"],
+        })
+    };
+
+    json!({
+        "name": name,
+        "contract": contract,
+        "function": function.name,
+        "description": function.doc.clone().unwrap_or_else(|| format!("Call {} function", function.name)),
+        "annotations": if read_only {
+            json!({ "readOnlyHint": true })
+        } else {
+            json!({ "readOnlyHint": false, "destructiveHint": true })
+        },
+        "inputSchema": with_defs(input, types),
+        "outputSchema": with_defs(output, types),
+    })
+}
+
+fn event_tool(name: &str, contract: &str, event: &EventSpec, naming: ToolNaming, types: &[TypeSpec]) -> Value {
+    let filters: Vec<&FieldSpec> = event
+        .topics
+        .iter()
+        .filter(|topic| event_topic_scval_type(&topic.type_ref).is_some())
+        .collect();
+    let filter_docs: Vec<String> = filters
+        .iter()
+        .map(|topic| {
+            topic
+                .doc
+                .clone()
+                .unwrap_or_else(|| format!("Only return events whose {} topic matches", topic.name))
+        })
+        .collect();
+    let mut input = object_schema(
+        filters
+            .iter()
+            .zip(&filter_docs)
+            .map(|(topic, doc)| (naming.argument(&topic.name), &topic.type_ref, Some(doc.as_str()))),
+    );
+    // Topic filters are optional, like the paging arguments
+    input["required"] = json!([]);
+    let paging = [
+        (
+            naming.builtin_argument("startLedger", "start_ledger"),
+            json!({ "type": "integer", "description": "First ledger to search (defaults to roughly the last 24 hours)" }),
+        ),
+        ("cursor", json!({ "type": "string", "description": "Pagination cursor returned by a previous call" })),
+        (
+            "limit",
+            json!({ "type": "integer", "minimum": 1, "description": "Maximum number of events to return (default 100)" }),
+        ),
+    ];
+    for (argument, schema) in paging {
+        input["properties"][argument] = schema;
+    }
+
+    let fields = |fields: &[FieldSpec]| -> Value {
+        let properties: serde_json::Map<String, Value> = fields
+            .iter()
+            .map(|f| (f.name.clone(), event_value_schema(&f.type_ref)))
+            .collect();
+        json!({ "type": "object", "properties": properties })
+    };
+    let output = json!({
+        "type": "object",
+        "properties": {
+            "events": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string" },
+                        "ledger": { "type": "integer" },
+                        "ledgerClosedAt": { "type": "string" },
+                        "txHash": { "type": "string" },
+                        "topics": fields(&event.topics),
+                        "data": fields(&event.data),
+                    },
+                    "required": ["id", "ledger", "ledgerClosedAt", "txHash", "topics", "data"],
+                },
+            },
+            "latestLedger": { "type": "integer" },
+            "cursor": { "type": "string" },
+        },
+        "required": ["events", "latestLedger"],
+    });
+
+    let description = match &event.doc {
+        Some(doc) => format!("Query {} events emitted by the contract. {}", event.name, doc),
+        None => format!("Query {} events emitted by the contract.", event.name),
+    };
+    json!({
+        "name": name,
+        "contract": contract,
+        "event": event.name,
+        "description": description,
+        "annotations": { "readOnlyHint": true },
+        "inputSchema": with_defs(input, types),
+        "outputSchema": output,
+    })
+}
+
+/// Schema of a decoded event value. Events are decoded with `scValToNative`
+/// and normalised to JSON (bigint → string, bytes → hex), so primitives match
+/// the argument schemas, but contract types and maps have no fixed shape.
+fn event_value_schema(type_ref: &TypeRef) -> Value {
+    match type_ref {
+        TypeRef::Custom(_) | TypeRef::Map { .. } => json!({}),
+        TypeRef::Option(inner) => json!({ "anyOf": [event_value_schema(inner), { "type": "null" }] }),
+        TypeRef::Vec(inner) => json!({ "type": "array", "items": event_value_schema(inner) }),
+        TypeRef::Tuple(types) => {
+            let items: Vec<Value> = types.iter().map(event_value_schema).collect();
+            json!({ "type": "array", "prefixItems": items, "items": false })
+        }
+        TypeRef::Result { ok, .. } => event_value_schema(ok),
+        _ => type_ref.to_json_schema(),
+    }
+}
@@ -0,0 +1,250 @@
+//! Native TypeScript contract bindings
+//!
+//! Renders `src/bindings/index.ts` for generated TypeScript servers straight
+//! from the parsed `ContractSpec`: one type per contract UDT, the `Errors` map
+//! and a typed `Client` built on `@stellar/stellar-sdk/contract`. The output
+//! follows the shape of `stellar contract bindings typescript`, so the tool
+//! handlers work unchanged, but generation no longer needs the Stellar CLI,
+//! a network connection or a separate `pnpm install` for the bindings package.
+
+use super::backend::{TargetBackend, TypeScriptBackend};
+use crate::codegen::emit::{ts_doc_comment, ts_double_quoted};
+use crate::spec::{ContractSpec, ErrorEnumSpec, FunctionSpec, TypeDef, TypeRef, TypeSpec};
+use crate::NetworkConfig;
+
+/// TypeScript type used by the bindings for a contract type.
+///
+/// Unlike `TypeRef::to_typescript()`, which describes the JSON arguments an
+/// MCP client sends, this is the native value the Stellar SDK encodes:
+/// `Buffer` for bytes, `bigint` for 64-bit and wider integers.
+pub fn binding_type(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Bool => "boolean".to_string(),
+        TypeRef::Void => "void".to_string(),
+        TypeRef::Status => "number".to_string(),
+        TypeRef::U32 => "u32".to_string(),
+        TypeRef::I32 => "i32".to_string(),
+        TypeRef::U64 => "u64".to_string(),
+        TypeRef::I64 => "i64".to_string(),
+        TypeRef::U128 => "u128".to_string(),
+        TypeRef::I128 => "i128".to_string(),
+        TypeRef::U256 => "u256".to_string(),
+        TypeRef::I256 => "i256".to_string(),
+        TypeRef::Timepoint | TypeRef::Duration => "bigint".to_string(),
+        TypeRef::Bytes | TypeRef::BytesN(_) => "Buffer".to_string(),
+        TypeRef::String | TypeRef::Symbol | TypeRef::Address => "string".to_string(),
+        TypeRef::Option(inner) => format!("Option<{}>", binding_type(inner)),
+        TypeRef::Result { ok, .. } => format!("Result<{}>", binding_type(ok)),
+        TypeRef::Vec(inner) => format!("Array<{}>", binding_type(inner)),
+        TypeRef::Map { key, value } => {
+            format!("Map<{}, {}>", binding_type(key), binding_type(value))
+        }
+        TypeRef::Tuple(types) => {
+            let items: Vec<String> = types.iter().map(binding_type).collect();
+            format!("readonly [{}]", items.join(", "))
+        }
+        TypeRef::Custom(name) => name.clone(),
+    }
+}
+
+/// Return type of a client method (`null` for functions without a result)
+fn return_type(output: &Option<TypeRef>) -> String {
+    match output {
+        None | Some(TypeRef::Void) => "null".to_string(),
+        Some(type_ref) => TypeScriptBackend.type_name(type_ref),
+    }
+}
+
+/// Render the complete `src/bindings/index.ts` module
+pub fn render_ts_bindings(
+    spec: &ContractSpec,
+    contract_id: &str,
+    network: &NetworkConfig,
+) -> String {
+    let mut content = String::new();
+
+    content.push_str("// Contract bindings generated by stellar-mcp from the contract spec.\n");
+    content.push_str("// Do not edit: changes are overwritten when the server is regenerated.\n");
+    content.push_str("import {\n");
+    content.push_str("  AssembledTransaction,\n");
+    content.push_str("  Client as ContractClient,\n");
+    content.push_str("  ClientOptions as ContractClientOptions,\n");
+    content.push_str("  MethodOptions,\n");
+    content.push_str("  Spec as ContractSpec,\n");
+    content.push_str("} from '@stellar/stellar-sdk/contract';\n");
+    content.push_str("import type {\n");
+    content.push_str("  i32,\n  i64,\n  i128,\n  i256,\n  Option,\n  Result,\n  u32,\n  u64,\n  u128,\n  u256,\n");
+    content.push_str("} from '@stellar/stellar-sdk/contract';\n\n");
+
+    if !contract_id.is_empty() {
+        content.push_str("export const networks = {\n");
+        content.push_str(&format!("  {}: {{\n", network_key(network)));
+        content.push_str(&format!(
+            "    networkPassphrase: {},\n",
+            ts_double_quoted(&network.network_passphrase)
+        ));
+        content.push_str(&format!("    contractId: {},\n", ts_double_quoted(contract_id)));
+        content.push_str("  },\n");
+        content.push_str("} as const;\n\n");
+    }
+
+    for type_spec in &spec.types {
+        content.push_str(&render_type(type_spec));
+        content.push('\n');
+    }
+
+    content.push_str(&render_errors(&spec.errors));
+    content.push('\n');
+
+    // Method signatures, merged into the Client class below
+    content.push_str("export interface Client {\n");
+    for (i, func) in spec.functions.iter().enumerate() {
+        if i > 0 {
+            content.push('\n');
+        }
+        content.push_str(&render_method(func));
+    }
+    content.push_str("}\n\n");
+
+    content.push_str("/** Contract spec entries (base64 XDR), taken from the contract WASM */\n");
+    content.push_str("export const specEntries = [\n");
+    for entry in &spec.raw_spec_entries {
+        content.push_str(&format!("  {},\n", ts_double_quoted(entry)));
+    }
+    content.push_str("];\n\n");
+
+    content.push_str("export class Client extends ContractClient {\n");
+    content.push_str("  constructor(public readonly options: ContractClientOptions) {\n");
+    content.push_str("    super(new ContractSpec(specEntries), options);\n");
+    content.push_str("  }\n\n");
+    content.push_str("  public readonly fromJSON = {\n");
+    for func in &spec.functions {
+        content.push_str(&format!(
+            "    {}: this.txFromJSON<{}>,\n",
+            func.name,
+            return_type(&func.output)
+        ));
+    }
+    content.push_str("  };\n");
+    content.push_str("}\n");
+
+    content
+}
+
+fn render_type(type_spec: &TypeSpec) -> String {
+    let mut content = ts_doc_comment(type_spec.doc.as_deref(), "");
+
+    match &type_spec.definition {
+        TypeDef::Struct { fields } => {
+            // Tuple structs (fields named 0, 1, ...) are encoded as vectors
+            if !fields.is_empty() && fields.iter().all(|f| f.name.parse::<u32>().is_ok()) {
+                let items: Vec<String> = fields.iter().map(|f| TypeScriptBackend.type_name(&f.type_ref)).collect();
+                content.push_str(&format!(
+                    "export type {} = readonly [{}];\n",
+                    type_spec.name,
+                    items.join(", ")
+                ));
+            } else {
+                content.push_str(&format!("export interface {} {{\n", type_spec.name));
+                for field in fields {
+                    content.push_str(&ts_doc_comment(field.doc.as_deref(), "  "));
+                    content.push_str(&format!(
+                        "  {}: {};\n",
+                        field.name,
+                        TypeScriptBackend.type_name(&field.type_ref)
+                    ));
+                }
+                content.push_str("}\n");
+            }
+        }
+        TypeDef::Enum { variants } => {
+            content.push_str(&format!("export enum {} {{\n", type_spec.name));
+            for variant in variants {
+                content.push_str(&ts_doc_comment(variant.doc.as_deref(), "  "));
+                content.push_str(&format!("  {} = {},\n", variant.name, variant.value));
+            }
+            content.push_str("}\n");
+        }
+        TypeDef::Union { cases } => {
+            // Cases are discriminated on `tag`, unit cases carry no values
+            let cases: Vec<String> = cases
+                .iter()
+                .map(|case| {
+                    let values = match &case.type_ref {
+                        None => "void".to_string(),
+                        Some(TypeRef::Tuple(items)) => {
+                            let items: Vec<String> = items.iter().map(binding_type).collect();
+                            format!("readonly [{}]", items.join(", "))
+                        }
+                        Some(other) => format!("readonly [{}]", TypeScriptBackend.type_name(other)),
+                    };
+                    format!("{{ tag: {}; values: {} }}", ts_double_quoted(&case.name), values)
+                })
+                .collect();
+            if cases.is_empty() {
+                content.push_str(&format!("export type {} = never;\n", type_spec.name));
+            } else {
+                content.push_str(&format!("export type {} =\n", type_spec.name));
+                content.push_str(&format!("  | {};\n", cases.join("\n  | ")));
+            }
+        }
+    }
+
+    content
+}
+
+fn render_errors(errors: &[ErrorEnumSpec]) -> String {
+    if errors.is_empty() {
+        return "export const Errors = {};\n".to_string();
+    }
+    let mut content = String::from("export const Errors = {\n");
+    for error in errors.iter().flat_map(|e| &e.cases) {
+        content.push_str(&ts_doc_comment(error.doc.as_deref(), "  "));
+        content.push_str(&format!(
+            "  {}: {{ message: {} }},\n",
+            error.code,
+            ts_double_quoted(&error.name)
+        ));
+    }
+    content.push_str("};\n");
+    content
+}
+
+fn render_method(func: &FunctionSpec) -> String {
+    let mut content = ts_doc_comment(func.doc.as_deref(), "  ");
+    let result = format!(
+        "Promise<AssembledTransaction<{}>>",
+        return_type(&func.output)
+    );
+
+    if func.inputs.is_empty() {
+        content.push_str(&format!(
+            "  {}: (options?: MethodOptions) => {};\n",
+            func.name, result
+        ));
+    } else {
+        let args: Vec<String> = func
+            .inputs
+            .iter()
+            .map(|p| format!("{}: {}", p.name, TypeScriptBackend.type_name(&p.type_ref)))
+            .collect();
+        content.push_str(&format!(
+            "  {}: (\n    args: {{ {} }},\n    options?: MethodOptions\n  ) => {};\n",
+            func.name,
+            args.join("; "),
+            result
+        ));
+    }
+
+    content
+}
+
+/// Key used in the `networks` export, as in the official bindings
+fn network_key(network: &NetworkConfig) -> &'static str {
+    match network.network_passphrase.as_str() {
+        "Public Global Stellar Network ; September 2015" => "mainnet",
+        "Test SDF Network ; September 2015" => "testnet",
+        "Test SDF Future Network ; October 2022" => "futurenet",
+        _ => "standalone",
+    }
+}
@@ -0,0 +1,321 @@
+//! Writing generated projects to disk, in place of or on top of an earlier run
+//!
+//! Generators render into a [`GeneratedProject`]; `write_project` then writes
+//! it into the output directory. Every generated file is recorded in
+//! `.stellar-mcp/manifest.json` with its SHA-256 checksum, and a copy of what
+//! was generated is kept under `.stellar-mcp/base/`. In update mode that lets
+//! us tell generator-owned files (checksum unchanged) from files the user has
+//! edited, which are three-way merged instead of overwritten.
+
+use super::merge::{carry_custom_regions, merge3};
+use super::project::GeneratedProject;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use crate::error::{GeneratorError, Result};
+use std::fs;
+use std::path::Path;
+
+/// Location of the generation manifest, relative to the project root
+pub const MANIFEST_PATH: &str = ".stellar-mcp/manifest.json";
+
+/// Copies of the last generated files, relative to the project root
+pub const BASE_DIR: &str = ".stellar-mcp/base";
+
+/// Checksums of the files written by the last generation
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GenerationManifest {
+    /// Version of the generator that wrote the files
+    pub generator_version: String,
+    /// Hex-encoded SHA-256 checksum per file, keyed by path relative to the project root
+    pub files: BTreeMap<String, String>,
+}
+
+impl GenerationManifest {
+    /// Read the manifest of a generated project, if it has one
+    pub fn load(project_dir: &Path) -> Result<Option<Self>> {
+        let path = project_dir.join(MANIFEST_PATH);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)
+            .map_err(GeneratorError::io(format!("Failed to read manifest '{}'", path.display())))?;
+        let manifest = serde_json::from_str(&content).map_err(|e| {
+            GeneratorError::InvalidInput(format!("Invalid manifest '{}': {}", path.display(), e))
+        })?;
+        Ok(Some(manifest))
+    }
+
+    /// Write the manifest into a generated project
+    pub fn save(&self, project_dir: &Path) -> Result<()> {
+        let path = project_dir.join(MANIFEST_PATH);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(GeneratorError::io(format!("Failed to create '{}'", dir.display())))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)? + "\n")
+            .map_err(GeneratorError::io(format!("Failed to write manifest '{}'", path.display())))?;
+        Ok(())
+    }
+}
+
+/// Hex-encoded SHA-256 checksum of a file's content
+pub fn checksum(content: &[u8]) -> String {
+    hex::encode(Sha256::digest(content))
+}
+
+/// What happened to a file when writing the project
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileAction {
+    /// New file
+    Created,
+    /// Generator-owned file replaced with the new content
+    Updated,
+    /// Content did not change
+    Unchanged,
+    /// User edits merged cleanly into the new content
+    Merged,
+    /// User edits conflict with the new content; the file contains conflict markers
+    Conflict(usize),
+    /// User-modified file with no previous generation to merge against; the
+    /// new content was written next to it
+    Sidecar(String),
+    /// File is no longer generated and was removed
+    Removed,
+    /// File is no longer generated but was modified, so it was kept
+    KeptModified,
+}
+
+/// Per-file outcome of writing a project
+#[derive(Debug, Clone, Default)]
+pub struct UpdateReport {
+    /// Path relative to the project root and what happened to it
+    pub files: Vec<(String, FileAction)>,
+}
+
+impl UpdateReport {
+    /// Files that need the user's attention (conflicts and sidecars)
+    pub fn conflicts(&self) -> Vec<&str> {
+        self.files
+            .iter()
+            .filter(|(_, action)| {
+                matches!(action, FileAction::Conflict(_) | FileAction::Sidecar(_))
+            })
+            .map(|(path, _)| path.as_str())
+            .collect()
+    }
+
+    /// Number of files with the given outcome
+    pub fn count(&self, matches: impl Fn(&FileAction) -> bool) -> usize {
+        self.files
+            .iter()
+            .filter(|(_, action)| matches(action))
+            .count()
+    }
+}
+
+/// A file change computed by [`plan_project`], not yet applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedFile {
+    /// Path relative to the project root
+    pub path: String,
+    /// What writing the project will do to the file
+    pub action: FileAction,
+    /// Current content on disk, if the file exists
+    pub current: Option<Vec<u8>>,
+    /// Content that will be written: to `path`, or to the sidecar for
+    /// [`FileAction::Sidecar`]. `None` when the file is left alone or removed.
+    pub content: Option<Vec<u8>>,
+}
+
+/// Every change writing a project would make, computed without touching the
+/// output directory
+#[derive(Debug, Clone)]
+pub struct ProjectPlan {
+    /// Generated files first, then files from the previous generation that
+    /// are no longer generated
+    pub files: Vec<PlannedFile>,
+    manifest: GenerationManifest,
+    /// Previously generated paths whose base copies are no longer needed
+    stale: Vec<String>,
+}
+
+impl ProjectPlan {
+    /// The outcome of every file, as [`write_project`] reports it
+    pub fn report(&self) -> UpdateReport {
+        UpdateReport {
+            files: self
+                .files
+                .iter()
+                .map(|file| (file.path.clone(), file.action.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// Work out what [`write_project`] would do, without writing anything
+pub fn plan_project(project: &GeneratedProject, output: &Path, update: bool) -> Result<ProjectPlan> {
+    let previous = GenerationManifest::load(output)?;
+    if update && previous.is_none() {
+        return Err(GeneratorError::InvalidInput(format!(
+            "'{}' has no generation manifest ({}). Regenerate it once with --force to enable updates.",
+            output.display(),
+            MANIFEST_PATH
+        )));
+    }
+    let previous = previous.unwrap_or_default();
+
+    let mut files = Vec::new();
+    let mut manifest = GenerationManifest {
+        generator_version: env!("CARGO_PKG_VERSION").to_string(),
+        files: BTreeMap::new(),
+    };
+
+    for (path, generated) in &project.files {
+        let relative = relative_path(path);
+        let current = fs::read(output.join(&relative)).ok();
+
+        let (action, content) = match &current {
+            None => (FileAction::Created, Some(generated.clone())),
+            Some(current) if current == generated => (FileAction::Unchanged, None),
+            Some(current) if update => plan_update(output, &relative, current, generated, &previous),
+            Some(_) => (FileAction::Updated, Some(generated.clone())),
+        };
+
+        manifest
+            .files
+            .insert(relative.clone(), checksum(generated));
+        files.push(PlannedFile {
+            path: relative,
+            action,
+            current,
+            content,
+        });
+    }
+
+    // Files from the previous generation that are no longer generated
+    let mut stale = Vec::new();
+    for (relative, previous_checksum) in &previous.files {
+        if manifest.files.contains_key(relative) {
+            continue;
+        }
+        stale.push(relative.clone());
+        let Ok(current) = fs::read(output.join(relative)) else {
+            continue;
+        };
+        let action = if &checksum(&current) == previous_checksum {
+            FileAction::Removed
+        } else {
+            FileAction::KeptModified
+        };
+        files.push(PlannedFile {
+            path: relative.clone(),
+            action,
+            current: Some(current),
+            content: None,
+        });
+    }
+
+    Ok(ProjectPlan {
+        files,
+        manifest,
+        stale,
+    })
+}
+
+/// Write a generated project into `output`.
+///
+/// Without `update` every generated file is written as-is (like `--force`).
+/// With `update` only files whose checksum still matches the manifest are
+/// overwritten; files the user has edited are three-way merged against the
+/// previous generation, with `stellar-mcp:custom-begin` regions always kept.
+pub fn write_project(
+    project: &GeneratedProject,
+    output: &Path,
+    update: bool,
+) -> Result<UpdateReport> {
+    let plan = plan_project(project, output, update)?;
+
+    for file in &plan.files {
+        let target = output.join(&file.path);
+        match (&file.action, &file.content) {
+            (FileAction::Sidecar(sidecar), Some(content)) => write_file(&output.join(sidecar), content)?,
+            (FileAction::Removed, _) => fs::remove_file(&target)
+                .map_err(GeneratorError::io(format!("Failed to remove '{}'", target.display())))?,
+            (_, Some(content)) => write_file(&target, content)?,
+            (_, None) => {}
+        }
+    }
+
+    // Keep what was generated as the base of the next three-way merge
+    for (path, generated) in &project.files {
+        write_file(&output.join(BASE_DIR).join(path), generated)?;
+    }
+    for relative in &plan.stale {
+        let _ = fs::remove_file(output.join(BASE_DIR).join(relative));
+    }
+
+    plan.manifest.save(output)?;
+    Ok(plan.report())
+}
+
+/// Outcome and new content of a file that exists, differs from the generated
+/// content, and is being updated in place
+fn plan_update(
+    output: &Path,
+    relative: &str,
+    current: &[u8],
+    generated: &[u8],
+    previous: &GenerationManifest,
+) -> (FileAction, Option<Vec<u8>>) {
+    // Untouched since the last generation: the generator still owns it
+    if previous.files.get(relative) == Some(&checksum(current)) {
+        return (FileAction::Updated, Some(generated.to_vec()));
+    }
+
+    // Modified by the user: merge against the previous generation
+    let base = fs::read(output.join(BASE_DIR).join(relative)).ok();
+    if base.as_deref() == Some(generated) {
+        // The generator's output did not change, keep the user's version
+        return (FileAction::Unchanged, None);
+    }
+    let texts = (
+        base.as_deref().map(std::str::from_utf8),
+        std::str::from_utf8(current),
+        std::str::from_utf8(generated),
+    );
+    if let (Some(Ok(base)), Ok(current), Ok(generated)) = texts {
+        let generated = carry_custom_regions(current, generated);
+        let merged = merge3(base, current, &generated);
+        let action = if merged.conflicts == 0 {
+            FileAction::Merged
+        } else {
+            FileAction::Conflict(merged.conflicts)
+        };
+        return (action, Some(merged.content.into_bytes()));
+    }
+
+    // Nothing to merge against: keep the user's file, write ours next to it
+    (
+        FileAction::Sidecar(format!("{}.generated", relative)),
+        Some(generated.to_vec()),
+    )
+}
+
+/// Manifest key of a project path: `/`-separated on every platform
+fn relative_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn write_file(path: &Path, content: &[u8]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(GeneratorError::io(format!("Failed to create '{}'", dir.display())))?;
+    }
+    fs::write(path, content)
+        .map_err(GeneratorError::io(format!("Failed to write '{}'", path.display())))?;
+    Ok(())
+}
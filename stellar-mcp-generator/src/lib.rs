@@ -5,13 +5,18 @@
 
 use clap::{Parser, Subcommand};
 
+pub mod codegen;
 pub mod commands;
+pub mod config;
+pub mod error;
 pub mod generator;
 pub mod spec;
 pub mod wizard;
 
+pub use commands::diff::DiffArgs;
 pub use commands::generate::GenerateArgs;
 pub use commands::validate::ValidateArgs;
+pub use error::{GeneratorError, Result};
 
 /// Stellar MCP Generator - Generate MCP servers from Soroban contracts
 #[derive(Parser)]
@@ -32,21 +37,33 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// Generate an MCP server from a deployed Soroban contract
-    Generate(GenerateArgs),
+    Generate(Box<GenerateArgs>),
 
     /// Validate a generated MCP server
     Validate(ValidateArgs),
+
+    /// Compare two contract specs and report breaking changes
+    Diff(DiffArgs),
 }
 
 /// Run the CLI with the parsed arguments
-pub async fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+pub async fn run(cli: Cli) -> Result<()> {
+    run_with(cli, &generator::BackendRegistry::default()).await
+}
+
+/// Run the CLI with the language backends in `registry`, e.g. the built-in
+/// ones plus a backend from another crate
+pub async fn run_with(cli: Cli, registry: &generator::BackendRegistry) -> Result<()> {
     match cli.command {
         Commands::Generate(args) => {
-            commands::generate::execute(args).await?;
+            commands::generate::execute_with(*args, registry).await?;
         }
         Commands::Validate(args) => {
             commands::validate::execute(args).await?;
         }
+        Commands::Diff(args) => {
+            commands::diff::execute(args).await?;
+        }
     }
     Ok(())
 }
@@ -61,7 +78,7 @@ pub struct NetworkConfig {
 
 impl NetworkConfig {
     /// Get network configuration from network name
-    pub fn from_name(name: &str) -> Result<Self, String> {
+    pub fn from_name(name: &str) -> Result<Self> {
         match name.to_lowercase().as_str() {
             "testnet" => Ok(Self {
                 name: "testnet".to_string(),
@@ -83,10 +100,10 @@ impl NetworkConfig {
                 rpc_url: "http://localhost:8000/soroban/rpc".to_string(),
                 network_passphrase: "Standalone Network ; February 2017".to_string(),
             }),
-            _ => Err(format!(
+            _ => Err(GeneratorError::InvalidInput(format!(
                 "Unknown network '{}'. Use testnet, mainnet, futurenet, local, or provide --rpc-url",
                 name
-            )),
+            ))),
         }
     }
 
@@ -0,0 +1,167 @@
+//! Read-only vs state-changing function classification
+//!
+//! The primary signal is a simulation of each function against the deployed
+//! contract (see `SpecFetcher::classify_functions`): an empty read-write
+//! footprint and no authorization entries mean the call only reads state.
+//! Functions that cannot be simulated stay `FunctionKind::Write`, and the
+//! override lists (`--read-only` / `--write`) always win.
+
+use super::types::{ContractSpec, FunctionKind, FunctionSpec, TypeDef, TypeRef, TypeSpec};
+use crate::error::{GeneratorError, Result};
+use stellar_xdr::curr::{
+    AccountId, Duration, Int128Parts, Int256Parts, PublicKey, ScAddress, ScBytes, ScMap,
+    ScMapEntry, ScString, ScSymbol, ScVal, ScVec, TimePoint, UInt128Parts, UInt256Parts, Uint256,
+};
+
+/// Maximum nesting of custom types when building placeholder arguments
+const MAX_PLACEHOLDER_DEPTH: usize = 8;
+
+/// Classify a simulated invocation from its footprint and auth entries
+pub fn kind_from_footprint(read_write_entries: usize, auth_entries: usize) -> FunctionKind {
+    if read_write_entries == 0 && auth_entries == 0 {
+        FunctionKind::ReadOnly
+    } else {
+        FunctionKind::Write
+    }
+}
+
+/// Apply the user's read-only / write override lists to the classification
+pub fn apply_overrides(
+    spec: &mut ContractSpec,
+    read_only: &[String],
+    write: &[String],
+) -> Result<()> {
+    if let Some(name) = read_only.iter().find(|name| write.contains(name)) {
+        return Err(GeneratorError::InvalidInput(format!(
+            "Function '{}' is listed as both read-only and write",
+            name
+        )));
+    }
+
+    for (names, kind) in [
+        (read_only, FunctionKind::ReadOnly),
+        (write, FunctionKind::Write),
+    ] {
+        for name in names {
+            let func = spec
+                .functions
+                .iter_mut()
+                .find(|f| &f.name == name)
+                .ok_or_else(|| {
+                    GeneratorError::InvalidInput(format!(
+                        "Unknown function '{}' in classification overrides",
+                        name
+                    ))
+                })?;
+            func.kind = kind;
+        }
+    }
+
+    Ok(())
+}
+
+/// Build placeholder arguments for simulating a function: zero numbers, empty
+/// bytes/strings/collections, `None` options and the all-zero account address.
+/// Returns `None` if a parameter type cannot be represented.
+pub fn placeholder_args(func: &FunctionSpec, types: &[TypeSpec]) -> Option<Vec<ScVal>> {
+    func.inputs
+        .iter()
+        .map(|input| placeholder_value(&input.type_ref, types, 0))
+        .collect()
+}
+
+fn placeholder_value(type_ref: &TypeRef, types: &[TypeSpec], depth: usize) -> Option<ScVal> {
+    if depth > MAX_PLACEHOLDER_DEPTH {
+        return None;
+    }
+
+    let value = match type_ref {
+        TypeRef::Bool => ScVal::Bool(false),
+        TypeRef::Void | TypeRef::Option(_) => ScVal::Void,
+        TypeRef::U32 => ScVal::U32(0),
+        TypeRef::I32 => ScVal::I32(0),
+        TypeRef::U64 => ScVal::U64(0),
+        TypeRef::I64 => ScVal::I64(0),
+        TypeRef::Timepoint => ScVal::Timepoint(TimePoint(0)),
+        TypeRef::Duration => ScVal::Duration(Duration(0)),
+        TypeRef::U128 => ScVal::U128(UInt128Parts { hi: 0, lo: 0 }),
+        TypeRef::I128 => ScVal::I128(Int128Parts { hi: 0, lo: 0 }),
+        TypeRef::U256 => ScVal::U256(UInt256Parts {
+            hi_hi: 0,
+            hi_lo: 0,
+            lo_hi: 0,
+            lo_lo: 0,
+        }),
+        TypeRef::I256 => ScVal::I256(Int256Parts {
+            hi_hi: 0,
+            hi_lo: 0,
+            lo_hi: 0,
+            lo_lo: 0,
+        }),
+        TypeRef::Bytes => ScVal::Bytes(ScBytes::default()),
+        TypeRef::BytesN(n) => ScVal::Bytes(ScBytes(vec![0; *n as usize].try_into().ok()?)),
+        TypeRef::String => ScVal::String(ScString::default()),
+        TypeRef::Symbol => ScVal::Symbol(ScSymbol::default()),
+        TypeRef::Address => ScVal::Address(ScAddress::Account(AccountId(
+            PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])),
+        ))),
+        TypeRef::Vec(_) => ScVal::Vec(Some(ScVec::default())),
+        TypeRef::Map { .. } => ScVal::Map(Some(ScMap::default())),
+        TypeRef::Tuple(items) => {
+            let values = items
+                .iter()
+                .map(|t| placeholder_value(t, types, depth + 1))
+                .collect::<Option<Vec<_>>>()?;
+            ScVal::Vec(Some(ScVec(values.try_into().ok()?)))
+        }
+        TypeRef::Custom(name) => {
+            let type_spec = types.iter().find(|t| &t.name == name)?;
+            placeholder_custom(&type_spec.definition, types, depth + 1)?
+        }
+        TypeRef::Status | TypeRef::Result { .. } => return None,
+    };
+
+    Some(value)
+}
+
+fn placeholder_custom(definition: &TypeDef, types: &[TypeSpec], depth: usize) -> Option<ScVal> {
+    match definition {
+        TypeDef::Struct { fields } => {
+            // Tuple structs (fields named 0, 1, ...) are encoded as vectors
+            if !fields.is_empty() && fields.iter().all(|f| f.name.parse::<u32>().is_ok()) {
+                let values = fields
+                    .iter()
+                    .map(|f| placeholder_value(&f.type_ref, types, depth))
+                    .collect::<Option<Vec<_>>>()?;
+                return Some(ScVal::Vec(Some(ScVec(values.try_into().ok()?))));
+            }
+
+            let mut entries = fields
+                .iter()
+                .map(|f| {
+                    Some(ScMapEntry {
+                        key: ScVal::Symbol(ScSymbol(f.name.as_str().try_into().ok()?)),
+                        val: placeholder_value(&f.type_ref, types, depth)?,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            entries.sort_by(|a, b| a.key.cmp(&b.key));
+            Some(ScVal::Map(Some(ScMap(entries.try_into().ok()?))))
+        }
+        TypeDef::Enum { variants } => variants.first().map(|v| ScVal::U32(v.value)),
+        TypeDef::Union { cases } => {
+            // Prefer a unit case, otherwise the first tuple case with placeholder payload
+            let case = cases
+                .iter()
+                .find(|c| c.type_ref.is_none())
+                .or_else(|| cases.first())?;
+            let mut values = vec![ScVal::Symbol(ScSymbol(case.name.as_str().try_into().ok()?))];
+            if let Some(TypeRef::Tuple(items)) = &case.type_ref {
+                for item in items {
+                    values.push(placeholder_value(item, types, depth)?);
+                }
+            }
+            Some(ScVal::Vec(Some(ScVec(values.try_into().ok()?))))
+        }
+    }
+}
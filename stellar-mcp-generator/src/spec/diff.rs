@@ -0,0 +1,494 @@
+//! Comparison of two contract specifications
+//!
+//! Reports what changed between two versions of a contract and whether each
+//! change breaks a server generated from the old version: anything that
+//! changes how arguments are encoded or results are decoded is breaking,
+//! purely additive changes are not.
+
+use super::types::{ContractSpec, EventSpec, FieldSpec, FunctionSpec, TypeDef, TypeRef, TypeSpec};
+use serde::Serialize;
+
+/// Whether a change breaks servers generated from the old spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    Breaking,
+    NonBreaking,
+}
+
+/// Kind of spec item a change applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeTarget {
+    Function,
+    Type,
+    Error,
+    Event,
+}
+
+/// A single difference between two specs
+#[derive(Debug, Clone, Serialize)]
+pub struct SpecChange {
+    pub severity: Severity,
+    pub target: ChangeTarget,
+    /// Name of the function, type, error or event
+    pub name: String,
+    /// Human readable description
+    pub message: String,
+}
+
+/// All differences between two specs
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SpecDiff {
+    pub changes: Vec<SpecChange>,
+}
+
+impl SpecDiff {
+    /// Compare an old and a new contract specification
+    pub fn compare(old: &ContractSpec, new: &ContractSpec) -> Self {
+        let mut diff = SpecDiff::default();
+        diff.compare_functions(&old.functions, &new.functions);
+        diff.compare_types(&old.types, &new.types);
+        diff.compare_errors(old, new);
+        diff.compare_events(&old.events, &new.events);
+        diff
+    }
+
+    /// True if any change is breaking
+    pub fn is_breaking(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|c| c.severity == Severity::Breaking)
+    }
+
+    /// Number of changes with the given severity
+    pub fn count(&self, severity: Severity) -> usize {
+        self.changes
+            .iter()
+            .filter(|c| c.severity == severity)
+            .count()
+    }
+
+    fn push(&mut self, severity: Severity, target: ChangeTarget, name: &str, message: String) {
+        self.changes.push(SpecChange {
+            severity,
+            target,
+            name: name.to_string(),
+            message,
+        });
+    }
+
+    fn compare_functions(&mut self, old: &[FunctionSpec], new: &[FunctionSpec]) {
+        use ChangeTarget::Function;
+
+        for old_fn in old {
+            let Some(new_fn) = new.iter().find(|f| f.name == old_fn.name) else {
+                self.push(
+                    Severity::Breaking,
+                    Function,
+                    &old_fn.name,
+                    format!("function `{}` removed", old_fn.name),
+                );
+                continue;
+            };
+
+            let fields = |f: &FunctionSpec| -> Vec<(String, TypeRef)> {
+                f.inputs
+                    .iter()
+                    .map(|p| (p.name.clone(), p.type_ref.clone()))
+                    .collect()
+            };
+            self.compare_fields(
+                Function,
+                &old_fn.name,
+                "parameter",
+                &fields(old_fn),
+                &fields(new_fn),
+                Severity::Breaking,
+            );
+
+            if old_fn.output != new_fn.output {
+                self.push(
+                    Severity::Breaking,
+                    Function,
+                    &old_fn.name,
+                    format!(
+                        "function `{}` return type changed from {} to {}",
+                        old_fn.name,
+                        output_name(&old_fn.output),
+                        output_name(&new_fn.output)
+                    ),
+                );
+            }
+        }
+
+        for new_fn in new.iter().filter(|f| !old.iter().any(|o| o.name == f.name)) {
+            self.push(
+                Severity::NonBreaking,
+                Function,
+                &new_fn.name,
+                format!("function `{}` added", new_fn.name),
+            );
+        }
+    }
+
+    /// Compare named, ordered fields (function parameters, struct fields,
+    /// event topics/data). `added` is the severity of a new field.
+    fn compare_fields(
+        &mut self,
+        target: ChangeTarget,
+        owner: &str,
+        label: &str,
+        old: &[(String, TypeRef)],
+        new: &[(String, TypeRef)],
+        added: Severity,
+    ) {
+        for (name, old_type) in old {
+            match new.iter().find(|(n, _)| n == name) {
+                None => self.push(
+                    Severity::Breaking,
+                    target,
+                    owner,
+                    format!("`{}`: {} `{}` removed", owner, label, name),
+                ),
+                Some((_, new_type)) if new_type != old_type => self.push(
+                    Severity::Breaking,
+                    target,
+                    owner,
+                    format!(
+                        "`{}`: {} `{}` type changed from {} to {}",
+                        owner,
+                        label,
+                        name,
+                        type_name(old_type),
+                        type_name(new_type)
+                    ),
+                ),
+                Some(_) => {}
+            }
+        }
+
+        for (name, new_type) in new.iter().filter(|(n, _)| !old.iter().any(|(o, _)| o == n)) {
+            self.push(
+                added,
+                target,
+                owner,
+                format!(
+                    "`{}`: {} `{}` ({}) added",
+                    owner,
+                    label,
+                    name,
+                    type_name(new_type)
+                ),
+            );
+        }
+
+        // Same names in a different order change positional encodings (tuples, vec data)
+        let common_old: Vec<&String> = old
+            .iter()
+            .map(|(n, _)| n)
+            .filter(|n| new.iter().any(|(m, _)| m == *n))
+            .collect();
+        let common_new: Vec<&String> = new
+            .iter()
+            .map(|(n, _)| n)
+            .filter(|n| old.iter().any(|(m, _)| m == *n))
+            .collect();
+        if common_old != common_new {
+            self.push(
+                Severity::Breaking,
+                target,
+                owner,
+                format!("`{}`: {}s reordered", owner, label),
+            );
+        }
+    }
+
+    fn compare_types(&mut self, old: &[TypeSpec], new: &[TypeSpec]) {
+        use ChangeTarget::Type;
+
+        for old_type in old {
+            let name = &old_type.name;
+            let Some(new_type) = new.iter().find(|t| &t.name == name) else {
+                self.push(
+                    Severity::Breaking,
+                    Type,
+                    name,
+                    format!("type `{}` removed", name),
+                );
+                continue;
+            };
+
+            match (&old_type.definition, &new_type.definition) {
+                (
+                    TypeDef::Struct { fields: old_fields },
+                    TypeDef::Struct { fields: new_fields },
+                ) => {
+                    // Structs are encoded with every field, so new fields break callers too
+                    self.compare_fields(
+                        Type,
+                        name,
+                        "field",
+                        &named(old_fields),
+                        &named(new_fields),
+                        Severity::Breaking,
+                    );
+                }
+                (
+                    TypeDef::Enum {
+                        variants: old_variants,
+                    },
+                    TypeDef::Enum {
+                        variants: new_variants,
+                    },
+                ) => {
+                    for variant in old_variants {
+                        match new_variants.iter().find(|v| v.name == variant.name) {
+                            None => self.push(
+                                Severity::Breaking,
+                                Type,
+                                name,
+                                format!("`{}`: variant `{}` removed", name, variant.name),
+                            ),
+                            Some(v) if v.value != variant.value => self.push(
+                                Severity::Breaking,
+                                Type,
+                                name,
+                                format!(
+                                    "`{}`: variant `{}` value changed from {} to {}",
+                                    name, variant.name, variant.value, v.value
+                                ),
+                            ),
+                            Some(_) => {}
+                        }
+                    }
+                    for variant in new_variants
+                        .iter()
+                        .filter(|v| !old_variants.iter().any(|o| o.name == v.name))
+                    {
+                        self.push(
+                            Severity::NonBreaking,
+                            Type,
+                            name,
+                            format!("`{}`: variant `{}` added", name, variant.name),
+                        );
+                    }
+                }
+                (TypeDef::Union { cases: old_cases }, TypeDef::Union { cases: new_cases }) => {
+                    for case in old_cases {
+                        match new_cases.iter().find(|c| c.name == case.name) {
+                            None => self.push(
+                                Severity::Breaking,
+                                Type,
+                                name,
+                                format!("`{}`: case `{}` removed", name, case.name),
+                            ),
+                            Some(c) if c.type_ref != case.type_ref => self.push(
+                                Severity::Breaking,
+                                Type,
+                                name,
+                                format!(
+                                    "`{}`: case `{}` payload changed from {} to {}",
+                                    name,
+                                    case.name,
+                                    output_name(&case.type_ref),
+                                    output_name(&c.type_ref)
+                                ),
+                            ),
+                            Some(_) => {}
+                        }
+                    }
+                    for case in new_cases
+                        .iter()
+                        .filter(|c| !old_cases.iter().any(|o| o.name == c.name))
+                    {
+                        self.push(
+                            Severity::NonBreaking,
+                            Type,
+                            name,
+                            format!("`{}`: case `{}` added", name, case.name),
+                        );
+                    }
+                }
+                (old_def, new_def) => self.push(
+                    Severity::Breaking,
+                    Type,
+                    name,
+                    format!(
+                        "type `{}` changed from {} to {}",
+                        name,
+                        definition_kind(old_def),
+                        definition_kind(new_def)
+                    ),
+                ),
+            }
+        }
+
+        for new_type in new.iter().filter(|t| !old.iter().any(|o| o.name == t.name)) {
+            self.push(
+                Severity::NonBreaking,
+                Type,
+                &new_type.name,
+                format!("type `{}` added", new_type.name),
+            );
+        }
+    }
+
+    fn compare_errors(&mut self, old: &ContractSpec, new: &ContractSpec) {
+        use ChangeTarget::Error;
+
+        for error in old.error_cases() {
+            match new.error_cases().find(|e| e.name == error.name) {
+                // Clients simply never see the error again
+                None => self.push(
+                    Severity::NonBreaking,
+                    Error,
+                    &error.name,
+                    format!("error `{}` (code {}) removed", error.name, error.code),
+                ),
+                Some(e) if e.code != error.code => self.push(
+                    Severity::Breaking,
+                    Error,
+                    &error.name,
+                    format!(
+                        "error `{}` code changed from {} to {}",
+                        error.name, error.code, e.code
+                    ),
+                ),
+                Some(_) => {}
+            }
+        }
+
+        for error in new
+            .error_cases()
+            .filter(|e| !old.error_cases().any(|o| o.name == e.name))
+        {
+            self.push(
+                Severity::NonBreaking,
+                Error,
+                &error.name,
+                format!("error `{}` (code {}) added", error.name, error.code),
+            );
+        }
+    }
+
+    fn compare_events(&mut self, old: &[EventSpec], new: &[EventSpec]) {
+        use ChangeTarget::Event;
+
+        for old_event in old {
+            let name = &old_event.name;
+            let Some(new_event) = new.iter().find(|e| &e.name == name) else {
+                self.push(
+                    Severity::Breaking,
+                    Event,
+                    name,
+                    format!("event `{}` removed", name),
+                );
+                continue;
+            };
+
+            if old_event.prefix_topics != new_event.prefix_topics {
+                self.push(
+                    Severity::Breaking,
+                    Event,
+                    name,
+                    format!(
+                        "`{}`: prefix topics changed from {:?} to {:?}",
+                        name, old_event.prefix_topics, new_event.prefix_topics
+                    ),
+                );
+            }
+            if old_event.data_format != new_event.data_format {
+                self.push(
+                    Severity::Breaking,
+                    Event,
+                    name,
+                    format!(
+                        "`{}`: data format changed from {:?} to {:?}",
+                        name, old_event.data_format, new_event.data_format
+                    ),
+                );
+            }
+            self.compare_fields(
+                Event,
+                name,
+                "topic",
+                &named(&old_event.topics),
+                &named(&new_event.topics),
+                Severity::Breaking,
+            );
+            self.compare_fields(
+                Event,
+                name,
+                "data field",
+                &named(&old_event.data),
+                &named(&new_event.data),
+                Severity::Breaking,
+            );
+        }
+
+        for new_event in new.iter().filter(|e| !old.iter().any(|o| o.name == e.name)) {
+            self.push(
+                Severity::NonBreaking,
+                Event,
+                &new_event.name,
+                format!("event `{}` added", new_event.name),
+            );
+        }
+    }
+}
+
+fn named(fields: &[FieldSpec]) -> Vec<(String, TypeRef)> {
+    fields
+        .iter()
+        .map(|f| (f.name.clone(), f.type_ref.clone()))
+        .collect()
+}
+
+fn definition_kind(definition: &TypeDef) -> &'static str {
+    match definition {
+        TypeDef::Struct { .. } => "struct",
+        TypeDef::Enum { .. } => "enum",
+        TypeDef::Union { .. } => "union",
+    }
+}
+
+fn output_name(type_ref: &Option<TypeRef>) -> String {
+    type_ref
+        .as_ref()
+        .map(type_name)
+        .unwrap_or_else(|| "()".to_string())
+}
+
+/// Soroban-style type name (e.g. `Option<Vec<Address>>`)
+fn type_name(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Bool => "bool".to_string(),
+        TypeRef::Void => "()".to_string(),
+        TypeRef::Status => "Error".to_string(),
+        TypeRef::U32 => "u32".to_string(),
+        TypeRef::I32 => "i32".to_string(),
+        TypeRef::U64 => "u64".to_string(),
+        TypeRef::I64 => "i64".to_string(),
+        TypeRef::Timepoint => "Timepoint".to_string(),
+        TypeRef::Duration => "Duration".to_string(),
+        TypeRef::U128 => "u128".to_string(),
+        TypeRef::I128 => "i128".to_string(),
+        TypeRef::U256 => "U256".to_string(),
+        TypeRef::I256 => "I256".to_string(),
+        TypeRef::Bytes => "Bytes".to_string(),
+        TypeRef::String => "String".to_string(),
+        TypeRef::Symbol => "Symbol".to_string(),
+        TypeRef::Address => "Address".to_string(),
+        TypeRef::Option(inner) => format!("Option<{}>", type_name(inner)),
+        TypeRef::Result { ok, err } => format!("Result<{}, {}>", type_name(ok), type_name(err)),
+        TypeRef::Vec(inner) => format!("Vec<{}>", type_name(inner)),
+        TypeRef::Map { key, value } => format!("Map<{}, {}>", type_name(key), type_name(value)),
+        TypeRef::Tuple(types) => {
+            let names: Vec<String> = types.iter().map(type_name).collect();
+            format!("({})", names.join(", "))
+        }
+        TypeRef::BytesN(n) => format!("BytesN<{}>", n),
+        TypeRef::Custom(name) => name.clone(),
+    }
+}
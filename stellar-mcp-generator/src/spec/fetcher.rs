@@ -2,11 +2,13 @@
 //!
 //! Fetches contract WASM from the Stellar network and extracts spec metadata.
 
+use super::classifier;
 use super::parser::SpecParser;
-use super::types::ContractSpec;
+use super::sac;
+use super::types::{ContractSpec, FunctionKind};
 use reqwest::Client;
+use crate::error::{GeneratorError, Result};
 use serde::{Deserialize, Serialize};
-use std::error::Error;
 
 /// Fetches contract specifications from the Stellar network
 pub struct SpecFetcher {
@@ -17,7 +19,7 @@ pub struct SpecFetcher {
 
 impl SpecFetcher {
     /// Create a new spec fetcher for the given RPC URL
-    pub fn new(rpc_url: &str) -> Result<Self, Box<dyn Error>> {
+    pub fn new(rpc_url: &str) -> Result<Self> {
         Ok(Self {
             rpc_url: rpc_url.to_string(),
             client: Client::new(),
@@ -26,7 +28,7 @@ impl SpecFetcher {
     }
 
     /// Create a new spec fetcher with verbose output
-    pub fn with_verbose(rpc_url: &str, verbose: bool) -> Result<Self, Box<dyn Error>> {
+    pub fn with_verbose(rpc_url: &str, verbose: bool) -> Result<Self> {
         Ok(Self {
             rpc_url: rpc_url.to_string(),
             client: Client::new(),
@@ -42,34 +44,179 @@ impl SpecFetcher {
     }
 
     /// Fetch contract specification from a deployed contract
-    pub async fn fetch_spec(&self, contract_id: &str) -> Result<ContractSpec, Box<dyn Error>> {
+    pub async fn fetch_spec(&self, contract_id: &str) -> Result<ContractSpec> {
+        Ok(self.fetch_spec_with_wasm_hash(contract_id).await?.0)
+    }
+
+    /// Fetch contract specification together with the hex-encoded hash of the
+    /// WASM the instance runs (`None` for Stellar Asset Contracts)
+    pub async fn fetch_spec_with_wasm_hash(
+        &self,
+        contract_id: &str,
+    ) -> Result<(ContractSpec, Option<String>)> {
         self.log("  [1/3] Fetching contract WASM ID...");
 
-        // Step 1: Get contract code (ledger entry)
-        let wasm_id = self.get_contract_wasm_id(contract_id).await
-            .map_err(|e| format!("Failed to get WASM ID: {}", e))?;
+        // Steps 1 and 2: Get the contract's WASM ID and code
+        let (wasm_id, wasm_bytes) = match self.fetch_wasm(contract_id).await {
+            Ok(fetched) => fetched,
+            Err(GeneratorError::StellarAssetContract { .. }) => {
+                // SACs have no WASM (and so no embedded spec): use the built-in one
+                println!("  Detected Stellar Asset Contract, using built-in SEP-41 + SAC admin spec");
+                return Ok((sac::stellar_asset_spec()?, None));
+            }
+            Err(e) => return Err(e),
+        };
+
+        self.log("  [3/3] Parsing contract spec...");
+        // Step 3: Parse spec from WASM
+        let spec = SpecParser::parse_wasm(&wasm_bytes)?;
+        self.log(&format!("  [3/3] Found {} functions", spec.functions.len()));
+
+        Ok((spec, Some(wasm_id)))
+    }
+
+    /// Fetch the hex-encoded WASM hash and the WASM code of a deployed contract
+    ///
+    /// Fails with [`GeneratorError::StellarAssetContract`] for Stellar Asset
+    /// Contracts, which run built-in code instead of a WASM.
+    pub async fn fetch_wasm(&self, contract_id: &str) -> Result<(String, Vec<u8>)> {
+        let wasm_id = match self.get_contract_executable(contract_id).await? {
+            ContractExecutable::Wasm(wasm_id) => wasm_id,
+            ContractExecutable::StellarAsset => {
+                return Err(GeneratorError::StellarAssetContract {
+                    contract_id: contract_id.to_string(),
+                })
+            }
+        };
         self.log(&format!("  [1/3] WASM ID: {}", wasm_id));
 
         self.log("  [2/3] Fetching WASM code...");
-        // Step 2: Get WASM code
-        let wasm_bytes = self.get_wasm_code(&wasm_id).await
-            .map_err(|e| format!("Failed to get WASM code: {}", e))?;
+        let wasm_bytes = self.get_wasm_code(&wasm_id).await?;
         self.log(&format!("  [2/3] WASM size: {} bytes", wasm_bytes.len()));
 
-        self.log("  [3/3] Parsing contract spec...");
-        // Step 3: Parse spec from WASM
-        let spec = SpecParser::parse_wasm(&wasm_bytes)
-            .map_err(|e| format!("Failed to parse spec: {}", e))?;
-        self.log(&format!("  [3/3] Found {} functions", spec.functions.len()));
+        Ok((wasm_id, wasm_bytes))
+    }
+
+    /// Classify each function as read-only or state-changing by simulating it
+    /// against the deployed contract with placeholder arguments.
+    /// Functions that cannot be simulated keep their current kind.
+    pub async fn classify_functions(
+        &self,
+        contract_id: &str,
+        spec: &mut ContractSpec,
+    ) -> Result<()> {
+        let contract = self.decode_contract_id(contract_id)?;
+
+        for i in 0..spec.functions.len() {
+            let name = spec.functions[i].name.clone();
+            let Some(args) = classifier::placeholder_args(&spec.functions[i], &spec.types) else {
+                self.log(&format!("    {}: no placeholder arguments, keeping it as write", name));
+                continue;
+            };
+
+            match self.simulate_invocation(&contract, &name, args).await {
+                Ok(kind) => {
+                    self.log(&format!("    {}: {:?}", name, kind));
+                    spec.functions[i].kind = kind;
+                }
+                Err(e) => self.log(&format!("    {}: simulation failed ({}), keeping it as write", name, e)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Simulate a single contract invocation and classify it from its footprint
+    async fn simulate_invocation(
+        &self,
+        contract: &[u8; 32],
+        function: &str,
+        args: Vec<stellar_xdr::curr::ScVal>,
+    ) -> Result<FunctionKind> {
+        use stellar_xdr::curr::{
+            ContractId, Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Limits,
+            Memo, MuxedAccount, Operation, OperationBody, Preconditions, ReadXdr, ScAddress,
+            ScSymbol, SequenceNumber, SorobanTransactionData, Transaction, TransactionEnvelope,
+            TransactionExt, TransactionV1Envelope, Uint256, WriteXdr,
+        };
 
-        Ok(spec)
+        // Same all-zero source account the JS SDK uses for read calls
+        let tx = Transaction {
+            source_account: MuxedAccount::Ed25519(Uint256([0; 32])),
+            fee: 100,
+            seq_num: SequenceNumber(0),
+            cond: Preconditions::None,
+            memo: Memo::None,
+            operations: vec![Operation {
+                source_account: None,
+                body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                    host_function: HostFunction::InvokeContract(InvokeContractArgs {
+                        contract_address: ScAddress::Contract(ContractId(Hash(*contract))),
+                        function_name: ScSymbol(function.try_into().map_err(invalid_xdr)?),
+                        args: args.try_into().map_err(invalid_xdr)?,
+                    }),
+                    auth: Default::default(),
+                }),
+            }]
+            .try_into()
+            .map_err(invalid_xdr)?,
+            ext: TransactionExt::V0,
+        };
+        let envelope = TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx,
+            signatures: Default::default(),
+        });
+        let envelope_xdr = base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            envelope.to_xdr(Limits::none()).map_err(invalid_xdr)?,
+        );
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: 3,
+            method: "simulateTransaction".to_string(),
+            params: SimulateTransactionParams {
+                transaction: envelope_xdr,
+            },
+        };
+
+        let response: JsonRpcResponse<SimulateTransactionResult> = self
+            .client
+            .post(&self.rpc_url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await?;
+        let result = response.into_result()?;
+
+        if let Some(error) = result.error {
+            return Err(GeneratorError::Network(format!("Simulation failed: {}", error)));
+        }
+
+        let transaction_data = result.transaction_data.ok_or_else(|| {
+            GeneratorError::Network("No transaction data in simulation result".to_string())
+        })?;
+        let data_bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, transaction_data)
+            .map_err(|e| GeneratorError::Network(format!("Invalid transaction data: {}", e)))?;
+        let data = SorobanTransactionData::from_xdr(&data_bytes, Limits::none()).map_err(invalid_xdr)?;
+
+        let auth_entries = result
+            .results
+            .iter()
+            .map(|r| r.auth.len())
+            .sum();
+
+        Ok(classifier::kind_from_footprint(
+            data.resources.footprint.read_write.len(),
+            auth_entries,
+        ))
     }
 
-    /// Get the WASM ID for a contract
-    async fn get_contract_wasm_id(&self, contract_id: &str) -> Result<String, Box<dyn Error>> {
+    /// Get the executable (WASM ID or Stellar Asset) backing a contract
+    async fn get_contract_executable(&self, contract_id: &str) -> Result<ContractExecutable> {
         self.log("    Creating ledger key for contract...");
-        let ledger_key = self.contract_data_key(contract_id)
-            .map_err(|e| format!("Failed to create ledger key: {}", e))?;
+        let ledger_key = self.contract_data_key(contract_id)?;
         self.log(&format!("    Ledger key (base64): {}...", &ledger_key[..50.min(ledger_key.len())]));
 
         let request = JsonRpcRequest {
@@ -90,32 +237,25 @@ impl SpecFetcher {
             .await?
             .json()
             .await?;
-
-        if let Some(error) = response.error {
-            return Err(format!("RPC error: {} - {}", error.code, error.message).into());
-        }
-
-        let result = response
-            .result
-            .ok_or("No result in RPC response")?;
+        let result = response.into_result()?;
 
         self.log(&format!("    Got {} ledger entries", result.entries.len()));
 
         let entry = result
             .entries
             .first()
-            .ok_or("Contract not found")?;
+            .ok_or_else(|| GeneratorError::ContractNotFound {
+                contract_id: contract_id.to_string(),
+            })?;
 
         self.log(&format!("    Parsing XDR entry ({} chars)...", entry.xdr.len()));
 
-        // Parse the XDR to extract WASM hash
-        let wasm_id = self.extract_wasm_id_from_entry(&entry.xdr)?;
-
-        Ok(wasm_id)
+        // Parse the XDR to extract the contract executable
+        self.extract_executable_from_entry(&entry.xdr)
     }
 
     /// Get WASM code by its ID
-    async fn get_wasm_code(&self, wasm_id: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    async fn get_wasm_code(&self, wasm_id: &str) -> Result<Vec<u8>> {
         let request = JsonRpcRequest {
             jsonrpc: "2.0".to_string(),
             id: 2,
@@ -133,19 +273,11 @@ impl SpecFetcher {
             .await?
             .json()
             .await?;
+        let result = response.into_result()?;
 
-        if let Some(error) = response.error {
-            return Err(format!("RPC error: {} - {}", error.code, error.message).into());
-        }
-
-        let result = response
-            .result
-            .ok_or("No result in RPC response")?;
-
-        let entry = result
-            .entries
-            .first()
-            .ok_or("WASM code not found")?;
+        let entry = result.entries.first().ok_or_else(|| {
+            GeneratorError::InvalidSpec(format!("WASM code {} not found", wasm_id))
+        })?;
 
         // Parse the XDR to extract WASM bytes
         let wasm_bytes = self.extract_wasm_from_entry(&entry.xdr)?;
@@ -154,7 +286,7 @@ impl SpecFetcher {
     }
 
     /// Create contract data ledger key XDR
-    fn contract_data_key(&self, contract_id: &str) -> Result<String, Box<dyn Error>> {
+    fn contract_data_key(&self, contract_id: &str) -> Result<String> {
         // Build LedgerKey for ContractData (Instance)
         use stellar_xdr::curr::{
             ContractDataDurability, LedgerKey, LedgerKeyContractData, ScAddress, ScVal,
@@ -172,96 +304,47 @@ impl SpecFetcher {
             durability: ContractDataDurability::Persistent,
         });
 
-        let xdr_bytes = stellar_xdr::curr::WriteXdr::to_xdr(&key, stellar_xdr::curr::Limits::none())?;
+        let xdr_bytes = stellar_xdr::curr::WriteXdr::to_xdr(&key, stellar_xdr::curr::Limits::none())
+            .map_err(invalid_xdr)?;
         Ok(base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &xdr_bytes))
     }
 
     /// Create WASM code ledger key XDR
-    fn wasm_code_key(&self, wasm_id: &str) -> Result<String, Box<dyn Error>> {
+    fn wasm_code_key(&self, wasm_id: &str) -> Result<String> {
         use stellar_xdr::curr::{LedgerKey, LedgerKeyContractCode};
 
-        let hash_bytes = hex::decode(wasm_id)?;
-        let mut hash_array = [0u8; 32];
-        hash_array.copy_from_slice(&hash_bytes);
+        let hash_array: [u8; 32] = hex::decode(wasm_id)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| GeneratorError::InvalidSpec(format!("Invalid WASM hash '{}'", wasm_id)))?;
 
         let key = LedgerKey::ContractCode(LedgerKeyContractCode {
             hash: stellar_xdr::curr::Hash(hash_array),
         });
 
-        let xdr_bytes = stellar_xdr::curr::WriteXdr::to_xdr(&key, stellar_xdr::curr::Limits::none())?;
+        let xdr_bytes = stellar_xdr::curr::WriteXdr::to_xdr(&key, stellar_xdr::curr::Limits::none())
+            .map_err(invalid_xdr)?;
         Ok(base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &xdr_bytes))
     }
 
     /// Decode contract ID (strkey) to bytes
-    fn decode_contract_id(&self, contract_id: &str) -> Result<[u8; 32], Box<dyn Error>> {
+    pub fn decode_contract_id(&self, contract_id: &str) -> Result<[u8; 32]> {
         // Simple strkey decoding for contract addresses
-        let decoded = self.strkey_decode(contract_id)?;
+        let invalid = |reason: String| GeneratorError::InvalidStrkey {
+            value: contract_id.to_string(),
+            reason,
+        };
+        let decoded = strkey_decode(contract_id).map_err(invalid)?;
         let mut result = [0u8; 32];
         result.copy_from_slice(&decoded);
         Ok(result)
     }
 
-    /// Decode strkey encoded string
-    fn strkey_decode(&self, encoded: &str) -> Result<Vec<u8>, Box<dyn Error>> {
-        // Stellar strkey uses base32 encoding with CRC16 checksum
-        // For contract IDs (C prefix), version byte is 2
-
-        if !encoded.starts_with('C') {
-            return Err("Invalid contract ID: must start with 'C'".into());
-        }
-
-        // Stellar uses uppercase base32 (RFC4648 without padding)
-        let encoded_upper = encoded.to_uppercase();
-        let alphabet = base32::Alphabet::Rfc4648 { padding: false };
-        let decoded = base32::decode(alphabet, &encoded_upper)
-            .ok_or_else(|| format!("Failed to decode base32 for: {}", encoded))?;
-
-        // Strkey format: 1 byte version + 32 bytes payload + 2 bytes CRC16 = 35 bytes
-        if decoded.len() != 35 {
-            return Err(format!(
-                "Invalid strkey length: expected 35 bytes, got {} bytes",
-                decoded.len()
-            ).into());
-        }
-
-        // Verify CRC16 checksum
-        let payload = &decoded[0..33]; // version + 32-byte payload
-        let checksum = &decoded[33..35];
-        let computed_crc = self.crc16_xmodem(payload);
-        let expected_crc = u16::from_le_bytes([checksum[0], checksum[1]]);
-
-        if computed_crc != expected_crc {
-            return Err(format!(
-                "Invalid strkey checksum: computed {:04x}, expected {:04x}",
-                computed_crc, expected_crc
-            ).into());
-        }
-
-        // Return the 32-byte payload (skip version byte)
-        Ok(decoded[1..33].to_vec())
-    }
-
-    /// CRC16-XMODEM checksum (used by Stellar strkey)
-    fn crc16_xmodem(&self, data: &[u8]) -> u16 {
-        let mut crc: u16 = 0;
-        for byte in data {
-            crc ^= (*byte as u16) << 8;
-            for _ in 0..8 {
-                if crc & 0x8000 != 0 {
-                    crc = (crc << 1) ^ 0x1021;
-                } else {
-                    crc <<= 1;
-                }
-            }
-        }
-        crc
-    }
-
-    /// Extract WASM ID from contract instance ledger entry
-    fn extract_wasm_id_from_entry(&self, xdr_base64: &str) -> Result<String, Box<dyn Error>> {
+    /// Extract the contract executable from contract instance ledger entry
+    fn extract_executable_from_entry(&self, xdr_base64: &str) -> Result<ContractExecutable> {
         use stellar_xdr::curr::{LedgerEntryData, ReadXdr};
 
-        let xdr_bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, xdr_base64)?;
+        let xdr_bytes = decode_entry_xdr(xdr_base64)?;
         self.log(&format!("    XDR bytes length: {}", xdr_bytes.len()));
         self.log(&format!("    XDR hex (first 50): {}...", hex::encode(&xdr_bytes[..50.min(xdr_bytes.len())])));
 
@@ -272,8 +355,9 @@ impl SpecFetcher {
         } else {
             // Fall back to parsing just the data part
             self.log("    Trying to parse as LedgerEntryData...");
-            LedgerEntryData::from_xdr(&xdr_bytes, stellar_xdr::curr::Limits::none())
-                .map_err(|e| format!("Failed to parse XDR as LedgerEntry or LedgerEntryData: {:?}", e))?
+            LedgerEntryData::from_xdr(&xdr_bytes, stellar_xdr::curr::Limits::none()).map_err(|e| {
+                GeneratorError::Network(format!("Failed to parse XDR as LedgerEntry or LedgerEntryData: {:?}", e))
+            })?
         };
 
         match data {
@@ -284,41 +368,117 @@ impl SpecFetcher {
                         self.log("    Got ContractInstance");
                         match instance.executable {
                             stellar_xdr::curr::ContractExecutable::Wasm(hash) => {
-                                Ok(hex::encode(hash.0))
+                                Ok(ContractExecutable::Wasm(hex::encode(hash.0)))
                             }
                             stellar_xdr::curr::ContractExecutable::StellarAsset => {
-                                Err("Contract is a Stellar Asset contract, not WASM".into())
+                                self.log("    Contract is a Stellar Asset contract");
+                                Ok(ContractExecutable::StellarAsset)
                             }
                         }
                     }
-                    other => Err(format!("Expected ContractInstance, got {:?}", other).into()),
+                    other => Err(GeneratorError::Network(format!("Expected ContractInstance, got {:?}", other))),
                 }
             }
-            other => Err(format!("Expected ContractData, got {:?}", other).into()),
+            other => Err(GeneratorError::Network(format!("Expected ContractData, got {:?}", other))),
         }
     }
 
     /// Extract WASM bytes from contract code ledger entry
-    fn extract_wasm_from_entry(&self, xdr_base64: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    fn extract_wasm_from_entry(&self, xdr_base64: &str) -> Result<Vec<u8>> {
         use stellar_xdr::curr::{LedgerEntryData, ReadXdr};
 
-        let xdr_bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, xdr_base64)?;
+        let xdr_bytes = decode_entry_xdr(xdr_base64)?;
 
         // Try parsing as LedgerEntry first, then fall back to LedgerEntryData
         let data = if let Ok(entry) = stellar_xdr::curr::LedgerEntry::from_xdr(&xdr_bytes, stellar_xdr::curr::Limits::none()) {
             entry.data
         } else {
             LedgerEntryData::from_xdr(&xdr_bytes, stellar_xdr::curr::Limits::none())
-                .map_err(|e| format!("Failed to parse WASM entry XDR: {:?}", e))?
+                .map_err(|e| GeneratorError::Network(format!("Failed to parse WASM entry XDR: {:?}", e)))?
         };
 
         match data {
             LedgerEntryData::ContractCode(code) => {
                 Ok(code.code.to_vec())
             }
-            other => Err(format!("Expected ContractCode, got {:?}", other).into()),
+            other => Err(GeneratorError::Network(format!("Expected ContractCode, got {:?}", other))),
+        }
+    }
+}
+
+/// Decode a contract strkey (`C...`) to its 32-byte payload
+fn strkey_decode(encoded: &str) -> std::result::Result<Vec<u8>, String> {
+    // Stellar strkey uses base32 encoding with CRC16 checksum
+    // For contract IDs (C prefix), version byte is 2
+
+    if !encoded.starts_with('C') {
+        return Err("must start with 'C'".to_string());
+    }
+
+    // Stellar uses uppercase base32 (RFC4648 without padding)
+    let encoded_upper = encoded.to_uppercase();
+    let alphabet = base32::Alphabet::Rfc4648 { padding: false };
+    let decoded = base32::decode(alphabet, &encoded_upper)
+        .ok_or_else(|| "not valid base32".to_string())?;
+
+    // Strkey format: 1 byte version + 32 bytes payload + 2 bytes CRC16 = 35 bytes
+    if decoded.len() != 35 {
+        return Err(format!(
+            "invalid strkey length: expected 35 bytes, got {} bytes",
+            decoded.len()
+        ));
+    }
+
+    // Verify CRC16 checksum
+    let payload = &decoded[0..33]; // version + 32-byte payload
+    let checksum = &decoded[33..35];
+    let computed_crc = crc16_xmodem(payload);
+    let expected_crc = u16::from_le_bytes([checksum[0], checksum[1]]);
+
+    if computed_crc != expected_crc {
+        return Err(format!(
+            "invalid strkey checksum: computed {:04x}, expected {:04x}",
+            computed_crc, expected_crc
+        ));
+    }
+
+    // Return the 32-byte payload (skip version byte)
+    Ok(decoded[1..33].to_vec())
+}
+
+/// CRC16-XMODEM checksum (used by Stellar strkey)
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            if crc & 0x8000 != 0 {
+                crc = (crc << 1) ^ 0x1021;
+            } else {
+                crc <<= 1;
+            }
         }
     }
+    crc
+}
+
+/// Decode the base64 XDR of a ledger entry returned by the RPC server
+fn decode_entry_xdr(xdr_base64: &str) -> Result<Vec<u8>> {
+    base64::Engine::decode(&base64::engine::general_purpose::STANDARD, xdr_base64)
+        .map_err(|e| GeneratorError::Network(format!("Invalid ledger entry XDR: {}", e)))
+}
+
+/// Map an XDR encoding failure while building a request
+fn invalid_xdr(e: stellar_xdr::curr::Error) -> GeneratorError {
+    GeneratorError::InvalidInput(format!("Failed to encode XDR: {}", e))
+}
+
+/// Executable backing a deployed contract instance
+enum ContractExecutable {
+    /// WASM contract, identified by the hex-encoded WASM hash
+    Wasm(String),
+    /// Built-in Stellar Asset Contract
+    StellarAsset,
 }
 
 // JSON-RPC types
@@ -340,6 +500,20 @@ struct JsonRpcResponse<R> {
     error: Option<JsonRpcError>,
 }
 
+impl<R> JsonRpcResponse<R> {
+    /// The result of a successful call, or the JSON-RPC error the server returned
+    fn into_result(self) -> Result<R> {
+        if let Some(error) = self.error {
+            return Err(GeneratorError::Rpc {
+                code: error.code,
+                message: error.message,
+            });
+        }
+        self.result
+            .ok_or_else(|| GeneratorError::Network("No result in RPC response".to_string()))
+    }
+}
+
 #[derive(Deserialize)]
 struct JsonRpcError {
     code: i32,
@@ -360,3 +534,23 @@ struct GetLedgerEntriesResult {
 struct LedgerEntryResult {
     xdr: String,
 }
+
+#[derive(Serialize)]
+struct SimulateTransactionParams {
+    transaction: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SimulateTransactionResult {
+    error: Option<String>,
+    transaction_data: Option<String>,
+    #[serde(default)]
+    results: Vec<SimulateHostFunctionResult>,
+}
+
+#[derive(Deserialize)]
+struct SimulateHostFunctionResult {
+    #[serde(default)]
+    auth: Vec<String>,
+}
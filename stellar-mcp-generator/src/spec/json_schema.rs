@@ -0,0 +1,230 @@
+//! Canonical JSON Schema for contract types
+//!
+//! Every generated server accepts tool arguments as plain JSON, and this module
+//! defines what that JSON looks like for each contract type. Zod schemas,
+//! Pydantic types and TypeScript types are all derived from it
+//! ([`super::json_types`]), and it is written as-is into the `tools.json`
+//! manifest (`generator::tool_manifest`):
+//!
+//! - integers up to 32 bits are JSON integers; 64-bit and wider integers,
+//!   timepoints and durations are decimal strings, so they survive JSON
+//!   parsers that use doubles
+//! - `Bytes` and `BytesN` are hex strings, addresses are `G...`/`C...` strkeys
+//! - options may be omitted or `null`, maps are arrays of `[key, value]` pairs
+//!   and tuples are fixed-length arrays
+//! - structs are objects, enums are their variant names and unions are
+//!   `{ tag, values }` objects, as in the official bindings
+//!
+//! Contract types are referenced as `#/$defs/<Name>`; [`with_defs`] embeds the
+//! definitions a schema needs so it can be used on its own.
+
+use super::types::{ContractSpec, TypeDef, TypeRef, TypeSpec};
+use serde_json::{json, Map, Value};
+
+/// Prefix of references to contract types
+pub const DEFS_PREFIX: &str = "#/$defs/";
+
+/// Decimal string of a non-negative integer
+pub const UNSIGNED_PATTERN: &str = "^(0|[1-9][0-9]*)$";
+/// Decimal string of an integer
+pub const SIGNED_PATTERN: &str = "^(0|-?[1-9][0-9]*)$";
+/// Hex string of any number of bytes
+pub const HEX_BYTES_PATTERN: &str = "^([0-9a-fA-F]{2})*$";
+/// Hex string (its length is set by `minLength`/`maxLength`)
+pub const HEX_PATTERN: &str = "^[0-9a-fA-F]*$";
+/// Account (`G...`) or contract (`C...`) strkey
+pub const ADDRESS_PATTERN: &str = "^[GC][A-Z2-7]{55}$";
+/// Characters allowed in a Soroban symbol
+pub const SYMBOL_PATTERN: &str = "^[A-Za-z0-9_]*$";
+
+impl TypeRef {
+    /// Canonical JSON Schema of the JSON value a tool accepts for this type
+    pub fn to_json_schema(&self) -> Value {
+        match self {
+            TypeRef::Bool => json!({ "type": "boolean" }),
+            TypeRef::Void => json!({ "type": "null" }),
+            TypeRef::Status | TypeRef::U32 => {
+                json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX })
+            }
+            TypeRef::I32 => json!({ "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX }),
+            TypeRef::U64
+            | TypeRef::Timepoint
+            | TypeRef::Duration
+            | TypeRef::U128
+            | TypeRef::U256 => json!({ "type": "string", "pattern": UNSIGNED_PATTERN }),
+            TypeRef::I64 | TypeRef::I128 | TypeRef::I256 => {
+                json!({ "type": "string", "pattern": SIGNED_PATTERN })
+            }
+            TypeRef::Bytes => json!({ "type": "string", "pattern": HEX_BYTES_PATTERN }),
+            TypeRef::BytesN(n) => json!({
+                "type": "string",
+                "pattern": HEX_PATTERN,
+                "minLength": n * 2,
+                "maxLength": n * 2,
+            }),
+            TypeRef::String => json!({ "type": "string" }),
+            TypeRef::Symbol => json!({ "type": "string", "pattern": SYMBOL_PATTERN, "maxLength": 32 }),
+            TypeRef::Address => json!({
+                "type": "string",
+                "pattern": ADDRESS_PATTERN,
+                "minLength": 56,
+                "maxLength": 56,
+            }),
+            TypeRef::Option(inner) => json!({ "anyOf": [inner.to_json_schema(), { "type": "null" }] }),
+            // Contract errors are reported as tool errors, so only the ok type is a value
+            TypeRef::Result { ok, .. } => ok.to_json_schema(),
+            TypeRef::Vec(inner) => json!({ "type": "array", "items": inner.to_json_schema() }),
+            TypeRef::Map { key, value } => json!({
+                "type": "array",
+                "items": tuple_schema(&[key.to_json_schema(), value.to_json_schema()]),
+            }),
+            TypeRef::Tuple(types) => {
+                let items: Vec<Value> = types.iter().map(TypeRef::to_json_schema).collect();
+                tuple_schema(&items)
+            }
+            TypeRef::Custom(name) => json!({ "$ref": format!("{}{}", DEFS_PREFIX, name) }),
+        }
+    }
+
+    /// Whether an argument of this type may be omitted (options)
+    pub fn is_optional(&self) -> bool {
+        matches!(self, TypeRef::Option(_))
+    }
+}
+
+impl TypeSpec {
+    /// JSON Schema definition of the type, referenced as `#/$defs/<name>`
+    pub fn to_json_schema(&self) -> Value {
+        let mut schema = match &self.definition {
+            TypeDef::Struct { fields } => object_schema(
+                fields.iter().map(|f| (f.name.clone(), &f.type_ref, f.doc.as_deref())),
+            ),
+            TypeDef::Enum { variants } => {
+                let names: Vec<&str> = variants.iter().map(|v| v.name.as_str()).collect();
+                json!({ "type": "string", "enum": names })
+            }
+            TypeDef::Union { cases } => {
+                let cases: Vec<Value> = cases
+                    .iter()
+                    .map(|case| {
+                        let mut properties = Map::new();
+                        properties.insert("tag".to_string(), json!({ "const": case.name }));
+                        let mut required = vec!["tag"];
+                        if let Some(payload) = &case.type_ref {
+                            properties.insert("values".to_string(), payload.to_json_schema());
+                            required.push("values");
+                        }
+                        let mut schema = json!({
+                            "type": "object",
+                            "properties": properties,
+                            "required": required,
+                            "additionalProperties": false,
+                        });
+                        describe(&mut schema, case.doc.as_deref());
+                        schema
+                    })
+                    .collect();
+                json!({ "oneOf": cases })
+            }
+        };
+        describe(&mut schema, self.doc.as_deref());
+        schema
+    }
+}
+
+impl ContractSpec {
+    /// Definitions of every contract type, keyed by name
+    pub fn json_schema_defs(&self) -> Map<String, Value> {
+        self.types
+            .iter()
+            .map(|t| (t.name.clone(), t.to_json_schema()))
+            .collect()
+    }
+}
+
+/// Schema of a fixed-length array
+fn tuple_schema(items: &[Value]) -> Value {
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "items": false,
+        "minItems": items.len(),
+        "maxItems": items.len(),
+    })
+}
+
+/// Schema of an object with a property per `(name, type, doc)`, such as a
+/// struct or a tool's arguments. Options are not required.
+pub fn object_schema<'a>(
+    fields: impl IntoIterator<Item = (String, &'a TypeRef, Option<&'a str>)>,
+) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (name, type_ref, doc) in fields {
+        let mut schema = type_ref.to_json_schema();
+        describe(&mut schema, doc);
+        if !type_ref.is_optional() {
+            required.push(name.clone());
+        }
+        properties.insert(name, schema);
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Add a `description` to a schema, if there is a doc
+pub fn describe(schema: &mut Value, doc: Option<&str>) {
+    if let (Some(doc), Value::Object(schema)) = (doc.map(str::trim), schema) {
+        if !doc.is_empty() {
+            schema.insert("description".to_string(), doc.into());
+        }
+    }
+}
+
+/// Name of the contract type a `$ref` schema points to
+pub fn ref_name(schema: &Value) -> Option<&str> {
+    schema.get("$ref")?.as_str()?.strip_prefix(DEFS_PREFIX)
+}
+
+/// `schema` with a `$defs` entry holding every contract type it references,
+/// directly or through other types. Unknown references are left dangling.
+pub fn with_defs(schema: Value, types: &[TypeSpec]) -> Value {
+    let mut defs = Map::new();
+    let mut pending = Vec::new();
+    collect_refs(&schema, &mut pending);
+    while let Some(name) = pending.pop() {
+        if defs.contains_key(&name) {
+            continue;
+        }
+        if let Some(type_spec) = types.iter().find(|t| t.name == name) {
+            let def = type_spec.to_json_schema();
+            collect_refs(&def, &mut pending);
+            defs.insert(name, def);
+        }
+    }
+
+    match schema {
+        Value::Object(mut object) if !defs.is_empty() => {
+            object.insert("$defs".to_string(), Value::Object(defs));
+            Value::Object(object)
+        }
+        schema => schema,
+    }
+}
+
+fn collect_refs(schema: &Value, refs: &mut Vec<String>) {
+    match schema {
+        Value::Object(object) => {
+            if let Some(name) = ref_name(schema) {
+                refs.push(name.to_string());
+            }
+            object.values().for_each(|v| collect_refs(v, refs));
+        }
+        Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+        _ => {}
+    }
+}
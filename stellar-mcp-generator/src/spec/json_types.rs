@@ -0,0 +1,216 @@
+//! Type expressions derived from the canonical JSON Schema
+//!
+//! Zod schemas and TypeScript types for TypeScript servers, and Pydantic type
+//! hints and `Field` constraints for Python servers, are translated from
+//! `TypeRef::to_json_schema` (`spec::json_schema`) instead of being mapped from
+//! `TypeRef` separately, so every language accepts the same JSON for a
+//! contract type. Only the subset of JSON Schema that `spec::json_schema`
+//! emits is translated; anything else becomes `unknown` / `Any`.
+
+use super::json_schema::ref_name;
+use crate::codegen::emit::{py_string, ts_object_key, ts_string};
+use serde_json::Value;
+
+/// Zod schema expression for `schema`. Contract types become
+/// `<refs><Name>Schema`, e.g. `schemas.TokenConfigSchema` with `refs`
+/// `"schemas."`.
+pub fn zod(schema: &Value, refs: &str) -> String {
+    if let Some(name) = ref_name(schema) {
+        return format!("{}{}Schema", refs, name);
+    }
+    if let Some(inner) = nullable(schema) {
+        return format!("{}.nullable()", zod(inner, refs));
+    }
+    if let Some(value) = schema.get("const").and_then(Value::as_str) {
+        return format!("z.literal({})", ts_string(value));
+    }
+    if let Some(names) = schema.get("enum").and_then(Value::as_array) {
+        let names: Vec<String> = names.iter().filter_map(Value::as_str).map(ts_string).collect();
+        return format!("z.enum([{}])", names.join(", "));
+    }
+    if let Some(cases) = schema.get("oneOf").and_then(Value::as_array) {
+        let cases: Vec<String> = cases.iter().map(|case| zod(case, refs)).collect();
+        return format!("z.discriminatedUnion('tag', [{}])", cases.join(", "));
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("boolean") => "z.boolean()".to_string(),
+        Some("null") => "z.null()".to_string(),
+        Some("integer") => {
+            let mut zod = "z.number().int()".to_string();
+            if let Some(min) = schema.get("minimum") {
+                zod.push_str(&format!(".min({})", min));
+            }
+            if let Some(max) = schema.get("maximum") {
+                zod.push_str(&format!(".max({})", max));
+            }
+            zod
+        }
+        Some("string") => {
+            let mut zod = "z.string()".to_string();
+            if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+                zod.push_str(&format!(".regex(/{}/)", pattern.replace('/', "\\/")));
+            }
+            match (schema.get("minLength"), schema.get("maxLength")) {
+                (Some(min), Some(max)) if min == max => zod.push_str(&format!(".length({})", min)),
+                (min, max) => {
+                    if let Some(min) = min {
+                        zod.push_str(&format!(".min({})", min));
+                    }
+                    if let Some(max) = max {
+                        zod.push_str(&format!(".max({})", max));
+                    }
+                }
+            }
+            zod
+        }
+        Some("array") => match schema.get("prefixItems").and_then(Value::as_array) {
+            Some(items) => {
+                let items: Vec<String> = items.iter().map(|item| zod(item, refs)).collect();
+                format!("z.tuple([{}])", items.join(", "))
+            }
+            None => format!("z.array({})", zod(&schema["items"], refs)),
+        },
+        Some("object") => {
+            let fields: Vec<String> = properties(schema)
+                .map(|(name, property, required)| {
+                    let optional = if required { "" } else { ".optional()" };
+                    format!("{}: {}{}", ts_object_key(name), zod(property, refs), optional)
+                })
+                .collect();
+            format!("z.object({{ {} }})", fields.join(", "))
+        }
+        _ => "z.unknown()".to_string(),
+    }
+}
+
+/// TypeScript type of the JSON value `schema` describes. Contract types
+/// become `<refs><Name>`.
+pub fn typescript(schema: &Value, refs: &str) -> String {
+    if let Some(name) = ref_name(schema) {
+        return format!("{}{}", refs, name);
+    }
+    if let Some(inner) = nullable(schema) {
+        return format!("{} | null", typescript(inner, refs));
+    }
+    if let Some(value) = schema.get("const").and_then(Value::as_str) {
+        return ts_string(value);
+    }
+    if let Some(names) = schema.get("enum").and_then(Value::as_array) {
+        let names: Vec<String> = names.iter().filter_map(Value::as_str).map(ts_string).collect();
+        return names.join(" | ");
+    }
+    if let Some(cases) = schema.get("oneOf").and_then(Value::as_array) {
+        let cases: Vec<String> = cases.iter().map(|case| typescript(case, refs)).collect();
+        return cases.join(" | ");
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("boolean") => "boolean".to_string(),
+        Some("null") => "null".to_string(),
+        Some("integer") => "number".to_string(),
+        Some("string") => "string".to_string(),
+        Some("array") => match schema.get("prefixItems").and_then(Value::as_array) {
+            Some(items) => {
+                let items: Vec<String> = items.iter().map(|item| typescript(item, refs)).collect();
+                format!("[{}]", items.join(", "))
+            }
+            None => {
+                let item = typescript(&schema["items"], refs);
+                if item.contains(" | ") {
+                    format!("({})[]", item)
+                } else {
+                    format!("{}[]", item)
+                }
+            }
+        },
+        Some("object") => {
+            let fields: Vec<String> = properties(schema)
+                .map(|(name, property, required)| {
+                    let optional = if required { "" } else { "?" };
+                    format!("{}{}: {}", ts_object_key(name), optional, typescript(property, refs))
+                })
+                .collect();
+            format!("{{ {} }}", fields.join("; "))
+        }
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Pydantic type hint for `schema`. Contract types become `<Name>Schema`.
+/// Objects and unions only appear as contract type definitions, which
+/// `pydantic_schemas` turns into classes; inline they are `Any`.
+pub fn pydantic(schema: &Value) -> String {
+    if let Some(name) = ref_name(schema) {
+        return format!("{}Schema", name);
+    }
+    if let Some(inner) = nullable(schema) {
+        return format!("Optional[{}]", pydantic(inner));
+    }
+    if let Some(value) = schema.get("const").and_then(Value::as_str) {
+        return format!("Literal[{}]", py_string(value));
+    }
+    if let Some(names) = schema.get("enum").and_then(Value::as_array) {
+        let names: Vec<String> = names.iter().filter_map(Value::as_str).map(py_string).collect();
+        return format!("Literal[{}]", names.join(", "));
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("boolean") => "bool".to_string(),
+        Some("null") => "None".to_string(),
+        Some("integer") => "int".to_string(),
+        Some("string") => "str".to_string(),
+        Some("array") => match schema.get("prefixItems").and_then(Value::as_array) {
+            Some(items) => {
+                let items: Vec<String> = items.iter().map(pydantic).collect();
+                format!("Tuple[{}]", items.join(", "))
+            }
+            None => format!("List[{}]", pydantic(&schema["items"])),
+        },
+        _ => "Any".to_string(),
+    }
+}
+
+/// Pydantic `Field` keyword arguments enforcing the constraints of a string
+/// or integer schema (or an optional one): lengths, pattern and bounds
+pub fn pydantic_constraints(schema: &Value) -> Vec<String> {
+    let schema = nullable(schema).unwrap_or(schema);
+    let mut constraints = Vec::new();
+    for (keyword, argument) in [
+        ("minLength", "min_length"),
+        ("maxLength", "max_length"),
+        ("minimum", "ge"),
+        ("maximum", "le"),
+    ] {
+        if let Some(value) = schema.get(keyword) {
+            constraints.push(format!("{}={}", argument, value));
+        }
+    }
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        constraints.push(format!("pattern={}", py_string(pattern)));
+    }
+    constraints
+}
+
+/// The non-null schema of an `anyOf: [<schema>, null]`
+fn nullable(schema: &Value) -> Option<&Value> {
+    match schema.get("anyOf").and_then(Value::as_array).map(Vec::as_slice) {
+        Some([inner, null]) if null.get("type").and_then(Value::as_str) == Some("null") => Some(inner),
+        _ => None,
+    }
+}
+
+/// `(name, schema, required)` of each property of an object schema
+fn properties(schema: &Value) -> impl Iterator<Item = (&str, &Value, bool)> {
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .map(move |(name, property)| (name.as_str(), property, required.contains(&name.as_str())))
+}
@@ -1,7 +1,13 @@
 //! Contract specification fetching and parsing
 
+pub mod classifier;
+pub mod diff;
 mod fetcher;
+pub mod json_schema;
+pub mod json_types;
 mod parser;
+pub mod sac;
+pub mod snapshot;
 pub mod types;
 
 pub use fetcher::SpecFetcher;
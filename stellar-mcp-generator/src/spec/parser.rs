@@ -3,31 +3,42 @@
 //! Uses soroban-spec-tools for reliable WASM spec parsing.
 
 use super::types::*;
-use std::error::Error;
+use crate::error::{GeneratorError, Result};
+use std::path::Path;
 
 /// Parser for contract specifications using soroban-spec-tools
 pub struct SpecParser;
 
 impl SpecParser {
     /// Parse contract specification from WASM bytes using soroban-spec-tools
-    pub fn parse_wasm(wasm_bytes: &[u8]) -> Result<ContractSpec, Box<dyn Error>> {
-        use stellar_xdr::curr::WriteXdr;
-        use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
-
+    pub fn parse_wasm(wasm_bytes: &[u8]) -> Result<ContractSpec> {
         // Use the official soroban-spec-tools library (same as scaffold-stellar)
         let soroban_spec = soroban_spec_tools::contract::Spec::new(wasm_bytes)
-            .map_err(|e| format!("Failed to parse WASM spec: {}", e))?;
+            .map_err(|e| GeneratorError::InvalidSpec(format!("Failed to parse WASM spec: {}", e)))?;
 
-        let mut spec = ContractSpec::new();
+        let mut spec = Self::parse_entries(&soroban_spec.spec)?;
 
         // Extract contract name from metadata if available
         spec.name = Self::extract_contract_name(&soroban_spec.meta);
 
+        Ok(spec)
+    }
+
+    /// Build a contract specification from already-decoded spec entries
+    /// (e.g. the built-in Stellar Asset Contract spec)
+    pub fn parse_entries(
+        entries: &[stellar_xdr::curr::ScSpecEntry],
+    ) -> Result<ContractSpec> {
+        use stellar_xdr::curr::WriteXdr;
+        use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+
+        let mut spec = ContractSpec::new();
+
         // Extract raw XDR spec entries (base64 encoded) for SDK ContractSpec
-        for entry in &soroban_spec.spec {
+        for entry in entries {
             // Serialize each ScSpecEntry to XDR bytes using to_xdr
             let xdr_bytes = entry.to_xdr(stellar_xdr::curr::Limits::none())
-                .map_err(|e| format!("Failed to serialize spec entry: {}", e))?;
+                .map_err(|e| GeneratorError::InvalidSpec(format!("Failed to serialize spec entry: {}", e)))?;
 
             // Base64 encode the XDR bytes
             let base64_xdr = BASE64.encode(&xdr_bytes);
@@ -37,22 +48,30 @@ impl SpecParser {
             Self::process_spec_entry(entry, &mut spec)?;
         }
 
+        Self::check_names(&spec)?;
         Ok(spec)
     }
 
+    /// Parse contract specification from a local WASM file (no network access)
+    pub fn parse_wasm_file(path: &Path) -> Result<ContractSpec> {
+        let wasm_bytes = std::fs::read(path)
+            .map_err(GeneratorError::io(format!("Failed to read WASM file '{}'", path.display())))?;
+
+        Self::parse_wasm(&wasm_bytes)
+    }
+
     /// Extract contract name from metadata entries
     /// Looks for a "name" key in the contract metadata
     fn extract_contract_name(meta: &[stellar_xdr::curr::ScMetaEntry]) -> Option<String> {
         use stellar_xdr::curr::ScMetaEntry;
 
         for entry in meta {
-            if let ScMetaEntry::ScMetaV0(meta_v0) = entry {
-                let key = meta_v0.key.to_utf8_string_lossy();
-                if key == "name" {
-                    let name = meta_v0.val.to_utf8_string_lossy();
-                    if !name.is_empty() {
-                        return Some(name);
-                    }
+            let ScMetaEntry::ScMetaV0(meta_v0) = entry;
+            let key = meta_v0.key.to_utf8_string_lossy();
+            if key == "name" {
+                let name = meta_v0.val.to_utf8_string_lossy();
+                if !name.is_empty() {
+                    return Some(name);
                 }
             }
         }
@@ -63,7 +82,7 @@ impl SpecParser {
     fn process_spec_entry(
         entry: &stellar_xdr::curr::ScSpecEntry,
         spec: &mut ContractSpec,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<()> {
         use stellar_xdr::curr::ScSpecEntry;
 
         match entry {
@@ -76,7 +95,7 @@ impl SpecParser {
 
                 let function_spec = FunctionSpec {
                     name: name.clone(),
-                    doc: if func.doc.len() > 0 {
+                    doc: if !func.doc.is_empty() {
                         Some(func.doc.to_utf8_string_lossy())
                     } else {
                         None
@@ -86,7 +105,7 @@ impl SpecParser {
                         .iter()
                         .map(|input| ParameterSpec {
                             name: input.name.to_utf8_string_lossy(),
-                            doc: if input.doc.len() > 0 {
+                            doc: if !input.doc.is_empty() {
                                 Some(input.doc.to_utf8_string_lossy())
                             } else {
                                 None
@@ -97,14 +116,16 @@ impl SpecParser {
                     output: func
                         .outputs
                         .first()
-                        .map(|out| Self::convert_type(out)),
+                        .map(Self::convert_type),
+                    // Classified later (simulation footprint or overrides)
+                    kind: FunctionKind::default(),
                 };
                 spec.functions.push(function_spec);
             }
             ScSpecEntry::UdtStructV0(struct_def) => {
                 let type_spec = TypeSpec {
                     name: struct_def.name.to_utf8_string_lossy(),
-                    doc: if struct_def.doc.len() > 0 {
+                    doc: if !struct_def.doc.is_empty() {
                         Some(struct_def.doc.to_utf8_string_lossy())
                     } else {
                         None
@@ -115,7 +136,7 @@ impl SpecParser {
                             .iter()
                             .map(|field| FieldSpec {
                                 name: field.name.to_utf8_string_lossy(),
-                                doc: if field.doc.len() > 0 {
+                                doc: if !field.doc.is_empty() {
                                     Some(field.doc.to_utf8_string_lossy())
                                 } else {
                                     None
@@ -130,7 +151,7 @@ impl SpecParser {
             ScSpecEntry::UdtUnionV0(union_def) => {
                 let type_spec = TypeSpec {
                     name: union_def.name.to_utf8_string_lossy(),
-                    doc: if union_def.doc.len() > 0 {
+                    doc: if !union_def.doc.is_empty() {
                         Some(union_def.doc.to_utf8_string_lossy())
                     } else {
                         None
@@ -144,7 +165,7 @@ impl SpecParser {
                                 match case {
                                     ScSpecUdtUnionCaseV0::VoidV0(v) => UnionCase {
                                         name: v.name.to_utf8_string_lossy(),
-                                        doc: if v.doc.len() > 0 {
+                                        doc: if !v.doc.is_empty() {
                                             Some(v.doc.to_utf8_string_lossy())
                                         } else {
                                             None
@@ -153,13 +174,13 @@ impl SpecParser {
                                     },
                                     ScSpecUdtUnionCaseV0::TupleV0(t) => UnionCase {
                                         name: t.name.to_utf8_string_lossy(),
-                                        doc: if t.doc.len() > 0 {
+                                        doc: if !t.doc.is_empty() {
                                             Some(t.doc.to_utf8_string_lossy())
                                         } else {
                                             None
                                         },
                                         type_ref: Some(TypeRef::Tuple(
-                                            t.type_.iter().map(|ty| Self::convert_type(ty)).collect(),
+                                            t.type_.iter().map(Self::convert_type).collect(),
                                         )),
                                     },
                                 }
@@ -172,7 +193,7 @@ impl SpecParser {
             ScSpecEntry::UdtEnumV0(enum_def) => {
                 let type_spec = TypeSpec {
                     name: enum_def.name.to_utf8_string_lossy(),
-                    doc: if enum_def.doc.len() > 0 {
+                    doc: if !enum_def.doc.is_empty() {
                         Some(enum_def.doc.to_utf8_string_lossy())
                     } else {
                         None
@@ -183,7 +204,7 @@ impl SpecParser {
                             .iter()
                             .map(|case| EnumVariant {
                                 name: case.name.to_utf8_string_lossy(),
-                                doc: if case.doc.len() > 0 {
+                                doc: if !case.doc.is_empty() {
                                     Some(case.doc.to_utf8_string_lossy())
                                 } else {
                                     None
@@ -196,26 +217,149 @@ impl SpecParser {
                 spec.types.push(type_spec);
             }
             ScSpecEntry::UdtErrorEnumV0(error_enum) => {
-                for case in error_enum.cases.iter() {
-                    spec.errors.push(ErrorSpec {
-                        name: case.name.to_utf8_string_lossy(),
-                        doc: if case.doc.len() > 0 {
-                            Some(case.doc.to_utf8_string_lossy())
+                spec.errors.push(ErrorEnumSpec {
+                    name: error_enum.name.to_utf8_string_lossy(),
+                    doc: if !error_enum.doc.is_empty() {
+                        Some(error_enum.doc.to_utf8_string_lossy())
+                    } else {
+                        None
+                    },
+                    cases: error_enum
+                        .cases
+                        .iter()
+                        .map(|case| ErrorSpec {
+                            name: case.name.to_utf8_string_lossy(),
+                            doc: if !case.doc.is_empty() {
+                                Some(case.doc.to_utf8_string_lossy())
+                            } else {
+                                None
+                            },
+                            code: case.value,
+                        })
+                        .collect(),
+                });
+            }
+            ScSpecEntry::EventV0(event) => {
+                use stellar_xdr::curr::{ScSpecEventDataFormat, ScSpecEventParamLocationV0};
+
+                let mut topics = Vec::new();
+                let mut data = Vec::new();
+                for param in event.params.iter() {
+                    let field = FieldSpec {
+                        name: param.name.to_utf8_string_lossy(),
+                        doc: if !param.doc.is_empty() {
+                            Some(param.doc.to_utf8_string_lossy())
                         } else {
                             None
                         },
-                        code: case.value,
-                    });
+                        type_ref: Self::convert_type(&param.type_),
+                    };
+                    match param.location {
+                        ScSpecEventParamLocationV0::TopicList => topics.push(field),
+                        ScSpecEventParamLocationV0::Data => data.push(field),
+                    }
                 }
-            }
-            ScSpecEntry::EventV0(_) => {
-                // Events are informational, skip for now
+
+                spec.events.push(EventSpec {
+                    name: event.name.to_utf8_string_lossy(),
+                    doc: if !event.doc.is_empty() {
+                        Some(event.doc.to_utf8_string_lossy())
+                    } else {
+                        None
+                    },
+                    prefix_topics: event
+                        .prefix_topics
+                        .iter()
+                        .map(|topic| topic.to_utf8_string_lossy())
+                        .collect(),
+                    topics,
+                    data,
+                    data_format: match event.data_format {
+                        ScSpecEventDataFormat::SingleValue => EventDataFormat::SingleValue,
+                        ScSpecEventDataFormat::Vec => EventDataFormat::Vec,
+                        ScSpecEventDataFormat::Map => EventDataFormat::Map,
+                    },
+                });
             }
         }
 
         Ok(())
     }
 
+    /// Reject names no Soroban contract can declare. Names become identifiers,
+    /// object keys and symbols in generated code, so they must use the symbol
+    /// alphabet (`a-zA-Z0-9_`); doc strings may contain anything.
+    fn check_names(spec: &ContractSpec) -> Result<()> {
+        fn type_names<'a>(type_ref: &'a TypeRef, names: &mut Vec<(&'static str, &'a str)>) {
+            match type_ref {
+                TypeRef::Custom(name) => names.push(("type", name)),
+                TypeRef::Option(inner) | TypeRef::Vec(inner) => type_names(inner, names),
+                TypeRef::Result { ok, err } => {
+                    type_names(ok, names);
+                    type_names(err, names);
+                }
+                TypeRef::Map { key, value } => {
+                    type_names(key, names);
+                    type_names(value, names);
+                }
+                TypeRef::Tuple(types) => types.iter().for_each(|t| type_names(t, names)),
+                _ => {}
+            }
+        }
+        fn field_names<'a>(fields: &'a [FieldSpec], kind: &'static str, names: &mut Vec<(&'static str, &'a str)>) {
+            for field in fields {
+                names.push((kind, &field.name));
+                type_names(&field.type_ref, names);
+            }
+        }
+
+        let mut names = Vec::new();
+        for func in &spec.functions {
+            names.push(("function", func.name.as_str()));
+            for input in &func.inputs {
+                names.push(("parameter", &input.name));
+                type_names(&input.type_ref, &mut names);
+            }
+            if let Some(output) = &func.output {
+                type_names(output, &mut names);
+            }
+        }
+        for type_spec in &spec.types {
+            names.push(("type", &type_spec.name));
+            match &type_spec.definition {
+                TypeDef::Struct { fields } => field_names(fields, "field", &mut names),
+                TypeDef::Enum { variants } => names.extend(variants.iter().map(|v| ("variant", v.name.as_str()))),
+                TypeDef::Union { cases } => {
+                    for case in cases {
+                        names.push(("case", &case.name));
+                        if let Some(type_ref) = &case.type_ref {
+                            type_names(type_ref, &mut names);
+                        }
+                    }
+                }
+            }
+        }
+        for error_enum in &spec.errors {
+            names.push(("error enum", &error_enum.name));
+            names.extend(error_enum.cases.iter().map(|e| ("error", e.name.as_str())));
+        }
+        for event in &spec.events {
+            names.push(("event", &event.name));
+            names.extend(event.prefix_topics.iter().map(|t| ("topic", t.as_str())));
+            field_names(&event.topics, "event parameter", &mut names);
+            field_names(&event.data, "event parameter", &mut names);
+        }
+
+        let is_symbol = |name: &str| !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        match names.into_iter().find(|(_, name)| !is_symbol(name)) {
+            Some((kind, name)) => Err(GeneratorError::InvalidSpec(format!(
+                "Invalid {} name {:?} in contract spec: names may only contain letters, digits and '_'",
+                kind, name
+            ))),
+            None => Ok(()),
+        }
+    }
+
     /// Convert stellar XDR type to our TypeRef
     fn convert_type(typ: &stellar_xdr::curr::ScSpecTypeDef) -> TypeRef {
         use stellar_xdr::curr::ScSpecTypeDef;
@@ -253,7 +397,7 @@ impl SpecParser {
                 value: Box::new(Self::convert_type(&map.value_type)),
             },
             ScSpecTypeDef::Tuple(tuple) => {
-                TypeRef::Tuple(tuple.value_types.iter().map(|t| Self::convert_type(t)).collect())
+                TypeRef::Tuple(tuple.value_types.iter().map(Self::convert_type).collect())
             }
             ScSpecTypeDef::BytesN(bytes_n) => TypeRef::BytesN(bytes_n.n),
             ScSpecTypeDef::Udt(udt) => TypeRef::Custom(udt.name.to_utf8_string_lossy()),
@@ -0,0 +1,190 @@
+//! Built-in Stellar Asset Contract (SAC) specification
+//!
+//! SACs are not backed by WASM, so there is no `contractspecv0` section to
+//! read. This module describes the SEP-41 token interface plus the SAC admin
+//! functions and events (CAP-67) so a normal MCP server can be generated for
+//! assets like USDC or native XLM.
+
+use super::parser::SpecParser;
+use super::types::ContractSpec;
+use crate::error::{GeneratorError, Result};
+use stellar_xdr::curr::{
+    ScSpecEntry, ScSpecEventDataFormat, ScSpecEventParamLocationV0, ScSpecEventParamV0,
+    ScSpecEventV0, ScSpecFunctionInputV0, ScSpecFunctionV0, ScSpecTypeDef, ScSymbol,
+};
+
+/// Build the contract specification of a Stellar Asset Contract
+pub fn stellar_asset_spec() -> Result<ContractSpec> {
+    SpecParser::parse_entries(&stellar_asset_spec_entries()?)
+}
+
+/// Spec entries for the SEP-41 token interface and the SAC admin interface
+pub fn stellar_asset_spec_entries() -> Result<Vec<ScSpecEntry>> {
+    entries().map_err(|e| GeneratorError::InvalidSpec(format!("Invalid built-in SAC spec: {}", e)))
+}
+
+fn entries() -> Result<Vec<ScSpecEntry>, stellar_xdr::curr::Error> {
+    use ScSpecTypeDef::{Address, Bool, String, I128, U32};
+
+    Ok(vec![
+        // SEP-41 token interface
+        function(
+            "allowance",
+            "Returns the allowance for `spender` to transfer from `from`.",
+            &[("from", Address), ("spender", Address)],
+            Some(I128),
+        )?,
+        function(
+            "approve",
+            "Set the allowance by `amount` for `spender` to transfer/burn from `from`. The allowance expires at `expiration_ledger`.",
+            &[("from", Address), ("spender", Address), ("amount", I128), ("expiration_ledger", U32)],
+            None,
+        )?,
+        function("balance", "Returns the balance of `id`.", &[("id", Address)], Some(I128))?,
+        function(
+            "transfer",
+            "Transfer `amount` from `from` to `to`.",
+            &[("from", Address), ("to", Address), ("amount", I128)],
+            None,
+        )?,
+        function(
+            "transfer_from",
+            "Transfer `amount` from `from` to `to`, consuming the allowance of `spender`.",
+            &[("spender", Address), ("from", Address), ("to", Address), ("amount", I128)],
+            None,
+        )?,
+        function("burn", "Burn `amount` from `from`.", &[("from", Address), ("amount", I128)], None)?,
+        function(
+            "burn_from",
+            "Burn `amount` from `from`, consuming the allowance of `spender`.",
+            &[("spender", Address), ("from", Address), ("amount", I128)],
+            None,
+        )?,
+        function("decimals", "Returns the number of decimals used to represent amounts of this token.", &[], Some(U32))?,
+        function("name", "Returns the name for this token.", &[], Some(String))?,
+        function("symbol", "Returns the symbol for this token.", &[], Some(String))?,
+        // SAC admin interface
+        function("admin", "Returns the admin of the contract.", &[], Some(Address))?,
+        function("set_admin", "Sets the administrator to the specified address `new_admin`.", &[("new_admin", Address)], None)?,
+        function(
+            "authorized",
+            "Returns true if `id` is authorized to use its balance.",
+            &[("id", Address)],
+            Some(Bool),
+        )?,
+        function(
+            "set_authorized",
+            "Sets whether the account is authorized to use its balance. If `authorize` is true, `id` should be able to use its balance.",
+            &[("id", Address), ("authorize", Bool)],
+            None,
+        )?,
+        function("mint", "Mints `amount` to `to`.", &[("to", Address), ("amount", I128)], None)?,
+        function("clawback", "Clawback `amount` from `from` account. `amount` is burned in the clawback process.", &[("from", Address), ("amount", I128)], None)?,
+        // Events (CAP-67): every event carries the SEP-11 asset string as its last topic
+        event(
+            "transfer",
+            "Emitted when `amount` is transferred from `from` to `to`.",
+            &[("from", Address), ("to", Address), ("sep0011_asset", String)],
+            &[("amount", I128)],
+            ScSpecEventDataFormat::SingleValue,
+        )?,
+        event(
+            "approve",
+            "Emitted when `from` sets the allowance of `spender`.",
+            &[("from", Address), ("spender", Address), ("sep0011_asset", String)],
+            &[("amount", I128), ("expiration_ledger", U32)],
+            ScSpecEventDataFormat::Vec,
+        )?,
+        event(
+            "mint",
+            "Emitted when `amount` is minted to `to`.",
+            &[("to", Address), ("sep0011_asset", String)],
+            &[("amount", I128)],
+            ScSpecEventDataFormat::SingleValue,
+        )?,
+        event(
+            "burn",
+            "Emitted when `amount` is burned from `from`.",
+            &[("from", Address), ("sep0011_asset", String)],
+            &[("amount", I128)],
+            ScSpecEventDataFormat::SingleValue,
+        )?,
+        event(
+            "clawback",
+            "Emitted when `amount` is clawed back from `from`.",
+            &[("from", Address), ("sep0011_asset", String)],
+            &[("amount", I128)],
+            ScSpecEventDataFormat::SingleValue,
+        )?,
+        event(
+            "set_admin",
+            "Emitted when the admin is changed to `new_admin`.",
+            &[("sep0011_asset", String)],
+            &[("new_admin", Address)],
+            ScSpecEventDataFormat::SingleValue,
+        )?,
+        event(
+            "set_authorized",
+            "Emitted when the authorization of `id` changes.",
+            &[("id", Address), ("sep0011_asset", String)],
+            &[("authorize", Bool)],
+            ScSpecEventDataFormat::SingleValue,
+        )?,
+    ])
+}
+
+fn function(
+    name: &str,
+    doc: &str,
+    inputs: &[(&str, ScSpecTypeDef)],
+    output: Option<ScSpecTypeDef>,
+) -> Result<ScSpecEntry, stellar_xdr::curr::Error> {
+    let inputs = inputs
+        .iter()
+        .map(|(name, type_)| {
+            Ok(ScSpecFunctionInputV0 {
+                doc: "".try_into()?,
+                name: (*name).try_into()?,
+                type_: type_.clone(),
+            })
+        })
+        .collect::<Result<Vec<_>, stellar_xdr::curr::Error>>()?;
+
+    Ok(ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+        doc: doc.try_into()?,
+        name: ScSymbol(name.try_into()?),
+        inputs: inputs.try_into()?,
+        outputs: output.into_iter().collect::<Vec<_>>().try_into()?,
+    }))
+}
+
+fn event(
+    name: &str,
+    doc: &str,
+    topics: &[(&str, ScSpecTypeDef)],
+    data: &[(&str, ScSpecTypeDef)],
+    data_format: ScSpecEventDataFormat,
+) -> Result<ScSpecEntry, stellar_xdr::curr::Error> {
+    let param = |(name, type_): &(&str, ScSpecTypeDef), location| {
+        Ok(ScSpecEventParamV0 {
+            doc: "".try_into()?,
+            name: (*name).try_into()?,
+            type_: type_.clone(),
+            location,
+        })
+    };
+    let params = topics
+        .iter()
+        .map(|p| param(p, ScSpecEventParamLocationV0::TopicList))
+        .chain(data.iter().map(|p| param(p, ScSpecEventParamLocationV0::Data)))
+        .collect::<Result<Vec<_>, stellar_xdr::curr::Error>>()?;
+
+    Ok(ScSpecEntry::EventV0(ScSpecEventV0 {
+        doc: doc.try_into()?,
+        lib: "".try_into()?,
+        name: ScSymbol(name.try_into()?),
+        prefix_topics: vec![ScSymbol(name.try_into()?)].try_into()?,
+        params: params.try_into()?,
+        data_format,
+    }))
+}
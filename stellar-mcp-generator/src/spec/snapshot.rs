@@ -0,0 +1,83 @@
+//! Contract spec snapshot stored in generated projects
+//!
+//! `.stellar-mcp/spec.json` records the `ContractSpec` a server was generated
+//! from, so later contract versions can be compared against it (servers with
+//! several contracts keep one snapshot per contract in `.stellar-mcp/specs/`).
+//! The WASM hash
+//! is also embedded in the generated server, which checks it against the
+//! deployed contract instance to detect upgrades.
+
+use super::types::ContractSpec;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::error::{GeneratorError, Result};
+use std::path::Path;
+
+/// Location of the snapshot, relative to the generated project root
+pub const SNAPSHOT_PATH: &str = ".stellar-mcp/spec.json";
+
+/// Per-contract snapshots of multi-contract servers, relative to the project root
+pub const SNAPSHOT_DIR: &str = ".stellar-mcp/specs";
+
+/// Snapshot location of one contract of a multi-contract server
+pub fn contract_snapshot_path(contract_name: &str) -> String {
+    format!("{}/{}.json", SNAPSHOT_DIR, contract_name)
+}
+
+/// Serialized contract spec plus where it came from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecSnapshot {
+    /// Version of the generator that wrote the snapshot
+    pub generator_version: String,
+    /// Contract ID the server was generated for (if any)
+    pub contract_id: Option<String>,
+    /// Network name
+    pub network: Option<String>,
+    /// Hex-encoded SHA-256 hash of the contract WASM (None for Stellar Asset Contracts)
+    pub wasm_hash: Option<String>,
+    /// The contract specification
+    pub spec: ContractSpec,
+}
+
+impl SpecSnapshot {
+    /// Read a snapshot file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(GeneratorError::io(format!("Failed to read spec snapshot '{}'", path.display())))?;
+        serde_json::from_str(&content).map_err(|e| {
+            GeneratorError::InvalidSpec(format!("Invalid spec snapshot '{}': {}", path.display(), e))
+        })
+    }
+
+    /// Read the snapshot of a generated project
+    pub fn load_from_project(project_dir: &Path) -> Result<Self> {
+        Self::load(&project_dir.join(SNAPSHOT_PATH))
+    }
+
+    /// Write the snapshot into a generated project, creating `.stellar-mcp/`
+    pub fn save_to_project(&self, project_dir: &Path) -> Result<()> {
+        self.save_at(project_dir, SNAPSHOT_PATH)
+    }
+
+    /// Write the snapshot to `relative_path` inside a generated project
+    pub fn save_at(&self, project_dir: &Path, relative_path: &str) -> Result<()> {
+        let path = project_dir.join(relative_path);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(GeneratorError::io(format!("Failed to create '{}'", dir.display())))?;
+        }
+        std::fs::write(&path, self.to_json()?)
+            .map_err(GeneratorError::io(format!("Failed to write spec snapshot '{}'", path.display())))?;
+        Ok(())
+    }
+
+    /// Snapshot file content: the snapshot as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)? + "\n")
+    }
+}
+
+/// Hex-encoded SHA-256 hash of a contract WASM, as stored on the ledger
+pub fn wasm_hash(wasm: &[u8]) -> String {
+    hex::encode(Sha256::digest(wasm))
+}
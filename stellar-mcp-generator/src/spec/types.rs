@@ -1,6 +1,8 @@
 //! Types representing parsed contract specifications
 
-use serde::{Deserialize, Serialize};
+use super::json_types;
+use crate::codegen::emit::py_string;
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Parsed contract specification
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,8 +13,11 @@ pub struct ContractSpec {
     pub functions: Vec<FunctionSpec>,
     /// Custom types defined in the contract
     pub types: Vec<TypeSpec>,
-    /// Contract errors
-    pub errors: Vec<ErrorSpec>,
+    /// Contract error enums, with their cases
+    #[serde(deserialize_with = "deserialize_error_enums")]
+    pub errors: Vec<ErrorEnumSpec>,
+    /// Contract events
+    pub events: Vec<EventSpec>,
     /// Raw XDR spec entries (base64 encoded) for SDK ContractSpec
     pub raw_spec_entries: Vec<String>,
 }
@@ -24,11 +29,19 @@ impl ContractSpec {
             functions: Vec::new(),
             types: Vec::new(),
             errors: Vec::new(),
+            events: Vec::new(),
             raw_spec_entries: Vec::new(),
         }
     }
 }
 
+impl ContractSpec {
+    /// Cases of every error enum, in spec order
+    pub fn error_cases(&self) -> impl Iterator<Item = &ErrorSpec> {
+        self.errors.iter().flat_map(|e| e.cases.iter())
+    }
+}
+
 impl Default for ContractSpec {
     fn default() -> Self {
         Self::new()
@@ -46,6 +59,19 @@ pub struct FunctionSpec {
     pub inputs: Vec<ParameterSpec>,
     /// Return type
     pub output: Option<TypeRef>,
+    /// Whether calling the function changes contract state
+    #[serde(default)]
+    pub kind: FunctionKind,
+}
+
+/// Read-only vs state-changing classification of a contract function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FunctionKind {
+    /// Only reads ledger state: the simulated result is the answer
+    ReadOnly,
+    /// Writes ledger state or requires authorization: the transaction must be signed
+    #[default]
+    Write,
 }
 
 /// Parameter specification
@@ -60,7 +86,7 @@ pub struct ParameterSpec {
 }
 
 /// Type reference
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TypeRef {
     /// Primitive types
     Bool,
@@ -110,149 +136,36 @@ pub enum TypeRef {
 }
 
 impl TypeRef {
-    /// Convert to TypeScript type string
+    /// TypeScript type of the JSON argument an MCP client sends for this type
+    /// (see `to_json_schema`)
     pub fn to_typescript(&self) -> String {
-        match self {
-            TypeRef::Bool => "boolean".to_string(),
-            TypeRef::Void => "void".to_string(),
-            TypeRef::Status => "number".to_string(),
-            TypeRef::U32 | TypeRef::I32 => "number".to_string(),
-            TypeRef::U64 | TypeRef::I64 => "bigint".to_string(),
-            TypeRef::Timepoint | TypeRef::Duration => "bigint".to_string(),
-            TypeRef::U128 | TypeRef::I128 | TypeRef::U256 | TypeRef::I256 => "bigint".to_string(),
-            TypeRef::Bytes => "string".to_string(), // Hex or base64 encoded
-            TypeRef::String | TypeRef::Symbol => "string".to_string(),
-            TypeRef::Address => "string".to_string(),
-            TypeRef::Option(inner) => format!("{} | null", inner.to_typescript()),
-            TypeRef::Result { ok, .. } => ok.to_typescript(),
-            TypeRef::Vec(inner) => format!("{}[]", inner.to_typescript()),
-            TypeRef::Map { key, value } => {
-                format!("Map<{}, {}>", key.to_typescript(), value.to_typescript())
-            }
-            TypeRef::Tuple(types) => {
-                let ts: Vec<String> = types.iter().map(|t| t.to_typescript()).collect();
-                format!("[{}]", ts.join(", "))
-            }
-            TypeRef::BytesN(_) => "string".to_string(), // Hex encoded
-            TypeRef::Custom(name) => name.clone(),
-        }
+        json_types::typescript(&self.to_json_schema(), "")
     }
 
-    /// Convert to Zod schema string
+    /// Zod schema validating the JSON argument for this type
     pub fn to_zod(&self) -> String {
-        match self {
-            TypeRef::Bool => "z.boolean()".to_string(),
-            TypeRef::Void => "z.void()".to_string(),
-            TypeRef::Status => "z.number()".to_string(),
-            TypeRef::U32 | TypeRef::I32 => "z.number()".to_string(),
-            TypeRef::U64 | TypeRef::I64 => "z.string()".to_string(), // BigInt as string
-            TypeRef::Timepoint | TypeRef::Duration => "z.string()".to_string(),
-            TypeRef::U128 | TypeRef::I128 | TypeRef::U256 | TypeRef::I256 => {
-                "z.string()".to_string()
-            }
-            TypeRef::Bytes => "z.string()".to_string(), // Base64 encoded
-            TypeRef::String | TypeRef::Symbol => "z.string()".to_string(),
-            TypeRef::Address => "z.string()".to_string(),
-            TypeRef::Option(inner) => format!("{}.nullable()", inner.to_zod()),
-            TypeRef::Result { ok, .. } => ok.to_zod(),
-            TypeRef::Vec(inner) => format!("z.array({})", inner.to_zod()),
-            TypeRef::Map { key, value } => {
-                format!("z.map({}, {})", key.to_zod(), value.to_zod())
-            }
-            TypeRef::Tuple(types) => {
-                let zods: Vec<String> = types.iter().map(|t| t.to_zod()).collect();
-                format!("z.tuple([{}])", zods.join(", "))
-            }
-            TypeRef::BytesN(n) => format!("z.string().length({})", n * 2), // Hex encoded
-            TypeRef::Custom(name) => format!("{}Schema", name),
-        }
+        json_types::zod(&self.to_json_schema(), "")
     }
 
-    /// Convert to Python/Pydantic type hint
+    /// Python/Pydantic type hint of the JSON argument for this type
     pub fn to_pydantic(&self) -> String {
-        match self {
-            TypeRef::Bool => "bool".to_string(),
-            TypeRef::Void => "None".to_string(),
-            TypeRef::Status => "int".to_string(),
-            TypeRef::U32 | TypeRef::I32 => "int".to_string(),
-            TypeRef::U64 | TypeRef::I64 => "int".to_string(),
-            TypeRef::Timepoint | TypeRef::Duration => "int".to_string(),
-            TypeRef::U128 | TypeRef::I128 | TypeRef::U256 | TypeRef::I256 => "str".to_string(), // BigInt as string
-            TypeRef::Bytes => "bytes".to_string(),
-            TypeRef::String | TypeRef::Symbol => "str".to_string(),
-            TypeRef::Address => "str".to_string(),
-            TypeRef::Option(inner) => format!("Optional[{}]", inner.to_pydantic()),
-            TypeRef::Result { ok, .. } => ok.to_pydantic(), // Result mapped to ok type (errors handled separately)
-            TypeRef::Vec(inner) => format!("List[{}]", inner.to_pydantic()),
-            TypeRef::Map { key, value } => {
-                format!("Dict[{}, {}]", key.to_pydantic(), value.to_pydantic())
-            }
-            TypeRef::Tuple(types) => {
-                let type_strs: Vec<String> = types.iter().map(|t| t.to_pydantic()).collect();
-                format!("Tuple[{}]", type_strs.join(", "))
-            }
-            TypeRef::BytesN(_) => "str".to_string(), // Hex string
-            TypeRef::Custom(name) => format!("{}Schema", name), // Pydantic model name
-        }
+        json_types::pydantic(&self.to_json_schema())
     }
 
-    /// Convert to Pydantic Field definition
+    /// Pydantic field definition with the constraints of the JSON Schema.
+    /// Options and fields that aren't `required` default to `None`.
     pub fn to_pydantic_field(&self, field_name: &str, description: &str, required: bool) -> String {
-        let field_type = self.to_pydantic();
-        let desc_safe = description.replace('"', "\\\"").replace('\n', " ");
-
-        match self {
-            TypeRef::Address => {
-                // Validate Stellar address (56 chars, starts with G or C)
-                if required {
-                    format!(
-                        "{}: str = Field(..., min_length=56, max_length=56, description=\"{}\")",
-                        field_name, desc_safe
-                    )
-                } else {
-                    format!(
-                        "{}: Optional[str] = Field(None, min_length=56, max_length=56, description=\"{}\")",
-                        field_name, desc_safe
-                    )
-                }
-            }
-            TypeRef::BytesN(n) => {
-                // Validate hex string length (2 hex chars per byte)
-                let hex_len = n * 2;
-                if required {
-                    format!(
-                        "{}: str = Field(..., min_length={}, max_length={}, description=\"{}\")",
-                        field_name, hex_len, hex_len, desc_safe
-                    )
-                } else {
-                    format!(
-                        "{}: Optional[str] = Field(None, min_length={}, max_length={}, description=\"{}\")",
-                        field_name, hex_len, hex_len, desc_safe
-                    )
-                }
-            }
-            TypeRef::Option(_) => {
-                // Optional field with default None
-                format!(
-                    "{}: {} = Field(None, description=\"{}\")",
-                    field_name, field_type, desc_safe
-                )
-            }
-            _ => {
-                // Standard field
-                if required {
-                    format!(
-                        "{}: {} = Field(..., description=\"{}\")",
-                        field_name, field_type, desc_safe
-                    )
-                } else {
-                    format!(
-                        "{}: {} = Field(None, description=\"{}\")",
-                        field_name, field_type, desc_safe
-                    )
-                }
-            }
+        let schema = self.to_json_schema();
+        let mut field_type = json_types::pydantic(&schema);
+        let optional = !required || self.is_optional();
+        if optional && !self.is_optional() {
+            field_type = format!("Optional[{}]", field_type);
         }
+
+        let mut arguments = vec![if optional { "None" } else { "..." }.to_string()];
+        arguments.extend(json_types::pydantic_constraints(&schema));
+        arguments.push(format!("description={}", py_string(description)));
+        format!("{}: {} = Field({})", field_name, field_type, arguments.join(", "))
     }
 }
 
@@ -311,7 +224,18 @@ pub struct UnionCase {
     pub type_ref: Option<TypeRef>,
 }
 
-/// Error specification
+/// Error enum specification (a `#[contracterror]` enum)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorEnumSpec {
+    /// Enum name
+    pub name: String,
+    /// Enum documentation
+    pub doc: Option<String>,
+    /// Error cases
+    pub cases: Vec<ErrorSpec>,
+}
+
+/// Error case specification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorSpec {
     /// Error name
@@ -321,3 +245,59 @@ pub struct ErrorSpec {
     /// Error code
     pub code: u32,
 }
+
+/// Read `ContractSpec::errors`. Snapshots written before error enums were
+/// kept list the cases on their own; those are read as one enum named `Error`.
+fn deserialize_error_enums<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<ErrorEnumSpec>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Entry {
+        Enum(ErrorEnumSpec),
+        Case(ErrorSpec),
+    }
+
+    let mut enums = Vec::new();
+    let mut cases = Vec::new();
+    for entry in Vec::<Entry>::deserialize(deserializer)? {
+        match entry {
+            Entry::Enum(error_enum) => enums.push(error_enum),
+            Entry::Case(case) => cases.push(case),
+        }
+    }
+    if !cases.is_empty() {
+        enums.push(ErrorEnumSpec {
+            name: "Error".to_string(),
+            doc: None,
+            cases,
+        });
+    }
+    Ok(enums)
+}
+
+/// Event specification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSpec {
+    /// Event name
+    pub name: String,
+    /// Event documentation
+    pub doc: Option<String>,
+    /// Static symbol topics emitted ahead of the parameter topics
+    pub prefix_topics: Vec<String>,
+    /// Parameters published as topics (in emission order)
+    pub topics: Vec<FieldSpec>,
+    /// Parameters published in the event data
+    pub data: Vec<FieldSpec>,
+    /// Layout of the event data
+    pub data_format: EventDataFormat,
+}
+
+/// Layout of the data payload of an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventDataFormat {
+    /// A single value (the only data parameter)
+    SingleValue,
+    /// A vector of values, in parameter order
+    Vec,
+    /// A map keyed by parameter name
+    Map,
+}
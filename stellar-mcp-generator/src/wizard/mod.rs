@@ -1,8 +1,10 @@
 //! Interactive wizard for MCP server generation
 
+use crate::config::{ContractConfig, FrontendConfig, ProjectConfig, CONFIG_FILE_NAME};
+use crate::error::Result;
 use console::{style, Emoji, Term};
-use dialoguer::{theme::ColorfulTheme, Input, Select};
-use std::path::PathBuf;
+use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
+use std::path::{Path, PathBuf};
 
 // Emojis for visual enhancement
 static ROCKET: Emoji = Emoji("🚀", ">");
@@ -24,8 +26,9 @@ pub struct GeneratorConfig {
     pub with_frontend: bool,
 }
 
-/// Run the interactive wizard to collect generation configuration
-pub async fn run_wizard() -> Result<GeneratorConfig, Box<dyn std::error::Error>> {
+/// Run the interactive wizard to collect generation configuration, offering
+/// `languages` (the first is the default)
+pub async fn run_wizard(languages: &[&str]) -> Result<GeneratorConfig> {
     let term = Term::stdout();
     let theme = ColorfulTheme::default();
 
@@ -84,10 +87,9 @@ pub async fn run_wizard() -> Result<GeneratorConfig, Box<dyn std::error::Error>>
     // Step 3: Language
     println!("{} {}", GEAR, style("Step 3: Language").cyan().bold());
     println!();
-    let languages = vec!["typescript", "python"];
     let lang_idx = Select::with_theme(&theme)
         .with_prompt("Select the target language")
-        .items(&languages)
+        .items(languages)
         .default(0)
         .interact()?;
 
@@ -160,10 +162,7 @@ pub async fn run_wizard() -> Result<GeneratorConfig, Box<dyn std::error::Error>>
     println!("  Server Name: {}", style(&server_name).yellow());
     println!();
 
-    println!("{} {}", SPARKLES, style("Starting generation...").green());
-    println!();
-
-    Ok(GeneratorConfig {
+    let config = GeneratorConfig {
         contract_id,
         network,
         rpc_url,
@@ -173,5 +172,48 @@ pub async fn run_wizard() -> Result<GeneratorConfig, Box<dyn std::error::Error>>
         server_name,
         lang,
         with_frontend,
-    })
+    };
+
+    // Offer to save the answers so the next run (or CI) needs no prompts
+    let config_path = Path::new(CONFIG_FILE_NAME);
+    let prompt = if config_path.exists() {
+        format!("Overwrite {} with these settings?", CONFIG_FILE_NAME)
+    } else {
+        format!("Save these settings to {} for future runs?", CONFIG_FILE_NAME)
+    };
+    let save = Confirm::with_theme(&theme)
+        .with_prompt(prompt)
+        .default(false)
+        .interact()?;
+    if save {
+        config.to_project_config().save(config_path)?;
+        println!("  Saved {}", style(CONFIG_FILE_NAME).yellow());
+    }
+    println!();
+
+    println!("{} {}", SPARKLES, style("Starting generation...").green());
+    println!();
+
+    Ok(config)
+}
+
+impl GeneratorConfig {
+    /// Convert the wizard answers into a `stellar-mcp.toml` configuration
+    pub fn to_project_config(&self) -> ProjectConfig {
+        ProjectConfig {
+            network: Some(self.network.clone()),
+            rpc_url: self.rpc_url.clone(),
+            network_passphrase: self.network_passphrase.clone(),
+            lang: Some(self.lang.clone()),
+            output: Some(self.output.clone()),
+            server_name: Some(self.server_name.clone()),
+            contract: ContractConfig {
+                id: Some(self.contract_id.clone()),
+                wasm: None,
+                name: self.name.clone(),
+            },
+            frontend: FrontendConfig { enabled: self.with_frontend },
+            ..Default::default()
+        }
+    }
 }
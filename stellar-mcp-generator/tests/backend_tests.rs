@@ -0,0 +1,205 @@
+//! Tests for target language backends and the backend registry
+
+mod common;
+
+use std::path::Path;
+use stellar_mcp_generator::generator::names::NameCollision;
+use stellar_mcp_generator::generator::tool_manifest::{tool_manifest, ToolNaming};
+use stellar_mcp_generator::generator::{
+    BackendRegistry, GeneratedProject, ProjectOptions, ServedContract, TargetBackend,
+};
+use stellar_mcp_generator::spec::TypeRef;
+use stellar_mcp_generator::{GeneratorError, NetworkConfig};
+use common::token;
+
+/// A third-party backend: one file listing the tools, from its own template
+struct ListBackend;
+
+impl TargetBackend for ListBackend {
+    fn name(&self) -> &str {
+        "list"
+    }
+
+    fn templates(&self) -> &[(&'static str, &'static str)] {
+        &[("list/tools.txt", "{{#each tools}}{{kebab_case name}}: {{type}}\n{{/each}}")]
+    }
+
+    fn type_name(&self, type_ref: &TypeRef) -> String {
+        type_ref.to_json_schema()["type"].as_str().unwrap_or("any").to_string()
+    }
+
+    fn find_collisions(&self, contracts: &[ServedContract]) -> Vec<NameCollision> {
+        if contracts[0].spec.functions.iter().any(|f| f.name == "collide") {
+            vec![NameCollision {
+                scope: "tool names".to_string(),
+                name: "collide".to_string(),
+                sources: vec!["function `collide`".to_string(), "built-in".to_string()],
+            }]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn render(&self, options: &ProjectOptions) -> stellar_mcp_generator::Result<GeneratedProject> {
+        let tools: Vec<serde_json::Value> = options.contracts[0]
+            .spec
+            .functions
+            .iter()
+            .map(|f| {
+                serde_json::json!({ "name": f.name, "type": self.type_name(f.output.as_ref().unwrap_or(&TypeRef::Void)) })
+            })
+            .collect();
+        let mut project = GeneratedProject::new();
+        project.add("tools.txt", options.templates.render("list/tools.txt", &serde_json::json!({ "tools": tools }))?);
+        Ok(project)
+    }
+
+    fn next_steps(&self, output: &Path, _http: bool) -> Vec<String> {
+        vec![format!("cat {}/tools.txt", output.display())]
+    }
+}
+
+fn options<'a>(
+    lang: &'a str,
+    network: &'a NetworkConfig,
+    contracts: &'a [ServedContract],
+    templates: &'a stellar_mcp_generator::generator::TemplateRenderer,
+) -> ProjectOptions<'a> {
+    ProjectOptions {
+        lang,
+        project_name: "token",
+        server_name: "token-mcp",
+        network,
+        contracts,
+        templates,
+        with_frontend: false,
+        with_rest: false,
+    }
+}
+
+#[test]
+fn test_default_registry_has_builtin_backends() {
+    let registry = BackendRegistry::default();
+    assert_eq!(registry.names(), ["typescript", "python", "rust", "go"]);
+
+    let ts = registry.get("typescript").unwrap();
+    assert!(ts.templates().iter().any(|(name, _)| *name == "index.ts"));
+    assert_eq!(ts.next_steps(Path::new("out"), false).last().unwrap(), "pnpm start");
+    let rust = registry.get("rust").unwrap();
+    assert_eq!(rust.next_steps(Path::new("out"), true).last().unwrap(), "USE_HTTP=true PORT=3000 cargo run --release");
+    let go = registry.get("go").unwrap();
+    assert_eq!(go.next_steps(Path::new("out"), false).last().unwrap(), "go run .");
+
+    match registry.get("java") {
+        Err(GeneratorError::InvalidInput(message)) => {
+            assert!(message.contains("Supported languages: typescript, python, rust, go"), "{}", message)
+        }
+        other => panic!("expected InvalidInput, got {:?}", other.map(|b| b.name().to_string())),
+    }
+}
+
+#[test]
+fn test_registered_backend_renders_with_its_templates() {
+    let mut registry = BackendRegistry::default();
+    registry.register(ListBackend);
+    assert_eq!(registry.names(), ["typescript", "python", "rust", "go", "list"]);
+
+    let templates = registry.template_renderer(None).unwrap();
+    assert!(templates.template_names().any(|name| name == "list/tools.txt"));
+    assert!(templates.template_names().any(|name| name == "python/server.py"));
+
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let contracts = vec![token()];
+    let project = registry.render_project(&options("list", &network, &contracts, &templates)).unwrap();
+    // The backend's files, plus the spec snapshot every project records
+    let paths: Vec<&Path> = project.paths().collect();
+    assert_eq!(paths, [Path::new(".stellar-mcp/spec.json"), Path::new("tools.txt")]);
+    let tools = project.get_str("tools.txt").unwrap();
+    assert!(tools.contains("balance: string\n"), "{}", tools);
+    assert!(tools.contains("set-admin: null\n"), "{}", tools);
+
+    // The built-in backends are unaffected
+    let ts = registry.render_project(&options("typescript", &network, &contracts, &templates)).unwrap();
+    assert!(ts.get("src/index.ts").is_some());
+}
+
+#[test]
+fn test_registered_backend_templates_can_be_overridden() {
+    let mut registry = BackendRegistry::empty();
+    registry.register(ListBackend);
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("list")).unwrap();
+    std::fs::write(dir.path().join("list/tools.txt.hbs"), "{{#each tools}}{{upper_case name}}\n{{/each}}").unwrap();
+    let templates = registry.template_renderer(Some(dir.path())).unwrap();
+
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let contracts = vec![token()];
+    let project = registry.render_project(&options("list", &network, &contracts, &templates)).unwrap();
+    assert!(project.get_str("tools.txt").unwrap().contains("TRANSFER\n"));
+
+    // Only the registered backends' templates exist
+    std::fs::write(dir.path().join("index.ts.hbs"), "").unwrap();
+    let err = registry.template_renderer(Some(dir.path())).err().unwrap().to_string();
+    assert!(err.contains("Unknown template"), "{}", err);
+}
+
+#[test]
+fn test_backend_collisions_stop_rendering() {
+    let mut registry = BackendRegistry::empty();
+    registry.register(ListBackend);
+    let templates = registry.template_renderer(None).unwrap();
+
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let mut contracts = vec![token()];
+    contracts[0].spec.functions[0].name = "collide".to_string();
+    let err = registry.render_project(&options("list", &network, &contracts, &templates)).unwrap_err();
+    assert!(matches!(err, GeneratorError::NameCollisions { ref collisions } if collisions.len() == 1), "{:?}", err);
+}
+
+/// A third-party backend whose server names tools like the Python one
+struct SnakeBackend;
+
+impl TargetBackend for SnakeBackend {
+    fn name(&self) -> &str {
+        "snake"
+    }
+
+    fn templates(&self) -> &[(&'static str, &'static str)] {
+        &[]
+    }
+
+    fn type_name(&self, type_ref: &TypeRef) -> String {
+        format!("{:?}", type_ref)
+    }
+
+    fn tool_naming(&self) -> ToolNaming {
+        ToolNaming::Python
+    }
+
+    fn render(&self, _options: &ProjectOptions) -> stellar_mcp_generator::Result<GeneratedProject> {
+        Ok(GeneratedProject::new())
+    }
+
+    fn next_steps(&self, _output: &Path, _http: bool) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+#[test]
+fn test_backend_naming_applies_to_tool_manifest() {
+    let contracts = vec![token()];
+    let names = |manifest: serde_json::Value| -> Vec<String> {
+        manifest["tools"].as_array().unwrap().iter().map(|t| t["name"].as_str().unwrap().to_string()).collect()
+    };
+
+    let kebab = tool_manifest(&ListBackend, &contracts);
+    assert_eq!(kebab["language"], "list");
+    assert!(names(kebab).contains(&"set-admin".to_string()));
+
+    let snake = tool_manifest(&SnakeBackend, &contracts);
+    assert_eq!(snake["language"], "snake");
+    assert!(names(snake.clone()).contains(&"set_admin".to_string()));
+    let events = snake["tools"].as_array().unwrap().iter().find(|t| t.get("function").is_none()).unwrap();
+    assert!(events["inputSchema"]["properties"].get("start_ledger").is_some());
+}
@@ -0,0 +1,26 @@
+//! Helpers shared by the integration tests
+
+// Each test crate uses only some of them
+#![allow(dead_code)]
+
+use stellar_mcp_generator::generator::ServedContract;
+use stellar_mcp_generator::spec::sac;
+use stellar_mcp_generator::spec::types::{FieldSpec, TypeRef};
+
+/// Contract ID the `token` contract is served with
+pub const TOKEN_ID: &str = "CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75";
+
+/// Struct or event field without a doc comment
+pub fn field(name: &str, type_ref: TypeRef) -> FieldSpec {
+    FieldSpec { name: name.to_string(), doc: None, type_ref }
+}
+
+/// Stellar Asset Contract served as `token`
+pub fn token() -> ServedContract {
+    ServedContract {
+        name: "token".to_string(),
+        contract_id: TOKEN_ID.to_string(),
+        spec: sac::stellar_asset_spec().unwrap(),
+        wasm_hash: None,
+    }
+}
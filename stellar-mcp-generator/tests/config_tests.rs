@@ -0,0 +1,229 @@
+//! Tests for the stellar-mcp.toml project configuration
+
+use clap::Parser;
+use std::path::PathBuf;
+use stellar_mcp_generator::config::{ProjectConfig, CONFIG_FILE_NAME};
+use stellar_mcp_generator::generator::contracts::validate_names;
+use stellar_mcp_generator::generator::ServedContract;
+use stellar_mcp_generator::spec::sac;
+use stellar_mcp_generator::GenerateArgs;
+
+const SAMPLE: &str = r#"
+network = "mainnet"
+lang = "python"
+output = "./token-mcp"
+server_name = "usdc"
+
+[contract]
+id = "CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75"
+name = "usdc"
+
+[functions]
+exclude = ["clawback"]
+read_only = ["balance"]
+
+[tools.transfer]
+description = "Send USDC to another account"
+
+[frontend]
+enabled = true
+
+[rest]
+enabled = true
+"#;
+
+fn args(flags: &[&str]) -> GenerateArgs {
+    GenerateArgs::try_parse_from(std::iter::once("generate").chain(flags.iter().copied()))
+        .expect("flags should parse")
+}
+
+#[test]
+fn test_config_fills_missing_flags() {
+    let config = ProjectConfig::from_toml(SAMPLE).unwrap();
+    let mut args = args(&["--lang", "typescript"]);
+    config.apply_to_args(&mut args);
+
+    assert_eq!(args.lang.as_deref(), Some("typescript"), "flags take precedence over the file");
+    assert_eq!(args.network.as_deref(), Some("mainnet"));
+    assert_eq!(args.output, Some(PathBuf::from("./token-mcp")));
+    assert_eq!(args.name.as_deref(), Some("usdc"));
+    assert_eq!(args.read_only, vec!["balance".to_string()]);
+    assert_eq!(args.with_frontend, Some(true));
+    assert_eq!(args.with_rest, Some(true));
+    assert!(args.contract_id.as_deref().unwrap().starts_with("CCW67"));
+}
+
+#[test]
+fn test_command_line_contract_replaces_the_configured_one() {
+    let config = ProjectConfig::from_toml("[contract]\nwasm = \"x.wasm\"\nname = \"local\"").unwrap();
+    let id = "CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75";
+    let mut by_id = args(&["--contract-id", id]);
+    config.apply_to_args(&mut by_id);
+
+    assert_eq!(by_id.contract_id.as_deref(), Some(id));
+    assert_eq!(by_id.wasm, None, "the configured WASM belongs to the configured contract");
+    assert_eq!(by_id.name, None, "so does its name");
+
+    let config = ProjectConfig::from_toml(SAMPLE).unwrap();
+    let mut by_wasm = args(&["--wasm", "y.wasm"]);
+    config.apply_to_args(&mut by_wasm);
+    assert_eq!(by_wasm.wasm, Some(PathBuf::from("y.wasm")));
+    assert_eq!(by_wasm.contract_id, None);
+    assert_eq!(by_wasm.name, None);
+}
+
+#[test]
+fn test_command_line_turns_off_configured_frontend_and_rest() {
+    let config = ProjectConfig::from_toml(SAMPLE).unwrap();
+    let mut args = args(&["--with-frontend=false", "--with-rest=false"]);
+    config.apply_to_args(&mut args);
+    assert_eq!(args.with_frontend, Some(false));
+    assert_eq!(args.with_rest, Some(false));
+
+    let mut args = GenerateArgs::try_parse_from(["generate", "--with-rest"]).unwrap();
+    assert_eq!(args.with_rest, Some(true), "a bare flag turns the option on");
+    ProjectConfig::default().apply_to_args(&mut args);
+    assert_eq!(args.with_rest, Some(true));
+    assert_eq!(args.with_frontend, Some(false));
+}
+
+#[test]
+fn test_config_selects_functions_and_overrides_descriptions() {
+    let config = ProjectConfig::from_toml(SAMPLE).unwrap();
+    let mut spec = sac::stellar_asset_spec().unwrap();
+    config.apply_to_spec(&mut spec).unwrap();
+
+    assert!(!spec.functions.iter().any(|f| f.name == "clawback"), "excluded functions are dropped");
+    let transfer = spec.functions.iter().find(|f| f.name == "transfer").unwrap();
+    assert_eq!(transfer.doc.as_deref(), Some("Send USDC to another account"));
+
+    let include_only = ProjectConfig::from_toml("[functions]\ninclude = [\"balance\", \"decimals\"]").unwrap();
+    let mut spec = sac::stellar_asset_spec().unwrap();
+    include_only.apply_to_spec(&mut spec).unwrap();
+    let names: Vec<&str> = spec.functions.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["balance", "decimals"]);
+}
+
+#[test]
+fn test_config_rejects_unknown_settings() {
+    assert!(ProjectConfig::from_toml("langauge = \"python\"").is_err(), "typos should not be ignored");
+
+    let unknown_tool = ProjectConfig::from_toml("[tools.nope]\ndescription = \"x\"").unwrap();
+    let mut spec = sac::stellar_asset_spec().unwrap();
+    assert!(unknown_tool.apply_to_spec(&mut spec).is_err());
+}
+
+#[test]
+fn test_config_round_trip_and_relative_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(CONFIG_FILE_NAME);
+
+    let config = ProjectConfig::from_toml(SAMPLE).unwrap();
+    config.save(&path).unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert!(written.contains("[contract]"), "{}", written);
+    assert!(!written.contains("rpc_url"), "unset settings should be omitted: {}", written);
+
+    let loaded = ProjectConfig::load(&path).unwrap();
+    assert_eq!(loaded.output, Some(dir.path().join("./token-mcp")),
+        "paths are relative to the configuration file");
+    assert_eq!(loaded.contract, config.contract);
+    assert_eq!(loaded.tools, config.tools);
+}
+
+const MULTI: &str = r#"
+server_name = "defi"
+
+[[contracts]]
+name = "token"
+id = "CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75"
+
+[[contracts]]
+name = "amm"
+wasm = "target/amm.wasm"
+
+[functions]
+exclude = ["token.clawback"]
+read_only = ["amm.quote"]
+
+[tools."token.transfer"]
+description = "Send tokens to another account"
+"#;
+
+#[test]
+fn test_config_with_several_contracts() {
+    let config = ProjectConfig::from_toml(MULTI).unwrap();
+    let mut args = args(&[]);
+    config.apply_to_args(&mut args);
+
+    let names: Vec<&str> = args.contracts.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["token", "amm"]);
+    assert!(args.contracts[0].contract_id.as_deref().unwrap().starts_with("CCW67"));
+    assert_eq!(args.contracts[1].wasm, Some(PathBuf::from("target/amm.wasm")));
+    assert!(args.contract_id.is_none());
+
+    // Function settings are split per contract, without the prefix
+    let token = config.for_contract("token");
+    assert_eq!(token.functions.exclude, vec!["clawback".to_string()]);
+    assert!(token.functions.read_only.is_empty());
+    assert!(token.tools.contains_key("transfer"));
+    let mut spec = sac::stellar_asset_spec().unwrap();
+    token.apply_to_spec(&mut spec).unwrap();
+    assert!(!spec.functions.iter().any(|f| f.name == "clawback"));
+
+    assert_eq!(config.for_contract("amm").functions.read_only, vec!["quote".to_string()]);
+    assert!(config.for_contract("amm").tools.is_empty());
+}
+
+#[test]
+fn test_config_checks_contract_prefixes() {
+    let config = ProjectConfig::from_toml(MULTI).unwrap();
+    assert!(config.check_contract_prefixes(&["token", "amm"]).is_ok());
+
+    let err = config.check_contract_prefixes(&["token"]).unwrap_err();
+    assert!(err.to_string().contains("Unknown contract 'amm'"), "{}", err);
+
+    let unqualified = ProjectConfig::from_toml("[functions]\nexclude = [\"clawback\"]").unwrap();
+    assert!(unqualified.check_contract_prefixes(&["token", "amm"]).is_err());
+
+    // [contract] and [[contracts]] are exclusive, entries need a source
+    let both = format!("[contract]\nid = \"CABC\"\n{}", MULTI);
+    assert!(ProjectConfig::from_toml(&both).is_err());
+    assert!(ProjectConfig::from_toml("[[contracts]]\nname = \"token\"").is_err());
+}
+
+#[test]
+fn test_contract_flags() {
+    let args = args(&["--contract", "token=CABC", "--contract", "amm=./amm.wasm"]);
+    assert_eq!(args.contracts[0].name, "token");
+    assert_eq!(args.contracts[0].contract_id.as_deref(), Some("CABC"));
+    assert_eq!(args.contracts[1].wasm, Some(PathBuf::from("./amm.wasm")));
+
+    assert!(GenerateArgs::try_parse_from(["generate", "--contract", "token"]).is_err());
+    assert!(
+        GenerateArgs::try_parse_from(["generate", "--contract", "token=CABC", "-c", "CDEF"]).is_err(),
+        "--contract replaces --contract-id"
+    );
+}
+
+#[test]
+fn test_contract_names_are_validated() {
+    let spec = sac::stellar_asset_spec().unwrap();
+    let served = |name: &str| ServedContract {
+        name: name.to_string(),
+        contract_id: String::new(),
+        spec: spec.clone(),
+        wasm_hash: None,
+    };
+
+    let token = served("my-token");
+    assert_eq!(token.tool_name("transfer", true), "my-token.transfer");
+    assert_eq!(token.tool_name("transfer", false), "transfer");
+    assert_eq!(token.env_var(true), "MY_TOKEN_CONTRACT_ID");
+    assert_eq!(token.env_var(false), "CONTRACT_ID");
+
+    assert!(validate_names(&[served("token"), served("amm")]).is_ok());
+    assert!(validate_names(&[served("Token")]).is_err());
+    assert!(validate_names(&[served("1amm")]).is_err());
+    assert!(validate_names(&[served("my-token"), served("my_token")]).is_err(), "same module name");
+}
@@ -1,6 +1,11 @@
 //! Tests for deployment artifacts: Dockerfile, vercel.json, rate limiting
 
 use std::fs;
+use stellar_mcp_generator::generator::{
+    render_project, GeneratedProject, ProjectOptions, ServedContract, TemplateRenderer,
+};
+use stellar_mcp_generator::spec::sac;
+use stellar_mcp_generator::NetworkConfig;
 
 // ── TypeScript Dockerfile ────────────────────────────────────────────────────
 
@@ -232,17 +237,13 @@ fn test_python_rate_limit_only_mcp_endpoint() {
 
 #[test]
 fn test_typescript_env_template_has_rate_limit() {
-    // The TypeScript .env.example is generated inline in mcp_generator.rs,
-    // so we verify the template at least documents the variable in the
-    // Handlebars template (for the generated README inside output).
-    // The actual env generation is tested via the generator source.
-    let generator_source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let content = fs::read_to_string("templates/env.example.hbs")
+        .expect("Failed to read env.example.hbs");
 
-    assert!(generator_source.contains("RATE_LIMIT"),
-        "TypeScript generator should write RATE_LIMIT to .env.example");
-    assert!(generator_source.contains("USE_HTTP"),
-        "TypeScript generator should write USE_HTTP to .env.example");
+    assert!(content.contains("RATE_LIMIT"),
+        "TypeScript .env.example should document RATE_LIMIT");
+    assert!(content.contains("USE_HTTP"),
+        "TypeScript .env.example should document USE_HTTP");
 }
 
 #[test]
@@ -258,71 +259,67 @@ fn test_python_env_template_has_rate_limit() {
 
 // ── Generator wiring ─────────────────────────────────────────────────────────
 
+fn render(lang: &str) -> GeneratedProject {
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let templates = TemplateRenderer::new().unwrap();
+    let contracts = vec![ServedContract {
+        name: "token".to_string(),
+        contract_id: "CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75".to_string(),
+        spec: sac::stellar_asset_spec().unwrap(),
+        wasm_hash: None,
+    }];
+    render_project(&ProjectOptions {
+        lang,
+        project_name: "token",
+        server_name: "token-mcp",
+        network: &network,
+        contracts: &contracts,
+        templates: &templates,
+        with_frontend: false,
+        with_rest: false,
+    })
+    .unwrap()
+}
+
 #[test]
 fn test_typescript_generator_writes_dockerfile() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
-
-    assert!(source.contains("generate_dockerfile"),
-        "TypeScript generator should have generate_dockerfile method");
-    assert!(source.contains("Dockerfile.hbs"),
-        "TypeScript generator should reference Dockerfile.hbs template");
-    assert!(source.contains("\"Dockerfile\""),
-        "TypeScript generator should write to 'Dockerfile'");
+    let project = render("typescript");
+    let dockerfile = project.get_str("Dockerfile").expect("TypeScript generator should write 'Dockerfile'");
+    assert!(dockerfile.contains("node:20-alpine"), "Dockerfile should be rendered from Dockerfile.hbs");
 }
 
 #[test]
 fn test_typescript_generator_writes_vercel_json() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
-
-    assert!(source.contains("generate_vercel_json"),
-        "TypeScript generator should have generate_vercel_json method");
-    assert!(source.contains("vercel.json.hbs"),
-        "TypeScript generator should reference vercel.json.hbs template");
-    assert!(source.contains("\"vercel.json\""),
-        "TypeScript generator should write to 'vercel.json'");
+    let project = render("typescript");
+    let vercel = project.get_str("vercel.json").expect("TypeScript generator should write 'vercel.json'");
+    assert!(vercel.contains("/mcp"), "vercel.json should be rendered from vercel.json.hbs");
 }
 
 #[test]
 fn test_python_generator_writes_dockerfile() {
-    let source = fs::read_to_string("src/generator/python_generator.rs")
-        .expect("Failed to read python_generator.rs");
-
-    assert!(source.contains("generate_dockerfile"),
-        "Python generator should have generate_dockerfile method");
-    assert!(source.contains("python/Dockerfile.hbs"),
-        "Python generator should reference python/Dockerfile.hbs template");
-    assert!(source.contains("\"Dockerfile\""),
-        "Python generator should write to 'Dockerfile'");
+    let project = render("python");
+    let dockerfile = project.get_str("Dockerfile").expect("Python generator should write 'Dockerfile'");
+    assert!(dockerfile.contains("python:3.11-slim"), "Dockerfile should be rendered from python/Dockerfile.hbs");
 }
 
 #[test]
 fn test_typescript_generator_writes_dockerignore() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
-
-    assert!(source.contains("generate_dockerignore"),
-        "TypeScript generator should have generate_dockerignore method");
-    assert!(source.contains(".dockerignore"),
-        "TypeScript generator should write .dockerignore");
+    let project = render("typescript");
+    let dockerignore = project.get_str(".dockerignore").expect("TypeScript generator should write .dockerignore");
+    assert!(dockerignore.contains("node_modules"));
 }
 
 #[test]
 fn test_python_generator_writes_dockerignore() {
-    let source = fs::read_to_string("src/generator/python_generator.rs")
-        .expect("Failed to read python_generator.rs");
-
-    assert!(source.contains("generate_dockerignore"),
-        "Python generator should have generate_dockerignore method");
-    assert!(source.contains(".dockerignore"),
-        "Python generator should write .dockerignore");
+    let project = render("python");
+    let dockerignore = project.get_str(".dockerignore").expect("Python generator should write .dockerignore");
+    assert!(dockerignore.contains("__pycache__"));
 }
 
 #[test]
 fn test_generated_readme_has_docker_section() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/README.md.hbs")
+        .expect("Failed to read README.md.hbs");
 
     assert!(source.contains("Docker Deployment"),
         "Generated README should include Docker Deployment section");
@@ -334,8 +331,8 @@ fn test_generated_readme_has_docker_section() {
 
 #[test]
 fn test_generated_readme_has_vercel_section() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/README.md.hbs")
+        .expect("Failed to read README.md.hbs");
 
     assert!(source.contains("Vercel Deployment"),
         "Generated README should include Vercel Deployment section");
@@ -345,8 +342,8 @@ fn test_generated_readme_has_vercel_section() {
 
 #[test]
 fn test_generated_readme_has_rate_limiting_section() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/README.md.hbs")
+        .expect("Failed to read README.md.hbs");
 
     assert!(source.contains("Rate Limiting"),
         "Generated README should include Rate Limiting section");
@@ -354,14 +351,16 @@ fn test_generated_readme_has_rate_limiting_section() {
         "Generated README should document RATE_LIMIT env var");
 }
 
-// ── Logger & Errors lib files (generated by mcp_generator.rs) ───────────────
+// ── Logger & Errors lib files (templates/lib) ────────────────────────────────
 
 #[test]
 fn test_generator_writes_logger_ts() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
+    let generator = fs::read_to_string("src/generator/mcp_generator.rs")
         .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/lib/logger.ts.hbs")
+        .expect("Failed to read lib/logger.ts.hbs");
 
-    assert!(source.contains("src/lib/logger.ts"),
+    assert!(generator.contains("src/lib/{}.ts"),
         "Generator should write src/lib/logger.ts");
     assert!(source.contains("export function log(tool: string, level:"),
         "logger.ts should export a structured log() function");
@@ -373,10 +372,12 @@ fn test_generator_writes_logger_ts() {
 
 #[test]
 fn test_generator_writes_errors_ts() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
+    let generator = fs::read_to_string("src/generator/mcp_generator.rs")
         .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/lib/errors.ts.hbs")
+        .expect("Failed to read lib/errors.ts.hbs");
 
-    assert!(source.contains("src/lib/errors.ts"),
+    assert!(generator.contains("\"errors\""),
         "Generator should write src/lib/errors.ts");
     assert!(source.contains("export function formatToolError"),
         "errors.ts should export formatToolError");
@@ -388,8 +389,8 @@ fn test_generator_writes_errors_ts() {
 
 #[test]
 fn test_generator_errors_ts_imports_from_logger() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/lib/errors.ts.hbs")
+        .expect("Failed to read lib/errors.ts.hbs");
 
     // errors.ts should import from logger.ts
     assert!(source.contains("import { log, jsonStringify } from './logger.js'"),
@@ -398,8 +399,8 @@ fn test_generator_errors_ts_imports_from_logger() {
 
 #[test]
 fn test_soroban_error_hints_cover_common_errors() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/lib/errors.ts.hbs")
+        .expect("Failed to read lib/errors.ts.hbs");
 
     assert!(source.contains("Error(Storage, ExistingValue)"),
         "Should have hint for ExistingValue");
@@ -415,12 +416,15 @@ fn test_soroban_error_hints_cover_common_errors() {
 
 #[test]
 fn test_soroban_error_parser_extracts_error_code() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/lib/errors.ts.hbs")
+        .expect("Failed to read lib/errors.ts.hbs");
 
-    // Should use regex to extract Error(Category, Code) pattern
-    assert!(source.contains(r"Error\([A-Za-z]+,\s*[A-Za-z]+\)"),
+    // Should use regex to extract Error(Category, Code) pattern, including
+    // contract errors like Error(Contract, #7)
+    assert!(source.contains(r"Error\([A-Za-z]+,\s*#?[A-Za-z0-9]+\)"),
         "parseSorobanError should regex-match Soroban error codes");
+    assert!(source.contains(r"Error\(Contract,\s*#(\d+)\)"),
+        "parseSorobanError should extract contract error codes");
 }
 
 // ── Template imports from lib ───────────────────────────────────────────────
@@ -458,9 +462,9 @@ fn test_tool_handlers_log_on_call_and_result() {
         .expect("Failed to read index.ts.hbs");
 
     // Contract tool handlers should log on entry and success
-    assert!(content.contains("log('{{name_kebab}}', 'info', 'called'"),
+    assert!(content.contains("log('{{{tool_name}}}', 'info', 'called'"),
         "Contract tool handlers should log on call");
-    assert!(content.contains("log('{{name_kebab}}', 'info', 'success'"),
+    assert!(content.contains("log('{{{tool_name}}}', 'info', 'success'"),
         "Contract tool handlers should log on success");
 }
 
@@ -485,7 +489,7 @@ fn test_all_error_handlers_use_format_tool_error() {
         .expect("Failed to read index.ts.hbs");
 
     // Contract tool handlers
-    assert!(content.contains("return formatToolError('{{name_kebab}}', error)"),
+    assert!(content.contains("return formatToolError('{{{tool_name}}}', error)"),
         "Contract tool handlers should use formatToolError");
     // Built-in tools
     assert!(content.contains("return formatToolError('sign-and-submit', error)"),
@@ -494,12 +498,12 @@ fn test_all_error_handlers_use_format_tool_error() {
         "prepare-transaction should use formatToolError");
 }
 
-// ── Generator render_index_template logging & formatToolError ───────────────
+// ── Generated index.ts logging & formatToolError ────────────────────────────
 
 #[test]
 fn test_generator_index_imports_from_lib() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
 
     assert!(source.contains("import { log, jsonStringify } from './lib/logger.js'"),
         "Generator should add logger import to index.ts");
@@ -509,20 +513,20 @@ fn test_generator_index_imports_from_lib() {
 
 #[test]
 fn test_generator_contract_tools_have_logging() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
 
-    // Contract tool handlers in render_index_template should have log calls
-    assert!(source.contains("log('{}', 'info', 'called', params)"),
+    // Contract tool handlers should have log calls
+    assert!(source.contains("log('{{{tool_name}}}', 'info', 'called', params)"),
         "Generator contract tool handlers should log on call");
-    assert!(source.contains("log('{}', 'info', 'success', result)"),
+    assert!(source.contains("log('{{{tool_name}}}', 'info', 'success', result)"),
         "Generator contract tool handlers should log on success");
 }
 
 #[test]
 fn test_generator_builtin_tools_have_logging() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
 
     assert!(source.contains("log('sign-and-submit', 'info', 'called')"),
         "Generator sign-and-submit should log on call");
@@ -534,10 +538,10 @@ fn test_generator_builtin_tools_have_logging() {
 
 #[test]
 fn test_generator_all_error_handlers_use_format_tool_error() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
 
-    assert!(source.contains("return formatToolError('{}', error)"),
+    assert!(source.contains("return formatToolError('{{{tool_name}}}', error)"),
         "Generator contract tool handlers should use formatToolError");
     assert!(source.contains("return formatToolError('sign-and-submit', error)"),
         "Generator sign-and-submit should use formatToolError");
@@ -551,8 +555,8 @@ fn test_generator_all_error_handlers_use_format_tool_error() {
 
 #[test]
 fn test_typescript_generator_has_cors_origins_env_var() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
 
     assert!(source.contains("CORS_ORIGINS"),
         "TypeScript generator should support CORS_ORIGINS env var");
@@ -560,8 +564,8 @@ fn test_typescript_generator_has_cors_origins_env_var() {
 
 #[test]
 fn test_typescript_cors_uses_env_var_not_wildcard() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
 
     // Should split CORS_ORIGINS by comma, not hardcode '*'
     assert!(source.contains("CORS_ORIGINS ?? '*'"),
@@ -572,8 +576,8 @@ fn test_typescript_cors_uses_env_var_not_wildcard() {
 
 #[test]
 fn test_typescript_env_example_documents_cors_origins() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/env.example.hbs")
+        .expect("Failed to read env.example.hbs");
 
     assert!(source.contains("CORS_ORIGINS"),
         "TypeScript .env.example should document CORS_ORIGINS");
@@ -605,8 +609,8 @@ fn test_python_env_example_documents_cors_origins() {
 
 #[test]
 fn test_typescript_generator_has_sigterm_handler() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
 
     assert!(source.contains("SIGTERM"),
         "TypeScript generator should add SIGTERM handler for graceful shutdown");
@@ -616,8 +620,8 @@ fn test_typescript_generator_has_sigterm_handler() {
 
 #[test]
 fn test_typescript_graceful_shutdown_closes_server() {
-    let source = fs::read_to_string("src/generator/mcp_generator.rs")
-        .expect("Failed to read mcp_generator.rs");
+    let source = fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
 
     assert!(source.contains("httpServer.close"),
         "Graceful shutdown should close the HTTP server before exiting");
@@ -0,0 +1,131 @@
+//! Tests for comparing contract specs (`stellar-mcp diff`)
+
+mod common;
+
+use stellar_mcp_generator::spec::diff::{ChangeTarget, Severity, SpecDiff};
+use stellar_mcp_generator::spec::types::*;
+use common::{field, token};
+
+fn token_spec() -> ContractSpec {
+    let mut spec = token().spec;
+    spec.types.push(TypeSpec {
+        name: "Config".to_string(),
+        doc: None,
+        definition: TypeDef::Struct {
+            fields: vec![field("cap", TypeRef::I128)],
+        },
+    });
+    spec.errors.push(ErrorEnumSpec {
+        name: "Error".to_string(),
+        doc: None,
+        cases: vec![ErrorSpec { name: "InsufficientBalance".to_string(), doc: None, code: 1 }],
+    });
+    spec
+}
+
+fn function<'a>(spec: &'a mut ContractSpec, name: &str) -> &'a mut FunctionSpec {
+    spec.functions.iter_mut().find(|f| f.name == name).unwrap()
+}
+
+fn messages(diff: &SpecDiff, severity: Severity) -> Vec<String> {
+    diff.changes.iter().filter(|c| c.severity == severity).map(|c| c.message.clone()).collect()
+}
+
+#[test]
+fn test_identical_specs_have_no_changes() {
+    let diff = SpecDiff::compare(&token_spec(), &token_spec());
+    assert!(diff.changes.is_empty(), "{:?}", diff.changes);
+    assert!(!diff.is_breaking());
+}
+
+#[test]
+fn test_additions_are_not_breaking() {
+    let old = token_spec();
+    let mut new = token_spec();
+    let mut pause = old.functions[0].clone();
+    pause.name = "pause".to_string();
+    new.functions.push(pause);
+    new.errors.push(ErrorEnumSpec {
+        name: "Error".to_string(),
+        doc: None,
+        cases: vec![ErrorSpec { name: "Paused".to_string(), doc: None, code: 2 }],
+    });
+
+    let diff = SpecDiff::compare(&old, &new);
+    assert!(!diff.is_breaking(), "{:?}", diff.changes);
+    assert_eq!(diff.count(Severity::NonBreaking), 2);
+    assert!(diff.changes.iter().any(|c| c.target == ChangeTarget::Error && c.name == "Paused"));
+}
+
+#[test]
+fn test_signature_changes_are_breaking() {
+    let old = token_spec();
+    let mut new = token_spec();
+    new.functions.retain(|f| f.name != "clawback");
+    function(&mut new, "transfer").inputs[2].type_ref = TypeRef::U64;
+    function(&mut new, "balance").output = Some(TypeRef::U64);
+    function(&mut new, "mint").inputs.push(ParameterSpec {
+        name: "memo".to_string(),
+        doc: None,
+        type_ref: TypeRef::String,
+    });
+
+    let diff = SpecDiff::compare(&old, &new);
+    let breaking = messages(&diff, Severity::Breaking);
+    assert!(diff.is_breaking());
+    assert!(breaking.contains(&"function `clawback` removed".to_string()), "{:?}", breaking);
+    assert!(breaking.contains(&"`transfer`: parameter `amount` type changed from i128 to u64".to_string()), "{:?}", breaking);
+    assert!(breaking.contains(&"function `balance` return type changed from i128 to u64".to_string()), "{:?}", breaking);
+    assert!(breaking.contains(&"`mint`: parameter `memo` (String) added".to_string()), "{:?}", breaking);
+}
+
+#[test]
+fn test_udt_and_error_changes() {
+    let old = token_spec();
+    let mut new = token_spec();
+    if let TypeDef::Struct { fields } = &mut new.types.last_mut().unwrap().definition {
+        fields[0].type_ref = TypeRef::Option(Box::new(TypeRef::I128));
+    }
+    new.errors[0].cases[0].code = 7;
+
+    let diff = SpecDiff::compare(&old, &new);
+    let breaking = messages(&diff, Severity::Breaking);
+    assert!(breaking.contains(&"`Config`: field `cap` type changed from i128 to Option<i128>".to_string()), "{:?}", breaking);
+    assert!(breaking.contains(&"error `InsufficientBalance` code changed from 1 to 7".to_string()), "{:?}", breaking);
+}
+
+#[test]
+fn test_diff_serializes_for_ci() {
+    let old = token_spec();
+    let mut new = token_spec();
+    new.functions.retain(|f| f.name != "burn");
+
+    let json = serde_json::to_value(SpecDiff::compare(&old, &new)).unwrap();
+    assert_eq!(json["changes"][0]["severity"], "breaking");
+    assert_eq!(json["changes"][0]["target"], "function");
+    assert_eq!(json["changes"][0]["name"], "burn");
+}
+
+#[test]
+fn test_snapshot_round_trip_through_project() {
+    use stellar_mcp_generator::spec::snapshot::{wasm_hash, SpecSnapshot, SNAPSHOT_PATH};
+
+    let dir = tempfile::tempdir().unwrap();
+    let snapshot = SpecSnapshot {
+        generator_version: "0.1.0".to_string(),
+        contract_id: None,
+        network: Some("testnet".to_string()),
+        wasm_hash: Some(wasm_hash(b"")),
+        spec: token_spec(),
+    };
+    snapshot.save_to_project(dir.path()).unwrap();
+    assert!(dir.path().join(SNAPSHOT_PATH).exists());
+
+    let loaded = SpecSnapshot::load_from_project(dir.path()).unwrap();
+    assert_eq!(
+        loaded.wasm_hash.as_deref(),
+        Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+    );
+    assert_eq!(loaded.network.as_deref(), Some("testnet"));
+    assert!(SpecDiff::compare(&loaded.spec, &token_spec()).changes.is_empty());
+}
@@ -0,0 +1,507 @@
+//! Tests for safe literal and identifier emission (`codegen::emit`), including
+//! fuzz tests rendering whole projects from specs with arbitrary doc strings
+
+use proptest::prelude::*;
+use stellar_mcp_generator::codegen::emit::*;
+use stellar_mcp_generator::generator::{render_project, ProjectOptions, ServedContract, TemplateRenderer};
+use stellar_mcp_generator::spec::*;
+use stellar_mcp_generator::NetworkConfig;
+
+/// Arbitrary strings, biased towards fragments that end literals and comments
+fn spec_string() -> impl Strategy<Value = String> {
+    let fragment = prop_oneof![
+        any::<char>().prop_map(String::from),
+        Just("'".to_string()),
+        Just("\"".to_string()),
+        Just("\"\"\"".to_string()),
+        Just("\\".to_string()),
+        Just("*/".to_string()),
+        Just("/*".to_string()),
+        Just("`".to_string()),
+        Just("${x}".to_string()),
+        Just("{{".to_string()),
+        Just("\n".to_string()),
+        Just("\r\n".to_string()),
+        Just("\u{2028}".to_string()),
+        Just("\u{2029}".to_string()),
+        Just("#".to_string()),
+        Just("');\nprocess.exit(1);//".to_string()),
+    ];
+    prop::collection::vec(fragment, 0..24).prop_map(|parts| parts.concat())
+}
+
+/// Decode the body of a TypeScript or Python string literal (the escapes
+/// `emit` produces)
+fn unescape(body: &str) -> String {
+    let mut out = String::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next().unwrap() {
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                out.push(char::from_u32(u32::from_str_radix(&hex, 16).unwrap()).unwrap());
+            }
+            'u' => {
+                let hex: String = chars.by_ref().take(4).collect();
+                out.push(char::from_u32(u32::from_str_radix(&hex, 16).unwrap()).unwrap());
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Code of a TypeScript file with whitespace and comments removed and every
+/// string literal replaced by `S`. Fails on unterminated literals or comments.
+fn ts_skeleton(source: &str) -> Result<String, String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    ts_code(&chars, &mut i, &mut out, false)?;
+    Ok(out)
+}
+
+fn ts_code(chars: &[char], i: &mut usize, out: &mut String, in_template: bool) -> Result<(), String> {
+    let is_line_end = |c: char| matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}');
+    let mut depth = 0;
+    while *i < chars.len() {
+        let c = chars[*i];
+        let next = chars.get(*i + 1).copied();
+        match c {
+            '/' if next == Some('/') => {
+                while *i < chars.len() && !is_line_end(chars[*i]) {
+                    *i += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                let start = *i;
+                *i += 2;
+                while !(chars.get(*i) == Some(&'*') && chars.get(*i + 1) == Some(&'/')) {
+                    if *i >= chars.len() {
+                        return Err(format!("unterminated comment at {}", start));
+                    }
+                    *i += 1;
+                }
+                *i += 2;
+            }
+            // A regular expression literal (a `/` where no operand precedes it)
+            '/' if out.ends_with(['(', ',', '=', ':', '[', '!', '&', '|', '?', '{', ';']) => {
+                let start = *i;
+                let mut in_class = false;
+                *i += 1;
+                while in_class || chars.get(*i) != Some(&'/') {
+                    match chars.get(*i) {
+                        None => return Err(format!("unterminated regex at {}", start)),
+                        Some(&ch) if is_line_end(ch) => return Err(format!("line break in regex at {}", start)),
+                        Some('\\') => *i += 2,
+                        Some(&ch) => {
+                            in_class = (in_class || ch == '[') && ch != ']';
+                            *i += 1;
+                        }
+                    }
+                }
+                *i += 1;
+                out.push('R');
+            }
+            '\'' | '"' => {
+                let start = *i;
+                *i += 1;
+                while chars.get(*i) != Some(&c) {
+                    match chars.get(*i) {
+                        None => return Err(format!("unterminated string at {}", start)),
+                        Some(&ch) if is_line_end(ch) => return Err(format!("line break in string at {}", start)),
+                        Some('\\') => *i += 2,
+                        Some(_) => *i += 1,
+                    }
+                }
+                *i += 1;
+                out.push('S');
+            }
+            '`' => {
+                let start = *i;
+                *i += 1;
+                out.push('`');
+                while chars.get(*i) != Some(&'`') {
+                    match chars.get(*i) {
+                        None => return Err(format!("unterminated template at {}", start)),
+                        Some('\\') => *i += 2,
+                        Some('$') if chars.get(*i + 1) == Some(&'{') => {
+                            *i += 2;
+                            out.push('{');
+                            ts_code(chars, i, out, true)?;
+                        }
+                        Some(_) => *i += 1,
+                    }
+                }
+                *i += 1;
+                out.push('`');
+            }
+            '}' if in_template && depth == 0 => {
+                *i += 1;
+                out.push('}');
+                return Ok(());
+            }
+            _ => {
+                if c == '{' {
+                    depth += 1;
+                } else if c == '}' {
+                    depth -= 1;
+                }
+                if !c.is_whitespace() {
+                    out.push(c);
+                }
+                *i += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Code of a Python file with whitespace and comments removed and every
+/// string literal replaced by `S`. Fails on unterminated literals.
+fn py_skeleton(source: &str) -> Result<String, String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '\'' | '"' => {
+                let start = i;
+                let triple = chars.get(i + 1) == Some(&c) && chars.get(i + 2) == Some(&c);
+                i += if triple { 3 } else { 1 };
+                loop {
+                    match chars.get(i) {
+                        None => return Err(format!("unterminated string at {}", start)),
+                        Some('\\') => i += 2,
+                        Some('\n') if !triple => return Err(format!("line break in string at {}", start)),
+                        Some(&q) if q == c && !triple => {
+                            i += 1;
+                            break;
+                        }
+                        Some(&q) if q == c && chars.get(i + 1) == Some(&c) && chars.get(i + 2) == Some(&c) => {
+                            i += 3;
+                            break;
+                        }
+                        Some(_) => i += 1,
+                    }
+                }
+                out.push('S');
+            }
+            c => {
+                if !c.is_whitespace() {
+                    out.push(c);
+                }
+                i += 1;
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// A spec using every kind of doc string, with the given docs
+fn spec_with_docs(docs: &[String]) -> ContractSpec {
+    let doc = |i: usize| Some(format!("a{}", docs[i % docs.len()]));
+    let field = |name: &str, type_ref: TypeRef, i: usize| FieldSpec {
+        name: name.to_string(),
+        doc: doc(i),
+        type_ref,
+    };
+    let mut spec = ContractSpec::new();
+    spec.types = vec![
+        TypeSpec {
+            name: "Config".to_string(),
+            doc: doc(0),
+            definition: TypeDef::Struct {
+                fields: vec![field("admin", TypeRef::Address, 1), field("from", TypeRef::I128, 2)],
+            },
+        },
+        TypeSpec {
+            name: "Pair".to_string(),
+            doc: doc(3),
+            definition: TypeDef::Struct {
+                fields: vec![field("0", TypeRef::U32, 4), field("1", TypeRef::String, 5)],
+            },
+        },
+        TypeSpec {
+            name: "Status".to_string(),
+            doc: doc(6),
+            definition: TypeDef::Enum {
+                variants: vec![EnumVariant { name: "Active".to_string(), doc: doc(7), value: 0 }],
+            },
+        },
+        TypeSpec {
+            name: "DataKey".to_string(),
+            doc: doc(8),
+            definition: TypeDef::Union {
+                cases: vec![
+                    UnionCase { name: "Admin".to_string(), doc: doc(9), type_ref: None },
+                    UnionCase { name: "Balance".to_string(), doc: doc(10), type_ref: Some(TypeRef::Address) },
+                ],
+            },
+        },
+    ];
+    spec.errors = vec![ErrorEnumSpec {
+        name: "Error".to_string(),
+        doc: doc(12),
+        cases: vec![ErrorSpec { name: "NotAdmin".to_string(), doc: doc(11), code: 1 }],
+    }];
+    spec.functions = vec![FunctionSpec {
+        name: "delete".to_string(),
+        doc: doc(12),
+        inputs: vec![
+            ParameterSpec { name: "from".to_string(), doc: doc(13), type_ref: TypeRef::Address },
+            ParameterSpec { name: "default".to_string(), doc: doc(14), type_ref: TypeRef::Custom("Config".to_string()) },
+            ParameterSpec { name: "class".to_string(), doc: doc(15), type_ref: TypeRef::Custom("DataKey".to_string()) },
+        ],
+        output: Some(TypeRef::Custom("Pair".to_string())),
+        kind: FunctionKind::Write,
+    }];
+    spec.events = vec![EventSpec {
+        name: "transfer".to_string(),
+        doc: doc(16),
+        prefix_topics: vec!["transfer".to_string()],
+        topics: vec![field("from", TypeRef::Address, 17)],
+        data: vec![field("amount", TypeRef::I128, 18)],
+        data_format: EventDataFormat::SingleValue,
+    }];
+    spec
+}
+
+/// Skeletons of every TypeScript and Python source file of the projects
+/// rendered from `spec`
+fn skeletons(spec: &ContractSpec) -> Vec<(String, String)> {
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let templates = TemplateRenderer::new().unwrap();
+    let contracts = vec![ServedContract {
+        name: "token".to_string(),
+        contract_id: String::new(),
+        spec: spec.clone(),
+        wasm_hash: None,
+    }];
+
+    let mut skeletons = Vec::new();
+    for lang in ["typescript", "python"] {
+        let project = render_project(&ProjectOptions {
+            lang,
+            project_name: "token",
+            server_name: "token-mcp",
+            network: &network,
+            contracts: &contracts,
+            templates: &templates,
+            with_frontend: false,
+            with_rest: false,
+        })
+        .unwrap();
+        for (path, content) in &project.files {
+            let source = std::str::from_utf8(content).unwrap();
+            let skeleton = match path.extension().and_then(|e| e.to_str()) {
+                Some("ts") => ts_skeleton(source),
+                Some("py") => py_skeleton(source),
+                _ => continue,
+            };
+            let skeleton = skeleton.unwrap_or_else(|e| panic!("{} ({}): {}\n{}", path.display(), lang, e, source));
+            skeletons.push((format!("{}:{}", lang, path.display()), skeleton));
+        }
+    }
+    skeletons
+}
+
+#[test]
+fn test_ts_literals_and_comments() {
+    assert_eq!(ts_string("it's"), r"'it\'s'");
+    assert_eq!(ts_string("a\\b\nc\u{2028}"), r"'a\\b\nc\u2028'");
+    assert_eq!(ts_string_body("`${x}`"), "`${x}`");
+    assert_eq!(ts_double_quoted("say \"hi\"\u{2029}"), r#""say \"hi\"\u2029""#);
+    assert_eq!(ts_comment_text("end */ here\r\nnext", " * "), "end *\\/ here\n * next");
+    assert_eq!(ts_doc_comment(Some(" */ "), "  "), "  /**\n   * *\\/\n   */\n");
+    assert_eq!(ts_doc_comment(Some("  "), ""), "");
+}
+
+#[test]
+fn test_py_literals_and_docstrings() {
+    assert_eq!(py_string("say \"hi\"\n"), r#""say \"hi\"\n""#);
+    assert_eq!(py_string("\u{0}"), r#""\x00""#);
+    assert_eq!(py_docstring_text("ends with \"\"\""), r#"ends with \"\"\""#);
+    assert_eq!(py_docstring(Some("a\u{2028}b"), "    "), "    \"\"\"\n    a\n    b\n    \"\"\"\n");
+    assert_eq!(py_docstring(Some("quote\""), ""), "\"\"\"quote\\\"\"\"\"\n");
+}
+
+#[test]
+fn test_go_literals() {
+    assert_eq!(go_string("say \"hi\"\n"), r#""say \"hi\"\n""#);
+    assert_eq!(go_string("\u{0}\u{feff}\u{85}"), r#""\u0000\ufeff\u0085""#);
+    assert_eq!(go_string("`{{x}}` \u{2028}"), "\"`{{x}}` \u{2028}\"");
+}
+
+#[test]
+fn test_identifiers_avoid_reserved_words() {
+    assert_eq!(ts_identifier("default"), "default_");
+    assert_eq!(ts_identifier("class"), "class_");
+    assert_eq!(ts_identifier("from"), "from");
+    assert_eq!(ts_identifier("transfer_from"), "transfer_from");
+    assert_eq!(ts_identifier("0"), "_0");
+    assert_eq!(ts_identifier("a-b"), "a_b");
+    assert_eq!(py_identifier("from"), "from_");
+    assert_eq!(py_identifier("None"), "None_");
+    assert_eq!(py_identifier("default"), "default");
+    assert_eq!(py_identifier(""), "_");
+
+    assert_eq!(ts_property("v", "default"), "v.default");
+    assert_eq!(ts_property("v", "0"), "v['0']");
+    assert_eq!(ts_object_key("0"), "'0'");
+}
+
+#[test]
+fn test_reserved_parameter_names_in_generated_code() {
+    let spec = spec_with_docs(&["doc".to_string()]);
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let templates = TemplateRenderer::new().unwrap();
+    let contracts = vec![ServedContract {
+        name: "token".to_string(),
+        contract_id: String::new(),
+        spec,
+        wasm_hash: None,
+    }];
+    let render = |lang| {
+        render_project(&ProjectOptions {
+            lang,
+            project_name: "token",
+            server_name: "token-mcp",
+            network: &network,
+            contracts: &contracts,
+            templates: &templates,
+            with_frontend: false,
+            with_rest: false,
+        })
+        .unwrap()
+    };
+
+    let ts = render("typescript");
+    assert!(ts.get_str("src/tools/token.ts").unwrap().contains("export async function delete_("));
+    assert!(ts.get_str("src/index.ts").unwrap().contains("tools.delete_(params"));
+
+    let py = render("python");
+    let server = py.get_str("server.py").unwrap();
+    assert!(
+        server.contains("    from_: Annotated[str, Field(min_length=56, max_length=56, pattern=\"^[GC][A-Z2-7]{55}$\")],\n"),
+        "{}",
+        server
+    );
+    assert!(server.contains("    class_: DataKeySchema,\n"), "{}", server);
+    let schemas = py.get_str("src/schemas.py").unwrap();
+    assert!(schemas.contains("class ConfigSchema(BaseModel):\n    \"\"\"adoc\"\"\"\n"), "{}", schemas);
+    assert!(schemas.contains("    from_: str = Field(..., pattern=\"^(0|-?[1-9][0-9]*)$\", description=\"adoc\", alias=\"from\")\n"), "{}", schemas);
+    assert!(schemas.contains("field_0: int = Field(..., ge=0, le=4294967295, description=\"adoc\", alias=\"0\")"), "{}", schemas);
+    assert!(schemas.contains("schema.field_0"), "{}", schemas);
+}
+
+#[test]
+fn test_parser_rejects_names_outside_the_symbol_alphabet() {
+    use stellar_xdr::curr::{ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0, ScSpecTypeDef};
+
+    let entry = |name: &str, param: &str| {
+        ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+            doc: "'); evil(); /* \"\"\"".try_into().unwrap(),
+            name: name.try_into().unwrap(),
+            inputs: vec![ScSpecFunctionInputV0 {
+                doc: "".try_into().unwrap(),
+                name: param.try_into().unwrap(),
+                type_: ScSpecTypeDef::U32,
+            }]
+            .try_into()
+            .unwrap(),
+            outputs: vec![].try_into().unwrap(),
+        })
+    };
+
+    assert!(SpecParser::parse_entries(&[entry("hello", "default")]).is_ok());
+    for (name, param) in [("hel'lo", "to"), ("hello", "to-x"), ("hello", ""), ("a b", "to")] {
+        let err = SpecParser::parse_entries(&[entry(name, param)]).unwrap_err();
+        assert!(
+            matches!(err, stellar_mcp_generator::GeneratorError::InvalidSpec(_)),
+            "{:?}",
+            err
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn fuzz_ts_string_round_trips(value in spec_string()) {
+        let literal = ts_string(&value);
+        prop_assert!(literal.starts_with('\'') && literal.ends_with('\''));
+        let body = &literal[1..literal.len() - 1];
+        prop_assert!(!body.contains(['\n', '\r', '\u{2028}', '\u{2029}']), "{:?}", body);
+        prop_assert_eq!(ts_skeleton(&literal), Ok("S".to_string()));
+        prop_assert_eq!(unescape(body), value);
+    }
+
+    #[test]
+    fn fuzz_py_string_round_trips(value in spec_string()) {
+        let literal = py_string(&value);
+        prop_assert!(!literal.contains(['\n', '\r']));
+        prop_assert_eq!(py_skeleton(&literal), Ok("S".to_string()));
+        prop_assert_eq!(unescape(&literal[1..literal.len() - 1]), value);
+    }
+
+    #[test]
+    fn fuzz_go_string_round_trips(value in spec_string()) {
+        let literal = go_string(&value);
+        prop_assert!(literal.starts_with('"') && literal.ends_with('"'));
+        let body = &literal[1..literal.len() - 1];
+        prop_assert!(!body.contains(['\n', '\r', '\u{0}', '\u{feff}']), "{:?}", body);
+        prop_assert_eq!(unescape(body), value);
+    }
+
+    #[test]
+    fn fuzz_comments_and_docstrings_stay_closed(value in spec_string()) {
+        let comment = format!("/**\n * {}\n */\nx", ts_comment_text(&value, " * "));
+        prop_assert_eq!(ts_skeleton(&comment), Ok("x".to_string()));
+        let doc = ts_doc_comment(Some(&value), "  ");
+        prop_assert_eq!(ts_skeleton(&format!("{}x", doc)), Ok("x".to_string()));
+
+        let docstring = format!("def f():\n    \"\"\"{}\"\"\"\n", py_docstring_text(&value));
+        prop_assert_eq!(py_skeleton(&docstring), Ok("deff():S".to_string()));
+        let docstring = format!("def f():\n{}", py_docstring(Some(&value), "    "));
+        prop_assert!(py_skeleton(&docstring).unwrap().starts_with("deff():"));
+    }
+
+    #[test]
+    fn fuzz_identifiers_are_valid(name in "[A-Za-z0-9_]{0,12}|default|class|from|None|delete") {
+        let ts = ts_identifier(&name);
+        prop_assert!(ts.chars().next().is_some_and(|c| !c.is_ascii_digit()));
+        prop_assert!(!["default", "class", "delete"].contains(&ts.as_str()));
+        let py = py_identifier(&name);
+        prop_assert!(py.chars().next().is_some_and(|c| !c.is_ascii_digit()));
+        prop_assert!(!["class", "from", "None"].contains(&py.as_str()));
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    /// Whatever the docs contain, they only change the contents of string
+    /// literals and comments in the generated code
+    #[test]
+    fn fuzz_spec_docs_cannot_change_generated_code(docs in prop::collection::vec(spec_string(), 1..6)) {
+        let baseline = skeletons(&spec_with_docs(&["x".to_string()]));
+        let fuzzed = skeletons(&spec_with_docs(&docs));
+        prop_assert_eq!(baseline.len(), fuzzed.len());
+        for ((path, expected), (_, actual)) in baseline.iter().zip(&fuzzed) {
+            prop_assert_eq!(expected, actual, "{}", path);
+        }
+    }
+}
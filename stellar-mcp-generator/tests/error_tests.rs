@@ -0,0 +1,80 @@
+//! Tests for GeneratorError variants and exit codes
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use stellar_mcp_generator::generator::update::write_project;
+use stellar_mcp_generator::generator::{GeneratedProject, TemplateRenderer};
+use stellar_mcp_generator::spec::SpecFetcher;
+use stellar_mcp_generator::{GeneratorError, NetworkConfig};
+
+const USDC: &str = "CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75";
+
+#[test]
+fn test_exit_codes_are_distinct_per_cause() {
+    let errors = [
+        GeneratorError::InvalidInput("bad flag".to_string()),
+        GeneratorError::ContractNotFound { contract_id: USDC.to_string() },
+        GeneratorError::Rpc { code: -32600, message: "invalid request".to_string() },
+        GeneratorError::InvalidSpec("no contractspecv0 section".to_string()),
+        GeneratorError::OutputExists { path: PathBuf::from("./mcp-server") },
+        GeneratorError::TemplateRender { name: "index".to_string(), message: "boom".to_string() },
+        GeneratorError::MergeConflicts { count: 1 },
+        GeneratorError::BreakingChanges { count: 2 },
+        GeneratorError::ValidationFailed { errors: vec![] },
+        GeneratorError::Serialization("bad".to_string()),
+    ];
+    let codes: HashSet<i32> = errors.iter().map(GeneratorError::exit_code).collect();
+    assert_eq!(codes.len(), errors.len(), "every cause has its own exit code");
+    assert!(!codes.contains(&0));
+
+    // Related causes share a code
+    let sac = GeneratorError::StellarAssetContract { contract_id: USDC.to_string() };
+    assert_eq!(sac.exit_code(), errors[1].exit_code());
+    assert_eq!(GeneratorError::Network("timeout".to_string()).exit_code(), errors[2].exit_code());
+
+    assert_eq!(errors[2].to_string(), "RPC error: -32600 - invalid request");
+    assert_eq!(
+        errors[4].to_string(),
+        "Output directory './mcp-server' already exists. Use --force to overwrite or --update to regenerate in place."
+    );
+}
+
+#[test]
+fn test_invalid_contract_ids_are_strkey_errors() {
+    let fetcher = SpecFetcher::new("http://localhost:8000").unwrap();
+    assert!(fetcher.decode_contract_id(USDC).is_ok());
+
+    let bad_checksum = format!("{}4", &USDC[..55]);
+    for id in ["GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H", "C123", bad_checksum.as_str()] {
+        match fetcher.decode_contract_id(id) {
+            Err(GeneratorError::InvalidStrkey { value, .. }) => assert_eq!(value, id),
+            other => panic!("expected InvalidStrkey for {}, got {:?}", id, other),
+        }
+    }
+}
+
+#[test]
+fn test_unknown_network_is_invalid_input() {
+    let err = NetworkConfig::from_name("devnet").unwrap_err();
+    assert!(matches!(err, GeneratorError::InvalidInput(_)), "{:?}", err);
+    assert_eq!(err.exit_code(), 2);
+}
+
+#[test]
+fn test_template_errors() {
+    let missing = TemplateRenderer::with_overrides(&PathBuf::from("/nonexistent/templates"));
+    assert!(matches!(missing, Err(GeneratorError::InvalidTemplate(_))));
+
+    let renderer = TemplateRenderer::new().unwrap();
+    match renderer.render("no-such-template", &serde_json::json!({})) {
+        Err(GeneratorError::TemplateRender { name, .. }) => assert_eq!(name, "no-such-template"),
+        other => panic!("expected TemplateRender, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_update_without_manifest_is_invalid_input() {
+    let output = tempfile::tempdir().unwrap();
+    let err = write_project(&GeneratedProject::new(), output.path(), true).unwrap_err();
+    assert!(matches!(err, GeneratorError::InvalidInput(_)), "{:?}", err);
+}
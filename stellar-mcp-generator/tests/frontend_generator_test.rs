@@ -1,5 +1,6 @@
 //! Tests for frontend generator with AI integration
 
+use stellar_mcp_generator::generator::update::write_project;
 use stellar_mcp_generator::generator::FrontendGenerator;
 use stellar_mcp_generator::NetworkConfig;
 use std::fs;
@@ -20,9 +21,10 @@ fn read_file(path: &PathBuf) -> String {
 fn test_frontend_directory_structure() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let frontend_dir = temp_dir.path().join("frontend");
 
@@ -38,9 +40,10 @@ fn test_frontend_directory_structure() {
 fn test_package_json_contains_ai_dependencies() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let package_json_path = temp_dir.path().join("frontend/package.json");
     let content = read_file(&package_json_path);
@@ -68,9 +71,10 @@ fn test_package_json_contains_ai_dependencies() {
 fn test_package_json_scripts() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let package_json_path = temp_dir.path().join("frontend/package.json");
     let content = read_file(&package_json_path);
@@ -86,9 +90,10 @@ fn test_package_json_scripts() {
 fn test_server_files_generated() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let frontend_dir = temp_dir.path().join("frontend");
 
@@ -101,9 +106,10 @@ fn test_server_files_generated() {
 fn test_server_index_content() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let server_index_path = temp_dir.path().join("frontend/src/server/index.ts");
     let content = read_file(&server_index_path);
@@ -130,9 +136,10 @@ fn test_server_index_content() {
 fn test_chat_route_content() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let chat_route_path = temp_dir.path().join("frontend/src/server/routes/chat.ts");
     let content = read_file(&chat_route_path);
@@ -163,9 +170,10 @@ fn test_chat_route_content() {
 fn test_client_chat_components_generated() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let components_dir = temp_dir.path().join("frontend/src/client/components");
 
@@ -184,9 +192,10 @@ fn test_client_chat_components_generated() {
 fn test_chat_interface_component() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let chat_interface_path = temp_dir.path().join("frontend/src/client/components/ChatInterface.tsx");
     let content = read_file(&chat_interface_path);
@@ -215,9 +224,10 @@ fn test_chat_interface_component() {
 fn test_message_list_component() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let message_list_path = temp_dir.path().join("frontend/src/client/components/MessageList.tsx");
     let content = read_file(&message_list_path);
@@ -240,9 +250,10 @@ fn test_message_list_component() {
 fn test_chat_input_component() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let chat_input_path = temp_dir.path().join("frontend/src/client/components/ChatInput.tsx");
     let content = read_file(&chat_input_path);
@@ -264,9 +275,10 @@ fn test_chat_input_component() {
 fn test_app_tsx_updated() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let app_path = temp_dir.path().join("frontend/src/client/App.tsx");
     let content = read_file(&app_path);
@@ -288,9 +300,10 @@ fn test_app_tsx_updated() {
 fn test_env_example_updated() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let env_path = temp_dir.path().join("frontend/.env.example");
     let content = read_file(&env_path);
@@ -318,9 +331,10 @@ fn test_env_example_updated() {
 fn test_readme_contains_ai_instructions() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let readme_path = temp_dir.path().join("frontend/README.md");
     let content = read_file(&readme_path);
@@ -349,9 +363,10 @@ fn test_readme_contains_ai_instructions() {
 fn test_mcp_client_unchanged() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let mcp_client_path = temp_dir.path().join("frontend/src/client/lib/mcp-client.ts");
     let content = read_file(&mcp_client_path);
@@ -366,9 +381,10 @@ fn test_mcp_client_unchanged() {
 fn test_index_html_updated_path() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let index_html_path = temp_dir.path().join("frontend/index.html");
     let content = read_file(&index_html_path);
@@ -381,9 +397,10 @@ fn test_index_html_updated_path() {
 fn test_files_in_correct_directories() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let frontend_dir = temp_dir.path().join("frontend");
 
@@ -409,9 +426,10 @@ fn test_files_in_correct_directories() {
 fn test_no_old_src_directory() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let frontend_dir = temp_dir.path().join("frontend");
 
@@ -425,9 +443,10 @@ fn test_no_old_src_directory() {
 fn test_vite_config_uses_tailwindcss_vite() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let vite_config_path = temp_dir.path().join("frontend/vite.config.ts");
     let content = read_file(&vite_config_path);
@@ -441,9 +460,10 @@ fn test_vite_config_uses_tailwindcss_vite() {
 fn test_frontend_readme_documents_architecture() {
     let temp_dir = TempDir::new().unwrap();
     let network = create_test_network();
-    let generator = FrontendGenerator::new(temp_dir.path(), "test-contract", &network);
+    let generator = FrontendGenerator::new("test-contract", &network);
 
-    generator.generate().expect("Generation failed");
+    let project = generator.render().expect("Generation failed");
+    write_project(&project, temp_dir.path(), false).expect("Write failed");
 
     let readme_path = temp_dir.path().join("frontend/README.md");
     let content = read_file(&readme_path);
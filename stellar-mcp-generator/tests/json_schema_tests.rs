@@ -0,0 +1,395 @@
+//! Tests for the canonical JSON Schema of contract types and the types derived from it
+
+mod common;
+
+use serde_json::{json, Value};
+use stellar_mcp_generator::spec::json_types;
+use stellar_mcp_generator::spec::json_schema::{object_schema, with_defs};
+use stellar_mcp_generator::spec::sac;
+use stellar_mcp_generator::spec::types::{ContractSpec, EnumVariant, TypeDef, TypeRef, TypeSpec, UnionCase};
+use common::field;
+
+fn custom(name: &str) -> TypeRef {
+    TypeRef::Custom(name.to_string())
+}
+
+fn types() -> Vec<TypeSpec> {
+    vec![
+        TypeSpec {
+            name: "Config".to_string(),
+            doc: Some("Pool configuration".to_string()),
+            definition: TypeDef::Struct {
+                fields: vec![
+                    field("admin", TypeRef::Address),
+                    field("fee", TypeRef::Option(Box::new(TypeRef::U32))),
+                    field("mode", custom("Mode")),
+                ],
+            },
+        },
+        TypeSpec {
+            name: "Mode".to_string(),
+            doc: None,
+            definition: TypeDef::Enum {
+                variants: vec![
+                    EnumVariant { name: "Open".to_string(), doc: None, value: 0 },
+                    EnumVariant { name: "Closed".to_string(), doc: None, value: 1 },
+                ],
+            },
+        },
+        TypeSpec {
+            name: "Asset".to_string(),
+            doc: None,
+            definition: TypeDef::Union {
+                cases: vec![
+                    UnionCase { name: "Native".to_string(), doc: None, type_ref: None },
+                    UnionCase {
+                        name: "Stellar".to_string(),
+                        doc: None,
+                        type_ref: Some(TypeRef::Tuple(vec![TypeRef::Address])),
+                    },
+                ],
+            },
+        },
+    ]
+}
+
+#[test]
+fn test_integer_schemas() {
+    assert_eq!(
+        TypeRef::U32.to_json_schema(),
+        json!({ "type": "integer", "minimum": 0, "maximum": 4294967295u32 })
+    );
+    assert_eq!(TypeRef::I32.to_json_schema()["minimum"], json!(i32::MIN));
+    // 64-bit and wider integers are decimal strings
+    for type_ref in [TypeRef::U64, TypeRef::U128, TypeRef::U256, TypeRef::Timepoint, TypeRef::Duration] {
+        assert_eq!(type_ref.to_json_schema(), json!({ "type": "string", "pattern": "^(0|[1-9][0-9]*)$" }));
+    }
+    for type_ref in [TypeRef::I64, TypeRef::I128, TypeRef::I256] {
+        assert_eq!(type_ref.to_json_schema(), json!({ "type": "string", "pattern": "^(0|-?[1-9][0-9]*)$" }));
+    }
+}
+
+#[test]
+fn test_string_schemas() {
+    assert_eq!(TypeRef::BytesN(32).to_json_schema()["minLength"], json!(64));
+    assert_eq!(TypeRef::BytesN(32).to_json_schema()["maxLength"], json!(64));
+    assert_eq!(TypeRef::Bytes.to_json_schema()["pattern"], json!("^([0-9a-fA-F]{2})*$"));
+    assert_eq!(TypeRef::Address.to_json_schema()["pattern"], json!("^[GC][A-Z2-7]{55}$"));
+    assert_eq!(TypeRef::Symbol.to_json_schema()["maxLength"], json!(32));
+}
+
+#[test]
+fn test_compound_schemas() {
+    let option = TypeRef::Option(Box::new(TypeRef::Bool));
+    assert_eq!(
+        option.to_json_schema(),
+        json!({ "anyOf": [{ "type": "boolean" }, { "type": "null" }] })
+    );
+
+    let map = TypeRef::Map { key: Box::new(TypeRef::Symbol), value: Box::new(custom("Config")) };
+    let schema = map.to_json_schema();
+    assert_eq!(schema["type"], "array");
+    assert_eq!(schema["items"]["prefixItems"][1], json!({ "$ref": "#/$defs/Config" }));
+    assert_eq!(schema["items"]["minItems"], 2);
+    assert_eq!(schema["items"]["items"], false);
+
+    let result = TypeRef::Result { ok: Box::new(TypeRef::U32), err: Box::new(custom("Error")) };
+    assert_eq!(result.to_json_schema(), TypeRef::U32.to_json_schema());
+}
+
+#[test]
+fn test_type_definition_schemas() {
+    let types = types();
+
+    let config = types[0].to_json_schema();
+    assert_eq!(config["description"], "Pool configuration");
+    assert_eq!(config["required"], json!(["admin", "mode"]), "options are not required");
+    assert_eq!(config["additionalProperties"], false);
+
+    assert_eq!(types[1].to_json_schema(), json!({ "type": "string", "enum": ["Open", "Closed"] }));
+
+    let asset = types[2].to_json_schema();
+    let cases = asset["oneOf"].as_array().unwrap();
+    assert_eq!(cases[0]["properties"], json!({ "tag": { "const": "Native" } }));
+    assert_eq!(cases[1]["required"], json!(["tag", "values"]));
+}
+
+#[test]
+fn test_with_defs_embeds_referenced_types_transitively() {
+    let types = types();
+    let schema = object_schema([("config".to_string(), &custom("Config"), None)]);
+
+    let schema = with_defs(schema, &types);
+    let defs = schema["$defs"].as_object().unwrap();
+    let names: Vec<&str> = defs.keys().map(String::as_str).collect();
+    assert_eq!(names, ["Config", "Mode"], "Mode is referenced through Config, Asset not at all");
+
+    let plain = with_defs(json!({ "type": "boolean" }), &types);
+    assert!(plain.get("$defs").is_none());
+}
+
+#[test]
+fn test_contract_defs_cover_every_type() {
+    let mut spec = ContractSpec::new();
+    spec.types = types();
+    let defs = spec.json_schema_defs();
+    assert_eq!(defs.len(), 3);
+    assert_eq!(defs["Mode"], spec.types[1].to_json_schema());
+}
+
+/// Structure of a type, as read from a JSON Schema or a generated type
+/// expression, ignoring constraints
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Bool,
+    Null,
+    Int,
+    Str,
+    Opt(Box<Shape>),
+    List(Box<Shape>),
+    Tuple(Vec<Shape>),
+    Ref(String),
+}
+
+fn schema_shape(schema: &Value) -> Shape {
+    if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+        return Shape::Ref(target.trim_start_matches("#/$defs/").to_string());
+    }
+    if let Some(cases) = schema.get("anyOf").and_then(Value::as_array) {
+        assert_eq!(cases[1], json!({ "type": "null" }));
+        return Shape::Opt(Box::new(schema_shape(&cases[0])));
+    }
+    match schema["type"].as_str().unwrap() {
+        "boolean" => Shape::Bool,
+        "null" => Shape::Null,
+        "integer" => Shape::Int,
+        "string" => Shape::Str,
+        "array" => match schema.get("prefixItems").and_then(Value::as_array) {
+            Some(items) => Shape::Tuple(items.iter().map(schema_shape).collect()),
+            None => Shape::List(Box::new(schema_shape(&schema["items"]))),
+        },
+        other => panic!("unexpected schema type {}", other),
+    }
+}
+
+/// Minimal reader for the type expressions `json_types` generates
+struct Reader<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Reader<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { rest: text }
+    }
+
+    fn eat(&mut self, prefix: &str) -> bool {
+        match self.rest.strip_prefix(prefix) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn ident(&mut self) -> &'a str {
+        let end = self
+            .rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(self.rest.len());
+        let (ident, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        ident
+    }
+
+    /// Items separated by `, ` until `close`
+    fn list(&mut self, close: &str, item: impl Fn(&mut Self) -> Shape) -> Vec<Shape> {
+        let mut items = vec![item(self)];
+        while self.eat(", ") {
+            items.push(item(self));
+        }
+        assert!(self.eat(close), "expected {} at {:?}", close, self.rest);
+        items
+    }
+
+    /// Skip `.min(0)`, `.regex(/.../)` and other chained constraints
+    fn zod_constraints(&mut self) {
+        loop {
+            if self.eat(".regex(/") {
+                let end = self.rest.find("/)").unwrap();
+                self.rest = &self.rest[end + 2..];
+            } else if self.rest.starts_with('.') && !self.rest.starts_with(".nullable()") {
+                let end = self.rest.find(')').unwrap();
+                self.rest = &self.rest[end + 1..];
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn zod(&mut self) -> Shape {
+        let shape = if self.eat("z.boolean()") {
+            Shape::Bool
+        } else if self.eat("z.null()") {
+            Shape::Null
+        } else if self.eat("z.number()") {
+            Shape::Int
+        } else if self.eat("z.string()") {
+            Shape::Str
+        } else if self.eat("z.array(") {
+            Shape::List(Box::new(self.list(")", Self::zod).remove(0)))
+        } else if self.eat("z.tuple([") {
+            Shape::Tuple(self.list("])", Self::zod))
+        } else {
+            Shape::Ref(self.ident().trim_end_matches("Schema").to_string())
+        };
+        self.zod_constraints();
+        if self.eat(".nullable()") {
+            Shape::Opt(Box::new(shape))
+        } else {
+            shape
+        }
+    }
+
+    fn typescript(&mut self) -> Shape {
+        let mut shape = if self.eat("(") {
+            let inner = self.typescript();
+            assert!(self.eat(")"));
+            inner
+        } else if self.eat("[") {
+            Shape::Tuple(self.list("]", Self::typescript))
+        } else {
+            match self.ident() {
+                "boolean" => Shape::Bool,
+                "null" => Shape::Null,
+                "number" => Shape::Int,
+                "string" => Shape::Str,
+                name => Shape::Ref(name.to_string()),
+            }
+        };
+        while self.eat("[]") {
+            shape = Shape::List(Box::new(shape));
+        }
+        if self.eat(" | null") {
+            shape = Shape::Opt(Box::new(shape));
+        }
+        shape
+    }
+
+    fn pydantic(&mut self) -> Shape {
+        match self.ident() {
+            "bool" => Shape::Bool,
+            "None" => Shape::Null,
+            "int" => Shape::Int,
+            "str" => Shape::Str,
+            "Optional" => Shape::Opt(Box::new(self.generic().remove(0))),
+            "List" => Shape::List(Box::new(self.generic().remove(0))),
+            "Tuple" => Shape::Tuple(self.generic()),
+            name => Shape::Ref(name.trim_end_matches("Schema").to_string()),
+        }
+    }
+
+    fn generic(&mut self) -> Vec<Shape> {
+        assert!(self.eat("["));
+        self.list("]", Self::pydantic)
+    }
+}
+
+fn parse<'a>(text: &'a str, read: impl Fn(&mut Reader<'a>) -> Shape) -> Shape {
+    let mut reader = Reader::new(text);
+    let shape = read(&mut reader);
+    assert!(reader.rest.is_empty(), "unread {:?} in {:?}", reader.rest, text);
+    shape
+}
+
+/// Every type the generators are expected to handle: the primitives, nested
+/// compound types and every type used by the Stellar Asset Contract
+fn corpus() -> Vec<TypeRef> {
+    let mut corpus = vec![
+        TypeRef::Bool,
+        TypeRef::Void,
+        TypeRef::Status,
+        TypeRef::U32,
+        TypeRef::I32,
+        TypeRef::U64,
+        TypeRef::I64,
+        TypeRef::Timepoint,
+        TypeRef::Duration,
+        TypeRef::U128,
+        TypeRef::I128,
+        TypeRef::U256,
+        TypeRef::I256,
+        TypeRef::Bytes,
+        TypeRef::BytesN(32),
+        TypeRef::String,
+        TypeRef::Symbol,
+        TypeRef::Address,
+        custom("Config"),
+        TypeRef::Option(Box::new(TypeRef::I128)),
+        TypeRef::Vec(Box::new(TypeRef::Option(Box::new(custom("Mode"))))),
+        TypeRef::Vec(Box::new(TypeRef::Vec(Box::new(TypeRef::BytesN(4))))),
+        TypeRef::Map { key: Box::new(TypeRef::Address), value: Box::new(TypeRef::Vec(Box::new(TypeRef::U64))) },
+        TypeRef::Tuple(vec![TypeRef::Symbol, TypeRef::Option(Box::new(TypeRef::U32)), custom("Asset")]),
+        TypeRef::Option(Box::new(TypeRef::Map {
+            key: Box::new(TypeRef::U32),
+            value: Box::new(TypeRef::Tuple(vec![TypeRef::Bool, TypeRef::I256])),
+        })),
+    ];
+
+    let sac = sac::stellar_asset_spec().unwrap();
+    for function in &sac.functions {
+        corpus.extend(function.inputs.iter().map(|input| input.type_ref.clone()));
+        corpus.extend(function.output.clone());
+    }
+    for event in &sac.events {
+        corpus.extend(event.topics.iter().chain(&event.data).map(|f| f.type_ref.clone()));
+    }
+    corpus
+}
+
+#[test]
+fn test_zod_typescript_and_pydantic_agree_with_the_json_schema() {
+    for type_ref in corpus() {
+        let expected = schema_shape(&type_ref.to_json_schema());
+        assert_eq!(parse(&type_ref.to_zod(), Reader::zod), expected, "zod for {:?}", type_ref);
+        assert_eq!(parse(&type_ref.to_typescript(), Reader::typescript), expected, "TypeScript for {:?}", type_ref);
+        assert_eq!(parse(&type_ref.to_pydantic(), Reader::pydantic), expected, "Pydantic for {:?}", type_ref);
+    }
+}
+
+#[test]
+fn test_zod_and_pydantic_enforce_the_schema_constraints() {
+    for type_ref in corpus() {
+        let schema = type_ref.to_json_schema();
+        let zod = type_ref.to_zod();
+        let constraints = json_types::pydantic_constraints(&schema).join(", ");
+
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            assert!(zod.contains(&format!(".regex(/{}/)", pattern)), "{} lacks {}", zod, pattern);
+            assert!(constraints.contains(&format!("pattern=\"{}\"", pattern)), "{} lacks {}", constraints, pattern);
+        }
+        if let Some(max) = schema.get("maximum") {
+            assert!(zod.contains(&format!(".max({})", max)), "{}", zod);
+            assert!(constraints.contains(&format!("le={}", max)), "{}", constraints);
+        }
+        if let Some(max) = schema.get("maxLength") {
+            assert!(zod.contains(&format!("{})", max)), "{}", zod);
+            assert!(constraints.contains(&format!("max_length={}", max)), "{}", constraints);
+        }
+    }
+}
+
+#[test]
+fn test_struct_zod_uses_the_object_schema() {
+    let schema = types()[0].to_json_schema();
+    assert_eq!(
+        json_types::zod(&schema, "schemas."),
+        "z.object({ admin: z.string().regex(/^[GC][A-Z2-7]{55}$/).length(56), \
+         fee: z.number().int().min(0).max(4294967295).nullable().optional(), \
+         mode: schemas.ModeSchema })"
+    );
+    assert_eq!(
+        json_types::typescript(&schema, ""),
+        "{ admin: string; fee?: number | null; mode: Mode }"
+    );
+}
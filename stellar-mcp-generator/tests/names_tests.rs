@@ -0,0 +1,233 @@
+//! Tests for generated name collision checks (`generator::names`)
+
+use stellar_mcp_generator::generator::names::{find_collisions, NameCollision};
+use stellar_mcp_generator::generator::{render_project, BackendRegistry, ProjectOptions, ServedContract, TemplateRenderer};
+use stellar_mcp_generator::spec::*;
+use stellar_mcp_generator::{GeneratorError, NetworkConfig};
+
+fn function(name: &str, inputs: &[&str]) -> FunctionSpec {
+    FunctionSpec {
+        name: name.to_string(),
+        doc: None,
+        inputs: inputs
+            .iter()
+            .map(|input| ParameterSpec {
+                name: input.to_string(),
+                doc: None,
+                type_ref: TypeRef::U32,
+            })
+            .collect(),
+        output: None,
+        kind: FunctionKind::Write,
+    }
+}
+
+fn event(name: &str, topics: &[&str]) -> EventSpec {
+    EventSpec {
+        name: name.to_string(),
+        doc: None,
+        prefix_topics: vec![name.to_string()],
+        topics: topics
+            .iter()
+            .map(|topic| FieldSpec {
+                name: topic.to_string(),
+                doc: None,
+                type_ref: TypeRef::Address,
+            })
+            .collect(),
+        data: Vec::new(),
+        data_format: EventDataFormat::Map,
+    }
+}
+
+fn struct_type(name: &str) -> TypeSpec {
+    TypeSpec {
+        name: name.to_string(),
+        doc: None,
+        definition: TypeDef::Struct {
+            fields: vec![FieldSpec {
+                name: "amount".to_string(),
+                doc: None,
+                type_ref: TypeRef::I128,
+            }],
+        },
+    }
+}
+
+fn contract(name: &str, spec: ContractSpec) -> ServedContract {
+    ServedContract {
+        name: name.to_string(),
+        contract_id: String::new(),
+        spec,
+        wasm_hash: None,
+    }
+}
+
+fn spec(functions: Vec<FunctionSpec>, events: Vec<EventSpec>, types: Vec<TypeSpec>) -> ContractSpec {
+    ContractSpec {
+        functions,
+        events,
+        types,
+        ..ContractSpec::new()
+    }
+}
+
+/// `(scope, name)` of every collision
+fn collided(lang: &str, contracts: &[ServedContract]) -> Vec<(String, String)> {
+    find_collisions(&BackendRegistry::default(), lang, contracts)
+        .unwrap()
+        .into_iter()
+        .map(|c| (c.scope, c.name))
+        .collect()
+}
+
+fn has(collisions: &[(String, String)], scope: &str, name: &str) -> bool {
+    collisions.iter().any(|(s, n)| s == scope && n == name)
+}
+
+#[test]
+fn test_distinct_spec_names_with_the_same_generated_name() {
+    let contracts = [contract("token", spec(vec![function("get_x", &[]), function("getX", &[])], vec![], vec![]))];
+
+    let ts = find_collisions(&BackendRegistry::default(), "typescript", &contracts).unwrap();
+    assert!(ts.contains(&NameCollision {
+        scope: "tool names".to_string(),
+        name: "get-x".to_string(),
+        sources: vec!["function `get_x`".to_string(), "function `getX`".to_string()],
+    }));
+    assert!(has(&collided("typescript", &contracts), "src/tools/token.ts", "getX"));
+
+    let py = collided("python", &contracts);
+    assert!(has(&py, "tool names", "get_x"));
+    assert!(has(&py, "server.py", "get_x"));
+    assert!(has(&py, "src/bindings.py: ClientAsync methods", "get_x"));
+}
+
+#[test]
+fn test_collisions_with_built_in_names() {
+    let contracts = [contract(
+        "token",
+        spec(
+            vec![function("sign_and_submit", &[]), function("contract_info", &[]), function("create_client", &[])],
+            vec![],
+            vec![struct_type("Client")],
+        ),
+    )];
+
+    let ts = collided("typescript", &contracts);
+    assert!(has(&ts, "tool names", "sign-and-submit"));
+    assert!(has(&ts, "tool names", "contract-info"));
+    assert!(has(&ts, "src/tools/token.ts", "createClient"));
+    assert!(has(&ts, "src/bindings/index.ts", "Client"));
+
+    let py = collided("python", &contracts);
+    assert!(has(&py, "server.py", "sign_and_submit"));
+    assert!(has(&py, "server.py", "contract_info"));
+    assert!(has(&py, "tool names", "contract_info"));
+    assert!(!has(&py, "tool names", "sign-and-submit"), "Python tools are named in snake_case");
+
+    let rust = collided("rust", &contracts);
+    assert!(has(&rust, "tool names", "sign-and-submit"));
+    assert!(has(&rust, "tool names", "contract-info"));
+    assert_eq!(rust.len(), 2, "Rust identifiers don't come from the spec: {:?}", rust);
+    assert_eq!(collided("go", &contracts), rust);
+}
+
+#[test]
+fn test_collisions_between_functions_events_and_types() {
+    let contracts = [contract(
+        "token",
+        spec(
+            vec![function("get_transfer_events", &[]), function("foo", &["amount"])],
+            vec![event("transfer", &["from"])],
+            vec![struct_type("Balance"), struct_type("balance"), struct_type("FooParams")],
+        ),
+    )];
+
+    let ts = collided("typescript", &contracts);
+    assert!(has(&ts, "tool names", "get-transfer-events"));
+    assert!(has(&ts, "src/tools/token.ts", "getTransferEvents"));
+    assert!(has(&ts, "src/schemas/token.ts", "BalanceSchema"));
+    assert!(has(&ts, "src/schemas/token.ts", "FooParamsSchema"));
+    assert!(has(&ts, "src/tools/token.ts", "convertBalance"));
+
+    let py = collided("python", &contracts);
+    assert!(has(&py, "tool names", "get_transfer_events"));
+    assert!(has(&py, "server.py", "get_transfer_events"));
+    assert!(has(&py, "src/schemas.py", "balance_to_bindings"));
+}
+
+#[test]
+fn test_parameter_collisions_with_generated_parameters() {
+    let contracts = [contract(
+        "token",
+        spec(
+            vec![function("set", &["ctx", "amount"]), function("pay", &["toAddr", "to_addr"])],
+            vec![event("transfer", &["limit", "from"])],
+            vec![],
+        ),
+    )];
+
+    let py = collided("python", &contracts);
+    assert!(has(&py, "server.py: parameters of set", "ctx"));
+    assert!(has(&py, "server.py: parameters of pay", "to_addr"));
+    assert!(has(&py, "server.py: parameters of get_transfer_events", "limit"));
+    assert_eq!(py.len(), 3, "{:?}", py);
+
+    let ts = collided("typescript", &contracts);
+    assert_eq!(ts, vec![("src/index.ts: inputs of get-transfer-events".to_string(), "limit".to_string())]);
+
+    let rust = collided("rust", &contracts);
+    assert_eq!(rust, vec![("tools.json: inputs of get-transfer-events".to_string(), "limit".to_string())]);
+    assert_eq!(collided("go", &contracts), rust);
+}
+
+#[test]
+fn test_namespaced_contracts_do_not_collide_with_each_other() {
+    let token = spec(vec![function("transfer", &[])], vec![event("transfer", &[])], vec![struct_type("Config")]);
+    let contracts = [contract("token", token.clone()), contract("vault", token)];
+
+    for lang in ["typescript", "python", "rust", "go"] {
+        assert_eq!(collided(lang, &contracts), Vec::new(), "{}", lang);
+    }
+}
+
+#[test]
+fn test_render_project_reports_collisions() {
+    let contracts = [contract("token", spec(vec![function("get_x", &[]), function("getX", &[])], vec![], vec![]))];
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let templates = TemplateRenderer::new().unwrap();
+
+    let err = render_project(&ProjectOptions {
+        lang: "typescript",
+        project_name: "token",
+        server_name: "token-mcp",
+        network: &network,
+        contracts: &contracts,
+        templates: &templates,
+        with_frontend: false,
+        with_rest: false,
+    })
+    .unwrap_err();
+
+    assert!(matches!(&err, GeneratorError::NameCollisions { collisions } if collisions.len() == 2), "{:?}", err);
+    assert_eq!(err.exit_code(), 5);
+    let message = err.to_string();
+    assert!(message.contains("tool names: 'get-x' is generated from function `get_x` and function `getX`"), "{}", message);
+    assert!(message.contains("[functions] exclude"), "{}", message);
+}
+
+#[test]
+fn test_stellar_asset_spec_has_no_collisions() {
+    let contracts = [contract("token", sac::stellar_asset_spec().unwrap())];
+    for lang in ["typescript", "python", "rust", "go"] {
+        assert_eq!(collided(lang, &contracts), Vec::new(), "{}", lang);
+    }
+}
+
+#[test]
+fn test_unknown_language_is_an_error() {
+    let contracts = [contract("token", sac::stellar_asset_spec().unwrap())];
+    let err = find_collisions(&BackendRegistry::default(), "java", &contracts).unwrap_err();
+    assert!(matches!(&err, GeneratorError::InvalidInput(message) if message.contains("Invalid language 'java'")), "{:?}", err);
+}
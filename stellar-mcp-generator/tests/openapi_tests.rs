@@ -0,0 +1,145 @@
+//! Tests for the OpenAPI document of the REST gateway (`--with-rest`)
+
+mod common;
+
+use serde_json::{json, Value};
+use stellar_mcp_generator::generator::backend::{PythonBackend, TypeScriptBackend};
+use stellar_mcp_generator::generator::openapi::openapi_document;
+use stellar_mcp_generator::generator::ServedContract;
+use stellar_mcp_generator::spec::types::{
+    ContractSpec, FieldSpec, FunctionKind, FunctionSpec, ParameterSpec, TypeDef, TypeRef, TypeSpec,
+};
+use common::token;
+
+fn pool() -> ServedContract {
+    let mut spec = ContractSpec::new();
+    spec.types.push(TypeSpec {
+        name: "Config".to_string(),
+        doc: None,
+        definition: TypeDef::Struct {
+            fields: vec![
+                FieldSpec { name: "admin".to_string(), doc: None, type_ref: TypeRef::Address },
+                FieldSpec { name: "fee".to_string(), doc: None, type_ref: TypeRef::U32 },
+            ],
+        },
+    });
+    spec.functions.push(FunctionSpec {
+        name: "set_config".to_string(),
+        doc: Some("Replace the pool configuration".to_string()),
+        inputs: vec![ParameterSpec {
+            name: "config".to_string(),
+            doc: None,
+            type_ref: TypeRef::Custom("Config".to_string()),
+        }],
+        output: None,
+        kind: FunctionKind::Write,
+    });
+    spec.functions.push(FunctionSpec {
+        name: "config".to_string(),
+        doc: None,
+        inputs: vec![],
+        output: Some(TypeRef::Custom("Config".to_string())),
+        kind: FunctionKind::ReadOnly,
+    });
+    ServedContract { name: "pool".to_string(), contract_id: String::new(), spec, wasm_hash: None }
+}
+
+fn post<'a>(document: &'a Value, path: &str) -> &'a Value {
+    document["paths"]
+        .get(path)
+        .unwrap_or_else(|| panic!("no {} path", path))
+        .get("post")
+        .unwrap()
+}
+
+fn request_schema(operation: &Value) -> &Value {
+    &operation["requestBody"]["content"]["application/json"]["schema"]
+}
+
+fn response_schema(operation: &Value) -> &Value {
+    &operation["responses"]["200"]["content"]["application/json"]["schema"]
+}
+
+#[test]
+fn test_one_operation_per_function_plus_transaction_tools() {
+    let document = openapi_document(&TypeScriptBackend, "pool", &[pool()]);
+
+    assert_eq!(document["openapi"], "3.1.0");
+    assert_eq!(document["info"]["title"], "pool-mcp REST API");
+    let paths: Vec<&String> = document["paths"].as_object().unwrap().keys().collect();
+    assert_eq!(
+        paths,
+        [
+            "/api/config",
+            "/api/prepare-sign-and-submit",
+            "/api/prepare-transaction",
+            "/api/set-config",
+            "/api/sign-and-submit",
+        ]
+    );
+
+    let set_config = post(&document, "/api/set-config");
+    assert_eq!(set_config["operationId"], "set-config");
+    assert_eq!(set_config["tags"], json!(["pool"]));
+    assert_eq!(set_config["description"], "Replace the pool configuration");
+    assert_eq!(set_config["requestBody"]["required"], true);
+    assert_eq!(response_schema(set_config)["required"], json!(["xdr"]));
+    for status in ["400", "422", "429"] {
+        let reference = set_config["responses"][status]["$ref"].as_str().unwrap();
+        let name = reference.strip_prefix("#/components/responses/").unwrap();
+        assert!(document["components"]["responses"].get(name).is_some(), "{} is not defined", reference);
+    }
+}
+
+#[test]
+fn test_contract_types_are_hoisted_into_components() {
+    let document = openapi_document(&TypeScriptBackend, "pool", &[pool()]);
+
+    let set_config = post(&document, "/api/set-config");
+    assert_eq!(
+        request_schema(set_config)["properties"]["config"]["$ref"],
+        "#/components/schemas/Config"
+    );
+    assert!(request_schema(set_config).get("$defs").is_none());
+    let config = post(&document, "/api/config");
+    assert_eq!(
+        response_schema(config)["properties"]["result"]["$ref"],
+        "#/components/schemas/Config"
+    );
+    assert_eq!(document["components"]["schemas"]["Config"]["required"], json!(["admin", "fee"]));
+    assert!(!document.to_string().contains("#/$defs/"), "every reference points at components");
+}
+
+#[test]
+fn test_multi_contract_routes_and_types_are_namespaced() {
+    let document = openapi_document(&TypeScriptBackend, "defi", &[pool(), token()]);
+
+    let set_config = post(&document, "/api/pool.set-config");
+    assert_eq!(set_config["tags"], json!(["pool"]));
+    assert_eq!(
+        request_schema(set_config)["properties"]["config"]["$ref"],
+        "#/components/schemas/pool.Config"
+    );
+    assert!(document["components"]["schemas"].get("pool.Config").is_some());
+    assert_eq!(post(&document, "/api/token.transfer")["tags"], json!(["token"]));
+    assert!(document["paths"].get("/api/sign-and-submit").is_some(), "transaction tools are shared");
+    assert!(document["paths"].get("/api/token.get-transfer-events").is_none(), "event tools stay MCP-only");
+}
+
+#[test]
+fn test_python_document_uses_python_names() {
+    let document = openapi_document(&PythonBackend, "pool", &[pool()]);
+
+    assert!(document["paths"].get("/api/set_config").is_some());
+    let sign = request_schema(post(&document, "/api/sign-and-submit"));
+    assert!(sign["properties"].get("secret_key").is_some());
+    assert!(sign["properties"].get("walletContractId").is_none(), "Python signs with a secret key only");
+    let prepare = post(&document, "/api/prepare-transaction");
+    assert_eq!(request_schema(prepare)["required"], json!(["xdr", "wallet_address", "tool_name"]));
+    assert_eq!(response_schema(prepare)["required"], json!(["transaction", "data"]));
+
+    let ts = openapi_document(&TypeScriptBackend, "pool", &[pool()]);
+    let prepare = post(&ts, "/api/prepare-transaction");
+    assert_eq!(request_schema(prepare)["required"], json!(["xdr", "walletAddress", "toolName"]));
+    assert_eq!(response_schema(prepare)["required"], json!(["walletReadyXdr", "preview"]));
+}
@@ -0,0 +1,311 @@
+//! Tests for in-memory project rendering (`render_project`, `GeneratedProject`)
+
+mod common;
+
+use stellar_mcp_generator::generator::update::{write_project, GenerationManifest};
+use stellar_mcp_generator::generator::{render_project, GeneratedProject, ProjectOptions, TemplateRenderer};
+use stellar_mcp_generator::{GeneratorError, NetworkConfig};
+use common::{token, TOKEN_ID};
+
+fn render(lang: &str, with_frontend: bool) -> Result<GeneratedProject, GeneratorError> {
+    render_with(lang, with_frontend, false)
+}
+
+fn render_with(lang: &str, with_frontend: bool, with_rest: bool) -> Result<GeneratedProject, GeneratorError> {
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let templates = TemplateRenderer::new().unwrap();
+    render_project(&ProjectOptions {
+        lang,
+        project_name: "token",
+        server_name: "token-mcp",
+        network: &network,
+        contracts: &[token()],
+        templates: &templates,
+        with_frontend,
+        with_rest,
+    })
+}
+
+#[test]
+fn test_render_typescript_project_in_memory() {
+    let project = render("typescript", false).unwrap();
+
+    for path in ["src/index.ts", "src/tools/token.ts", "src/bindings/index.ts", "package.json", "Dockerfile"] {
+        assert!(project.get(path).is_some(), "{} missing", path);
+    }
+    assert!(project.get_str("src/index.ts").unwrap().contains("token-mcp"));
+    assert!(!project.paths().any(|p| p.starts_with("frontend")));
+
+    // The spec snapshot is part of the project, like every other generated file
+    let snapshot: serde_json::Value = serde_json::from_str(project.get_str(".stellar-mcp/spec.json").unwrap()).unwrap();
+    assert_eq!(snapshot["network"], "testnet");
+    assert_eq!(snapshot["contract_id"], TOKEN_ID);
+    assert!(snapshot["spec"]["functions"].as_array().unwrap().iter().any(|f| f["name"] == "transfer"));
+}
+
+#[test]
+fn test_typescript_index_escapes_network_and_server_name() {
+    let network = NetworkConfig {
+        name: "custom".to_string(),
+        rpc_url: "https://rpc.example.com/?key='abc'".to_string(),
+        network_passphrase: "Dev Network ; it's \\ mine".to_string(),
+    };
+    let templates = TemplateRenderer::new().unwrap();
+    let project = render_project(&ProjectOptions {
+        lang: "typescript",
+        project_name: "token",
+        server_name: "o'token",
+        network: &network,
+        contracts: &[token()],
+        templates: &templates,
+        with_frontend: false,
+        with_rest: false,
+    })
+    .unwrap();
+
+    let index = project.get_str("src/index.ts").unwrap();
+    assert!(index.contains("process.env.RPC_URL || 'https://rpc.example.com/?key=\\'abc\\''"));
+    assert!(index.contains("process.env.NETWORK_PASSPHRASE || 'Dev Network ; it\\'s \\\\ mine'"));
+    assert!(index.contains("name: 'o\\'token-mcp'"));
+}
+
+#[test]
+fn test_render_python_project_with_frontend() {
+    let project = render("python", true).unwrap();
+
+    assert!(project.get("server.py").is_some());
+    assert!(project.get("src/bindings.py").is_some());
+    assert!(project.get("frontend/package.json").is_some());
+    assert!(project.get("frontend/src/client/App.tsx").is_some());
+    assert!(project.get("package.json").is_none(), "no TypeScript server files");
+}
+
+#[test]
+fn test_render_rust_project_in_memory() {
+    let project = render("rust", false).unwrap();
+
+    for path in ["src/main.rs", "src/server.rs", "src/contracts.rs", "Cargo.toml", "Dockerfile", "tools.json"] {
+        assert!(project.get(path).is_some(), "{} missing", path);
+    }
+    assert!(project.get_str("Cargo.toml").unwrap().contains("name = \"token-mcp-server\""));
+    let contracts = project.get_str("src/contracts.rs").unwrap();
+    assert!(contracts.contains(r#"env_var: "CONTRACT_ID","#), "{}", contracts);
+    assert!(contracts.contains(
+        r#"inputs: &[Field { name: "from", ty: Type::Address }, Field { name: "to", ty: Type::Address }, Field { name: "amount", ty: Type::I128 }],"#
+    ), "{}", contracts);
+    let server = project.get_str("src/server.rs").unwrap();
+    assert!(server.contains("include_str!(\"../tools.json\")"));
+    assert!(server.contains("// stellar-mcp:custom-begin tools"));
+}
+
+#[test]
+fn test_render_go_project_in_memory() {
+    let project = render("go", false).unwrap();
+
+    for path in ["main.go", "server.go", "contracts.go", "go.mod", "Dockerfile", "tools.json"] {
+        assert!(project.get(path).is_some(), "{} missing", path);
+    }
+    assert!(project.get_str("go.mod").unwrap().starts_with("module token-mcp-server\n"));
+    let contracts = project.get_str("contracts.go").unwrap();
+    assert!(contracts.contains("EnvVar:          \"CONTRACT_ID\","), "{}", contracts);
+    assert!(contracts.contains(
+        r#"Inputs:   []Field{{"from", TAddress}, {"to", TAddress}, {"amount", TI128}},"#
+    ), "{}", contracts);
+    let server = project.get_str("server.go").unwrap();
+    assert!(server.contains("//go:embed tools.json"));
+    assert!(server.contains("// stellar-mcp:custom-begin tools"));
+}
+
+#[test]
+fn test_render_rejects_unknown_language() {
+    let err = render("java", false).unwrap_err();
+    assert!(matches!(err, GeneratorError::InvalidInput(_)), "{:?}", err);
+}
+
+#[test]
+fn test_render_rest_gateway() {
+    assert!(render("typescript", false).unwrap().get("openapi.json").is_none(), "REST is opt-in");
+
+    let project = render_with("typescript", false, true).unwrap();
+    let document: serde_json::Value = serde_json::from_str(project.get_str("openapi.json").unwrap()).unwrap();
+    assert!(document["paths"].get("/api/transfer").is_some());
+    let index = project.get_str("src/index.ts").unwrap();
+    assert!(index.contains("app.post('/api/:tool'"), "{}", index);
+    assert!(index.contains("app.use(['/mcp', '/api'],"));
+    assert!(index.contains("client.callTool({ name, arguments: args })"));
+    assert!(project.get_str("Dockerfile").unwrap().contains("COPY --from=builder /app/openapi.json ./"));
+    let vercel: serde_json::Value = serde_json::from_str(project.get_str("vercel.json").unwrap()).unwrap();
+    assert_eq!(vercel["routes"][1]["src"], "/api/(.*)");
+
+    let project = render_with("python", false, true).unwrap();
+    let document: serde_json::Value = serde_json::from_str(project.get_str("openapi.json").unwrap()).unwrap();
+    assert!(document["paths"].get("/api/set_admin").is_some());
+    let server = project.get_str("server.py").unwrap();
+    assert!(server.contains("@mcp.custom_route(\"/api/{tool}\", methods=[\"POST\"])"), "{}", server);
+    assert!(server.contains("path.startswith(\"/mcp\") or path.startswith(\"/api\")"));
+
+    // Without --with-rest the servers have no REST routes
+    assert!(!render("typescript", false).unwrap().get_str("src/index.ts").unwrap().contains("/api/"));
+    assert!(!render("python", false).unwrap().get_str("server.py").unwrap().contains("/api/"));
+    let vercel: serde_json::Value =
+        serde_json::from_str(render("typescript", false).unwrap().get_str("vercel.json").unwrap()).unwrap();
+    assert_eq!(vercel["routes"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_rest_gateway_is_rejected_for_other_languages() {
+    for lang in ["rust", "go"] {
+        let err = render_with(lang, false, true).unwrap_err();
+        assert!(matches!(&err, GeneratorError::InvalidInput(message) if message.contains("--with-rest")), "{:?}", err);
+    }
+}
+
+#[test]
+fn test_rendering_is_deterministic_and_the_writer_persists_it() {
+    let project = render("typescript", false).unwrap();
+    assert_eq!(project, render("typescript", false).unwrap());
+
+    // The writer persists every file and records the manifest
+    let managed = tempfile::tempdir().unwrap();
+    write_project(&project, managed.path(), false).unwrap();
+    for (path, content) in &project.files {
+        assert_eq!(&std::fs::read(managed.path().join(path)).unwrap(), content);
+    }
+    let manifest = GenerationManifest::load(managed.path()).unwrap().unwrap();
+    assert_eq!(manifest.files.len(), project.files.len());
+}
+
+#[test]
+fn test_contract_error_codes_are_rendered_for_tool_failures() {
+    use stellar_mcp_generator::spec::{ErrorEnumSpec, ErrorSpec};
+
+    let mut contracts = vec![token()];
+    contracts[0].spec.errors = vec![ErrorEnumSpec {
+        name: "TokenError".to_string(),
+        doc: None,
+        cases: vec![
+            ErrorSpec { name: "InsufficientBalance".to_string(), doc: Some("Balance is too low".to_string()), code: 7 },
+            ErrorSpec { name: "Paused".to_string(), doc: None, code: 8 },
+        ],
+    }];
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let templates = TemplateRenderer::new().unwrap();
+    let render = |lang| {
+        render_project(&ProjectOptions {
+            lang,
+            project_name: "token",
+            server_name: "token-mcp",
+            network: &network,
+            contracts: &contracts,
+            templates: &templates,
+            with_frontend: false,
+            with_rest: false,
+        })
+        .unwrap()
+    };
+
+    let ts = render("typescript");
+    let tools = ts.get_str("src/tools/token.ts").unwrap();
+    assert!(tools.contains("export const CONTRACT_ERRORS: ContractErrors = {"), "{}", tools);
+    assert!(tools.contains(r#""7": {
+    "doc": "Balance is too low",
+    "name": "InsufficientBalance"
+  },"#), "{}", tools);
+    assert!(tools.contains(r#""8": {
+    "name": "Paused"
+  }"#), "{}", tools);
+    let index = ts.get_str("src/index.ts").unwrap();
+    assert!(index.contains("return formatToolError('transfer', error, tools.CONTRACT_ERRORS);"), "{}", index);
+    assert!(ts.get_str("src/lib/errors.ts").unwrap().contains("contractError = { code, ...contractErrors[code] };"));
+
+    let py = render("python");
+    let client = py.get_str("src/contract_client.py").unwrap();
+    assert!(client.contains(
+        "CONTRACT_ERRORS: Dict[int, Dict[str, str]] = {\n    7: {\"name\": \"InsufficientBalance\", \"doc\": \"Balance is too low\"},\n    8: {\"name\": \"Paused\"},\n}\n"
+    ), "{}", client);
+    let server = py.get_str("server.py").unwrap();
+    assert!(server.contains("raise format_tool_error(e, CONTRACT_ERRORS) from e"), "{}", server);
+    assert!(py.get("src/lib/errors.py").is_some());
+
+    let rust = render("rust");
+    let table = rust.get_str("src/contracts.rs").unwrap();
+    assert!(table.contains(r#"ContractError { code: 7, name: "InsufficientBalance", doc: Some("Balance is too low") },"#), "{}", table);
+    assert!(table.contains(r#"ContractError { code: 8, name: "Paused", doc: None },"#), "{}", table);
+
+    let go = render("go");
+    let table = go.get_str("contracts.go").unwrap();
+    assert!(table.contains(r#"{Code: 7, Name: "InsufficientBalance", Doc: "Balance is too low"},"#), "{}", table);
+    assert!(table.contains(r#"{Code: 8, Name: "Paused", Doc: ""},"#), "{}", table);
+}
+
+#[test]
+fn test_tool_manifest_describes_each_language_server() {
+    use stellar_mcp_generator::spec::FunctionKind;
+
+    let mut contracts = vec![token()];
+    for function in &mut contracts[0].spec.functions {
+        if function.name == "balance" {
+            function.kind = FunctionKind::ReadOnly;
+        }
+    }
+    let network = NetworkConfig::from_name("testnet").unwrap();
+    let templates = TemplateRenderer::new().unwrap();
+    let manifest = |lang| -> serde_json::Value {
+        let project = render_project(&ProjectOptions {
+            lang,
+            project_name: "token",
+            server_name: "token-mcp",
+            network: &network,
+            contracts: &contracts,
+            templates: &templates,
+            with_frontend: false,
+            with_rest: false,
+        })
+        .unwrap();
+        serde_json::from_str(project.get_str("tools.json").unwrap()).unwrap()
+    };
+    let tool = |manifest: &serde_json::Value, name: &str| -> serde_json::Value {
+        manifest["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|tool| tool["name"] == name)
+            .unwrap_or_else(|| panic!("no {} tool", name))
+            .clone()
+    };
+
+    let ts = manifest("typescript");
+    let py = manifest("python");
+    assert_eq!(ts["language"], "typescript");
+    assert_eq!(ts["tools"].as_array().unwrap().len(), py["tools"].as_array().unwrap().len());
+
+    // Tool and argument names follow each server, the schemas are the same
+    let ts_transfer = tool(&ts, "transfer");
+    let py_transfer = tool(&py, "transfer");
+    assert_eq!(ts_transfer["inputSchema"]["required"], serde_json::json!(["from", "to", "amount"]));
+    assert_eq!(py_transfer["inputSchema"]["required"], serde_json::json!(["from_", "to", "amount"]));
+    assert_eq!(
+        ts_transfer["inputSchema"]["properties"]["from"],
+        py_transfer["inputSchema"]["properties"]["from_"]
+    );
+    assert_eq!(ts_transfer["inputSchema"]["properties"]["amount"]["pattern"], "^(0|-?[1-9][0-9]*)$");
+    assert_eq!(ts_transfer["annotations"]["destructiveHint"], true);
+    assert_eq!(ts_transfer["outputSchema"]["required"], serde_json::json!(["xdr"]));
+
+    let ts_balance = tool(&ts, "balance");
+    assert_eq!(ts_balance["annotations"], serde_json::json!({ "readOnlyHint": true }));
+    assert_eq!(ts_balance["outputSchema"]["properties"]["result"]["type"], "string");
+    assert_eq!(tool(&py, "set_admin")["inputSchema"], tool(&ts, "set-admin")["inputSchema"]);
+
+    // The Rust and Go servers name their tools and arguments like the TypeScript one
+    let rust = manifest("rust");
+    assert_eq!(rust["language"], "rust");
+    assert_eq!(rust["tools"], ts["tools"]);
+    let go = manifest("go");
+    assert_eq!(go["language"], "go");
+    assert_eq!(go["tools"], ts["tools"]);
+
+    let events = tool(&ts, "get-transfer-events");
+    assert!(events["inputSchema"]["properties"].get("startLedger").is_some());
+    assert!(tool(&py, "get_transfer_events")["inputSchema"]["properties"].get("start_ledger").is_some());
+}
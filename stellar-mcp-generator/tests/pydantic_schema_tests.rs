@@ -1,19 +1,17 @@
 //! Tests for Pydantic schema generation
 
-use stellar_mcp_generator::spec::types::{
-    ContractSpec, EnumVariant, FieldSpec, FunctionSpec, ParameterSpec, TypeDef, TypeRef, TypeSpec,
-};
+use stellar_mcp_generator::spec::types::{ContractSpec, EnumVariant, FieldSpec, TypeDef, TypeRef, TypeSpec};
 
 #[test]
 fn test_type_ref_to_pydantic_primitives() {
     assert_eq!(TypeRef::Bool.to_pydantic(), "bool");
     assert_eq!(TypeRef::U32.to_pydantic(), "int");
     assert_eq!(TypeRef::I32.to_pydantic(), "int");
-    assert_eq!(TypeRef::U64.to_pydantic(), "int");
+    assert_eq!(TypeRef::U64.to_pydantic(), "str");
     assert_eq!(TypeRef::U128.to_pydantic(), "str");
     assert_eq!(TypeRef::String.to_pydantic(), "str");
     assert_eq!(TypeRef::Address.to_pydantic(), "str");
-    assert_eq!(TypeRef::Bytes.to_pydantic(), "bytes");
+    assert_eq!(TypeRef::Bytes.to_pydantic(), "str");
 }
 
 #[test]
@@ -43,7 +41,8 @@ fn test_type_ref_to_pydantic_map() {
         key: Box::new(TypeRef::String),
         value: Box::new(TypeRef::U32),
     };
-    assert_eq!(map.to_pydantic(), "Dict[str, int]");
+    // Maps are lists of (key, value) pairs, so keys needn't be strings
+    assert_eq!(map.to_pydantic(), "List[Tuple[str, int]]");
 }
 
 #[test]
@@ -134,6 +133,7 @@ fn test_pydantic_schema_generation_struct() {
             },
         }],
         errors: vec![],
+        events: vec![],
         raw_spec_entries: vec![],
     };
 
@@ -173,14 +173,14 @@ fn test_pydantic_schema_generation_enum() {
             },
         }],
         errors: vec![],
+        events: vec![],
         raw_spec_entries: vec![],
     };
 
     let schemas = pydantic_schemas::generate_pydantic_schemas(&spec);
 
-    assert!(schemas.contains("class TokenTypeSchema(BaseModel)"));
-    assert!(schemas.contains("model_config = ConfigDict(frozen=True)"));
-    assert!(schemas.contains(r#"tag: Literal["Allowlist", "Blocklist"]"#));
+    // Enums are passed as their variant names
+    assert!(schemas.contains("# Token type enum\nTokenTypeSchema = Literal[\"Allowlist\", \"Blocklist\"]\n"), "{}", schemas);
 }
 
 #[test]
@@ -202,6 +202,7 @@ fn test_pydantic_conversion_helpers_generated() {
             },
         }],
         errors: vec![],
+        events: vec![],
         raw_spec_entries: vec![],
     };
 
@@ -210,7 +211,7 @@ fn test_pydantic_conversion_helpers_generated() {
     assert!(conversions.contains("def tokenconfig_to_bindings"));
     assert!(conversions.contains("schema: TokenConfigSchema"));
     assert!(conversions.contains("-> TokenConfig"));
-    assert!(conversions.contains("Address(schema.admin)"));
+    assert!(conversions.contains("admin=Address(schema.admin),"));
 }
 
 #[test]
@@ -222,6 +223,7 @@ fn test_pydantic_imports_included() {
         functions: vec![],
         types: vec![],
         errors: vec![],
+        events: vec![],
         raw_spec_entries: vec![],
     };
 
@@ -263,7 +265,7 @@ fn test_bigint_types_as_strings() {
 
 #[test]
 fn test_complex_nested_pydantic_type() {
-    // Test: Optional[List[Dict[str, TokenConfigSchema]]]
+    // Test: Optional[List[List[Tuple[str, TokenConfigSchema]]]]
     let complex = TypeRef::Option(Box::new(TypeRef::Vec(Box::new(TypeRef::Map {
         key: Box::new(TypeRef::String),
         value: Box::new(TypeRef::Custom("TokenConfig".to_string())),
@@ -271,7 +273,7 @@ fn test_complex_nested_pydantic_type() {
 
     assert_eq!(
         complex.to_pydantic(),
-        "Optional[List[Dict[str, TokenConfigSchema]]]"
+        "Optional[List[List[Tuple[str, TokenConfigSchema]]]]"
     );
 }
 
@@ -284,6 +286,7 @@ fn test_generated_pydantic_import_statements() {
         functions: vec![],
         types: vec![],
         errors: vec![],
+        events: vec![],
         raw_spec_entries: vec![],
     };
 
@@ -302,7 +305,7 @@ fn test_generated_pydantic_import_statements() {
 }
 
 #[test]
-fn test_pydantic_field_frozen_for_enums() {
+fn test_pydantic_enum_schema_is_a_literal() {
     use stellar_mcp_generator::generator::pydantic_schemas;
 
     let spec = ContractSpec {
@@ -320,11 +323,13 @@ fn test_pydantic_field_frozen_for_enums() {
             },
         }],
         errors: vec![],
+        events: vec![],
         raw_spec_entries: vec![],
     };
 
     let schemas = pydantic_schemas::generate_pydantic_schemas(&spec);
 
-    // Enums should be frozen (immutable)
-    assert!(schemas.contains("model_config = ConfigDict(frozen=True)"));
+    // Enums are plain (immutable) strings
+    assert!(schemas.contains("StatusSchema = Literal[\"Active\"]\n"), "{}", schemas);
+    assert!(!schemas.contains("class StatusSchema"), "{}", schemas);
 }
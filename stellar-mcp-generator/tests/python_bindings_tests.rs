@@ -0,0 +1,117 @@
+//! Tests for the native Python contract bindings
+
+mod common;
+
+use stellar_mcp_generator::generator::pydantic_schemas::{generate_conversion_helpers, schema_to_binding_expr};
+use stellar_mcp_generator::generator::python_bindings::{decode_expr, encode_expr, render_python_bindings};
+use stellar_mcp_generator::spec::sac;
+use stellar_mcp_generator::spec::types::*;
+use common::field;
+
+fn spec_with_types() -> ContractSpec {
+    let mut spec = ContractSpec::new();
+    spec.types.push(TypeSpec {
+        name: "Grant".to_string(),
+        doc: Some("A token grant".to_string()),
+        definition: TypeDef::Struct {
+            fields: vec![
+                field("owner", TypeRef::Address),
+                field("hash", TypeRef::BytesN(32)),
+                field("amount", TypeRef::I128),
+            ],
+        },
+    });
+    spec.types.push(TypeSpec {
+        name: "Level".to_string(),
+        doc: None,
+        definition: TypeDef::Enum {
+            variants: vec![EnumVariant { name: "Low".to_string(), doc: None, value: 1 }],
+        },
+    });
+    spec.types.push(TypeSpec {
+        name: "DataKey".to_string(),
+        doc: None,
+        definition: TypeDef::Union {
+            cases: vec![
+                UnionCase { name: "Admin".to_string(), doc: None, type_ref: None },
+                UnionCase {
+                    name: "Balance".to_string(),
+                    doc: None,
+                    type_ref: Some(TypeRef::Tuple(vec![TypeRef::Address])),
+                },
+            ],
+        },
+    });
+    spec
+}
+
+#[test]
+fn test_scval_expressions_follow_the_spec_type() {
+    assert_eq!(encode_expr(&TypeRef::String, "name"), "scval.to_string(name)");
+    assert_eq!(encode_expr(&TypeRef::Bytes, "data"), "scval.to_bytes(data)");
+    assert_eq!(
+        encode_expr(&TypeRef::Option(Box::new(TypeRef::Custom("Grant".to_string()))), "g"),
+        "(scval.to_void() if g is None else g.to_scval())"
+    );
+    assert_eq!(
+        encode_expr(&TypeRef::Vec(Box::new(TypeRef::Vec(Box::new(TypeRef::U32)))), "v"),
+        "scval.to_vec([scval.to_vec([scval.to_uint32(item1) for item1 in item0]) for item0 in v])"
+    );
+    assert_eq!(
+        decode_expr(&TypeRef::String, "v"),
+        "scval.from_string(v).decode(\"utf-8\", \"replace\")"
+    );
+    assert_eq!(
+        decode_expr(&TypeRef::Tuple(vec![TypeRef::Symbol]), "v"),
+        "(lambda items0: (scval.from_symbol(items0[0]),))(scval.from_vec(v))"
+    );
+}
+
+#[test]
+fn test_schema_conversions_match_pydantic_types() {
+    // Pydantic carries 64-bit and wider integers as strings and bytes as hex
+    assert_eq!(schema_to_binding_expr(&TypeRef::I128, "amount"), "int(amount)");
+    assert_eq!(schema_to_binding_expr(&TypeRef::U64, "ledger"), "int(ledger)");
+    assert_eq!(schema_to_binding_expr(&TypeRef::BytesN(32), "salt"), "bytes.fromhex(salt)");
+    assert_eq!(schema_to_binding_expr(&TypeRef::String, "name"), "name");
+    assert_eq!(schema_to_binding_expr(&TypeRef::Bytes, "data"), "bytes.fromhex(data)");
+    let map = TypeRef::Map { key: Box::new(TypeRef::Symbol), value: Box::new(TypeRef::U32) };
+    assert_eq!(schema_to_binding_expr(&map, "limits"), "dict(limits)");
+    assert_eq!(schema_to_binding_expr(&TypeRef::Vec(Box::new(TypeRef::U32)), "ids"), "ids");
+    assert_eq!(
+        schema_to_binding_expr(&TypeRef::Option(Box::new(TypeRef::U128)), "cap"),
+        "(None if cap is None else int(cap))"
+    );
+
+    let helpers = generate_conversion_helpers(&spec_with_types());
+    assert!(helpers.contains("        hash=bytes.fromhex(schema.hash),\n        amount=int(schema.amount),\n"), "{}", helpers);
+    assert!(helpers.contains("    return Level[schema]\n"));
+    assert!(helpers.contains(
+        "    if schema.tag == \"Balance\":\n        return DataKey(tag=schema.tag, value=(Address(schema.values[0]),))\n    return DataKey(tag=schema.tag)\n"
+    ), "{}", helpers);
+}
+
+#[test]
+fn test_bindings_define_types_and_client() {
+    let mut spec = sac::stellar_asset_spec().unwrap();
+    spec.types = spec_with_types().types;
+    spec.errors.push(ErrorEnumSpec {
+        name: "Error".to_string(),
+        doc: None,
+        cases: vec![ErrorSpec { name: "NotAdmin".to_string(), doc: None, code: 3 }],
+    });
+
+    let bindings = render_python_bindings(&spec, "token");
+    assert!(bindings.contains("@dataclass\nclass Grant:\n    \"\"\"A token grant\"\"\"\n    owner: Address\n    hash: bytes\n    amount: int\n"), "{}", bindings);
+    // Struct keys are encoded in sorted order
+    assert!(bindings.contains("            \"amount\": scval.to_int128(self.amount),\n            \"hash\": scval.to_bytes(self.hash),\n            \"owner\": scval.to_address(self.owner),\n"));
+    assert!(bindings.contains("class Level(IntEnum):\n    Low = 1\n"));
+    assert!(bindings.contains("            return scval.to_vec([scval.to_symbol(\"Balance\"), scval.to_address(self.value[0])])\n"));
+    assert!(bindings.contains("    3: \"NotAdmin\",\n"));
+
+    assert!(bindings.contains("class ClientAsync(ContractClientAsync):"));
+    assert!(bindings.contains(
+        "    async def transfer(\n        self,\n        from_: Address,\n        to: Address,\n        amount: int,\n    ) -> AssembledTransactionAsync[None]:\n"
+    ), "{}", bindings);
+    assert!(bindings.contains("            parse_result_xdr_fn=lambda v: scval.from_int128(v),\n"));
+}
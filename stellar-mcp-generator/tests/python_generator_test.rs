@@ -56,7 +56,7 @@ fn test_server_template_has_sign_and_submit_tool() {
 
     assert!(server_content.contains("async def sign_and_submit"),
         "server.py should contain sign_and_submit function");
-    assert!(server_content.contains("@mcp.tool()"),
+    assert!(server_content.contains("@mcp.tool(\"sign-and-submit\")"),
         "sign_and_submit should be decorated as MCP tool");
     assert!(server_content.contains("from src.lib.utils import sign_transaction"),
         "Should import sign_transaction from lib");
@@ -69,7 +69,7 @@ fn test_contract_client_integrates_bindings() {
     let client_content = fs::read_to_string("templates/python/contract_client.py.hbs")
         .expect("Failed to read contract_client.py.hbs");
 
-    assert!(client_content.contains("from .bindings.bindings import ClientAsync"),
+    assert!(client_content.contains("from .bindings import ClientAsync"),
         "Should import ClientAsync from generated bindings");
     assert!(client_content.contains("self.client = GeneratedClient"),
         "Should initialize GeneratedClient");
@@ -117,3 +117,42 @@ fn test_pyproject_uses_package_name() {
     assert!(!pyproject_content.contains("name = \"{{contract_name}}-mcp-server\""),
         "Should not use contract_name directly (may contain spaces)");
 }
+
+#[test]
+fn test_server_template_has_event_tools() {
+    let server_content = fs::read_to_string("templates/python/server.py.hbs")
+        .expect("Failed to read server.py.hbs");
+
+    assert!(server_content.contains("@mcp.tool(\"{{../tool_prefix}}{{tool}}\", annotations={\"readOnlyHint\": True})"),
+        "Each contract event should get a get_<event>_events tool");
+    assert!(server_content.contains("@mcp.resource(\"{{{events_uri}}}\""),
+        "Event catalogue should be exposed as a resource");
+}
+
+#[test]
+fn test_contract_client_queries_events() {
+    let client_content = fs::read_to_string("templates/python/contract_client.py.hbs")
+        .expect("Failed to read contract_client.py.hbs");
+
+    assert!(client_content.contains("await server.get_events("),
+        "Event queries should use RPC getEvents");
+    assert!(client_content.contains("EventFilterType.CONTRACT"),
+        "Event queries should filter on the contract");
+    assert!(client_content.contains("scval.to_native(event.value)"),
+        "Event data should be decoded to native values");
+}
+
+#[test]
+fn test_server_template_checks_spec_drift() {
+    let server_content = fs::read_to_string("templates/python/server.py.hbs")
+        .expect("Failed to read server.py.hbs");
+    let info_content = fs::read_to_string("templates/python/lib/contract_info.py.hbs")
+        .expect("Failed to read contract_info.py.hbs");
+
+    assert!(server_content.contains("@mcp.tool(\"{{tool_prefix}}contract_info\", annotations={\"readOnlyHint\": True})"),
+        "A contract_info tool should report the drift check");
+    assert!(server_content.contains("    check_spec_drift()"),
+        "The server should check for contract upgrades at startup");
+    assert!(info_content.contains("SCV_LEDGER_KEY_CONTRACT_INSTANCE"),
+        "The deployed WASM hash should be read from the contract instance");
+}
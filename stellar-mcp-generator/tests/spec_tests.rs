@@ -6,18 +6,20 @@ use stellar_mcp_generator::spec::types::*;
 fn test_type_ref_to_typescript() {
     assert_eq!(TypeRef::Bool.to_typescript(), "boolean");
     assert_eq!(TypeRef::U32.to_typescript(), "number");
-    assert_eq!(TypeRef::I64.to_typescript(), "bigint");
+    assert_eq!(TypeRef::I64.to_typescript(), "string"); // Decimal string
     assert_eq!(TypeRef::String.to_typescript(), "string");
     assert_eq!(TypeRef::Address.to_typescript(), "string");
-    assert_eq!(TypeRef::Bytes.to_typescript(), "string"); // Hex or base64 encoded
+    assert_eq!(TypeRef::Bytes.to_typescript(), "string"); // Hex encoded
 }
 
 #[test]
 fn test_type_ref_to_zod() {
     assert_eq!(TypeRef::Bool.to_zod(), "z.boolean()");
-    assert_eq!(TypeRef::U32.to_zod(), "z.number()");
+    assert_eq!(TypeRef::U32.to_zod(), "z.number().int().min(0).max(4294967295)");
     assert_eq!(TypeRef::String.to_zod(), "z.string()");
-    assert_eq!(TypeRef::Address.to_zod(), "z.string()");
+    assert_eq!(TypeRef::Address.to_zod(), "z.string().regex(/^[GC][A-Z2-7]{55}$/).length(56)");
+    assert_eq!(TypeRef::U64.to_zod(), "z.string().regex(/^(0|[1-9][0-9]*)$/)");
+    assert_eq!(TypeRef::BytesN(32).to_zod(), "z.string().regex(/^[0-9a-fA-F]*$/).length(64)");
 }
 
 #[test]
@@ -40,8 +42,9 @@ fn test_map_type() {
         key: Box::new(TypeRef::String),
         value: Box::new(TypeRef::U64),
     };
-    assert_eq!(map_type.to_typescript(), "Map<string, bigint>");
-    assert_eq!(map_type.to_zod(), "z.map(z.string(), z.string())");
+    // Maps are arrays of [key, value] pairs in JSON
+    assert_eq!(map_type.to_typescript(), "[string, string][]");
+    assert_eq!(map_type.to_zod(), "z.array(z.tuple([z.string(), z.string().regex(/^(0|[1-9][0-9]*)$/)]))");
 }
 
 #[test]
@@ -51,3 +54,277 @@ fn test_contract_spec_default() {
     assert!(spec.types.is_empty());
     assert!(spec.errors.is_empty());
 }
+
+// ── Local WASM parsing ────────────────────────────────────────────────────────
+
+/// Build a minimal WASM module carrying only a `contractspecv0` custom section
+fn wasm_with_spec(entries: &[stellar_xdr::curr::ScSpecEntry]) -> Vec<u8> {
+    use stellar_xdr::curr::{Limits, WriteXdr};
+
+    let mut payload = Vec::new();
+    for entry in entries {
+        payload.extend(entry.to_xdr(Limits::none()).unwrap());
+    }
+
+    let name = b"contractspecv0";
+    let mut section = vec![name.len() as u8];
+    section.extend_from_slice(name);
+    section.extend(payload);
+
+    let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+    wasm.push(0); // custom section id
+    let mut size = section.len();
+    loop {
+        let byte = (size & 0x7f) as u8;
+        size >>= 7;
+        if size == 0 {
+            wasm.push(byte);
+            break;
+        }
+        wasm.push(byte | 0x80);
+    }
+    wasm.extend(section);
+    wasm
+}
+
+#[test]
+fn test_parse_wasm_file_reads_local_spec() {
+    use stellar_mcp_generator::spec::SpecParser;
+    use stellar_xdr::curr::{ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0, ScSpecTypeDef};
+
+    let func = ScSpecFunctionV0 {
+        doc: "Say hello".try_into().unwrap(),
+        name: "hello".try_into().unwrap(),
+        inputs: vec![ScSpecFunctionInputV0 {
+            doc: "".try_into().unwrap(),
+            name: "to".try_into().unwrap(),
+            type_: ScSpecTypeDef::Symbol,
+        }]
+        .try_into()
+        .unwrap(),
+        outputs: vec![ScSpecTypeDef::U32].try_into().unwrap(),
+    };
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("hello.wasm");
+    std::fs::write(&path, wasm_with_spec(&[ScSpecEntry::FunctionV0(func)])).unwrap();
+
+    let spec = SpecParser::parse_wasm_file(&path).expect("local WASM should parse");
+    assert_eq!(spec.functions.len(), 1);
+    assert_eq!(spec.functions[0].name, "hello");
+    assert_eq!(spec.functions[0].inputs[0].name, "to");
+    assert_eq!(spec.raw_spec_entries.len(), 1);
+}
+
+#[test]
+fn test_parse_wasm_file_missing_file() {
+    use stellar_mcp_generator::spec::SpecParser;
+
+    let err = SpecParser::parse_wasm_file(std::path::Path::new("does-not-exist.wasm"))
+        .expect_err("missing file should fail");
+    assert!(err.to_string().contains("does-not-exist.wasm"));
+}
+
+#[test]
+fn test_parse_wasm_extracts_events() {
+    use stellar_mcp_generator::spec::{EventDataFormat, SpecParser, TypeRef};
+    use stellar_xdr::curr::{
+        ScSpecEntry, ScSpecEventDataFormat, ScSpecEventParamLocationV0, ScSpecEventParamV0,
+        ScSpecEventV0, ScSpecTypeDef,
+    };
+
+    let param = |name: &str, type_: ScSpecTypeDef, location: ScSpecEventParamLocationV0| {
+        ScSpecEventParamV0 {
+            doc: "".try_into().unwrap(),
+            name: name.try_into().unwrap(),
+            type_,
+            location,
+        }
+    };
+    let event = ScSpecEventV0 {
+        doc: "Tokens moved between accounts".try_into().unwrap(),
+        lib: "".try_into().unwrap(),
+        name: "Transfer".try_into().unwrap(),
+        prefix_topics: vec!["transfer".try_into().unwrap()].try_into().unwrap(),
+        params: vec![
+            param("from", ScSpecTypeDef::Address, ScSpecEventParamLocationV0::TopicList),
+            param("to", ScSpecTypeDef::Address, ScSpecEventParamLocationV0::TopicList),
+            param("amount", ScSpecTypeDef::I128, ScSpecEventParamLocationV0::Data),
+        ]
+        .try_into()
+        .unwrap(),
+        data_format: ScSpecEventDataFormat::SingleValue,
+    };
+
+    let spec = SpecParser::parse_wasm(&wasm_with_spec(&[ScSpecEntry::EventV0(event)]))
+        .expect("event spec should parse");
+
+    assert_eq!(spec.events.len(), 1);
+    let event = &spec.events[0];
+    assert_eq!(event.name, "Transfer");
+    assert_eq!(event.doc.as_deref(), Some("Tokens moved between accounts"));
+    assert_eq!(event.prefix_topics, vec!["transfer".to_string()]);
+    assert_eq!(event.topics.len(), 2);
+    assert_eq!(event.topics[0].name, "from");
+    assert!(matches!(event.topics[1].type_ref, TypeRef::Address));
+    assert_eq!(event.data.len(), 1);
+    assert!(matches!(event.data[0].type_ref, TypeRef::I128));
+    assert_eq!(event.data_format, EventDataFormat::SingleValue);
+}
+
+#[test]
+fn test_parse_error_enums_keeps_grouping() {
+    use stellar_mcp_generator::spec::SpecParser;
+    use stellar_xdr::curr::{ScSpecEntry, ScSpecUdtErrorEnumCaseV0, ScSpecUdtErrorEnumV0};
+
+    let error_enum = |name: &str, cases: &[(&str, &str, u32)]| {
+        ScSpecEntry::UdtErrorEnumV0(ScSpecUdtErrorEnumV0 {
+            doc: format!("{} errors", name).try_into().unwrap(),
+            lib: "".try_into().unwrap(),
+            name: name.try_into().unwrap(),
+            cases: cases
+                .iter()
+                .map(|(name, doc, value)| ScSpecUdtErrorEnumCaseV0 {
+                    doc: (*doc).try_into().unwrap(),
+                    name: (*name).try_into().unwrap(),
+                    value: *value,
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        })
+    };
+
+    let spec = SpecParser::parse_entries(&[
+        error_enum("TokenError", &[("InsufficientBalance", "Balance is too low", 7), ("Paused", "", 8)]),
+        error_enum("AdminError", &[("NotAdmin", "", 1)]),
+    ])
+    .unwrap();
+
+    assert_eq!(spec.errors.len(), 2);
+    assert_eq!(spec.errors[0].name, "TokenError");
+    assert_eq!(spec.errors[0].doc.as_deref(), Some("TokenError errors"));
+    assert_eq!(spec.errors[0].cases[0].doc.as_deref(), Some("Balance is too low"));
+    assert_eq!(spec.errors[0].cases[1].doc, None);
+    let codes: Vec<(&str, u32)> = spec.error_cases().map(|e| (e.name.as_str(), e.code)).collect();
+    assert_eq!(codes, vec![("InsufficientBalance", 7), ("Paused", 8), ("NotAdmin", 1)]);
+}
+
+#[test]
+fn test_legacy_snapshot_errors_read_as_one_enum() {
+    let json = r#"{
+        "name": null,
+        "functions": [],
+        "types": [],
+        "errors": [
+            { "name": "InsufficientBalance", "doc": null, "code": 7 },
+            { "name": "Paused", "doc": null, "code": 8 }
+        ],
+        "events": [],
+        "raw_spec_entries": []
+    }"#;
+
+    let spec: ContractSpec = serde_json::from_str(json).unwrap();
+    assert_eq!(spec.errors.len(), 1);
+    assert_eq!(spec.errors[0].name, "Error");
+    assert_eq!(spec.error_cases().count(), 2);
+
+    // Current snapshots round-trip with their enums
+    let round_trip: ContractSpec = serde_json::from_str(&serde_json::to_string(&spec).unwrap()).unwrap();
+    assert_eq!(round_trip.errors.len(), 1);
+    assert_eq!(round_trip.errors[0].cases[1].name, "Paused");
+}
+
+// ── Stellar Asset Contract fallback ──────────────────────────────────────────
+
+#[test]
+fn test_stellar_asset_spec_has_sep41_and_admin_interface() {
+    use stellar_mcp_generator::spec::sac;
+
+    let spec = sac::stellar_asset_spec().expect("built-in SAC spec should parse");
+    let names: Vec<&str> = spec.functions.iter().map(|f| f.name.as_str()).collect();
+
+    for sep41 in ["allowance", "approve", "balance", "transfer", "transfer_from", "burn",
+        "burn_from", "decimals", "name", "symbol"] {
+        assert!(names.contains(&sep41), "SAC spec should include SEP-41 function {}", sep41);
+    }
+    for admin in ["admin", "set_admin", "authorized", "set_authorized", "mint", "clawback"] {
+        assert!(names.contains(&admin), "SAC spec should include admin function {}", admin);
+    }
+
+    let balance = spec.functions.iter().find(|f| f.name == "balance").unwrap();
+    assert_eq!(balance.inputs[0].name, "id");
+    assert!(matches!(balance.output, Some(TypeRef::I128)));
+
+    // Raw entries are kept so the spec round-trips like one read from WASM
+    assert_eq!(spec.raw_spec_entries.len(), spec.functions.len() + spec.events.len());
+    assert!(spec.events.iter().any(|e| e.name == "transfer" && e.topics.len() == 3));
+}
+
+// ── Read-only vs write classification ────────────────────────────────────────
+
+#[test]
+fn test_kind_from_footprint() {
+    use stellar_mcp_generator::spec::classifier::kind_from_footprint;
+
+    assert_eq!(kind_from_footprint(0, 0), FunctionKind::ReadOnly);
+    assert_eq!(kind_from_footprint(1, 0), FunctionKind::Write, "writes ledger entries");
+    assert_eq!(kind_from_footprint(0, 1), FunctionKind::Write, "requires authorization");
+}
+
+#[test]
+fn test_classification_overrides() {
+    use stellar_mcp_generator::spec::{classifier, sac};
+
+    let mut spec = sac::stellar_asset_spec().unwrap();
+    assert!(spec.functions.iter().all(|f| f.kind == FunctionKind::Write),
+        "functions should default to write until classified");
+
+    classifier::apply_overrides(&mut spec, &["balance".to_string(), "decimals".to_string()], &[])
+        .unwrap();
+    let kind = |name: &str| spec.functions.iter().find(|f| f.name == name).unwrap().kind;
+    assert_eq!(kind("balance"), FunctionKind::ReadOnly);
+    assert_eq!(kind("decimals"), FunctionKind::ReadOnly);
+    assert_eq!(kind("transfer"), FunctionKind::Write);
+
+    let unknown = classifier::apply_overrides(&mut spec, &["nope".to_string()], &[]);
+    assert!(unknown.is_err(), "unknown functions should be rejected");
+    let both = classifier::apply_overrides(&mut spec, &["mint".to_string()], &["mint".to_string()]);
+    assert!(both.is_err(), "a function cannot be both read-only and write");
+}
+
+#[test]
+fn test_placeholder_args_cover_function_inputs() {
+    use stellar_mcp_generator::spec::{classifier, sac};
+    use stellar_xdr::curr::ScVal;
+
+    let spec = sac::stellar_asset_spec().unwrap();
+    let approve = spec.functions.iter().find(|f| f.name == "approve").unwrap();
+    let args = classifier::placeholder_args(approve, &spec.types).expect("SEP-41 args are simple");
+    assert_eq!(args.len(), 4);
+    assert!(matches!(args[0], ScVal::Address(_)));
+    assert!(matches!(args[2], ScVal::I128(_)));
+    assert!(matches!(args[3], ScVal::U32(0)));
+
+    // Custom types are built from their definition
+    let types = vec![TypeSpec {
+        name: "Mode".to_string(),
+        doc: None,
+        definition: TypeDef::Enum {
+            variants: vec![EnumVariant { name: "Fast".to_string(), doc: None, value: 3 }],
+        },
+    }];
+    let func = FunctionSpec {
+        name: "set_mode".to_string(),
+        doc: None,
+        inputs: vec![ParameterSpec {
+            name: "mode".to_string(),
+            doc: None,
+            type_ref: TypeRef::Custom("Mode".to_string()),
+        }],
+        output: None,
+        kind: FunctionKind::Write,
+    };
+    assert!(matches!(classifier::placeholder_args(&func, &types).unwrap()[0], ScVal::U32(3)));
+    assert!(classifier::placeholder_args(&func, &[]).is_none(), "unknown types cannot be simulated");
+}
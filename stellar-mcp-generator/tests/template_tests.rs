@@ -1,8 +1,13 @@
 //! Unit tests for template helpers
 
+mod common;
+
 use stellar_mcp_generator::generator::{to_kebab_case, to_camel_case, to_pascal_case,
-    output_zod_for_type, output_schema_raw_shape};
-use stellar_mcp_generator::spec::TypeRef;
+    output_zod_for_type, output_schema_raw_shape, union_schema, ts_convert_expr, ts_type_converters,
+    TemplateRenderer};
+use serde_json::json;
+use stellar_mcp_generator::spec::{EnumVariant, TypeDef, TypeRef, TypeSpec, UnionCase};
+use common::field;
 
 #[test]
 fn test_to_kebab_case() {
@@ -35,7 +40,8 @@ fn test_to_pascal_case() {
 fn test_output_zod_for_type_address() {
     // Address → z.string() (no schemas. prefix needed for primitives)
     let result = output_zod_for_type(&TypeRef::Address);
-    assert_eq!(result, "z.string()");
+    assert_eq!(result, TypeRef::Address.to_zod());
+    assert!(result.starts_with("z.string()"), "{}", result);
 }
 
 #[test]
@@ -73,3 +79,218 @@ fn test_output_schema_raw_shape_address() {
     assert!(shape.contains("z.unknown()"), "simulationResult should use z.unknown(): {}", shape);
     assert!(shape.contains(".optional()"), "simulationResult should be optional: {}", shape);
 }
+
+// ── Union schemas ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_union_schema_types_tuple_payloads() {
+    let cases = vec![
+        UnionCase {
+            name: "Ed25519".to_string(),
+            doc: None,
+            type_ref: Some(TypeRef::Tuple(vec![TypeRef::BytesN(32)])),
+        },
+        UnionCase {
+            name: "Policy".to_string(),
+            doc: None,
+            type_ref: Some(TypeRef::Tuple(vec![TypeRef::Address, TypeRef::Custom("Limits".to_string())])),
+        },
+        UnionCase { name: "None".to_string(), doc: None, type_ref: None },
+    ];
+    let schema = union_schema("signer", &cases);
+
+    assert!(schema.starts_with("export const SignerSchema = z.discriminatedUnion('tag', ["),
+        "union should be discriminated on tag: {}", schema);
+    assert!(schema.contains("z.object({ tag: z.literal('Ed25519'), values: z.tuple([z.string().regex(/^[0-9a-fA-F]*$/).length(64)]) })"),
+        "tuple payload should be typed: {}", schema);
+    assert!(schema.contains("values: z.tuple([z.string().regex(/^[GC][A-Z2-7]{55}$/).length(56), LimitsSchema])"),
+        "custom types in payloads should reference their schema: {}", schema);
+    assert!(schema.contains("z.object({ tag: z.literal('None') })"),
+        "unit cases should only carry the tag: {}", schema);
+    assert!(!schema.contains("as any"), "union schema should not be cast to any: {}", schema);
+}
+
+#[test]
+fn test_generator_registers_event_tools() {
+    let source = std::fs::read_to_string("src/generator/mcp_generator.rs")
+        .expect("Failed to read mcp_generator.rs");
+    let tools = std::fs::read_to_string("templates/tools.ts.hbs")
+        .expect("Failed to read tools.ts.hbs");
+
+    assert!(source.contains("\"get-{}-events\""),
+        "Each contract event should get a get-<event>-events tool");
+    assert!(tools.contains("server.getEvents("),
+        "Event tools should be backed by RPC getEvents");
+    assert!(source.contains("\"contract://events\""),
+        "Event catalogue should be exposed as a resource");
+}
+
+fn sample_types() -> Vec<TypeSpec> {
+    vec![
+        TypeSpec {
+            name: "Grant".to_string(),
+            doc: None,
+            definition: TypeDef::Struct {
+                fields: vec![
+                    field("recipient", TypeRef::Address),
+                    field("units", TypeRef::I128),
+                    field("memo", TypeRef::Option(Box::new(TypeRef::Bytes))),
+                    field("kind", TypeRef::Custom("Kind".to_string())),
+                ],
+            },
+        },
+        TypeSpec {
+            name: "Kind".to_string(),
+            doc: None,
+            definition: TypeDef::Enum {
+                variants: vec![
+                    EnumVariant { name: "Fixed".to_string(), doc: None, value: 0 },
+                    EnumVariant { name: "Vesting".to_string(), doc: None, value: 1 },
+                ],
+            },
+        },
+        TypeSpec {
+            name: "Label".to_string(),
+            doc: None,
+            definition: TypeDef::Struct { fields: vec![field("text", TypeRef::String)] },
+        },
+        TypeSpec {
+            name: "Action".to_string(),
+            doc: None,
+            definition: TypeDef::Union {
+                cases: vec![
+                    UnionCase {
+                        name: "Pay".to_string(),
+                        doc: None,
+                        type_ref: Some(TypeRef::Tuple(vec![TypeRef::Custom("Grant".to_string())])),
+                    },
+                    UnionCase { name: "Stop".to_string(), doc: None, type_ref: None },
+                ],
+            },
+        },
+    ]
+}
+
+#[test]
+fn test_convert_expr_is_driven_by_types_not_names() {
+    let types = sample_types();
+
+    assert_eq!(ts_convert_expr(&TypeRef::U64, "params.x", &types).as_deref(), Some("BigInt(params.x)"));
+    assert_eq!(
+        ts_convert_expr(&TypeRef::BytesN(32), "params.x", &types).as_deref(),
+        Some("Buffer.from(params.x, 'hex')")
+    );
+    assert_eq!(
+        ts_convert_expr(&TypeRef::Option(Box::new(TypeRef::I128)), "params.x", &types).as_deref(),
+        Some("(params.x == null ? undefined : BigInt(params.x))")
+    );
+    assert_eq!(
+        ts_convert_expr(&TypeRef::Vec(Box::new(TypeRef::Custom("Grant".to_string()))), "params.x", &types).as_deref(),
+        Some("params.x.map((v0: any) => convertGrant(v0))")
+    );
+    assert_eq!(ts_convert_expr(&TypeRef::Address, "params.x", &types), None);
+    assert_eq!(ts_convert_expr(&TypeRef::U32, "params.x", &types), None);
+    assert_eq!(
+        ts_convert_expr(&TypeRef::Custom("Label".to_string()), "params.x", &types),
+        None,
+        "types without convertible fields should be passed through"
+    );
+}
+
+#[test]
+fn test_type_converters_walk_nested_types() {
+    let converters = ts_type_converters(&sample_types());
+
+    assert!(converters.contains("function convertGrant(v: any): any"), "{}", converters);
+    assert!(converters.contains("units: BigInt(v.units)"), "{}", converters);
+    assert!(converters.contains("memo: (v.memo == null ? undefined : Buffer.from(v.memo, 'hex'))"), "{}", converters);
+    assert!(converters.contains("kind: convertKind(v.kind)"), "{}", converters);
+    assert!(converters.contains("{ 'Fixed': 0, 'Vesting': 1 }"), "{}", converters);
+    assert!(converters.contains("case 'Pay':"), "{}", converters);
+    assert!(converters.contains("values: [convertGrant(v.values[0])]"), "{}", converters);
+    assert!(!converters.contains("convertLabel"), "{}", converters);
+}
+
+#[test]
+fn test_generator_annotates_read_only_and_write_tools() {
+    let index = std::fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
+    let tools = std::fs::read_to_string("templates/tools.ts.hbs")
+        .expect("Failed to read tools.ts.hbs");
+
+    assert!(index.contains("annotations: { readOnlyHint: true }"),
+        "Read-only functions should carry readOnlyHint");
+    assert!(index.contains("annotations: { readOnlyHint: false, destructiveHint: true }"),
+        "State-changing functions should carry destructiveHint");
+    assert!(tools.contains("return { result: assembled.result };"),
+        "Read-only tools should return the simulated result directly");
+}
+
+#[test]
+fn test_generator_checks_wasm_hash_drift() {
+    let source = std::fs::read_to_string("src/generator/mcp_generator.rs")
+        .expect("Failed to read mcp_generator.rs");
+    let index = std::fs::read_to_string("templates/index.ts.hbs")
+        .expect("Failed to read index.ts.hbs");
+    let contract_info = std::fs::read_to_string("templates/lib/contract-info.ts.hbs")
+        .expect("Failed to read lib/contract-info.ts.hbs");
+
+    assert!(contract_info.contains("xdr.ScVal.scvLedgerKeyContractInstance()"),
+        "The deployed WASM hash should be read from the contract instance");
+    assert!(index.contains("await checkSpecDrift();"),
+        "The server should check for contract upgrades at startup");
+    assert!(index.contains("process.env.ON_SPEC_DRIFT === 'refuse'"),
+        "ON_SPEC_DRIFT=refuse should stop the server on drift");
+    assert!(source.contains("\"contract-info\""),
+        "A contract-info tool should report the drift check");
+}
+
+#[test]
+fn test_template_overrides_replace_builtin_templates() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("lib")).unwrap();
+    std::fs::write(dir.path().join("README.md.hbs"), "# {{pascal_case project_name}}\n").unwrap();
+    std::fs::write(dir.path().join("lib/logger.ts.hbs"), "// {{upper_case name}} {{snake_case name}}\n").unwrap();
+
+    let renderer = TemplateRenderer::with_overrides(dir.path()).unwrap();
+    let readme = renderer.render("README.md", &json!({ "project_name": "token-mcp" })).unwrap();
+    assert_eq!(readme, "# TokenMcp\n");
+    let logger = renderer.render("lib/logger.ts", &json!({ "name": "tokenId" })).unwrap();
+    assert_eq!(logger, "// TOKENID token_id\n");
+
+    // Templates without an override keep the built-in content
+    let dockerfile = renderer.render("Dockerfile", &json!({})).unwrap();
+    assert!(dockerfile.contains("AS builder"));
+}
+
+#[test]
+fn test_template_overrides_reject_unknown_names() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("indx.ts.hbs"), "typo").unwrap();
+    let err = TemplateRenderer::with_overrides(dir.path()).err().unwrap().to_string();
+    assert!(err.contains("Unknown template"), "{}", err);
+    assert!(err.contains("index.ts"), "known templates should be listed: {}", err);
+
+    let missing = dir.path().join("missing");
+    let err = TemplateRenderer::with_overrides(&missing).err().unwrap().to_string();
+    assert!(err.contains("not found"), "{}", err);
+
+    std::fs::remove_file(dir.path().join("indx.ts.hbs")).unwrap();
+    std::fs::write(dir.path().join("index.ts.hbs"), "{{#if}}").unwrap();
+    let err = TemplateRenderer::with_overrides(dir.path()).err().unwrap().to_string();
+    assert!(err.contains("Invalid template"), "{}", err);
+}
+
+#[test]
+fn test_type_helpers_render_type_refs() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("schemas.ts.hbs"),
+        "{{ts_type type_ref}} | {{zod_type type_ref}} | {{pydantic_type type_ref}}",
+    )
+    .unwrap();
+    let renderer = TemplateRenderer::with_overrides(dir.path()).unwrap();
+    let type_ref = TypeRef::Vec(Box::new(TypeRef::U32));
+    let out = renderer.render("schemas.ts", &json!({ "type_ref": type_ref })).unwrap();
+    assert_eq!(out, format!("{} | {} | {}", type_ref.to_typescript(), type_ref.to_zod(), type_ref.to_pydantic()));
+}
@@ -0,0 +1,115 @@
+//! Tests for the native TypeScript contract bindings
+
+mod common;
+
+use stellar_mcp_generator::generator::ts_bindings::{binding_type, render_ts_bindings};
+use stellar_mcp_generator::spec::sac;
+use stellar_mcp_generator::spec::types::*;
+use stellar_mcp_generator::NetworkConfig;
+use common::field;
+
+fn testnet() -> NetworkConfig {
+    NetworkConfig::from_name("testnet").unwrap()
+}
+
+#[test]
+fn test_binding_types_use_native_values() {
+    assert_eq!(binding_type(&TypeRef::I128), "i128");
+    assert_eq!(binding_type(&TypeRef::BytesN(32)), "Buffer");
+    assert_eq!(binding_type(&TypeRef::Timepoint), "bigint");
+    assert_eq!(binding_type(&TypeRef::Option(Box::new(TypeRef::Address))), "Option<string>");
+    assert_eq!(
+        binding_type(&TypeRef::Vec(Box::new(TypeRef::Tuple(vec![TypeRef::Symbol, TypeRef::U32])))),
+        "Array<readonly [string, u32]>"
+    );
+}
+
+#[test]
+fn test_client_embeds_spec_entries() {
+    let spec = sac::stellar_asset_spec().unwrap();
+    assert!(!spec.raw_spec_entries.is_empty());
+
+    let bindings = render_ts_bindings(&spec, "CABC", &testnet());
+    assert!(bindings.contains("export class Client extends ContractClient {"));
+    assert!(bindings.contains("super(new ContractSpec(specEntries), options);"));
+    for entry in &spec.raw_spec_entries {
+        assert!(bindings.contains(&format!("  \"{}\",\n", entry)));
+    }
+    assert!(bindings.contains(
+        "  transfer: (\n    args: { from: string; to: string; amount: i128 },\n    options?: MethodOptions\n  ) => Promise<AssembledTransaction<null>>;\n"
+    ), "{}", bindings);
+    assert!(bindings.contains("    balance: this.txFromJSON<i128>,\n"));
+    assert!(bindings.contains("  testnet: {\n    networkPassphrase: \"Test SDF Network ; September 2015\",\n    contractId: \"CABC\",\n"));
+
+    // Without a contract ID (e.g. --wasm) there is no networks entry
+    assert!(!render_ts_bindings(&spec, "", &testnet()).contains("export const networks"));
+}
+
+#[test]
+fn test_custom_types_and_errors() {
+    let mut spec = ContractSpec::new();
+    spec.types.push(TypeSpec {
+        name: "Grant".to_string(),
+        doc: Some("A token grant".to_string()),
+        definition: TypeDef::Struct {
+            fields: vec![field("owner", TypeRef::Address), field("hash", TypeRef::BytesN(32))],
+        },
+    });
+    spec.types.push(TypeSpec {
+        name: "Pair".to_string(),
+        doc: None,
+        definition: TypeDef::Struct { fields: vec![field("0", TypeRef::U32), field("1", TypeRef::String)] },
+    });
+    spec.types.push(TypeSpec {
+        name: "Level".to_string(),
+        doc: None,
+        definition: TypeDef::Enum {
+            variants: vec![EnumVariant { name: "Low".to_string(), doc: None, value: 1 }],
+        },
+    });
+    spec.types.push(TypeSpec {
+        name: "DataKey".to_string(),
+        doc: None,
+        definition: TypeDef::Union {
+            cases: vec![
+                UnionCase { name: "Admin".to_string(), doc: None, type_ref: None },
+                UnionCase {
+                    name: "Balance".to_string(),
+                    doc: None,
+                    type_ref: Some(TypeRef::Tuple(vec![TypeRef::Address])),
+                },
+            ],
+        },
+    });
+    spec.errors.push(ErrorEnumSpec {
+        name: "Error".to_string(),
+        doc: None,
+        cases: vec![ErrorSpec { name: "NotAdmin".to_string(), doc: None, code: 3 }],
+    });
+
+    let bindings = render_ts_bindings(&spec, "", &testnet());
+    assert!(bindings.contains("/**\n * A token grant\n */\nexport interface Grant {\n  owner: string;\n  hash: Buffer;\n}\n"), "{}", bindings);
+    assert!(bindings.contains("export type Pair = readonly [u32, string];\n"));
+    assert!(bindings.contains("export enum Level {\n  Low = 1,\n}\n"));
+    assert!(bindings.contains(
+        "export type DataKey =\n  | { tag: \"Admin\"; values: void }\n  | { tag: \"Balance\"; values: readonly [string] };\n"
+    ), "{}", bindings);
+    assert!(bindings.contains("export const Errors = {\n  3: { message: \"NotAdmin\" },\n};\n"));
+}
+
+#[test]
+fn test_tools_import_native_bindings() {
+    let source = std::fs::read_to_string("src/generator/mcp_generator.rs")
+        .expect("Failed to read mcp_generator.rs");
+    let tools = std::fs::read_to_string("templates/tools.ts.hbs")
+        .expect("Failed to read tools.ts.hbs");
+    let package_json = std::fs::read_to_string("templates/package.json.hbs")
+        .expect("Failed to read package.json.hbs");
+
+    assert!(tools.contains("import { Client } from '../bindings/{{{bindings_module}}}.js';"),
+        "Tool handlers should import the generated bindings module directly");
+    assert!(!source.contains("build:bindings") && !package_json.contains("build:bindings"),
+        "The bindings no longer need a separate build step");
+    assert!(!source.contains("Command::new(\"stellar\")"),
+        "Generation should not shell out to the Stellar CLI");
+}
@@ -0,0 +1,179 @@
+//! Tests for regenerating projects in place (`generate --update`)
+
+use stellar_mcp_generator::generator::merge::{carry_custom_regions, merge3, unified_diff};
+use stellar_mcp_generator::generator::update::{
+    plan_project, write_project, FileAction, GenerationManifest, BASE_DIR,
+};
+use stellar_mcp_generator::generator::GeneratedProject;
+use std::fs;
+use std::path::Path;
+
+fn action<'a>(report: &'a [(String, FileAction)], path: &str) -> &'a FileAction {
+    &report.iter().find(|(p, _)| p == path).unwrap().1
+}
+
+fn write(dir: &Path, path: &str, content: &str) {
+    let path = dir.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+fn test_merge3_combines_non_overlapping_changes() {
+    let base = "a\nb\nc\nd\ne\n";
+    let ours = "a\nB\nc\nd\ne\n";
+    let theirs = "a\nb\nc\nd\nE\nf\n";
+
+    let merged = merge3(base, ours, theirs);
+    assert_eq!(merged.conflicts, 0);
+    assert_eq!(merged.content, "a\nB\nc\nd\nE\nf\n");
+}
+
+#[test]
+fn test_merge3_marks_overlapping_changes() {
+    let merged = merge3("a\nb\nc\n", "a\nyours\nc\n", "a\ngenerated\nc\n");
+    assert_eq!(merged.conflicts, 1);
+    assert_eq!(
+        merged.content,
+        "a\n<<<<<<< yours\nyours\n=======\ngenerated\n>>>>>>> generated\nc\n"
+    );
+
+    // The same change on both sides is not a conflict
+    let merged = merge3("a\nb\n", "a\nc\n", "a\nc\n");
+    assert_eq!(merged.conflicts, 0);
+    assert_eq!(merged.content, "a\nc\n");
+}
+
+#[test]
+fn test_custom_regions_survive_regeneration() {
+    let current = "x\n// stellar-mcp:custom-begin tools\nmy_tool();\n// stellar-mcp:custom-end tools\n";
+    let generated = "y\n// stellar-mcp:custom-begin tools\n// placeholder\n// stellar-mcp:custom-end tools\nz\n";
+
+    assert_eq!(
+        carry_custom_regions(current, generated),
+        "y\n// stellar-mcp:custom-begin tools\nmy_tool();\n// stellar-mcp:custom-end tools\nz\n"
+    );
+}
+
+#[test]
+fn test_generated_region_without_end_marker_keeps_the_rest_of_the_file() {
+    let current = "// stellar-mcp:custom-begin tools\nmy_tool();\n// stellar-mcp:custom-end tools\n";
+    let generated = "y\n// stellar-mcp:custom-begin tools\n// placeholder\nz\n";
+
+    assert_eq!(carry_custom_regions(current, generated), generated);
+}
+
+#[test]
+fn test_update_overwrites_owned_files_and_merges_edited_ones() {
+    let output = tempfile::tempdir().unwrap();
+    let mut first = GeneratedProject::new();
+    first.add("src/index.ts", "import a;\n\nconst port = 3000;\nstart();\n");
+    first.add("src/lib/logger.ts", "log v1\n");
+    first.add("src/old.ts", "old\n");
+    write_project(&first, output.path(), false).unwrap();
+
+    let manifest = GenerationManifest::load(output.path()).unwrap().unwrap();
+    assert_eq!(manifest.files.len(), 3);
+    assert!(output.path().join(BASE_DIR).join("src/index.ts").exists());
+
+    // The user edits index.ts, the next generation changes every file
+    write(output.path(), "src/index.ts", "import a;\n\nconst port = 8080;\nstart();\n");
+    let mut second = GeneratedProject::new();
+    second.add("src/index.ts", "import a;\nimport b;\n\nconst port = 3000;\nstart();\n");
+    second.add("src/lib/logger.ts", "log v2\n");
+    second.add("src/new.ts", "new\n");
+
+    let report = write_project(&second, output.path(), true).unwrap();
+    assert_eq!(action(&report.files, "src/index.ts"), &FileAction::Merged);
+    assert_eq!(action(&report.files, "src/lib/logger.ts"), &FileAction::Updated);
+    assert_eq!(action(&report.files, "src/new.ts"), &FileAction::Created);
+    assert_eq!(action(&report.files, "src/old.ts"), &FileAction::Removed);
+    assert!(report.conflicts().is_empty());
+
+    let index = fs::read_to_string(output.path().join("src/index.ts")).unwrap();
+    assert_eq!(index, "import a;\nimport b;\n\nconst port = 8080;\nstart();\n");
+    assert!(!output.path().join("src/old.ts").exists());
+}
+
+#[test]
+fn test_update_requires_manifest_and_reports_untracked_files() {
+    let output = tempfile::tempdir().unwrap();
+    let mut project = GeneratedProject::new();
+    project.add("server.py", "generated\n");
+    write(output.path(), "server.py", "hand written\n");
+
+    let err = write_project(&project, output.path(), true).unwrap_err();
+    assert!(err.to_string().contains("no generation manifest"), "{}", err);
+
+    // A file with no previous generation to merge against gets a sidecar
+    GenerationManifest::default().save(output.path()).unwrap();
+    let report = write_project(&project, output.path(), true).unwrap();
+    assert_eq!(
+        action(&report.files, "server.py"),
+        &FileAction::Sidecar("server.py.generated".to_string())
+    );
+    assert_eq!(report.conflicts(), vec!["server.py"]);
+    assert_eq!(fs::read_to_string(output.path().join("server.py")).unwrap(), "hand written\n");
+    assert_eq!(
+        fs::read_to_string(output.path().join("server.py.generated")).unwrap(),
+        "generated\n"
+    );
+}
+
+#[test]
+fn test_unified_diff() {
+    assert_eq!(unified_diff("a\nb\n", "a\nb\n", "a/f", "b/f"), "");
+
+    let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+    let new = "1\n2\nthree\n4\n5\n6\n7\n8\n9\n10\n11\n";
+    assert_eq!(
+        unified_diff(old, new, "a/f", "b/f"),
+        "--- a/f\n+++ b/f\n\
+         @@ -1,6 +1,6 @@\n 1\n 2\n-3\n+three\n 4\n 5\n 6\n\
+         @@ -8,3 +8,4 @@\n 8\n 9\n 10\n+11\n"
+    );
+
+    assert_eq!(
+        unified_diff("", "new", "/dev/null", "b/f"),
+        "--- /dev/null\n+++ b/f\n@@ -0,0 +1,1 @@\n+new\n\\ No newline at end of file\n"
+    );
+}
+
+#[test]
+fn test_plan_project_lists_changes_without_writing() {
+    let output = tempfile::tempdir().unwrap();
+    let mut first = GeneratedProject::new();
+    first.add("index.ts", "v1\n");
+    first.add("same.ts", "same\n");
+    first.add("old.ts", "old\n");
+    write_project(&first, output.path(), false).unwrap();
+    let manifest = GenerationManifest::load(output.path()).unwrap();
+
+    let mut second = GeneratedProject::new();
+    second.add("index.ts", "v2\n");
+    second.add("same.ts", "same\n");
+    second.add("new.ts", "new\n");
+
+    let plan = plan_project(&second, output.path(), true).unwrap();
+    let report = plan.report();
+    assert_eq!(action(&report.files, "index.ts"), &FileAction::Updated);
+    assert_eq!(action(&report.files, "same.ts"), &FileAction::Unchanged);
+    assert_eq!(action(&report.files, "new.ts"), &FileAction::Created);
+    assert_eq!(action(&report.files, "old.ts"), &FileAction::Removed);
+
+    let index = plan.files.iter().find(|f| f.path == "index.ts").unwrap();
+    assert_eq!(index.current.as_deref(), Some(&b"v1\n"[..]));
+    assert_eq!(index.content.as_deref(), Some(&b"v2\n"[..]));
+
+    // Nothing was touched
+    assert_eq!(fs::read_to_string(output.path().join("index.ts")).unwrap(), "v1\n");
+    assert!(output.path().join("old.ts").exists());
+    assert!(!output.path().join("new.ts").exists());
+    assert_eq!(GenerationManifest::load(output.path()).unwrap(), manifest);
+
+    // Planning into a directory that does not exist creates every file
+    let missing = output.path().join("missing");
+    let plan = plan_project(&second, &missing, false).unwrap();
+    assert!(plan.files.iter().all(|f| f.action == FileAction::Created));
+    assert!(!missing.exists());
+}
@@ -1,5 +1,6 @@
 //! Generate command - Creates policy smart contracts via interactive wizard
 
+use crate::error::Result;
 use crate::{generator, wizard};
 use clap::Parser;
 use console::style;
@@ -13,7 +14,7 @@ pub struct GenerateArgs {
 }
 
 /// Execute the generate command
-pub async fn execute(args: GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
+pub async fn execute(args: GenerateArgs) -> Result<()> {
     // Run the interactive wizard to collect policy configuration
     let config = wizard::run_wizard().await?;
 
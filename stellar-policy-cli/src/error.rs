@@ -0,0 +1,74 @@
+//! Error type of the policy library and the `stellar-policy` CLI
+
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Result alias used throughout the crate
+pub type Result<T, E = PolicyError> = std::result::Result<T, E>;
+
+/// Errors reported while collecting a policy configuration or generating its project
+#[derive(Debug, Error)]
+pub enum PolicyError {
+    /// The user left the wizard before finishing it
+    #[error("User cancelled wizard")]
+    Cancelled,
+
+    /// Reading input from the terminal failed
+    #[error("Prompt failed: {0}")]
+    Prompt(#[from] dialoguer::Error),
+
+    /// A `PolicyConfig` value is invalid (name, addresses, function names, limits)
+    #[error("Invalid {field}: {message}")]
+    InvalidConfig { field: &'static str, message: String },
+
+    /// An embedded template does not parse
+    #[error("Invalid template '{name}': {source}")]
+    Template {
+        name: &'static str,
+        #[source]
+        source: Box<handlebars::TemplateError>,
+    },
+
+    /// A template failed to render with the policy data
+    #[error("Failed to render template '{name}': {source}")]
+    Render {
+        name: String,
+        #[source]
+        source: Box<handlebars::RenderError>,
+    },
+
+    /// Creating a directory or writing a generated file failed
+    #[error("Failed to write '{}': {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl PolicyError {
+    /// Process exit code the CLI uses for this error
+    ///
+    /// | Code | Error |
+    /// | ---- | ----- |
+    /// | 2    | `InvalidConfig` |
+    /// | 3    | `Prompt` |
+    /// | 4    | `Template`, `Render` |
+    /// | 5    | `Io` |
+    /// | 130  | `Cancelled` |
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Io { .. } => 1,
+            Self::InvalidConfig { .. } => 2,
+            Self::Template { .. } | Self::Render { .. } => 7,
+            Self::Prompt(_) => 11,
+            Self::Cancelled => 130,
+        }
+    }
+
+    /// Build an `Io` error for `path`
+    pub(crate) fn io(path: impl Into<PathBuf>) -> impl FnOnce(std::io::Error) -> Self {
+        let path = path.into();
+        move |source| Self::Io { path, source }
+    }
+}
@@ -1,19 +1,39 @@
 //! Policy project generation
 
+mod project;
 pub mod project_builder;
 pub mod template_data;
 pub mod templates;
 
+use crate::error::Result;
 use crate::types::PolicyConfig;
 use template_data::TemplateData;
 
+pub use project::GeneratedProject;
+
+/// Render a complete policy project from configuration, without writing anything
+///
+/// Validates the PolicyConfig, converts it to TemplateData and renders all
+/// templates. Write the result with [`project_builder::write_project`].
+pub fn render_project(config: &PolicyConfig) -> Result<GeneratedProject> {
+    config.validate()?;
+
+    // Convert config to template data
+    let template_data = TemplateData::from_policy_config(config);
+
+    let mut project = GeneratedProject::new();
+    for (path, content) in templates::render_all_templates(&template_data)? {
+        project.add(path, content);
+    }
+
+    Ok(project)
+}
+
 /// Generate a complete policy project from configuration
 ///
 /// This is the main entry point for project generation. It:
-/// 1. Converts PolicyConfig to TemplateData
-/// 2. Creates the directory structure
-/// 3. Renders all templates
-/// 4. Writes all files to disk
+/// 1. Renders the project in memory with [`render_project`]
+/// 2. Writes all files to disk
 ///
 /// # Arguments
 /// * `config` - Policy configuration from the wizard
@@ -21,22 +41,12 @@ use template_data::TemplateData;
 ///
 /// # Returns
 /// * `Ok(())` if generation succeeds
-/// * `Err` if any step fails (directory creation, template rendering, file writing)
+/// * `Err` if the configuration is invalid or any step fails (directory
+///   creation, template rendering, file writing)
 pub fn generate_project(
     config: &PolicyConfig,
     output_dir: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    // Convert config to template data
-    let template_data = TemplateData::from_policy_config(config);
-
-    // Create directory structure
-    project_builder::create_directory_structure(output_dir, &config.name)?;
-
-    // Render all templates
-    let files = templates::render_all_templates(&template_data)?;
-
-    // Write all files
-    project_builder::write_all_files(output_dir, files)?;
-
-    Ok(())
+) -> Result<()> {
+    let project = render_project(config)?;
+    project_builder::write_project(output_dir, &project)
 }
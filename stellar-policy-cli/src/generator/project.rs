@@ -0,0 +1,37 @@
+//! In-memory generated policy projects
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Files of a generated policy project, keyed by path relative to the project root
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeneratedProject {
+    pub files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl GeneratedProject {
+    /// Create an empty project
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file, replacing any earlier content at the same path
+    pub fn add(&mut self, path: impl Into<PathBuf>, content: impl Into<Vec<u8>>) {
+        self.files.insert(path.into(), content.into());
+    }
+
+    /// Content of a file
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&[u8]> {
+        self.files.get(path.as_ref()).map(Vec::as_slice)
+    }
+
+    /// Content of a UTF-8 text file
+    pub fn get_str(&self, path: impl AsRef<Path>) -> Option<&str> {
+        self.get(path).and_then(|content| std::str::from_utf8(content).ok())
+    }
+
+    /// Paths of all files, in order
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+}
@@ -1,5 +1,7 @@
 //! Project scaffolding and file writing
 
+use super::GeneratedProject;
+use crate::error::{PolicyError, Result};
 use std::fs;
 use std::path::Path;
 
@@ -10,26 +12,28 @@ use std::os::unix::fs::PermissionsExt;
 pub fn create_directory_structure(
     output_dir: &str,
     policy_name: &str,
-) -> Result<(), std::io::Error> {
+) -> Result<()> {
     let base = Path::new(output_dir);
 
     // Create contracts directory structure
-    fs::create_dir_all(base.join("contracts").join(policy_name).join("src"))?;
+    let src_dir = base.join("contracts").join(policy_name).join("src");
+    fs::create_dir_all(&src_dir).map_err(PolicyError::io(&src_dir))?;
 
     // Create examples directory
-    fs::create_dir_all(base.join("examples"))?;
+    let examples_dir = base.join("examples");
+    fs::create_dir_all(&examples_dir).map_err(PolicyError::io(&examples_dir))?;
 
     Ok(())
 }
 
 /// Write a file with the given content
-pub fn write_file(path: &str, content: &str) -> Result<(), std::io::Error> {
+pub fn write_file(path: &str, content: &str) -> Result<()> {
     // Ensure parent directory exists
     if let Some(parent) = Path::new(path).parent() {
-        fs::create_dir_all(parent)?;
+        fs::create_dir_all(parent).map_err(PolicyError::io(parent))?;
     }
 
-    fs::write(path, content)?;
+    fs::write(path, content).map_err(PolicyError::io(path))?;
     Ok(())
 }
 
@@ -37,17 +41,29 @@ pub fn write_file(path: &str, content: &str) -> Result<(), std::io::Error> {
 pub fn write_all_files(
     output_dir: &str,
     files: Vec<(String, String)>,
-) -> Result<(), std::io::Error> {
+) -> Result<()> {
+    let mut project = GeneratedProject::new();
     for (relative_path, content) in files {
-        let full_path = Path::new(output_dir).join(&relative_path);
-        write_file(full_path.to_str().unwrap(), &content)?;
+        project.add(relative_path, content);
+    }
+    write_project(output_dir, &project)
+}
+
+/// Write a rendered project to the output directory, creating directories as needed
+pub fn write_project(output_dir: &str, project: &GeneratedProject) -> Result<()> {
+    for (relative_path, content) in &project.files {
+        let full_path = Path::new(output_dir).join(relative_path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent).map_err(PolicyError::io(parent))?;
+        }
+        fs::write(&full_path, content).map_err(PolicyError::io(&full_path))?;
 
         // Set executable permissions for shell scripts (Unix only)
         #[cfg(unix)]
-        if relative_path.ends_with(".sh") {
-            let mut perms = fs::metadata(&full_path)?.permissions();
+        if relative_path.extension().is_some_and(|ext| ext == "sh") {
+            let mut perms = fs::metadata(&full_path).map_err(PolicyError::io(&full_path))?.permissions();
             perms.set_mode(0o755); // rwxr-xr-x
-            fs::set_permissions(&full_path, perms)?;
+            fs::set_permissions(&full_path, perms).map_err(PolicyError::io(&full_path))?;
         }
     }
 
@@ -106,6 +122,26 @@ mod tests {
         assert_eq!(content, "nested content");
     }
 
+    #[test]
+    fn test_write_project_marks_scripts_executable() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut project = GeneratedProject::new();
+        project.add("README.md", "# Policy");
+        project.add("scripts/fix-bindings.sh", "#!/bin/sh\n");
+
+        write_project(temp_dir.path().to_str().unwrap(), &project).unwrap();
+
+        assert_eq!(fs::read_to_string(temp_dir.path().join("README.md")).unwrap(), "# Policy");
+        #[cfg(unix)]
+        {
+            let mode = fs::metadata(temp_dir.path().join("scripts/fix-bindings.sh"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o111, 0o111);
+        }
+    }
+
     #[test]
     fn test_write_all_files() {
         let temp_dir = TempDir::new().unwrap();
@@ -96,9 +96,9 @@ impl TemplateData {
             rate_limiting: config.rate_limiting.as_ref().map(|rl| RateLimitData {
                 min_ledgers: rl.min_ledgers,
             }),
-            function_whitelist_0: config.function_whitelist.as_ref().and_then(|v| v.get(0).cloned()),
-            contract_whitelist_0: config.contract_whitelist.as_ref().and_then(|v| v.get(0).cloned()),
-            recipient_whitelist_0: config.recipient_whitelist.as_ref().and_then(|v| v.get(0).cloned()),
+            function_whitelist_0: config.function_whitelist.as_ref().and_then(|v| v.first().cloned()),
+            contract_whitelist_0: config.contract_whitelist.as_ref().and_then(|v| v.first().cloned()),
+            recipient_whitelist_0: config.recipient_whitelist.as_ref().and_then(|v| v.first().cloned()),
         }
     }
 }
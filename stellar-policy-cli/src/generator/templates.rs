@@ -2,6 +2,7 @@
 
 use handlebars::Handlebars;
 use super::template_data::TemplateData;
+use crate::error::{PolicyError, Result};
 
 // Embedded templates using include_str!
 const CONTRACT_LIB_TEMPLATE: &str = include_str!("../../templates/contract/lib.rs.hbs");
@@ -20,24 +21,31 @@ const TSCONFIG_TEMPLATE: &str = include_str!("../../templates/integration/tsconf
 const FIX_BINDINGS_SCRIPT: &str = include_str!("../../templates/scripts/fix-bindings.sh");
 
 /// Load all templates and register with Handlebars
-pub fn load_templates() -> Result<Handlebars<'static>, Box<dyn std::error::Error>> {
+pub fn load_templates() -> Result<Handlebars<'static>> {
     let mut handlebars = Handlebars::new();
 
     // Register all templates
-    handlebars.register_template_string("contract_lib", CONTRACT_LIB_TEMPLATE)?;
-    handlebars.register_template_string("contract_types", CONTRACT_TYPES_TEMPLATE)?;
-    handlebars.register_template_string("contract_test", CONTRACT_TEST_TEMPLATE)?;
-    handlebars.register_template_string("workspace_cargo", WORKSPACE_CARGO_TEMPLATE)?;
-    handlebars.register_template_string("policy_cargo", POLICY_CARGO_TEMPLATE)?;
-    handlebars.register_template_string("rust_toolchain", RUST_TOOLCHAIN_TEMPLATE)?;
-    handlebars.register_template_string("makefile", MAKEFILE_TEMPLATE)?;
-    handlebars.register_template_string("readme", README_TEMPLATE)?;
-    handlebars.register_template_string("demo", DEMO_TEMPLATE)?;
-    handlebars.register_template_string("env_example", ENV_EXAMPLE_TEMPLATE)?;
-    handlebars.register_template_string("typescript_example", TYPESCRIPT_EXAMPLE_TEMPLATE)?;
-    handlebars.register_template_string("package_json", PACKAGE_JSON_TEMPLATE)?;
-    handlebars.register_template_string("tsconfig", TSCONFIG_TEMPLATE)?;
-    handlebars.register_template_string("fix_bindings_script", FIX_BINDINGS_SCRIPT)?;
+    let templates = [
+        ("contract_lib", CONTRACT_LIB_TEMPLATE),
+        ("contract_types", CONTRACT_TYPES_TEMPLATE),
+        ("contract_test", CONTRACT_TEST_TEMPLATE),
+        ("workspace_cargo", WORKSPACE_CARGO_TEMPLATE),
+        ("policy_cargo", POLICY_CARGO_TEMPLATE),
+        ("rust_toolchain", RUST_TOOLCHAIN_TEMPLATE),
+        ("makefile", MAKEFILE_TEMPLATE),
+        ("readme", README_TEMPLATE),
+        ("demo", DEMO_TEMPLATE),
+        ("env_example", ENV_EXAMPLE_TEMPLATE),
+        ("typescript_example", TYPESCRIPT_EXAMPLE_TEMPLATE),
+        ("package_json", PACKAGE_JSON_TEMPLATE),
+        ("tsconfig", TSCONFIG_TEMPLATE),
+        ("fix_bindings_script", FIX_BINDINGS_SCRIPT),
+    ];
+    for (name, template) in templates {
+        handlebars
+            .register_template_string(name, template)
+            .map_err(|e| PolicyError::Template { name, source: Box::new(e) })?;
+    }
 
     Ok(handlebars)
 }
@@ -47,15 +55,17 @@ pub fn render_template(
     handlebars: &Handlebars,
     name: &str,
     data: &TemplateData,
-) -> Result<String, Box<dyn std::error::Error>> {
-    let rendered = handlebars.render(name, data)?;
-    Ok(rendered)
+) -> Result<String> {
+    handlebars.render(name, data).map_err(|e| PolicyError::Render {
+        name: name.to_string(),
+        source: Box::new(e),
+    })
 }
 
 /// Render all templates and return a map of file paths to contents
 pub fn render_all_templates(
     data: &TemplateData,
-) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+) -> Result<Vec<(String, String)>> {
     let handlebars = load_templates()?;
     let mut files = Vec::new();
 
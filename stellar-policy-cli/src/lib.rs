@@ -6,11 +6,14 @@
 use clap::{Parser, Subcommand};
 
 pub mod commands;
+pub mod error;
 pub mod generator;
 pub mod types;
+pub mod validation;
 pub mod wizard;
 
 pub use commands::generate::GenerateArgs;
+pub use error::{PolicyError, Result};
 pub use types::PolicyConfig;
 
 /// Stellar Policy CLI - Generate policy smart contracts for smart wallets
@@ -36,7 +39,7 @@ pub enum Commands {
 }
 
 /// Run the CLI with the parsed arguments
-pub async fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+pub async fn run(cli: Cli) -> Result<()> {
     match cli.command {
         Commands::Generate(args) => {
             commands::generate::execute(args).await?;
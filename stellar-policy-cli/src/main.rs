@@ -7,6 +7,6 @@ async fn main() {
 
     if let Err(err) = run(cli).await {
         eprintln!("Error: {}", err);
-        std::process::exit(1);
+        std::process::exit(err.exit_code());
     }
 }
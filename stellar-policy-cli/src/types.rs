@@ -1,5 +1,7 @@
 //! Policy configuration types
 
+use crate::error::{PolicyError, Result};
+use crate::validation;
 use serde::{Deserialize, Serialize};
 
 /// Policy configuration for generated smart contracts
@@ -30,6 +32,33 @@ pub struct PolicyConfig {
     pub admin_managed: bool,
 }
 
+impl PolicyConfig {
+    /// Check the values the wizard validates, for configurations built in code
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |field| move |message| PolicyError::InvalidConfig { field, message };
+
+        validation::validate_policy_name(&self.name).map_err(invalid("policy name"))?;
+        for name in self.function_whitelist.iter().flatten() {
+            validation::validate_function_name(name).map_err(invalid("function name"))?;
+        }
+        let contracts = self.contract_whitelist.iter().flatten();
+        let token = self.amount_cap.as_ref().and_then(|cap| cap.token_contract.as_ref());
+        for addr in contracts.chain(token) {
+            validation::validate_contract_address(addr).map_err(invalid("contract address"))?;
+        }
+        for addr in self.recipient_whitelist.iter().flatten() {
+            validation::validate_account_address(addr).map_err(invalid("account address"))?;
+        }
+        if let Some(cap) = &self.amount_cap {
+            validation::validate_amount(&cap.max_amount.to_string()).map_err(invalid("amount cap"))?;
+        }
+        if let Some(rate) = &self.rate_limiting {
+            validation::validate_ledgers(&rate.min_ledgers.to_string()).map_err(invalid("rate limit"))?;
+        }
+        Ok(())
+    }
+}
+
 /// Amount cap configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AmountCapConfig {
@@ -84,4 +113,31 @@ mod tests {
         assert_eq!(config.description, None);
         assert_eq!(config.admin_managed, false);
     }
+
+    #[test]
+    fn test_policy_config_validate() {
+        let config = PolicyConfig {
+            name: "test-policy".to_string(),
+            function_whitelist: Some(vec!["transfer".to_string()]),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let bad_name = PolicyConfig {
+            name: "../Policy".to_string(),
+            ..config.clone()
+        };
+        let err = bad_name.validate().unwrap_err();
+        assert!(matches!(err, PolicyError::InvalidConfig { field: "policy name", .. }), "{}", err);
+        assert_eq!(err.exit_code(), 2);
+
+        let bad_recipient = PolicyConfig {
+            recipient_whitelist: Some(vec!["CAHLJEQUCNTV7JPAPCMLCBIHOX7FFB57DUARJ6XGTW27FPCVKKY7JM2A".to_string()]),
+            ..config
+        };
+        assert!(matches!(
+            bad_recipient.validate(),
+            Err(PolicyError::InvalidConfig { field: "account address", .. })
+        ));
+    }
 }
@@ -1,7 +1,9 @@
 //! Enhanced interactive wizard with colors, navigation, and better UX
 
+use crate::error::{PolicyError, Result};
 use crate::types::{AmountCapConfig, PolicyConfig, RateLimitConfig};
-use crate::wizard::{analyzer, questions};
+use crate::validation;
+use crate::wizard::analyzer;
 use console::{style, Emoji, Term};
 use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
 use std::fmt;
@@ -138,6 +140,12 @@ pub struct EnhancedWizard {
     theme: ColorfulTheme,
 }
 
+impl Default for EnhancedWizard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EnhancedWizard {
     pub fn new() -> Self {
         Self {
@@ -149,7 +157,7 @@ impl EnhancedWizard {
     }
 
     /// Run the wizard and return the final configuration
-    pub async fn run(mut self) -> Result<PolicyConfig, Box<dyn std::error::Error>> {
+    pub async fn run(mut self) -> Result<PolicyConfig> {
         loop {
             self.clear_screen()?;
             self.display_header()?;
@@ -157,7 +165,7 @@ impl EnhancedWizard {
             match self.current_step {
                 WizardStep::Welcome => {
                     if !self.step_welcome()? {
-                        return Err("User cancelled wizard".into());
+                        return Err(PolicyError::Cancelled);
                     }
                 }
                 WizardStep::PolicyName => {
@@ -231,12 +239,12 @@ impl EnhancedWizard {
         Ok(self.state.to_config())
     }
 
-    fn clear_screen(&self) -> Result<(), Box<dyn std::error::Error>> {
-        self.term.clear_screen()?;
+    fn clear_screen(&self) -> Result<()> {
+        self.term.clear_screen().map_err(dialoguer::Error::from)?;
         Ok(())
     }
 
-    fn display_header(&self) -> Result<(), Box<dyn std::error::Error>> {
+    fn display_header(&self) -> Result<()> {
         println!("{}", style("═".repeat(80)).cyan());
         println!(
             "{} {}",
@@ -270,7 +278,7 @@ impl EnhancedWizard {
         }
     }
 
-    fn step_welcome(&self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_welcome(&self) -> Result<bool> {
         println!("{}", style("Welcome to the Stellar Policy Generator!").bold().green());
         println!();
         println!("This wizard will guide you through creating a custom policy smart contract");
@@ -290,7 +298,7 @@ impl EnhancedWizard {
             .interact()?)
     }
 
-    fn step_policy_name(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_policy_name(&mut self) -> Result<bool> {
         self.display_section_header("Policy Name", "Choose a unique name for your policy contract");
 
         println!("{} {}", INFO, style("Requirements:").dim());
@@ -302,7 +310,7 @@ impl EnhancedWizard {
         let name: String = Input::with_theme(&self.theme)
             .with_prompt("Policy name")
             .validate_with(|input: &String| -> Result<(), String> {
-                questions::validate_policy_name(input)
+                validation::validate_policy_name(input)
             })
             .interact_text()?;
 
@@ -311,7 +319,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn step_description(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_description(&mut self) -> Result<bool> {
         self.display_section_header("Description", "Add an optional description for your policy");
 
         let has_description = Confirm::with_theme(&self.theme)
@@ -332,7 +340,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn step_function_whitelist(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_function_whitelist(&mut self) -> Result<bool> {
         self.display_section_header(
             "Function Whitelist",
             "Restrict which contract functions can be called"
@@ -356,7 +364,7 @@ impl EnhancedWizard {
                 .validate_with(|input: &String| -> Result<(), String> {
                     let functions: Vec<&str> = input.split(',').map(|s| s.trim()).collect();
                     for func in functions {
-                        questions::validate_function_name(func)?;
+                        validation::validate_function_name(func)?;
                     }
                     Ok(())
                 })
@@ -376,7 +384,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn step_contract_whitelist(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_contract_whitelist(&mut self) -> Result<bool> {
         self.display_section_header(
             "Contract Whitelist",
             "Restrict which contracts can be interacted with"
@@ -405,7 +413,7 @@ impl EnhancedWizard {
                 .validate_with(|input: &String| -> Result<(), String> {
                     let addresses: Vec<&str> = input.split(',').map(|s| s.trim()).collect();
                     for addr in addresses {
-                        questions::validate_contract_address(addr)?;
+                        validation::validate_contract_address(addr)?;
                     }
                     Ok(())
                 })
@@ -425,7 +433,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn step_recipient_whitelist(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_recipient_whitelist(&mut self) -> Result<bool> {
         self.display_section_header(
             "Recipient Whitelist",
             "Restrict destination addresses for transfers"
@@ -454,7 +462,7 @@ impl EnhancedWizard {
                 .validate_with(|input: &String| -> Result<(), String> {
                     let addresses: Vec<&str> = input.split(',').map(|s| s.trim()).collect();
                     for addr in addresses {
-                        questions::validate_account_address(addr)?;
+                        validation::validate_account_address(addr)?;
                     }
                     Ok(())
                 })
@@ -474,7 +482,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn step_amount_cap(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_amount_cap(&mut self) -> Result<bool> {
         self.display_section_header(
             "Amount Cap",
             "Set maximum transaction amounts"
@@ -496,7 +504,7 @@ impl EnhancedWizard {
             let amount: String = Input::with_theme(&self.theme)
                 .with_prompt("Maximum amount (in stroops, 1 XLM = 10,000,000 stroops)")
                 .validate_with(|input: &String| -> Result<(), String> {
-                    questions::validate_amount(input)
+                    validation::validate_amount(input)
                 })
                 .interact_text()?;
 
@@ -511,7 +519,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn step_rate_limiting(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_rate_limiting(&mut self) -> Result<bool> {
         self.display_section_header(
             "Rate Limiting",
             "Control transaction frequency"
@@ -534,7 +542,7 @@ impl EnhancedWizard {
             let ledgers: String = Input::with_theme(&self.theme)
                 .with_prompt("Minimum ledgers between transactions")
                 .validate_with(|input: &String| -> Result<(), String> {
-                    questions::validate_ledgers(input)
+                    validation::validate_ledgers(input)
                 })
                 .interact_text()?;
 
@@ -548,7 +556,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn step_analysis(&self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_analysis(&self) -> Result<bool> {
         self.display_section_header(
             "Policy Analysis",
             "Review your policy configuration"
@@ -564,7 +572,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn step_admin_decision(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn step_admin_decision(&mut self) -> Result<bool> {
         self.display_section_header(
             "Admin Management",
             "Configure administrative capabilities"
@@ -596,7 +604,7 @@ impl EnhancedWizard {
         self.prompt_continue_or_back()
     }
 
-    fn display_completion(&self) -> Result<(), Box<dyn std::error::Error>> {
+    fn display_completion(&self) -> Result<()> {
         println!();
         println!("{} {}", CHECK_MARK, style("Policy Configuration Complete!").bold().green());
         println!();
@@ -638,7 +646,7 @@ impl EnhancedWizard {
         println!();
     }
 
-    fn prompt_continue_or_back(&self) -> Result<bool, Box<dyn std::error::Error>> {
+    fn prompt_continue_or_back(&self) -> Result<bool> {
         if self.current_step.previous().is_none() {
             // Can't go back from first step
             return Ok(true);
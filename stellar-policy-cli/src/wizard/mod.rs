@@ -4,6 +4,7 @@ pub mod analyzer;
 pub mod questions;
 pub mod enhanced;
 
+use crate::error::Result;
 use crate::types::PolicyConfig;
 
 /// Run the interactive wizard to collect policy configuration
@@ -11,6 +12,6 @@ use crate::types::PolicyConfig;
 /// Uses the enhanced wizard with colors, progress tracking, and back navigation.
 ///
 /// Returns a complete PolicyConfig ready for generation
-pub async fn run_wizard() -> Result<PolicyConfig, Box<dyn std::error::Error>> {
+pub async fn run_wizard() -> Result<PolicyConfig> {
     enhanced::EnhancedWizard::new().run().await
 }
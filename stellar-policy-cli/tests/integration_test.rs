@@ -12,6 +12,34 @@ use std::process::Command;
 use std::path::PathBuf;
 use tempfile::TempDir;
 
+#[test]
+fn test_render_project_matches_generated_files() {
+    let config = PolicyConfig {
+        name: "render-policy".to_string(),
+        description: None,
+        function_whitelist: Some(vec!["transfer".to_string()]),
+        contract_whitelist: None,
+        recipient_whitelist: None,
+        amount_cap: None,
+        rate_limiting: None,
+        admin_managed: false,
+    };
+
+    // Rendering touches nothing on disk
+    let project = generator::render_project(&config).unwrap();
+    assert!(project
+        .get_str("contracts/render-policy/src/lib.rs")
+        .unwrap()
+        .contains("pub fn policy__"));
+    assert!(project.get("contracts/render-policy/src/types.rs").is_none());
+
+    let temp_dir = TempDir::new().unwrap();
+    generator::generate_project(&config, temp_dir.path().to_str().unwrap()).unwrap();
+    for (path, content) in &project.files {
+        assert_eq!(&fs::read(temp_dir.path().join(path)).unwrap(), content, "{}", path.display());
+    }
+}
+
 #[test]
 fn test_generate_simple_policy_end_to_end() {
     // Create a simple policy with only function whitelist